@@ -0,0 +1,9 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate rjvm;
+
+// Any input must come back as a class or an error, never a panic or abort
+fuzz_target!(|data: &[u8]| {
+    let _ = rjvm::read_class(data);
+});
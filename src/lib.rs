@@ -11,6 +11,7 @@ unused_mut,
 extern crate glob;
 extern crate os_type;
 extern crate zip;
+extern crate libc;
 #[macro_use]
 extern crate lazy_static;
 use std::path::Path;
@@ -23,9 +24,17 @@ use std::io::BufReader;
 
 pub use reader::runner::Runtime;
 pub use reader::runner::Variable;
-pub use reader::runner::make_string;
+pub use reader::runner::RunnerError;
+pub use reader::runner::run_main;
+pub use reader::runner::{Clock, HostClock, VirtualClock};
+pub use reader::runner::{make_string, make_string_utf16};
+pub use reader::class_reader::{ClassResult, ClassReadError, decode_modified_utf8, encode_modified_utf8};
+pub use reader::class_reader::{AttributeItem, FieldItem, InnerClassItem, ElementValue, Annotation, TypeAnnotationTarget, TypeAnnotation, MethodParameter, BootstrapMethod};
+pub use reader::handles::ObjectHandle;
+pub use reader::handles::ArrayHandle;
+pub use reader::handles::{make_int_array, extract_int_array, extract_string, extract_string_utf16, make_hash_map, extract_hash_map};
 
-fn get_rt_jar() -> Vec<zip::ZipArchive<File>> {
+fn get_rt_jar() -> (String, Vec<zip::ZipArchive<File>>) {
     let rt_path : String = match os_type::current_platform().os_type {
         os_type::OSType::OSX => {
             let jdk_path = Command::new("/usr/libexec/java_home").arg("-v").arg("1.8").output().expect("Failed to determine JDK location");
@@ -52,27 +61,52 @@ fn get_rt_jar() -> Vec<zip::ZipArchive<File>> {
         panic!("Couldn't load rt zip {:?}", maybe_zip.unwrap_err());
     }
 
-    return vec!(maybe_zip.unwrap());
+    return (rt_path, vec!(maybe_zip.unwrap()));
+}
+
+// Configured before System is initialised so the properties it builds see java.home
+fn make_runtime(class_paths: &Vec<String>, initialise: bool) -> Runtime {
+    let (rt_path, jars) = get_rt_jar();
+    let mut runtime = reader::runner::get_runtime(class_paths, jars, false);
+    // rt.jar is in the lib directory of java.home
+    let java_home = Path::new(&rt_path).parent().and_then(|x| x.parent()).map(|x| x.to_string_lossy().into_owned());
+    runtime.set_property("java.home", java_home.unwrap_or(String::new()).as_str());
+    if initialise {
+        runtime.initialise().expect("Failed to initialize system");
+    }
+    return runtime;
 }
 
 fn read(filename: &Path) -> reader::class_reader::ClassResult {
     let reader = File::open(filename).expect(format!("Could not open {}", filename.display()).as_str());
-    let mut buf_reader = BufReader::new(reader);
+    let mut buf_reader = reader::class_reader::OffsetReader::new(BufReader::new(reader));
     let mut class_result = reader::class_reader::read_stage_1(&mut buf_reader).expect("Couldn't read headers of class file");
     reader::class_reader::read_stage_2(&mut buf_reader, &mut class_result).expect("Couldn't read rest of class file");
     return class_result;
 }
 
+// Parses a class file held in memory, reporting malformed input as an error
+pub fn read_class(data: &[u8]) -> Result<ClassResult, ClassReadError> {
+    let mut buf_reader = reader::class_reader::OffsetReader::new(data);
+    let mut class_result = try!(reader::class_reader::read_stage_1(&mut buf_reader));
+    try!(reader::class_reader::read_stage_2(&mut buf_reader, &mut class_result));
+    return Ok(class_result);
+}
 
 pub fn get_runtime(class_paths: &Vec<String>) -> reader::runner::Runtime {
-    return reader::runner::get_runtime(class_paths, get_rt_jar(), true);
+    return make_runtime(class_paths, true);
 }
 
 pub fn get_runtime_bypass_initialisation(class_paths: &Vec<String>) -> Runtime {
-    return reader::runner::get_runtime(class_paths, get_rt_jar(), false);
+    return make_runtime(class_paths, false);
 }
 
 pub fn run_method(runtime: &mut reader::runner::Runtime, filename: &Path, method: &str, arguments: &Vec<reader::runner::Variable>, return_descriptor: &str) -> reader::runner::Variable {
+    return run(runtime, filename, method, arguments, return_descriptor).unwrap();
+}
+
+// Like run_method, but uncaught exceptions and System.exit come back as RunnerError::Exception and RunnerError::ExitCode
+pub fn run(runtime: &mut Runtime, filename: &Path, method: &str, arguments: &Vec<Variable>, return_descriptor: &str) -> Result<Variable, RunnerError> {
     let class_result = read(filename);
-    return reader::runner::run_method(runtime, &class_result, method, arguments, String::from(return_descriptor)).unwrap();
+    return reader::runner::run_method(runtime, &class_result, method, arguments, String::from(return_descriptor));
 }
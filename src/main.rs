@@ -0,0 +1,63 @@
+extern crate rjvm;
+
+use rjvm::{ObjectHandle, RunnerError, Runtime, Variable};
+use std::env;
+use std::process;
+
+const USAGE: &'static str = "Usage: rjvm [-cp <class path>] <main class> [arguments...]";
+
+fn describe_exception(runtime: &mut Runtime, exception: &Variable) -> String {
+    let handle = match ObjectHandle::from_variable(exception) {
+        Some(handle) => handle,
+        None => return format!("{}", exception),
+    };
+    let name = handle.class_name().replace('/', ".");
+    return match handle.get_field(runtime, "detailMessage") {
+        Ok(ref message) if !message.is_null() => format!("{}: {}", name, message.extract_string()),
+        _ => name,
+    };
+}
+
+// The process exit status for how the program finished, running the shutdown hooks if System.exit didn't
+fn finish(runtime: &mut Runtime, result: Result<(), RunnerError>) -> i32 {
+    let status = match result {
+        Ok(()) => 0,
+        Err(RunnerError::ExitCode(status)) => return status,
+        Err(RunnerError::Exception(exception)) => {
+            eprintln!("Exception in thread \"main\" {}", describe_exception(runtime, &exception));
+            1
+        }
+        Err(error) => {
+            eprintln!("Error: {:?}", error);
+            return 1;
+        }
+    };
+    return match runtime.shutdown() {
+        Ok(()) => status,
+        Err(RunnerError::ExitCode(status)) => status,
+        Err(error) => {
+            eprintln!("Error during shutdown: {:?}", error);
+            1
+        }
+    };
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let mut class_paths = vec!(String::from("."));
+    if args.len() >= 2 && (args[0] == "-cp" || args[0] == "-classpath") {
+        class_paths = args[1].split(':').map(String::from).collect();
+        args.drain(0..2);
+    }
+    if args.is_empty() || args[0].starts_with('-') {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+    let main_class = args.remove(0).replace('.', "/");
+
+    let mut runtime = rjvm::get_runtime_bypass_initialisation(&class_paths);
+    runtime.set_stdin_host();
+    let result = runtime.initialise().and_then(|_| rjvm::run_main(&mut runtime, main_class.as_str(), &args));
+    let status = finish(&mut runtime, result);
+    process::exit(status);
+}
@@ -0,0 +1,288 @@
+extern crate byteorder;
+
+use reader::class_format::*;
+use reader::class_reader::*;
+use std::io::Read;
+
+use self::byteorder::{BigEndian, ReadBytesExt};
+
+// Typed forms of the standard attributes beyond Code (JVMS 4.7.6 to 4.7.24). Indices are left as constant pool
+// indices, already checked to point at the right kind of constant.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InnerClassItem {
+    pub inner_class_info_index: u16,
+    pub outer_class_info_index: u16,
+    pub inner_name_index: u16,
+    pub inner_class_access_flags: u16
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ElementValue {
+    Const{tag: u8, index: u16},
+    Enum{type_name_index: u16, const_name_index: u16},
+    Class{index: u16},
+    Annotation(Annotation),
+    Array(Vec<ElementValue>)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Annotation {
+    pub type_index: u16,
+    pub elements: Vec<(u16, ElementValue)>
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeAnnotationTarget {
+    TypeParameter(u8),
+    Supertype(u16),
+    TypeParameterBound(u8, u8),
+    Empty,
+    FormalParameter(u8),
+    Throws(u16),
+    LocalVariable(Vec<(u16, u16, u16)>),
+    Catch(u16),
+    Offset(u16),
+    TypeArgument(u16, u8)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeAnnotation {
+    pub target_type: u8,
+    pub target: TypeAnnotationTarget,
+    pub type_path: Vec<(u8, u8)>,
+    pub annotation: Annotation
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodParameter {
+    pub name_index: u16,
+    pub access_flags: u16
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BootstrapMethod {
+    pub method_ref: u16,
+    pub arguments: Vec<u16>
+}
+
+// Nesting is bounded so crafted input can't exhaust the stack
+const MAX_ELEMENT_DEPTH: usize = 256;
+
+fn optional_utf8(cp: &ConstantPool, index: u16, offset: usize, what: &str) -> Result<(), ClassReadError> {
+    if index != 0 {
+        try!(utf8_at(cp, index, offset, what));
+    }
+    return Ok(());
+}
+
+fn read_element_value<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>, depth: usize) -> Result<ElementValue, ClassReadError> {
+    let offset = reader.offset;
+    if depth > MAX_ELEMENT_DEPTH {
+        return format_error(offset, String::from("Annotation element values nested too deeply"));
+    }
+    let tag = try!(reader.read_u8());
+    return match tag {
+        b'B' | b'C' | b'D' | b'F' | b'I' | b'J' | b'S' | b'Z' | b's' => {
+            let index = try!(reader.read_u16::<BigEndian>());
+            let valid = match (tag, cp.pool.get(&index)) {
+                (b'D', Some(&ConstantPoolItem::CONSTANT_Double{..})) => true,
+                (b'F', Some(&ConstantPoolItem::CONSTANT_Float{..})) => true,
+                (b'J', Some(&ConstantPoolItem::CONSTANT_Long{..})) => true,
+                (b's', Some(&ConstantPoolItem::CONSTANT_Utf8(..))) => true,
+                (b'B', Some(&ConstantPoolItem::CONSTANT_Integer{..})) | (b'C', Some(&ConstantPoolItem::CONSTANT_Integer{..})) |
+                (b'I', Some(&ConstantPoolItem::CONSTANT_Integer{..})) | (b'S', Some(&ConstantPoolItem::CONSTANT_Integer{..})) |
+                (b'Z', Some(&ConstantPoolItem::CONSTANT_Integer{..})) => true,
+                _ => false,
+            };
+            if !valid {
+                return format_error(offset, format!("Element value of type {} can't use constant {}", tag as char, index));
+            }
+            Ok(ElementValue::Const{tag: tag, index: index})
+        }
+        b'e' => {
+            let type_name_index = try!(reader.read_u16::<BigEndian>());
+            let const_name_index = try!(reader.read_u16::<BigEndian>());
+            try!(utf8_at(cp, type_name_index, offset, "Enum type"));
+            try!(utf8_at(cp, const_name_index, offset, "Enum constant"));
+            Ok(ElementValue::Enum{type_name_index: type_name_index, const_name_index: const_name_index})
+        }
+        b'c' => {
+            let index = try!(reader.read_u16::<BigEndian>());
+            try!(utf8_at(cp, index, offset, "Class element"));
+            Ok(ElementValue::Class{index: index})
+        }
+        b'@' => Ok(ElementValue::Annotation(try!(read_annotation(cp, reader, depth + 1)))),
+        b'[' => {
+            let count = try!(reader.read_u16::<BigEndian>());
+            let mut values = Vec::new();
+            for _ in 0..count {
+                values.push(try!(read_element_value(cp, reader, depth + 1)));
+            }
+            Ok(ElementValue::Array(values))
+        }
+        _ => format_error(offset, format!("Unknown element value tag {}", tag)),
+    };
+}
+
+fn read_annotation<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>, depth: usize) -> Result<Annotation, ClassReadError> {
+    let offset = reader.offset;
+    let type_index = try!(reader.read_u16::<BigEndian>());
+    let type_name = try!(utf8_at(cp, type_index, offset, "Annotation type"));
+    if !is_field_descriptor(type_name.as_str()) {
+        return format_error(offset, format!("Invalid annotation type '{}'", type_name));
+    }
+    let count = try!(reader.read_u16::<BigEndian>());
+    let mut elements = Vec::new();
+    for _ in 0..count {
+        let name_index = try!(reader.read_u16::<BigEndian>());
+        try!(utf8_at(cp, name_index, reader.offset - 2, "Element name"));
+        elements.push((name_index, try!(read_element_value(cp, reader, depth))));
+    }
+    return Ok(Annotation { type_index: type_index, elements: elements });
+}
+
+pub fn read_annotations<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>) -> Result<Vec<Annotation>, ClassReadError> {
+    let count = try!(reader.read_u16::<BigEndian>());
+    let mut annotations = Vec::new();
+    for _ in 0..count {
+        annotations.push(try!(read_annotation(cp, reader, 0)));
+    }
+    return Ok(annotations);
+}
+
+pub fn read_parameter_annotations<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>) -> Result<Vec<Vec<Annotation>>, ClassReadError> {
+    let count = try!(reader.read_u8());
+    let mut parameters = Vec::new();
+    for _ in 0..count {
+        parameters.push(try!(read_annotations(cp, reader)));
+    }
+    return Ok(parameters);
+}
+
+fn read_type_annotation<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>) -> Result<TypeAnnotation, ClassReadError> {
+    let offset = reader.offset;
+    let target_type = try!(reader.read_u8());
+    let target = match target_type {
+        0x00 | 0x01 => TypeAnnotationTarget::TypeParameter(try!(reader.read_u8())),
+        0x10 => TypeAnnotationTarget::Supertype(try!(reader.read_u16::<BigEndian>())),
+        0x11 | 0x12 => TypeAnnotationTarget::TypeParameterBound(try!(reader.read_u8()), try!(reader.read_u8())),
+        0x13...0x15 => TypeAnnotationTarget::Empty,
+        0x16 => TypeAnnotationTarget::FormalParameter(try!(reader.read_u8())),
+        0x17 => TypeAnnotationTarget::Throws(try!(reader.read_u16::<BigEndian>())),
+        0x40 | 0x41 => {
+            let count = try!(reader.read_u16::<BigEndian>());
+            let mut table = Vec::new();
+            for _ in 0..count {
+                table.push((try!(reader.read_u16::<BigEndian>()), try!(reader.read_u16::<BigEndian>()), try!(reader.read_u16::<BigEndian>())));
+            }
+            TypeAnnotationTarget::LocalVariable(table)
+        }
+        0x42 => TypeAnnotationTarget::Catch(try!(reader.read_u16::<BigEndian>())),
+        0x43...0x46 => TypeAnnotationTarget::Offset(try!(reader.read_u16::<BigEndian>())),
+        0x47...0x4B => TypeAnnotationTarget::TypeArgument(try!(reader.read_u16::<BigEndian>()), try!(reader.read_u8())),
+        _ => return format_error(offset, format!("Unknown type annotation target {:#x}", target_type)),
+    };
+    let path_length = try!(reader.read_u8());
+    let mut type_path = Vec::new();
+    for _ in 0..path_length {
+        type_path.push((try!(reader.read_u8()), try!(reader.read_u8())));
+    }
+    let annotation = try!(read_annotation(cp, reader, 0));
+    return Ok(TypeAnnotation { target_type: target_type, target: target, type_path: type_path, annotation: annotation });
+}
+
+pub fn read_type_annotations<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>) -> Result<Vec<TypeAnnotation>, ClassReadError> {
+    let count = try!(reader.read_u16::<BigEndian>());
+    let mut annotations = Vec::new();
+    for _ in 0..count {
+        annotations.push(try!(read_type_annotation(cp, reader)));
+    }
+    return Ok(annotations);
+}
+
+pub fn read_element_default<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>) -> Result<ElementValue, ClassReadError> {
+    return read_element_value(cp, reader, 0);
+}
+
+pub fn read_inner_classes<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>) -> Result<Vec<InnerClassItem>, ClassReadError> {
+    let count = try!(reader.read_u16::<BigEndian>());
+    let mut classes = Vec::new();
+    for _ in 0..count {
+        let offset = reader.offset;
+        let item = InnerClassItem {
+            inner_class_info_index: try!(reader.read_u16::<BigEndian>()),
+            outer_class_info_index: try!(reader.read_u16::<BigEndian>()),
+            inner_name_index: try!(reader.read_u16::<BigEndian>()),
+            inner_class_access_flags: try!(reader.read_u16::<BigEndian>())
+        };
+        try!(class_at(cp, item.inner_class_info_index, offset, "Inner class"));
+        if item.outer_class_info_index != 0 {
+            try!(class_at(cp, item.outer_class_info_index, offset, "Outer class"));
+        }
+        try!(optional_utf8(cp, item.inner_name_index, offset, "Inner class name"));
+        classes.push(item);
+    }
+    return Ok(classes);
+}
+
+pub fn read_method_parameters<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>) -> Result<Vec<MethodParameter>, ClassReadError> {
+    let count = try!(reader.read_u8());
+    let mut parameters = Vec::new();
+    for _ in 0..count {
+        let offset = reader.offset;
+        let parameter = MethodParameter {
+            name_index: try!(reader.read_u16::<BigEndian>()),
+            access_flags: try!(reader.read_u16::<BigEndian>())
+        };
+        try!(optional_utf8(cp, parameter.name_index, offset, "Parameter name"));
+        parameters.push(parameter);
+    }
+    return Ok(parameters);
+}
+
+pub fn read_bootstrap_methods<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>) -> Result<Vec<BootstrapMethod>, ClassReadError> {
+    let count = try!(reader.read_u16::<BigEndian>());
+    let mut methods = Vec::new();
+    for _ in 0..count {
+        let offset = reader.offset;
+        let method_ref = try!(reader.read_u16::<BigEndian>());
+        match cp.pool.get(&method_ref) {
+            Some(&ConstantPoolItem::CONSTANT_MethodHandle{..}) => {}
+            _ => return format_error(offset, format!("Bootstrap method index {} is not a MethodHandle constant", method_ref)),
+        }
+        let argument_count = try!(reader.read_u16::<BigEndian>());
+        let mut arguments = Vec::new();
+        for _ in 0..argument_count {
+            let index = try!(reader.read_u16::<BigEndian>());
+            match cp.pool.get(&index) {
+                Some(&ConstantPoolItem::CONSTANT_Integer{..}) | Some(&ConstantPoolItem::CONSTANT_Float{..}) |
+                Some(&ConstantPoolItem::CONSTANT_Long{..}) | Some(&ConstantPoolItem::CONSTANT_Double{..}) |
+                Some(&ConstantPoolItem::CONSTANT_Class{..}) | Some(&ConstantPoolItem::CONSTANT_String{..}) |
+                Some(&ConstantPoolItem::CONSTANT_MethodHandle{..}) | Some(&ConstantPoolItem::CONSTANT_MethodType{..}) => {}
+                _ => return format_error(offset, format!("Bootstrap argument {} is not a loadable constant", index)),
+            }
+            arguments.push(index);
+        }
+        methods.push(BootstrapMethod { method_ref: method_ref, arguments: arguments });
+    }
+    return Ok(methods);
+}
+
+// Lookups shared by the class and member accessors
+
+pub fn annotations_in(attributes: &Vec<AttributeItem>, visible: bool) -> &[Annotation] {
+    return attributes.iter().filter_map(|x| match (x, visible) {
+        (&AttributeItem::RuntimeVisibleAnnotations{annotations: Some(ref annotations), ..}, true) => Some(&annotations[..]),
+        (&AttributeItem::RuntimeInvisibleAnnotations{annotations: Some(ref annotations), ..}, false) => Some(&annotations[..]),
+        _ => None
+    }).next().unwrap_or(&[]);
+}
+
+pub fn type_annotations_in(attributes: &Vec<AttributeItem>, visible: bool) -> &[TypeAnnotation] {
+    return attributes.iter().filter_map(|x| match (x, visible) {
+        (&AttributeItem::RuntimeVisibleTypeAnnotations{annotations: Some(ref annotations), ..}, true) => Some(&annotations[..]),
+        (&AttributeItem::RuntimeInvisibleTypeAnnotations{annotations: Some(ref annotations), ..}, false) => Some(&annotations[..]),
+        _ => None
+    }).next().unwrap_or(&[]);
+}
@@ -0,0 +1,517 @@
+use reader::jvm::construction::*;
+use reader::runner::*;
+use reader::builtins::*;
+use libc;
+use std::ffi::CString;
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::i32;
+use std::io;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Component, Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+// Flags shared with the Java side of UnixFileSystem and RandomAccessFile
+const BA_EXISTS: i32 = 0x01;
+const BA_REGULAR: i32 = 0x02;
+const BA_DIRECTORY: i32 = 0x04;
+const ACCESS_EXECUTE: i32 = 0x01;
+const ACCESS_WRITE: i32 = 0x02;
+const ACCESS_READ: i32 = 0x04;
+const O_RDWR: i32 = 2;
+
+// The reason HotSpot would give, "No such file or directory" rather than Rust's "... (os error 2)"
+fn io_reason(error: &io::Error) -> String {
+    let message = error.to_string();
+    return match message.find(" (os error") {
+        Some(end) => String::from(&message[..end]),
+        None => message,
+    };
+}
+
+fn io_exception<T>(runtime: &mut Runtime, message: &str) -> Result<T, RunnerError> {
+    return Err(RunnerError::Exception(try!(construct_exception(runtime, "java/io/IOException", message))));
+}
+
+fn file_not_found(runtime: &mut Runtime, path: &str, reason: &str) -> Result<(), RunnerError> {
+    let message = format!("{} ({})", path, reason);
+    return Err(RunnerError::Exception(try!(construct_exception(runtime, "java/io/FileNotFoundException", message.as_str()))));
+}
+
+fn stream_descriptor(runtime: &mut Runtime, stream: &Variable, class_name: &str) -> Result<Option<Rc<Object>>, RunnerError> {
+    return Ok(try!(get_field(runtime, &stream.to_ref(), class_name, "fd")).to_ref());
+}
+
+fn stream_fd(runtime: &mut Runtime, stream: &Variable, class_name: &str) -> Result<i32, RunnerError> {
+    let descriptor = try!(stream_descriptor(runtime, stream, class_name));
+    return Ok(try!(get_field(runtime, &descriptor, "java/io/FileDescriptor", "fd")).to_int());
+}
+
+fn set_stream_fd(runtime: &mut Runtime, stream: &Variable, class_name: &str, fd: i32) -> Result<(), RunnerError> {
+    let descriptor = try!(stream_descriptor(runtime, stream, class_name));
+    return put_field_specific_class_name(runtime, &descriptor, "java/io/FileDescriptor", "fd", Variable::Int(fd));
+}
+
+// Runs an operation on an open file, turning a closed descriptor or a failure into an IOException
+fn with_file<T, F>(runtime: &mut Runtime, fd: i32, operation: F) -> Result<T, RunnerError>
+    where F: FnOnce(&mut File) -> io::Result<T>
+{
+    let result = match runtime.files.get(fd) {
+        Some(file) => operation(file),
+        None => return io_exception(runtime, "Stream Closed"),
+    };
+    return match result {
+        Ok(value) => Ok(value),
+        Err(error) => io_exception(runtime, io_reason(&error).as_str()),
+    };
+}
+
+fn open_stream(runtime: &mut Runtime, stream: &Variable, class_name: &str, path: &str, options: &OpenOptions) -> Result<(), RunnerError> {
+    let host_path = match runtime.files.resolve(path) {
+        Some(host_path) => host_path,
+        None => return file_not_found(runtime, path, "Permission denied"),
+    };
+    if host_path.is_dir() {
+        return file_not_found(runtime, path, "Is a directory");
+    }
+    let file = match options.open(&host_path) {
+        Ok(file) => file,
+        Err(error) => return file_not_found(runtime, path, io_reason(&error).as_str()),
+    };
+    let fd = runtime.files.open(file);
+    return set_stream_fd(runtime, stream, class_name, fd);
+}
+
+fn close_stream(runtime: &mut Runtime, stream: &Variable, class_name: &str) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, stream, class_name));
+    if fd == -1 {
+        return Ok(());
+    }
+    runtime.files.close(fd);
+    return set_stream_fd(runtime, stream, class_name, -1);
+}
+
+// The array a readBytes or writeBytes works on, after the checks the JDK's native code makes
+fn checked_bytes(runtime: &mut Runtime, bytes: &Variable, offset: i32, length: i32) -> Result<Rc<ArrayObject>, RunnerError> {
+    let array = bytes.to_arrayobj();
+    if array.is_null {
+        let exception = try!(construct_object(runtime, &"java/lang/NullPointerException"));
+        return Err(RunnerError::Exception(exception));
+    }
+    let array_length = array.elements.borrow().len() as i64;
+    if offset < 0 || length < 0 || offset as i64 + length as i64 > array_length {
+        let exception = try!(construct_object(runtime, &"java/lang/IndexOutOfBoundsException"));
+        return Err(RunnerError::Exception(exception));
+    }
+    return Ok(array);
+}
+
+// Standard input comes from the runtime's source, everything else from the host file
+fn read_fd(runtime: &mut Runtime, fd: i32, buffer: &mut [u8]) -> Result<usize, RunnerError> {
+    if fd != 0 {
+        return with_file(runtime, fd, |file| file.read(buffer));
+    }
+    return match runtime.stdin.read(buffer) {
+        Ok(count) => Ok(count),
+        Err(error) => io_exception(runtime, io_reason(&error).as_str()),
+    };
+}
+
+fn read_byte(runtime: &mut Runtime, fd: i32) -> Result<(), RunnerError> {
+    let mut buffer = [0u8; 1];
+    let count = try!(read_fd(runtime, fd, &mut buffer));
+    runtime.push_on_stack(Variable::Int(if count == 0 {-1} else {buffer[0] as i32}));
+    return Ok(());
+}
+
+// Fills part of a byte array, -1 at the end of the file
+fn read_bytes(runtime: &mut Runtime, fd: i32, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let offset = args[2].to_int();
+    let length = args[3].to_int();
+    let array = try!(checked_bytes(runtime, &args[1], offset, length));
+    if length == 0 {
+        runtime.push_on_stack(Variable::Int(0));
+        return Ok(());
+    }
+
+    let mut buffer = vec![0u8; length as usize];
+    let count = try!(read_fd(runtime, fd, buffer.as_mut_slice()));
+    let mut elements = array.elements.borrow_mut();
+    for n in 0..count {
+        elements[offset as usize + n] = Variable::Byte(buffer[n] as i8);
+    }
+    runtime.push_on_stack(Variable::Int(if count == 0 {-1} else {count as i32}));
+    return Ok(());
+}
+
+// The standard streams go to the runtime's sinks, everything else to the host file. FileOutputStream has no
+// flush of its own, Java code flushes by writing out its buffers, so the sinks are flushed after every write
+fn write_fd(runtime: &mut Runtime, fd: i32, data: &[u8]) -> Result<(), RunnerError> {
+    let result = match fd {
+        1 => runtime.stdout.write_all(data).and_then(|_| runtime.stdout.flush()),
+        2 => runtime.stderr.write_all(data).and_then(|_| runtime.stderr.flush()),
+        _ => return with_file(runtime, fd, |file| file.write_all(data)),
+    };
+    return match result {
+        Ok(()) => Ok(()),
+        Err(error) => io_exception(runtime, io_reason(&error).as_str()),
+    };
+}
+
+fn write_bytes(runtime: &mut Runtime, fd: i32, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let offset = args[2].to_int();
+    let length = args[3].to_int();
+    let array = try!(checked_bytes(runtime, &args[1], offset, length));
+    let data: Vec<u8> = array.elements.borrow()[offset as usize..(offset + length) as usize].iter().map(|x| x.to_byte() as u8).collect();
+    return write_fd(runtime, fd, data.as_slice());
+}
+
+fn file_input_stream_open0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = args[1].extract_string();
+    runnerPrint!(runtime, true, 2, "BUILTIN: FileInputStream.open0 {}", path);
+    return open_stream(runtime, &args[0], "java/io/FileInputStream", path.as_str(), OpenOptions::new().read(true));
+}
+
+fn file_input_stream_read0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/FileInputStream"));
+    return read_byte(runtime, fd);
+}
+
+fn file_input_stream_read_bytes(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/FileInputStream"));
+    return read_bytes(runtime, fd, args);
+}
+
+fn file_input_stream_skip(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/FileInputStream"));
+    let count = args[1].to_long();
+    if fd == 0 {
+        return skip_stdin(runtime, count);
+    }
+    let skipped = try!(with_file(runtime, fd, |file| {
+        let start = try!(file.seek(SeekFrom::Current(0)));
+        let end = try!(file.seek(SeekFrom::Current(count)));
+        Ok(end as i64 - start as i64)
+    }));
+    runtime.push_on_stack(Variable::Long(skipped));
+    return Ok(());
+}
+
+// Standard input can't seek, so skipped bytes are read and thrown away
+fn skip_stdin(runtime: &mut Runtime, count: i64) -> Result<(), RunnerError> {
+    let mut skipped = 0;
+    let mut buffer = [0u8; 512];
+    while skipped < count {
+        let wanted = if count - skipped < buffer.len() as i64 {(count - skipped) as usize} else {buffer.len()};
+        let read = try!(read_fd(runtime, 0, &mut buffer[..wanted]));
+        if read == 0 {
+            break;
+        }
+        skipped += read as i64;
+    }
+    runtime.push_on_stack(Variable::Long(skipped));
+    return Ok(());
+}
+
+fn file_input_stream_available0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/FileInputStream"));
+    // A plain Read can't say how much is waiting
+    if fd == 0 {
+        runtime.push_on_stack(Variable::Int(0));
+        return Ok(());
+    }
+    let remaining = try!(with_file(runtime, fd, |file| {
+        let position = try!(file.seek(SeekFrom::Current(0)));
+        Ok(try!(file.metadata()).len().saturating_sub(position))
+    }));
+    runtime.push_on_stack(Variable::Int(if remaining > i32::MAX as u64 {i32::MAX} else {remaining as i32}));
+    return Ok(());
+}
+
+fn file_input_stream_close0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return close_stream(runtime, &args[0], "java/io/FileInputStream");
+}
+
+fn file_output_stream_open0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = args[1].extract_string();
+    let append = args[2].to_bool();
+    runnerPrint!(runtime, true, 2, "BUILTIN: FileOutputStream.open0 {} {}", path, append);
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).append(append).truncate(!append);
+    return open_stream(runtime, &args[0], "java/io/FileOutputStream", path.as_str(), &options);
+}
+
+fn file_output_stream_write(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/FileOutputStream"));
+    return write_fd(runtime, fd, &[args[1].to_int() as u8]);
+}
+
+fn file_output_stream_write_bytes(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/FileOutputStream"));
+    return write_bytes(runtime, fd, args);
+}
+
+fn file_output_stream_close0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return close_stream(runtime, &args[0], "java/io/FileOutputStream");
+}
+
+fn random_access_file_open0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = args[1].extract_string();
+    let mode = args[2].to_int();
+    runnerPrint!(runtime, true, 2, "BUILTIN: RandomAccessFile.open0 {} {}", path, mode);
+    let mut options = OpenOptions::new();
+    options.read(true);
+    if mode & O_RDWR != 0 {
+        options.write(true).create(true);
+    }
+    return open_stream(runtime, &args[0], "java/io/RandomAccessFile", path.as_str(), &options);
+}
+
+fn random_access_file_read0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/RandomAccessFile"));
+    return read_byte(runtime, fd);
+}
+
+fn random_access_file_read_bytes(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/RandomAccessFile"));
+    return read_bytes(runtime, fd, args);
+}
+
+fn random_access_file_write0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/RandomAccessFile"));
+    return write_fd(runtime, fd, &[args[1].to_int() as u8]);
+}
+
+fn random_access_file_write_bytes(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/RandomAccessFile"));
+    return write_bytes(runtime, fd, args);
+}
+
+fn random_access_file_get_file_pointer(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/RandomAccessFile"));
+    let position = try!(with_file(runtime, fd, |file| file.seek(SeekFrom::Current(0))));
+    runtime.push_on_stack(Variable::Long(position as i64));
+    return Ok(());
+}
+
+fn random_access_file_seek0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/RandomAccessFile"));
+    let position = args[1].to_long();
+    if position < 0 {
+        return io_exception(runtime, "Negative seek offset");
+    }
+    try!(with_file(runtime, fd, |file| file.seek(SeekFrom::Start(position as u64))));
+    return Ok(());
+}
+
+fn random_access_file_length(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/RandomAccessFile"));
+    let length = try!(with_file(runtime, fd, |file| file.metadata().map(|x| x.len())));
+    runtime.push_on_stack(Variable::Long(length as i64));
+    return Ok(());
+}
+
+// Like the JDK, a file pointer past the new end is moved back to it
+fn random_access_file_set_length(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let fd = try!(stream_fd(runtime, &args[0], "java/io/RandomAccessFile"));
+    let length = args[1].to_long();
+    if length < 0 {
+        return io_exception(runtime, "Invalid argument");
+    }
+    try!(with_file(runtime, fd, |file| {
+        let position = try!(file.seek(SeekFrom::Current(0)));
+        try!(file.set_len(length as u64));
+        if position > length as u64 {
+            try!(file.seek(SeekFrom::Start(length as u64)));
+        }
+        Ok(())
+    }));
+    return Ok(());
+}
+
+fn random_access_file_close0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return close_stream(runtime, &args[0], "java/io/RandomAccessFile");
+}
+
+// None when the path is outside the file root
+fn host_path(runtime: &mut Runtime, file: &Variable) -> Result<Option<PathBuf>, RunnerError> {
+    let path = try!(get_field(runtime, &file.to_ref(), "java/io/File", "path")).extract_string();
+    return Ok(runtime.files.resolve(path.as_str()));
+}
+
+fn unix_file_system_get_boolean_attributes0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = try!(host_path(runtime, &args[1]));
+    let attributes = match path.and_then(|x| fs::metadata(x).ok()) {
+        Some(metadata) => BA_EXISTS | if metadata.is_dir() {BA_DIRECTORY} else if metadata.is_file() {BA_REGULAR} else {0},
+        None => 0,
+    };
+    runtime.push_on_stack(Variable::Int(attributes));
+    return Ok(());
+}
+
+fn unix_file_system_check_access(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = try!(host_path(runtime, &args[1]));
+    let mode = match args[2].to_int() {
+        ACCESS_READ => libc::R_OK,
+        ACCESS_WRITE => libc::W_OK,
+        ACCESS_EXECUTE => libc::X_OK,
+        _ => libc::F_OK,
+    };
+    // access(2) weighs the owner, group and other bits against who we run as
+    let allowed = match path.and_then(|x| CString::new(x.as_os_str().as_bytes()).ok()) {
+        Some(c_path) => unsafe { libc::access(c_path.as_ptr(), mode) == 0 },
+        None => false,
+    };
+    runtime.push_on_stack(Variable::Boolean(allowed));
+    return Ok(());
+}
+
+fn unix_file_system_get_last_modified_time(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = try!(host_path(runtime, &args[1]));
+    let modified = path.and_then(|x| fs::metadata(x).ok())
+        .and_then(|x| x.modified().ok())
+        .and_then(|x| x.duration_since(UNIX_EPOCH).ok())
+        .map(|x| x.as_secs() as i64 * 1000 + x.subsec_nanos() as i64 / 1000000);
+    runtime.push_on_stack(Variable::Long(modified.unwrap_or(0)));
+    return Ok(());
+}
+
+fn unix_file_system_get_length(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = try!(host_path(runtime, &args[1]));
+    let length = path.and_then(|x| fs::metadata(x).ok()).map(|x| x.len() as i64);
+    runtime.push_on_stack(Variable::Long(length.unwrap_or(0)));
+    return Ok(());
+}
+
+// False when the file already exists, an IOException for any other failure
+fn unix_file_system_create_file_exclusively(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = args[1].extract_string();
+    let host_path = match runtime.files.resolve(path.as_str()) {
+        Some(host_path) => host_path,
+        None => return io_exception(runtime, "Permission denied"),
+    };
+    let created = match OpenOptions::new().write(true).create_new(true).open(host_path) {
+        Ok(_) => true,
+        Err(ref error) if error.kind() == io::ErrorKind::AlreadyExists => false,
+        Err(error) => return io_exception(runtime, io_reason(&error).as_str()),
+    };
+    runtime.push_on_stack(Variable::Boolean(created));
+    return Ok(());
+}
+
+fn unix_file_system_delete0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = try!(host_path(runtime, &args[1]));
+    let deleted = match path {
+        Some(ref path) if path.is_dir() => fs::remove_dir(path).is_ok(),
+        Some(ref path) => fs::remove_file(path).is_ok(),
+        None => false,
+    };
+    runtime.push_on_stack(Variable::Boolean(deleted));
+    return Ok(());
+}
+
+// The names in a directory, or a null array when it can't be read
+fn unix_file_system_list(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = try!(host_path(runtime, &args[1]));
+    let names: Option<Vec<String>> = path.and_then(|x| fs::read_dir(x).ok()).map(|entries| {
+        entries.filter_map(|x| x.ok()).map(|x| x.file_name().to_string_lossy().into_owned()).collect()
+    });
+    let ret = match names {
+        Some(names) => {
+            let mut strings = Vec::new();
+            for name in names {
+                strings.push(try!(make_string(runtime, name.as_str())));
+            }
+            try!(construct_array_by_name(runtime, "java/lang/String", Some(strings)))
+        }
+        None => try!(construct_array_by_name(runtime, "java/lang/String", None)),
+    };
+    runtime.push_on_stack(ret);
+    return Ok(());
+}
+
+fn unix_file_system_create_directory(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = try!(host_path(runtime, &args[1]));
+    let created = path.map(|x| fs::create_dir(x).is_ok()).unwrap_or(false);
+    runtime.push_on_stack(Variable::Boolean(created));
+    return Ok(());
+}
+
+fn unix_file_system_rename0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let from = try!(host_path(runtime, &args[1]));
+    let to = try!(host_path(runtime, &args[2]));
+    let renamed = match (from, to) {
+        (Some(from), Some(to)) => fs::rename(from, to).is_ok(),
+        _ => false,
+    };
+    runtime.push_on_stack(Variable::Boolean(renamed));
+    return Ok(());
+}
+
+// The host's canonical path when there is no file root. Under one, and for paths that don't exist, . and .. are
+// removed from the Java path without looking at the host, so nothing outside the root shows through
+fn unix_file_system_canonicalize0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let path = args[1].extract_string();
+    if runtime.files.root.is_none() {
+        if let Ok(real) = fs::canonicalize(path.as_str()) {
+            let ret = try!(make_string(runtime, real.to_string_lossy().as_ref()));
+            runtime.push_on_stack(ret);
+            return Ok(());
+        }
+    }
+    let mut canonical = PathBuf::new();
+    for component in Path::new(path.as_str()).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if canonical.parent().is_some() {
+                    canonical.pop();
+                }
+            }
+            other => canonical.push(other.as_os_str()),
+        }
+    }
+    let ret = try!(make_string(runtime, canonical.to_string_lossy().as_ref()));
+    runtime.push_on_stack(ret);
+    return Ok(());
+}
+
+pub fn register(m: &mut NativeTable) {
+    m.insert(("java/io/FileDescriptor", "initIDs", "()V"), nop);
+    m.insert(("java/io/FileInputStream", "initIDs", "()V"), nop);
+    m.insert(("java/io/FileInputStream", "open0", "(Ljava/lang/String;)V"), file_input_stream_open0);
+    m.insert(("java/io/FileInputStream", "read0", "()I"), file_input_stream_read0);
+    m.insert(("java/io/FileInputStream", "readBytes", "([BII)I"), file_input_stream_read_bytes);
+    m.insert(("java/io/FileInputStream", "skip", "(J)J"), file_input_stream_skip);
+    m.insert(("java/io/FileInputStream", "available0", "()I"), file_input_stream_available0);
+    m.insert(("java/io/FileInputStream", "close0", "()V"), file_input_stream_close0);
+    m.insert(("java/io/FileOutputStream", "initIDs", "()V"), nop);
+    m.insert(("java/io/FileOutputStream", "open0", "(Ljava/lang/String;Z)V"), file_output_stream_open0);
+    m.insert(("java/io/FileOutputStream", "write", "(IZ)V"), file_output_stream_write);
+    m.insert(("java/io/FileOutputStream", "writeBytes", "([BIIZ)V"), file_output_stream_write_bytes);
+    m.insert(("java/io/FileOutputStream", "close0", "()V"), file_output_stream_close0);
+    m.insert(("java/io/RandomAccessFile", "initIDs", "()V"), nop);
+    m.insert(("java/io/RandomAccessFile", "open0", "(Ljava/lang/String;I)V"), random_access_file_open0);
+    m.insert(("java/io/RandomAccessFile", "read0", "()I"), random_access_file_read0);
+    m.insert(("java/io/RandomAccessFile", "readBytes", "([BII)I"), random_access_file_read_bytes);
+    m.insert(("java/io/RandomAccessFile", "write0", "(I)V"), random_access_file_write0);
+    m.insert(("java/io/RandomAccessFile", "writeBytes", "([BII)V"), random_access_file_write_bytes);
+    m.insert(("java/io/RandomAccessFile", "getFilePointer", "()J"), random_access_file_get_file_pointer);
+    m.insert(("java/io/RandomAccessFile", "seek0", "(J)V"), random_access_file_seek0);
+    m.insert(("java/io/RandomAccessFile", "length", "()J"), random_access_file_length);
+    m.insert(("java/io/RandomAccessFile", "setLength", "(J)V"), random_access_file_set_length);
+    m.insert(("java/io/RandomAccessFile", "close0", "()V"), random_access_file_close0);
+    m.insert(("java/io/UnixFileSystem", "initIDs", "()V"), nop);
+    m.insert(("java/io/UnixFileSystem", "canonicalize0", "(Ljava/lang/String;)Ljava/lang/String;"), unix_file_system_canonicalize0);
+    m.insert(("java/io/UnixFileSystem", "getBooleanAttributes0", "(Ljava/io/File;)I"), unix_file_system_get_boolean_attributes0);
+    m.insert(("java/io/UnixFileSystem", "checkAccess", "(Ljava/io/File;I)Z"), unix_file_system_check_access);
+    m.insert(("java/io/UnixFileSystem", "getLastModifiedTime", "(Ljava/io/File;)J"), unix_file_system_get_last_modified_time);
+    m.insert(("java/io/UnixFileSystem", "getLength", "(Ljava/io/File;)J"), unix_file_system_get_length);
+    m.insert(("java/io/UnixFileSystem", "createFileExclusively", "(Ljava/lang/String;)Z"), unix_file_system_create_file_exclusively);
+    m.insert(("java/io/UnixFileSystem", "delete0", "(Ljava/io/File;)Z"), unix_file_system_delete0);
+    m.insert(("java/io/UnixFileSystem", "list", "(Ljava/io/File;)[Ljava/lang/String;"), unix_file_system_list);
+    m.insert(("java/io/UnixFileSystem", "createDirectory", "(Ljava/io/File;)Z"), unix_file_system_create_directory);
+    m.insert(("java/io/UnixFileSystem", "rename0", "(Ljava/io/File;Ljava/io/File;)Z"), unix_file_system_rename0);
+}
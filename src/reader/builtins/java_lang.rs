@@ -6,7 +6,11 @@ use reader::runner::*;
 use reader::util::*;
 use reader::class_reader::*;
 use std;
+use std::env;
+use std::fs;
+use std::path::Path;
 use std::rc::Rc;
+use reader::builtins::*;
 
 fn set_property(runtime: &mut Runtime, properties: &Variable, key: &str, value: &str) -> Result<(), RunnerError> {
     let keyvar = make_string(runtime, key).expect("Couldn't create string for argument");
@@ -15,7 +19,41 @@ fn set_property(runtime: &mut Runtime, properties: &Variable, key: &str, value:
     return Ok(());
 }
 
-fn make_field(runtime: &mut Runtime, clazz: &Variable, name: Rc<String>, descriptor: Rc<String>, access: u16, slot: i32)  -> Result<Variable, RunnerError> {
+fn visible_annotations(x: &AttributeItem) -> Option<&Vec<u8>> {
+    return match x { &AttributeItem::RuntimeVisibleAnnotations{ref info, ..} => Some(info), _ => None };
+}
+
+fn visible_parameter_annotations(x: &AttributeItem) -> Option<&Vec<u8>> {
+    return match x { &AttributeItem::RuntimeVisibleParameterAnnotations{ref info, ..} => Some(info), _ => None };
+}
+
+fn visible_type_annotations(x: &AttributeItem) -> Option<&Vec<u8>> {
+    return match x { &AttributeItem::RuntimeVisibleTypeAnnotations{ref info, ..} => Some(info), _ => None };
+}
+
+fn annotation_default(x: &AttributeItem) -> Option<&Vec<u8>> {
+    return match x { &AttributeItem::AnnotationDefault{ref info, ..} => Some(info), _ => None };
+}
+
+// Attribute contents as the byte[] the JDK's AnnotationParser reads, or a null array when there is no such attribute
+fn raw_attribute(runtime: &mut Runtime, attributes: &Vec<AttributeItem>, select: fn(&AttributeItem) -> Option<&Vec<u8>>) -> Result<Variable, RunnerError> {
+    let data = attributes.iter().filter_map(select).next().map(|x| x.iter().map(|b| Variable::Byte(*b as i8)).collect());
+    return construct_primitive_array(runtime, "B", data);
+}
+
+// The loaded class behind a Class object, which arrays and primitives don't have
+fn find_member<'a>(class: &'a Class, name: &str, descriptor: &str, method: bool) -> Result<Option<&'a FieldItem>, RunnerError> {
+    let members = if method {&class.cr.methods} else {&class.cr.fields};
+    for member in members {
+        if try!(class.cr.constant_pool.get_str(member.name_index)).as_str() == name &&
+            try!(class.cr.constant_pool.get_str(member.descriptor_index)).as_str() == descriptor {
+            return Ok(Some(member));
+        }
+    }
+    return Ok(None);
+}
+
+fn make_field(runtime: &mut Runtime, clazz: &Variable, name: Rc<String>, descriptor: Rc<String>, field: &FieldItem, slot: i32)  -> Result<Variable, RunnerError> {
     let class_name = "java/lang/reflect/Field";
     let name_var = try!(make_string(runtime, name.as_str()));
     let name_var_interned = try!(string_intern(runtime, &name_var));
@@ -27,292 +65,1140 @@ fn make_field(runtime: &mut Runtime, clazz: &Variable, name: Rc<String>, descrip
     try!(put_field(runtime, &var.to_ref(), "type", type_obj));
     try!(put_field(runtime, &var.to_ref(), "slot", Variable::Int(slot)));
     try!(put_field(runtime, &var.to_ref(), "clazz", clazz.clone()));
-    try!(put_field(runtime, &var.to_ref(), "modifiers", Variable::Int(access as i32)));
+    try!(put_field(runtime, &var.to_ref(), "modifiers", Variable::Int(field.access_flags as i32)));
+    let annotations = try!(raw_attribute(runtime, &field.attributes, visible_annotations));
+    try!(put_field(runtime, &var.to_ref(), "annotations", annotations));
     return Ok(var);
 }
 
-fn make_method(runtime: &mut Runtime, name: Rc<String>, descriptor: Rc<String>, _access: u16)  -> Result<Variable, RunnerError> {
+// What Method and Constructor objects share. The slot is the index into the class file's methods.
+fn fill_executable(runtime: &mut Runtime, var: &Variable, clazz: &Variable, class: &Class, descriptor: &str, method: &FieldItem, slot: usize) -> Result<(), RunnerError> {
+    let obj = var.to_ref();
+    try!(put_field(runtime, &obj, "clazz", clazz.clone()));
+    try!(put_field(runtime, &obj, "slot", Variable::Int(slot as i32)));
+    let signature_var = try!(make_string(runtime, descriptor));
+    try!(put_field(runtime, &obj, "signature", signature_var));
+    try!(put_field(runtime, &obj, "modifiers", Variable::Int((method.access_flags & ACC_RECOGNISED_METHOD) as i32)));
+
+    let mut parameter_types = Vec::new();
+    for parameter in try!(parameter_descriptors(descriptor)) {
+        parameter_types.push(try!(get_class_object_for_type(runtime, parameter.as_str())));
+    }
+    let parameter_array = try!(construct_array_by_name(runtime, "java/lang/Class", Some(parameter_types)));
+    try!(put_field(runtime, &obj, "parameterTypes", parameter_array));
+
+    let mut exception_types = Vec::new();
+    for attribute in &method.attributes {
+        if let &AttributeItem::Exceptions{ref indicies} = attribute {
+            for index in indicies {
+                let name = try!(class.cr.constant_pool.get_class_name(*index));
+                exception_types.push(try!(get_class_object_from_descriptor(runtime, type_name_to_descriptor(&name).as_str())));
+            }
+        }
+    }
+    let exception_array = try!(construct_array_by_name(runtime, "java/lang/Class", Some(exception_types)));
+    try!(put_field(runtime, &obj, "exceptionTypes", exception_array));
+
+    let annotations = try!(raw_attribute(runtime, &method.attributes, visible_annotations));
+    try!(put_field(runtime, &obj, "annotations", annotations));
+    let parameter_annotations = try!(raw_attribute(runtime, &method.attributes, visible_parameter_annotations));
+    try!(put_field(runtime, &obj, "parameterAnnotations", parameter_annotations));
+    return Ok(());
+}
+
+fn make_method(runtime: &mut Runtime, clazz: &Variable, class: &Class, name: Rc<String>, descriptor: Rc<String>, method: &FieldItem, slot: usize)  -> Result<Variable, RunnerError> {
     let class_name = &"java/lang/reflect/Method";
     let name_var = try!(make_string(runtime, name.as_str()));
     let name_var_interned = try!(string_intern(runtime, &name_var));
-    let signature_var = try!(make_string(runtime, descriptor.as_str()));
     let var = try!(construct_object(runtime, class_name));
     try!(put_field(runtime, &var.to_ref(), "name", name_var_interned));
-    try!(put_field(runtime, &var.to_ref(), "signature", signature_var));
+    try!(fill_executable(runtime, &var, clazz, class, descriptor.as_str(), method, slot));
+    let return_descriptor = descriptor.splitn(2, ')').nth(1).unwrap_or("V");
+    let return_type = try!(get_class_object_for_type(runtime, return_descriptor));
+    try!(put_field(runtime, &var.to_ref(), "returnType", return_type));
+    let default = try!(raw_attribute(runtime, &method.attributes, annotation_default));
+    try!(put_field(runtime, &var.to_ref(), "annotationDefault", default));
+    return Ok(var);
+}
+
+fn make_constructor(runtime: &mut Runtime, clazz: &Variable, class: &Class, descriptor: Rc<String>, method: &FieldItem, slot: usize)  -> Result<Variable, RunnerError> {
+    let var = try!(construct_object(runtime, "java/lang/reflect/Constructor"));
+    try!(fill_executable(runtime, &var, clazz, class, descriptor.as_str(), method, slot));
     return Ok(var);
 }
 
-pub fn try_builtin(class_name: &Rc<String>, method_name: &Rc<String>, descriptor: &Rc<String>, args: &Vec<Variable>, runtime: &mut Runtime) -> Result<bool, RunnerError> {
-    match (class_name.as_str(), method_name.as_str(), descriptor.as_str()) {
-        ("java/lang/Class", "registerNatives", "()V") => {}
-        ("java/lang/Class", "isArray", "()Z") => {
-            let obj = args[0].clone().to_ref();
-            let value = obj.unwrap().get_member(&String::from("__is_array")).unwrap();
-            runnerPrint!(runtime, true, 2, "BUILTIN: is_array {}", value);
-            runtime.push_on_stack(value.clone());
+fn class_is_array(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let obj = args[0].clone().to_ref();
+    let value = obj.unwrap().get_member(&String::from("__is_array")).unwrap();
+    runnerPrint!(runtime, true, 2, "BUILTIN: is_array {}", value);
+    runtime.push_on_stack(value.clone());
+    return Ok(());
+}
+
+fn class_is_primitive(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let obj = args[0].clone().to_ref();
+    let value = obj.unwrap().get_member(&String::from("__is_primitive")).unwrap();
+    runnerPrint!(runtime, true, 2, "BUILTIN: is_primitive {}", value);
+    runtime.push_on_stack(value.clone());
+    return Ok(());
+}
+
+fn class_get_primitive_class(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let obj = args[0].clone().to_ref();
+    let string = try!(extract_from_string(runtime, &obj));
+    let descriptor = type_name_to_descriptor(&string);
+    runnerPrint!(runtime, true, 2, "BUILTIN: getPrimitiveClass {} {}", string, descriptor);
+    let var = try!(get_primitive_class_object(runtime, descriptor));
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn class_is_assignable_from(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class_object_1 = args[0].clone().to_ref();
+    let mut class1 = class_object_1.unwrap().get_member(&String::from("__class")).unwrap().to_ref_type();
+    let class_object_2 = args[1].clone().to_ref();
+    let class2 = class_object_2.unwrap().get_member(&String::from("__class")).unwrap().to_ref_type();
+    while class1 != class2 {
+        if class1.super_class.borrow().is_none() { break; }
+        let new_class1 = class1.super_class.borrow().clone().unwrap();
+        class1 = new_class1;
+    }
+
+    runtime.push_on_stack(Variable::Boolean(class1 == class2));
+    return Ok(());
+}
+
+fn class_get_component_type(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class_object_1 = args[0].clone().to_ref();
+    let is_array = class_object_1.as_ref().unwrap().get_member(&String::from("__is_array")).unwrap().to_bool();
+    if !is_array {
+        return Err(RunnerError::ClassInvalid2(format!("getComponentType on non-array {}", class_object_1.as_ref().unwrap())));
+    }
+    let var = class_object_1.as_ref().unwrap().get_member(&String::from("__componentType")).unwrap().clone();
+    runnerPrint!(runtime, true, 2, "BUILTIN: getComponentType {}", var);
+
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn class_for_name0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let descriptor_string_obj = args[0].clone().to_ref();
+    let descriptor = try!(extract_from_string(runtime, &descriptor_string_obj));
+    let initialize = args[1].to_bool();
+    let ref class_loader = args[2];
+    let ref caller_class = args[3];
+    runnerPrint!(runtime, true, 2, "BUILTIN: forName0 {} {} {} {}", descriptor, initialize, class_loader, caller_class);
+    // Load class
+    let maybe_class = load_class(runtime, descriptor.as_str());
+    if maybe_class.is_err() {
+        let err = maybe_class.unwrap_err();
+        match err {
+            RunnerError::ClassNotLoaded(ref _s) => {
+                let exception = try!(construct_object(runtime, &"java/lang/ClassNotFoundException"));
+                return Err(RunnerError::Exception(exception))
+            },
+            _ => return Err(err)
+        };
+    }
+
+    let var = try!(get_class_object_from_descriptor(runtime, type_name_to_descriptor(&descriptor).as_str()));
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn class_desired_assertion_status0(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runtime.push_on_stack(Variable::Boolean(false));
+    return Ok(());
+}
+
+fn class_get_declared_fields0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class = try!(try!(class_of(runtime, &args[0])).ok_or(RunnerError::ClassInvalid("Members asked of a class without any")));
+    let public_only = args[1].to_bool();
+
+    runnerPrint!(runtime, true, 2, "BUILTIN: getDeclaredFields0 {}", class.name);
+
+    let mut field_objects : Vec<Variable> = Vec::new();
+    for field in &class.cr.fields {
+        if !public_only || (field.access_flags & ACC_PUBLIC != 0) {
+            let name_string = try!(class.cr.constant_pool.get_str(field.name_index));
+            let descriptor_string = try!(class.cr.constant_pool.get_str(field.descriptor_index));
+            let offset = if field.access_flags & ACC_STATIC != 0 {0} else {class.find_member_offset(&name_string).unwrap()};
+            let field_object = try!(make_field(runtime, &args[0], name_string, descriptor_string, field, offset as i32));
+            field_objects.push(field_object);
         }
-        ("java/lang/Class", "isPrimitive", "()Z") => {
-            let obj = args[0].clone().to_ref();
-            let value = obj.unwrap().get_member(&String::from("__is_primitive")).unwrap();
-            runnerPrint!(runtime, true, 2, "BUILTIN: is_primitive {}", value);
-            runtime.push_on_stack(value.clone());
+    }
+    let fields_array = try!(construct_array_by_name(runtime, &"java/lang/reflect/Field", Some(field_objects)));
+    runtime.push_on_stack(fields_array);
+    return Ok(());
+}
+
+fn class_get_declared_methods0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class = try!(try!(class_of(runtime, &args[0])).ok_or(RunnerError::ClassInvalid("Members asked of a class without any")));
+    let public_only = args[1].to_bool();
+
+    let mut method_objects : Vec<Variable> = Vec::new();
+    for (slot, method) in class.cr.methods.iter().enumerate() {
+        if public_only && (method.access_flags & ACC_PUBLIC == 0) {
+            continue;
         }
-        ("java/lang/Class", "getPrimitiveClass", "(Ljava/lang/String;)Ljava/lang/Class;") => {
-            let obj = args[0].clone().to_ref();
-            let string = try!(extract_from_string(runtime, &obj));
-            let descriptor = type_name_to_descriptor(&string);
-            runnerPrint!(runtime, true, 2, "BUILTIN: getPrimitiveClass {} {}", string, descriptor);
-            let var = try!(get_primitive_class_object(runtime, descriptor));
-            runtime.push_on_stack(var);
+
+        let name_string = try!(class.cr.constant_pool.get_str(method.name_index));
+        if name_string.starts_with('<') {
+            continue;
         }
-        ("java/lang/Class", "isAssignableFrom", "(Ljava/lang/Class;)Z") => {
-            let class_object_1 = args[0].clone().to_ref();
-            let mut class1 = class_object_1.unwrap().get_member(&String::from("__class")).unwrap().to_ref_type();
-            let class_object_2 = args[1].clone().to_ref();
-            let class2 = class_object_2.unwrap().get_member(&String::from("__class")).unwrap().to_ref_type();
-            while class1 != class2 {
-                if class1.super_class.borrow().is_none() { break; }
-                let new_class1 = class1.super_class.borrow().clone().unwrap();
-                class1 = new_class1;
-            }
+        let descriptor_string = try!(class.cr.constant_pool.get_str(method.descriptor_index));
+        let methods_object = try!(make_method(runtime, &args[0], &class, name_string, descriptor_string, method, slot));
+        method_objects.push(methods_object);
+    }
+    let methods_array = try!(construct_array_by_name(runtime, &"java/lang/reflect/Method", Some(method_objects)));
+    runtime.push_on_stack(methods_array);
+    return Ok(());
+}
 
-            runtime.push_on_stack(Variable::Boolean(class1 == class2));
+fn class_get_declared_constructors0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let public_only = args[1].to_bool();
+    let mut constructor_objects : Vec<Variable> = Vec::new();
+    if let Some(class) = try!(class_of(runtime, &args[0])) {
+        runnerPrint!(runtime, true, 2, "BUILTIN: getDeclaredConstructors0 {}", class.name);
+        for (slot, method) in class.cr.methods.iter().enumerate() {
+            if public_only && (method.access_flags & ACC_PUBLIC == 0) {
+                continue;
+            }
+            if try!(class.cr.constant_pool.get_str(method.name_index)).as_str() != "<init>" {
+                continue;
+            }
+            let descriptor_string = try!(class.cr.constant_pool.get_str(method.descriptor_index));
+            constructor_objects.push(try!(make_constructor(runtime, &args[0], &class, descriptor_string, method, slot)));
         }
-        ("java/lang/Class", "getComponentType", "()Ljava/lang/Class;") => {
-            let class_object_1 = args[0].clone().to_ref();
-            let is_array = class_object_1.as_ref().unwrap().get_member(&String::from("__is_array")).unwrap().to_bool();
-            if !is_array {
-                return Err(RunnerError::ClassInvalid2(format!("getComponentType on non-array {}", class_object_1.as_ref().unwrap())));
+    }
+    let constructors_array = try!(construct_array_by_name(runtime, &"java/lang/reflect/Constructor", Some(constructor_objects)));
+    runtime.push_on_stack(constructors_array);
+    return Ok(());
+}
+
+// Member classes take the flags they were declared with from InnerClasses, which can include private and static
+fn class_modifiers(runtime: &mut Runtime, class_obj: &Variable) -> Result<u16, RunnerError> {
+    return Ok(match try!(class_of(runtime, class_obj)) {
+        Some(class) => match own_inner_class(&class) {
+            Some(inner) => inner.inner_class_access_flags,
+            None => class.cr.access_flags & !ACC_SUPER,
+        },
+        None => ACC_PUBLIC | ACC_FINAL | ACC_ABSTRACT,
+    });
+}
+
+fn class_get_modifiers(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let modifiers = match array_element_class_object(&args[0]) {
+        Some(element) => array_modifiers(try!(class_modifiers(runtime, &element))),
+        None => try!(class_modifiers(runtime, &args[0])),
+    };
+    runnerPrint!(runtime, true, 2, "BUILTIN: getModifiers {} {}", args[0], modifiers);
+    runtime.push_on_stack(Variable::Int(modifiers as i32));
+    return Ok(());
+}
+
+fn class_get_superclass(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class_obj = args[0].to_ref().unwrap();
+    let is_array = class_obj.get_member(&String::from("__is_array")).map(|x| x.to_bool()).unwrap_or(false);
+    let var = match try!(class_of(runtime, &args[0])) {
+        Some(ref class) if class.cr.access_flags & ACC_INTERFACE == 0 => match class.super_class.borrow().clone() {
+            Some(super_class) => try!(get_class_object_from_descriptor(runtime, generate_class_descriptor(&super_class).as_str())),
+            None => try!(construct_null_object_by_name(runtime, "Ljava/lang/Class;")),
+        },
+        _ if is_array => try!(get_class_object_from_descriptor(runtime, "Ljava/lang/Object;")),
+        _ => try!(construct_null_object_by_name(runtime, "Ljava/lang/Class;")),
+    };
+    runnerPrint!(runtime, true, 2, "BUILTIN: getSuperclass {} {}", args[0], var);
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn class_is_interface(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let is_interface = try!(class_of(runtime, &args[0])).map(|x| x.cr.access_flags & ACC_INTERFACE != 0).unwrap_or(false);
+    runnerPrint!(runtime, true, 2, "BUILTIN: isInterface {} {}", args[0], is_interface);
+    runtime.push_on_stack(Variable::Boolean(is_interface));
+    return Ok(());
+}
+
+// Arrays implement Cloneable and Serializable without saying so anywhere
+fn class_get_interfaces0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class_obj = args[0].to_ref().unwrap();
+    let is_array = class_obj.get_member(&String::from("__is_array")).map(|x| x.to_bool()).unwrap_or(false);
+    let names: Vec<Rc<String>> = match try!(class_of(runtime, &args[0])) {
+        Some(class) => {
+            let mut names = Vec::new();
+            for index in &class.cr.interfaces {
+                names.push(try!(class.cr.constant_pool.get_class_name(*index)));
             }
-            let var = class_object_1.as_ref().unwrap().get_member(&String::from("__componentType")).unwrap().clone();
-            runnerPrint!(runtime, true, 2, "BUILTIN: getComponentType {}", var);
+            names
+        }
+        None if is_array => vec!(Rc::new(String::from("java/lang/Cloneable")), Rc::new(String::from("java/io/Serializable"))),
+        None => Vec::new(),
+    };
+    let mut interfaces = Vec::new();
+    for name in names {
+        interfaces.push(try!(get_class_object_from_name(runtime, name.as_str())));
+    }
+    runnerPrint!(runtime, true, 2, "BUILTIN: getInterfaces0 {} {}", args[0], interfaces.len());
+    let array = try!(construct_array_by_name(runtime, "java/lang/Class", Some(interfaces)));
+    runtime.push_on_stack(array);
+    return Ok(());
+}
 
-            runtime.push_on_stack(var);
+// The InnerClasses entry a nested class has for itself, which every class nesting it repeats
+fn own_inner_class(class: &Class) -> Option<&InnerClassItem> {
+    return class.cr.inner_classes().iter().find(|x| x.inner_class_info_index == class.cr.this_class_index);
+}
+
+fn class_get_declaring_class0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let outer = match try!(class_of(runtime, &args[0])) {
+        Some(class) => match own_inner_class(&class) {
+            Some(inner) if inner.outer_class_info_index != 0 => Some(try!(class.cr.constant_pool.get_class_name(inner.outer_class_info_index))),
+            _ => None,
         },
-        ("java/lang/Class", "forName0", "(Ljava/lang/String;ZLjava/lang/ClassLoader;Ljava/lang/Class;)Ljava/lang/Class;") => {
-            let descriptor_string_obj = args[0].clone().to_ref();
-            let descriptor = try!(extract_from_string(runtime, &descriptor_string_obj));
-            let initialize = args[1].to_bool();
-            let ref class_loader = args[2];
-            let ref caller_class = args[3];
-            runnerPrint!(runtime, true, 2, "BUILTIN: forName0 {} {} {} {}", descriptor, initialize, class_loader, caller_class);
-            // Load class
-            let maybe_class = load_class(runtime, descriptor.as_str());
-            if maybe_class.is_err() {
-                let err = maybe_class.unwrap_err();
-                match err {
-                    RunnerError::ClassNotLoaded(ref _s) => {
-                        let exception = try!(construct_object(runtime, &"java/lang/ClassNotFoundException"));
-                        return Err(RunnerError::Exception(exception))
-                    },
-                    _ => return Err(err)
+        None => None,
+    };
+    let var = match outer {
+        Some(name) => try!(get_class_object_from_name(runtime, name.as_str())),
+        None => try!(construct_null_object_by_name(runtime, "Ljava/lang/Class;")),
+    };
+    runnerPrint!(runtime, true, 2, "BUILTIN: getDeclaringClass0 {} {}", args[0], var);
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+// Local and anonymous classes give the class and, unless declared in an initialiser, the method they're in
+fn class_get_enclosing_method0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let enclosing = match try!(class_of(runtime, &args[0])) {
+        Some(class) => match class.cr.enclosing_method() {
+            Some((class_index, method_index)) => {
+                let class_name = try!(class.cr.constant_pool.get_class_name(class_index));
+                let method = match method_index {
+                    Some(index) => Some(try!(class.cr.constant_pool.get_name_and_type(index))),
+                    None => None,
                 };
+                Some((class_name, method))
             }
+            None => None,
+        },
+        None => None,
+    };
+    let var = match enclosing {
+        Some((class_name, method)) => {
+            let class_var = try!(get_class_object_from_name(runtime, class_name.as_str()));
+            let (name_var, descriptor_var) = match method {
+                Some((name, descriptor)) => (try!(make_string(runtime, name.as_str())), try!(make_string(runtime, descriptor.as_str()))),
+                None => (try!(construct_null_object_by_name(runtime, "Ljava/lang/String;")), try!(construct_null_object_by_name(runtime, "Ljava/lang/String;"))),
+            };
+            try!(construct_array_by_name(runtime, "java/lang/Object", Some(vec!(class_var, name_var, descriptor_var))))
+        }
+        None => try!(construct_array_by_name(runtime, "java/lang/Object", None)),
+    };
+    runnerPrint!(runtime, true, 2, "BUILTIN: getEnclosingMethod0 {} {}", args[0], var);
+    runtime.push_on_stack(var);
+    return Ok(());
+}
 
-            let var = try!(get_class_object_from_descriptor(runtime, type_name_to_descriptor(&descriptor).as_str()));
-            runtime.push_on_stack(var);
+// The name a member class was given in source, null for top level and anonymous classes
+fn class_get_simple_binary_name(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let name = match try!(class_of(runtime, &args[0])) {
+        Some(class) => match own_inner_class(&class) {
+            Some(inner) if inner.inner_name_index != 0 => Some(try!(class.cr.constant_pool.get_utf16(inner.inner_name_index))),
+            _ => None,
+        },
+        None => None,
+    };
+    let var = match name {
+        Some(units) => try!(make_string_utf16(runtime, &units)),
+        None => try!(construct_null_object_by_name(runtime, "Ljava/lang/String;")),
+    };
+    runnerPrint!(runtime, true, 2, "BUILTIN: getSimpleBinaryName {} {}", args[0], var);
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn class_get_generic_signature0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let signature = match try!(class_of(runtime, &args[0])) {
+        Some(class) => match class.cr.signature {
+            Some(index) => Some(try!(class.cr.constant_pool.get_utf16(index))),
+            None => None,
+        },
+        None => None,
+    };
+    let var = match signature {
+        Some(units) => try!(make_string_utf16(runtime, &units)),
+        None => try!(construct_null_object_by_name(runtime, "Ljava/lang/String;")),
+    };
+    runnerPrint!(runtime, true, 2, "BUILTIN: getGenericSignature0 {} {}", args[0], var);
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn class_get_raw_annotations(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: getRawAnnotations {}", args[0]);
+    let var = match try!(class_of(runtime, &args[0])) {
+        Some(class) => try!(raw_attribute(runtime, &class.cr.attributes, visible_annotations)),
+        None => try!(construct_primitive_array(runtime, "B", None)),
+    };
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn class_get_raw_type_annotations(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: getRawTypeAnnotations {}", args[0]);
+    let var = match try!(class_of(runtime, &args[0])) {
+        Some(class) => try!(raw_attribute(runtime, &class.cr.attributes, visible_type_annotations)),
+        None => try!(construct_primitive_array(runtime, "B", None)),
+    };
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+// The pool object only holds on to the Class, which the ConstantPool natives read constants from
+fn class_get_constant_pool(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: getConstantPool {}", args[0]);
+    let var = try!(construct_object(runtime, "sun/reflect/ConstantPool"));
+    try!(put_field(runtime, &var.to_ref(), "constantPoolOop", args[0].clone()));
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+// Finds the member a Field, Method or Constructor object stands for again by name and descriptor
+fn reflected_type_annotations(runtime: &mut Runtime, obj: &Variable, method: bool) -> Result<Variable, RunnerError> {
+    let obj = obj.to_ref();
+    let class_name = obj.as_ref().unwrap().type_ref().name.clone();
+    let clazz = try!(get_field(runtime, &obj, class_name.as_str(), "clazz"));
+    let name_var = if class_name == "java/lang/reflect/Constructor" {None} else {Some(try!(get_field(runtime, &obj, class_name.as_str(), "name")))};
+    let name = match name_var {
+        Some(var) => try!(extract_from_string(runtime, &var.to_ref())),
+        None => String::from("<init>"),
+    };
+    let signature_var = try!(get_field(runtime, &obj, class_name.as_str(), "signature"));
+    let descriptor = try!(extract_from_string(runtime, &signature_var.to_ref()));
+    runnerPrint!(runtime, true, 2, "BUILTIN: getTypeAnnotationBytes0 {} {}", name, descriptor);
+    let class = try!(class_of(runtime, &clazz)).unwrap();
+    return match try!(find_member(&class, name.as_str(), descriptor.as_str(), method)) {
+        Some(member) => raw_attribute(runtime, &member.attributes, visible_type_annotations),
+        None => construct_primitive_array(runtime, "B", None),
+    };
+}
+
+fn field_get_type_annotation_bytes0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let var = try!(reflected_type_annotations(runtime, &args[0], false));
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn executable_get_type_annotation_bytes0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let var = try!(reflected_type_annotations(runtime, &args[0], true));
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn array_store_exception(runtime: &mut Runtime, message: String) -> Result<(), RunnerError> {
+    return Err(RunnerError::Exception(try!(construct_exception(runtime, "java/lang/ArrayStoreException", message.as_str()))));
+}
+
+fn system_arraycopy(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: arrayCopy {} {} {} {} {}", args[0], args[1], args[2], args[3], args[4]);
+
+    if args[0].is_null() || args[2].is_null() {
+        let exception = try!(construct_object(runtime, &"java/lang/NullPointerException"));
+        return Err(RunnerError::Exception(exception));
+    }
+    for var in &[&args[0], &args[2]] {
+        if !var.is_array_reference() {
+            return array_store_exception(runtime, format!("arraycopy: {} is not an array", var.get_descriptor()));
         }
-        ("java/lang/Class", "desiredAssertionStatus0", "(Ljava/lang/Class;)Z") => {runtime.push_on_stack(Variable::Boolean(false));}
-        ("java/lang/Class", "getDeclaredFields0", "(Z)[Ljava/lang/reflect/Field;") => {
-            let class_obj = args[0].to_ref();
-            let class = class_obj.unwrap().get_member(&String::from("__class")).unwrap().to_ref_type();
-            let public_only = args[1].to_bool();
-
-            runnerPrint!(runtime, true, 2, "BUILTIN: getDeclaredFields0 {}", class.name);
-
-            let mut field_objects : Vec<Variable> = Vec::new();
-            for field in &class.cr.fields {
-                if !public_only || (field.access_flags & ACC_PUBLIC != 0) {
-                    let name_string = try!(class.cr.constant_pool.get_str(field.name_index));
-                    let descriptor_string = try!(class.cr.constant_pool.get_str(field.descriptor_index));
-                    let offset = if field.access_flags & ACC_STATIC != 0 {0} else {class.find_member_offset(&name_string).unwrap()};
-                    let field_object = try!(make_field(runtime, &args[0], name_string, descriptor_string, field.access_flags, offset as i32));
-                    field_objects.push(field_object);
-                }
-            }
-            let fields_array = try!(construct_array_by_name(runtime, &"java/lang/reflect/Field", Some(field_objects)));
-            runtime.push_on_stack(fields_array);
+    }
+
+    let src = args[0].to_arrayobj();
+    let src_pos = args[1].to_int() as i64;
+    let dest = args[2].to_arrayobj();
+    let dest_pos = args[3].to_int() as i64;
+    let length = args[4].to_int() as i64;
+
+    let src_type = args[0].get_descriptor()[1..].to_string();
+    let dest_type = args[2].get_descriptor()[1..].to_string();
+    let src_primitive = src_type.len() == 1;
+    let dest_primitive = dest_type.len() == 1;
+    if (src_primitive || dest_primitive) && src_type != dest_type {
+        return array_store_exception(runtime, format!("arraycopy: type mismatch: can not copy {}[] into {}[]", src_type, dest_type));
+    }
+
+    let src_length = src.elements.borrow().len() as i64;
+    let dest_length = dest.elements.borrow().len() as i64;
+    if src_pos < 0 || dest_pos < 0 || length < 0 || src_pos + length > src_length || dest_pos + length > dest_length {
+        let message = format!("arraycopy: copying {} from {} of {} to {} of {}", length, src_pos, src_length, dest_pos, dest_length);
+        let exception = try!(construct_exception(runtime, "java/lang/ArrayIndexOutOfBoundsException", message.as_str()));
+        return Err(RunnerError::Exception(exception));
+    }
+
+    // Taking the source range out first copies overlapping ranges of one array as if through a temporary
+    let copied: Vec<Variable> = src.elements.borrow()[src_pos as usize..(src_pos + length) as usize].to_vec();
+    let checked = !src_primitive && !try!(is_assignable_descriptor(runtime, dest_type.as_str(), src_type.as_str()));
+    for (n, element) in copied.into_iter().enumerate() {
+        // Elements before one that doesn't fit the destination stay copied
+        if checked && !try!(is_assignable_value(runtime, dest_type.as_str(), &element)) {
+            return array_store_exception(runtime, format!("arraycopy: element type mismatch: {} into {}[]", element.get_descriptor(), dest_type));
         }
-        ("java/lang/Class", "getDeclaredMethods0", "(Z)[Ljava/lang/reflect/Method;") => {
-            let class_obj = args[0].to_ref();
-            let class = class_obj.unwrap().get_member(&String::from("__class")).unwrap().to_ref_type();
-            let public_only = args[1].to_bool();
-
-            let mut method_objects : Vec<Variable> = Vec::new();
-            for method in &class.cr.methods {
-                if public_only && (method.access_flags & ACC_PUBLIC == 0) {
-                    continue;
-                }
-
-                let name_string = try!(class.cr.constant_pool.get_str(method.name_index));
-                let descriptor_string = try!(class.cr.constant_pool.get_str(method.descriptor_index));
-                let methods_object = try!(make_method(runtime, name_string, descriptor_string, method.access_flags));
-                method_objects.push(methods_object);
-            }
-            let methods_array = try!(construct_array_by_name(runtime, &"java/lang/reflect/Method", Some(method_objects)));
-            runtime.push_on_stack(methods_array);
+        dest.elements.borrow_mut()[dest_pos as usize + n] = element;
+    }
+    return Ok(());
+}
+
+// The names HotSpot reports for the host, which differ from Rust's on a few platforms
+fn host_os_name() -> String {
+    return String::from(match env::consts::OS {
+        "linux" => "Linux",
+        "macos" => "Mac OS X",
+        "windows" => "Windows",
+        "freebsd" => "FreeBSD",
+        other => other,
+    });
+}
+
+fn host_os_arch() -> String {
+    return String::from(match (env::consts::OS, env::consts::ARCH) {
+        ("macos", arch) => arch,
+        (_, "x86_64") => "amd64",
+        (_, "x86") => "i386",
+        (_, arch) => arch,
+    });
+}
+
+fn host_os_version() -> String {
+    return fs::read_to_string("/proc/sys/kernel/osrelease").map(|x| String::from(x.trim())).unwrap_or(String::from("unknown"));
+}
+
+// The codeset of the first locale set in LC_ALL, LC_CTYPE or LANG, as in en_GB.ISO-8859-1@euro
+fn locale_encoding(runtime: &Runtime) -> String {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"].iter()
+        .filter_map(|x| runtime.environment.get(*x))
+        .find(|x| !x.is_empty());
+    let locale = match locale {
+        Some(locale) => locale.split('@').next().unwrap(),
+        None => return String::from("UTF-8"),
+    };
+    if locale == "C" || locale == "POSIX" {
+        return String::from("US-ASCII");
+    }
+    return match locale.find('.').map(|x| &locale[x + 1..]) {
+        Some(codeset) if codeset.eq_ignore_ascii_case("utf8") || codeset.eq_ignore_ascii_case("utf-8") => String::from("UTF-8"),
+        Some(codeset) if !codeset.is_empty() => String::from(codeset),
+        _ => String::from("UTF-8"),
+    };
+}
+
+// What initProperties fills in before the runtime's own properties are applied over it
+fn default_properties(runtime: &Runtime) -> Vec<(&'static str, String)> {
+    let environment_value = |key: &str| runtime.environment.get(key).cloned().unwrap_or(String::from("?"));
+    // Under a file root Java code sees the root as /, so host directories outside it mean nothing to it.
+    // Relative paths resolve against the root too, which makes it the working directory
+    let user_dir = if runtime.files.root.is_some() {
+        String::from("/")
+    } else {
+        env::current_dir().map(|x| x.to_string_lossy().into_owned()).unwrap_or(String::from("/"))
+    };
+    let user_home = match (runtime.environment.get("HOME"), runtime.files.root.is_some()) {
+        (Some(home), true) => runtime.files.java_path(Path::new(home)).unwrap_or(String::from("/")),
+        (None, true) => String::from("/"),
+        (_, false) => environment_value("HOME"),
+    };
+    let encoding = locale_encoding(runtime);
+    return vec!(
+        ("java.version", String::from("1.8.0")),
+        ("java.vendor", String::from("rjvm")),
+        ("java.vendor.url", String::from("https://github.com/nerd2/rjvm")),
+        ("java.home", String::new()),
+        ("java.class.version", String::from("52.0")),
+        ("java.class.path", runtime.class_paths.join(":")),
+        ("java.library.path", String::new()),
+        ("java.io.tmpdir", String::from("/tmp")),
+        ("java.specification.version", String::from("1.8")),
+        ("java.specification.name", String::from("Java Platform API Specification")),
+        ("java.specification.vendor", String::from("Oracle Corporation")),
+        ("java.vm.specification.version", String::from("1.8")),
+        ("java.vm.specification.name", String::from("Java Virtual Machine Specification")),
+        ("java.vm.specification.vendor", String::from("Oracle Corporation")),
+        ("java.vm.name", String::from("rjvm")),
+        ("java.vm.vendor", String::from("rjvm")),
+        ("java.vm.version", String::from(env!("CARGO_PKG_VERSION"))),
+        ("java.vm.info", String::from(if runtime.jit.enabled {"mixed mode"} else {"interpreted mode"})),
+        ("java.runtime.name", String::from("rjvm")),
+        ("java.runtime.version", String::from("1.8.0")),
+        ("os.name", host_os_name()),
+        ("os.arch", host_os_arch()),
+        ("os.version", host_os_version()),
+        ("file.separator", String::from("/")),
+        ("path.separator", String::from(":")),
+        ("line.separator", String::from("\n")),
+        ("file.encoding", encoding.clone()),
+        ("sun.jnu.encoding", encoding),
+        ("sun.arch.data.model", (8 * std::mem::size_of::<usize>()).to_string()),
+        ("sun.cpu.endian", String::from(if cfg!(target_endian = "big") {"big"} else {"little"})),
+        ("user.name", environment_value("USER")),
+        ("user.home", user_home),
+        ("user.dir", user_dir),
+        // Keeps reflection on the native accessors rather than generating bytecode for them after a few calls
+        ("sun.reflect.inflationThreshold", String::from("2147483647")),
+    );
+}
+
+fn system_init_properties(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let properties = args[0].clone();
+    try!(invoke_nested(runtime, properties.to_ref_type().clone(), vec!(properties.clone()), "<init>", "()V", false));
+    runnerPrint!(runtime, true, 2, "BUILTIN: initProperties {}", properties);
+    for (key, value) in default_properties(runtime) {
+        try!(set_property(runtime, &properties, key, value.as_str()));
+    }
+    let configured: Vec<(String, String)> = runtime.properties.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+    for (key, value) in configured {
+        try!(set_property(runtime, &properties, key.as_str(), value.as_str()));
+    }
+    runtime.push_on_stack(properties);
+    return Ok(());
+}
+
+// Names and values alternate, as bytes in the platform encoding
+fn process_environment_environ(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: environ");
+    let environment: Vec<(String, String)> = runtime.environment.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+    let mut entries = Vec::new();
+    for (key, value) in environment {
+        for text in &[key, value] {
+            let bytes = text.as_bytes().iter().map(|x| Variable::Byte(*x as i8)).collect();
+            entries.push(try!(construct_primitive_array(runtime, "B", Some(bytes))));
+        }
+    }
+    let ret = try!(construct_array_by_descriptor(runtime, "[B", Some(entries)));
+    runtime.push_on_stack(ret);
+    return Ok(());
+}
+
+fn system_set_in0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let stream = args[0].clone();
+    runnerPrint!(runtime, true, 2, "BUILTIN: setIn0 {}", stream);
+    try!(put_static(runtime, "java/lang/System", "in", stream));
+    return Ok(());
+}
+
+fn system_set_out0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let stream = args[0].clone();
+    runnerPrint!(runtime, true, 2, "BUILTIN: setOut0 {}", stream);
+    try!(put_static(runtime, "java/lang/System", "out", stream));
+    return Ok(());
+}
+
+fn system_set_err0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let stream = args[0].clone();
+    runnerPrint!(runtime, true, 2, "BUILTIN: setErr0 {}", stream);
+    try!(put_static(runtime, "java/lang/System", "err", stream));
+    return Ok(());
+}
+
+fn system_load_library(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let lib_string_obj = args[0].clone().to_ref();
+    let lib = try!(extract_from_string(runtime, &lib_string_obj));
+    runnerPrint!(runtime, true, 2, "BUILTIN: loadLibrary {}", lib);
+    return Ok(());
+}
+
+// Unwinds everything back to whoever started the runtime, which decides what exiting means
+fn shutdown_halt0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let status = args[0].to_int();
+    runnerPrint!(runtime, true, 2, "BUILTIN: halt0 {}", status);
+    return Err(RunnerError::ExitCode(status));
+}
+
+// Hooks are threads, which never start here, so each one's run() is called in turn rather than
+// starting them all and joining them
+fn application_shutdown_hooks_run_hooks(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class = try!(load_class(runtime, "java/lang/ApplicationShutdownHooks"));
+    let hooks = class.statics.borrow().get("hooks").cloned();
+    // No more hooks can be added once they start running
+    let null_hooks = try!(construct_null_object_by_name(runtime, "java/util/IdentityHashMap"));
+    try!(put_static(runtime, "java/lang/ApplicationShutdownHooks", "hooks", null_hooks));
+    let hooks = match hooks {
+        Some(ref hooks) if !hooks.is_null() => hooks.to_ref(),
+        _ => return Ok(()),
+    };
+
+    // Keys and values alternate in the table, and every hook is its own key
+    let table = try!(get_field(runtime, &hooks, "java/util/IdentityHashMap", "table")).to_arrayobj();
+    let threads: Vec<Variable> = table.elements.borrow().iter().step_by(2).filter(|x| !x.is_null()).cloned().collect();
+    for thread in threads {
+        let thread_class = thread.to_ref().unwrap().type_ref();
+        let (run_class, _) = try!(Class::find_method(&thread_class, "run", "()V")
+            .ok_or(RunnerError::ClassInvalid2(format!("Shutdown hook {} has no run method", thread_class.name))));
+        runnerPrint!(runtime, true, 2, "BUILTIN: running shutdown hook {}", thread_class.name);
+        match invoke_nested(runtime, run_class, vec!(thread), "run", "()V", false) {
+            // An uncaught exception ends its own hook and no others
+            Err(RunnerError::Exception(_)) => {}
+            result => try!(result),
         }
-        ("java/lang/System", "arraycopy", "(Ljava/lang/Object;ILjava/lang/Object;II)V") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: arrayCopy {} {} {} {} {}", args[0], args[1], args[2], args[3], args[4]);
+    }
+    return Ok(());
+}
+
+fn runtime_available_processors(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: availableProcessors");
+    runtime.push_on_stack(Variable::Int(1));
+    return Ok(());
+}
+
+fn runtime_free_memory(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let free_mem : i64 = runtime.free_mem;
+    runnerPrint!(runtime, true, 2, "BUILTIN: freeMemory: {}", free_mem);
+    runtime.push_on_stack(Variable::Long(free_mem));
+    return Ok(());
+}
 
-            let src = args[0].to_arrayobj();
-            let src_pos = args[1].to_int();
-            let dest = args[2].to_arrayobj();
-            let dest_pos = args[3].to_int();
-            let length = args[4].to_int();
+fn runtime_gc(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: gc");
+    gc_hint_run(runtime);
+    return Ok(());
+}
+
+fn object_notify_all(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: TODO notifyAll {}", args[0]);
+    return Ok(());
+}
 
-            if src.is_null || dest.is_null {
-                let exception = try!(construct_object(runtime, &"java/lang/NullPointerException"));
+// Arrays can always be cloned, other objects only when their class implements Cloneable
+fn object_clone(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: clone {}", args[0]);
+    let var = match &args[0] {
+        &Variable::ArrayReference(ref array) => construct_array_clone(runtime, array),
+        &Variable::Reference(ref class, Some(ref obj)) => {
+            if !try!(implements_interface(runtime, &obj.type_ref(), "java/lang/Cloneable")) {
+                let exception = try!(construct_exception(runtime, "java/lang/CloneNotSupportedException", obj.type_ref().name.replace('/', ".").as_str()));
                 return Err(RunnerError::Exception(exception));
             }
+            let copy = obj.shallow_copy(runtime);
+            register_object(runtime, &copy);
+            Variable::Reference(class.clone(), Some(copy))
+        }
+        _ => return Err(RunnerError::ClassInvalid2(format!("clone on {}", args[0]))),
+    };
+    runtime.push_on_stack(var);
+    return Ok(());
+}
 
-            let src_data = src.elements.borrow();
-            let mut dest_data = dest.elements.borrow_mut();
+fn string_intern_native(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let interned = try!(string_intern(runtime, &args[0]));
+    runnerPrint!(runtime, true, 2, "BUILTIN: intern {} {:p}", args[0], &*interned.to_ref().unwrap());
+    runtime.push_on_stack(interned);
+    return Ok(());
+}
 
-            for n in 0..length {
-                dest_data[(dest_pos + n) as usize] = src_data[(src_pos + n) as usize].clone();
-            }
-        },
-        ("java/lang/System", "registerNatives", "()V") => {
-        },
-        ("java/lang/System", "initProperties", "(Ljava/util/Properties;)Ljava/util/Properties;") => {
-            let properties = args[0].clone();
-            try!(invoke_nested(runtime, properties.to_ref_type().clone(), vec!(properties.clone()), "<init>", "()V", false));
-            runnerPrint!(runtime, true, 2, "BUILTIN: initProperties {}", properties);
-            try!(set_property(runtime, &properties, "file.encoding", "us-ascii"));
-            try!(set_property(runtime, &properties, "line.separator", "\n"));
-            runtime.push_on_stack(properties);
-        },
-        ("java/lang/System", "setIn0", "(Ljava/io/InputStream;)V") => {
-            let stream = args[0].clone();
-            runnerPrint!(runtime, true, 2, "BUILTIN: setIn0 {}", stream);
-            try!(put_static(runtime, "java/lang/System", "in", stream));
-        },
-        ("java/lang/System", "setOut0", "(Ljava/io/PrintStream;)V") => {
-            let stream = args[0].clone();
-            runnerPrint!(runtime, true, 2, "BUILTIN: setOut0 {}", stream);
-            try!(put_static(runtime, "java/lang/System", "out", stream));
-        },
-        ("java/lang/System", "setErr0", "(Ljava/io/PrintStream;)V") => {
-            let stream = args[0].clone();
-            runnerPrint!(runtime, true, 2, "BUILTIN: setErr0 {}", stream);
-            try!(put_static(runtime, "java/lang/System", "err", stream));
-        },
-        ("java/lang/System", "loadLibrary", "(Ljava/lang/String;)V") => {
-            let lib_string_obj = args[0].clone().to_ref();
-            let lib = try!(extract_from_string(runtime, &lib_string_obj));
-            runnerPrint!(runtime, true, 2, "BUILTIN: loadLibrary {}", lib);
+fn float_float_to_raw_int_bits(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let float = args[0].to_float();
+    let bits = unsafe {std::mem::transmute::<f32, u32>(float)};
+    runnerPrint!(runtime, true, 2, "BUILTIN: floatToRawIntBits {} {}", float, bits);
+    runtime.push_on_stack(Variable::Int(bits as i32));
+    return Ok(());
+}
+
+fn float_int_bits_to_float(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let int = args[0].to_int();
+    let float = unsafe {std::mem::transmute::<i32, f32>(int)};
+    runnerPrint!(runtime, true, 2, "BUILTIN: intBitsToFloat {} {}", int, float);
+    runtime.push_on_stack(Variable::Float(float));
+    return Ok(());
+}
+
+fn double_double_to_raw_long_bits(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let double = args[0].to_double();
+    let bits = unsafe {std::mem::transmute::<f64, u64>(double)};
+    runnerPrint!(runtime, true, 2, "BUILTIN: doubleToRawIntBits {} {}", double, bits);
+    runtime.push_on_stack(Variable::Long(bits as i64));
+    return Ok(());
+}
+
+fn double_long_bits_to_double(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let long = args[0].to_long();
+    let double = unsafe {std::mem::transmute::<i64, f64>(long)};
+    runnerPrint!(runtime, true, 2, "BUILTIN: doubleToRawIntBits {} {}", long, double);
+    runtime.push_on_stack(Variable::Double(double));
+    return Ok(());
+}
+
+fn object_hash_code(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let code = try!(args[0].hash_code(runtime));
+    runnerPrint!(runtime, true, 2, "BUILTIN: hashcode {}", code);
+    runtime.push_on_stack(Variable::Int(code));
+    return Ok(());
+}
+
+fn system_identity_hash_code(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let code = try!(args[0].hash_code(runtime));
+    runnerPrint!(runtime, true, 2, "BUILTIN: identityHashCode {}", code); // TODO test
+    runtime.push_on_stack(Variable::Int(code));
+    return Ok(());
+}
+
+fn system_current_time_millis(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let millis = runtime.clock.current_millis();
+    runnerPrint!(runtime, true, 2, "BUILTIN: currentTimeMillis {}", millis);
+    runtime.push_on_stack(Variable::Long(millis));
+    return Ok(());
+}
+
+fn system_nano_time(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let nanos = runtime.clock.nano_time();
+    runnerPrint!(runtime, true, 2, "BUILTIN: nanoTime {}", nanos);
+    runtime.push_on_stack(Variable::Long(nanos));
+    return Ok(());
+}
+
+fn object_get_class(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let ref descriptor = args[0].get_descriptor();
+    let var = try!(get_class_object_from_descriptor(runtime, descriptor.as_str()));
+    runnerPrint!(runtime, true, 2, "BUILTIN: getClass {} {}", descriptor, var);
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+// Throwable's constructors call this, but no stack trace is recorded so the throwable comes back as it is
+fn throwable_fill_in_stack_trace(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: fillInStackTrace {}", args[0]);
+    runtime.push_on_stack(args[0].clone());
+    return Ok(());
+}
+
+fn thread_is_alive(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let obj = args[0].clone().to_ref();
+    let var = obj.unwrap().get_member(&String::from("__alive")).unwrap_or(Variable::Boolean(false)).clone();
+    runnerPrint!(runtime, true, 2, "BUILTIN: isAlive {}", var);
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn thread_start0(_runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    // TODO
+    return Ok(());
+}
+
+fn thread_set_priority0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let obj = args[0].clone().to_ref();
+    runnerPrint!(runtime, true, 2, "BUILTIN: setPriority0 {} {}", args[0], args[1]);
+    try!(put_field(runtime, &obj.clone(), &"priority", args[1].clone()));
+    return Ok(());
+}
+
+// There is only ever one thread running, so sleeping just waits on the clock
+fn thread_sleep(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let millis = args[0].to_long();
+    runnerPrint!(runtime, true, 2, "BUILTIN: sleep {}", millis);
+    if millis < 0 {
+        let exception = try!(construct_exception(runtime, "java/lang/IllegalArgumentException", "timeout value is negative"));
+        return Err(RunnerError::Exception(exception));
+    }
+    runtime.clock.sleep(millis);
+    return Ok(());
+}
+
+fn thread_current_thread(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: currentThread");
+    if runtime.current_thread.is_none() {
+        runnerPrint!(runtime, true, 2, "BUILTIN: currentThread - creating thread");
+        let thread_group;
+        {
+            let var = try!(construct_object(runtime, &"java/lang/ThreadGroup"));
+            let obj = var.to_ref();
+            try!(invoke_nested(runtime, obj.unwrap().type_ref().clone(), vec!(var.clone()), "<init>", "()V", false));
+            thread_group = var.clone();
         }
-        ("java/lang/Runtime", "availableProcessors", "()I") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: availableProcessors");
-            runtime.push_on_stack(Variable::Int(1));
-        },
-        ("java/lang/Runtime", "freeMemory", "()J") => {
-            let free_mem : i64 = runtime.free_mem;
-            runnerPrint!(runtime, true, 2, "BUILTIN: freeMemory: {}", free_mem);
-            runtime.push_on_stack(Variable::Long(free_mem));
-        },
-        ("java/lang/Runtime", "gc", "()V") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: gc");
-            gc_hint_run(runtime);
-        },
-        ("java/lang/Object", "registerNatives", "()V") => {return Ok(true)},
-        ("java/lang/Object", "notifyAll", "()V") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: TODO notifyAll {}", args[0]);
-            return Ok(true)
-        },
-        ("java/lang/String", "intern", "()Ljava/lang/String;") => {
-            let interned = try!(string_intern(runtime, &args[0]));
-            runnerPrint!(runtime, true, 2, "BUILTIN: intern {} {:p}", args[0], &*interned.to_ref().unwrap());
-            runtime.push_on_stack(interned);
-        },
-        ("java/lang/Float", "floatToRawIntBits", "(F)I") => {
-            let float = args[0].to_float();
-            let bits = unsafe {std::mem::transmute::<f32, u32>(float)};
-            runnerPrint!(runtime, true, 2, "BUILTIN: floatToRawIntBits {} {}", float, bits);
-            runtime.push_on_stack(Variable::Int(bits as i32));
-        },
-        ("java/lang/Float", "intBitsToFloat", "(I)F") => {
-            let int = args[0].to_int();
-            let float = unsafe {std::mem::transmute::<i32, f32>(int)};
-            runnerPrint!(runtime, true, 2, "BUILTIN: intBitsToFloat {} {}", int, float);
-            runtime.push_on_stack(Variable::Float(float));
-        },
-        ("java/lang/Double", "doubleToRawLongBits", "(D)J") => {
-            let double = args[0].to_double();
-            let bits = unsafe {std::mem::transmute::<f64, u64>(double)};
-            runnerPrint!(runtime, true, 2, "BUILTIN: doubleToRawIntBits {} {}", double, bits);
-            runtime.push_on_stack(Variable::Long(bits as i64));
-        },
-        ("java/lang/Double", "longBitsToDouble", "(J)D") => {
-            let long = args[0].to_long();
-            let double = unsafe {std::mem::transmute::<i64, f64>(long)};
-            runnerPrint!(runtime, true, 2, "BUILTIN: doubleToRawIntBits {} {}", long, double);
-            runtime.push_on_stack(Variable::Double(double));
-        },
-        ("java/lang/SecurityManager", "checkPermission", "(Ljava/security/Permission;)V") => {
-        },
-        ("java/lang/Object", "hashCode", "()I") => {
-            let code = try!(args[0].hash_code(runtime));
-            runnerPrint!(runtime, true, 2, "BUILTIN: hashcode {}", code);
-            runtime.push_on_stack(Variable::Int(code));
-        },
-        ("java/lang/System", "identityHashCode", "(Ljava/lang/Object;)I") => {
-            let code = try!(args[0].hash_code(runtime));
-            runnerPrint!(runtime, true, 2, "BUILTIN: identityHashCode {}", code); // TODO test
-            runtime.push_on_stack(Variable::Int(code));
-        },
-        ("java/lang/Object", "getClass", "()Ljava/lang/Class;") => {
-            let ref descriptor = args[0].get_descriptor();
-            let var = try!(get_class_object_from_descriptor(runtime, descriptor.as_str()));
-            runnerPrint!(runtime, true, 2, "BUILTIN: getClass {} {}", descriptor, var);
-            runtime.push_on_stack(var);
-        },
-        ("java/lang/ClassLoader", "registerNatives", "()V") => {},
-        ("java/lang/Thread", "registerNatives", "()V") => {},
-        ("java/lang/Thread", "isAlive", "()Z") => {
-            let obj = args[0].clone().to_ref();
-            let var = obj.unwrap().get_member(&String::from("__alive")).unwrap_or(Variable::Boolean(false)).clone();
-            runnerPrint!(runtime, true, 2, "BUILTIN: isAlive {}", var);
-            runtime.push_on_stack(var);
-        },
-        ("java/lang/Thread", "start0", "()V") => {
-            // TODO
+
+        {
+            let var = try!(construct_object(runtime, &"java/lang/Thread"));
+
+            runtime.current_thread = Some(var.clone());
+            let obj = var.to_ref();
+
+            let thread_str = try!(make_string(runtime, &"thread"));
+            try!(put_field(runtime, &obj, &String::from("name"), thread_str));
+            try!(put_field(runtime, &obj, &String::from("priority"), Variable::Int(1)));
+            try!(put_field(runtime, &obj, &String::from("group"), thread_group));
+            try!(put_field(runtime, &obj, &String::from("__alive"), Variable::Boolean(true)));
         }
-        ("java/lang/Thread", "setPriority0", "(I)V") => {
-            let obj = args[0].clone().to_ref();
-            runnerPrint!(runtime, true, 2, "BUILTIN: setPriority0 {} {}", args[0], args[1]);
-            try!(put_field(runtime, &obj.clone(), &"priority", args[1].clone()));
+    }
+    let thread = runtime.current_thread.as_ref().unwrap().clone();
+    runtime.push_on_stack(thread);
+    return Ok(());
+}
+
+// The array a java.lang.reflect.Array native was handed, which may be null or something else entirely
+fn reflected_array(runtime: &mut Runtime, var: &Variable) -> Result<Rc<ArrayObject>, RunnerError> {
+    if var.is_null() {
+        let exception = try!(construct_object(runtime, "java/lang/NullPointerException"));
+        return Err(RunnerError::Exception(exception));
+    }
+    return match var {
+        &Variable::ArrayReference(ref array) => Ok(array.clone()),
+        _ => Err(RunnerError::Exception(try!(construct_exception(runtime, "java/lang/IllegalArgumentException", "Argument is not an array")))),
+    };
+}
+
+fn reflected_array_index(runtime: &mut Runtime, array: &ArrayObject, index: i32) -> Result<usize, RunnerError> {
+    if index < 0 || index as usize >= array.elements.borrow().len() {
+        let exception = try!(construct_exception(runtime, "java/lang/ArrayIndexOutOfBoundsException", index.to_string().as_str()));
+        return Err(RunnerError::Exception(exception));
+    }
+    return Ok(index as usize);
+}
+
+// L for arrays of objects, [ for arrays of arrays, otherwise the primitive elements' descriptor
+fn array_element_char(array: &ArrayObject) -> char {
+    if array.element_type_ref.is_some() {
+        return 'L';
+    }
+    return array.element_type_str.chars().next().unwrap();
+}
+
+fn type_mismatch(runtime: &mut Runtime, message: &str) -> Result<(), RunnerError> {
+    return Err(RunnerError::Exception(try!(construct_exception(runtime, "java/lang/IllegalArgumentException", message))));
+}
+
+fn array_get_length(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let array = try!(reflected_array(runtime, &args[0]));
+    let length = array.elements.borrow().len();
+    runnerPrint!(runtime, true, 2, "BUILTIN: Array.getLength {}", length);
+    runtime.push_on_stack(Variable::Int(length as i32));
+    return Ok(());
+}
+
+fn array_get(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let array = try!(reflected_array(runtime, &args[0]));
+    let index = try!(reflected_array_index(runtime, &array, args[1].to_int()));
+    let element = array.elements.borrow()[index].clone();
+    runnerPrint!(runtime, true, 2, "BUILTIN: Array.get {} {}", index, element);
+    let var = match array_element_char(&array) {
+        'L' | '[' => element,
+        type_char => try!(box_primitive(runtime, type_char, &element)),
+    };
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+// The typed getters widen the element the way a primitive assignment would
+fn array_get_typed(runtime: &mut Runtime, args: &Vec<Variable>, type_char: char) -> Result<(), RunnerError> {
+    let array = try!(reflected_array(runtime, &args[0]));
+    let index = try!(reflected_array_index(runtime, &array, args[1].to_int()));
+    let element = array.elements.borrow()[index].clone();
+    runnerPrint!(runtime, true, 2, "BUILTIN: Array.get{} {} {}", type_char, index, element);
+    return match widen_primitive(array_element_char(&array), type_char, &element) {
+        Some(var) => {
+            runtime.push_on_stack(var);
+            Ok(())
         }
-        ("java/lang/Thread", "currentThread", "()Ljava/lang/Thread;") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: currentThread");
-            if runtime.current_thread.is_none() {
-                runnerPrint!(runtime, true, 2, "BUILTIN: currentThread - creating thread");
-                let thread_group;
-                {
-                    let var = try!(construct_object(runtime, &"java/lang/ThreadGroup"));
-                    let obj = var.to_ref();
-                    try!(invoke_nested(runtime, obj.unwrap().type_ref().clone(), vec!(var.clone()), "<init>", "()V", false));
-                    thread_group = var.clone();
-                }
-
-                {
-                    let var = try!(construct_object(runtime, &"java/lang/Thread"));
-
-                    runtime.current_thread = Some(var.clone());
-                    let obj = var.to_ref();
-
-                    let thread_str = try!(make_string(runtime, &"thread"));
-                    try!(put_field(runtime, &obj, &String::from("name"), thread_str));
-                    try!(put_field(runtime, &obj, &String::from("priority"), Variable::Int(1)));
-                    try!(put_field(runtime, &obj, &String::from("group"), thread_group));
-                    try!(put_field(runtime, &obj, &String::from("__alive"), Variable::Boolean(true)));
-                }
+        None => type_mismatch(runtime, "argument type mismatch"),
+    };
+}
+
+fn array_get_boolean(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_get_typed(runtime, args, 'Z'); }
+fn array_get_byte(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_get_typed(runtime, args, 'B'); }
+fn array_get_char(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_get_typed(runtime, args, 'C'); }
+fn array_get_short(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_get_typed(runtime, args, 'S'); }
+fn array_get_int(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_get_typed(runtime, args, 'I'); }
+fn array_get_long(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_get_typed(runtime, args, 'J'); }
+fn array_get_float(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_get_typed(runtime, args, 'F'); }
+fn array_get_double(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_get_typed(runtime, args, 'D'); }
+
+fn array_set(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let array = try!(reflected_array(runtime, &args[0]));
+    let index = try!(reflected_array_index(runtime, &array, args[1].to_int()));
+    runnerPrint!(runtime, true, 2, "BUILTIN: Array.set {} {}", index, args[2]);
+    let value = match array_element_char(&array) {
+        'L' | '[' => {
+            let element_type = Variable::ArrayReference(array.clone()).get_descriptor()[1..].to_string();
+            if !try!(is_assignable_value(runtime, element_type.as_str(), &args[2])) {
+                return type_mismatch(runtime, "array element type mismatch");
             }
-            let thread = runtime.current_thread.as_ref().unwrap().clone();
-            runtime.push_on_stack(thread);
-        },
-        _ => return Ok(false)
+            args[2].clone()
+        }
+        type_char => match try!(unbox_primitive(runtime, type_char, &args[2])) {
+            Some(unboxed) => unboxed,
+            None => return type_mismatch(runtime, "argument type mismatch"),
+        }
     };
-    return Ok(true);
+    array.elements.borrow_mut()[index] = value;
+    return Ok(());
+}
+
+// The typed setters widen the value to the element type, long and double values take two argument slots
+fn array_set_typed(runtime: &mut Runtime, args: &Vec<Variable>, type_char: char) -> Result<(), RunnerError> {
+    let array = try!(reflected_array(runtime, &args[0]));
+    let index = try!(reflected_array_index(runtime, &array, args[1].to_int()));
+    runnerPrint!(runtime, true, 2, "BUILTIN: Array.set{} {} {}", type_char, index, args[2]);
+    return match widen_primitive(type_char, array_element_char(&array), &args[2]) {
+        Some(value) => {
+            array.elements.borrow_mut()[index] = value;
+            Ok(())
+        }
+        None => type_mismatch(runtime, "argument type mismatch"),
+    };
+}
+
+fn array_set_boolean(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_set_typed(runtime, args, 'Z'); }
+fn array_set_byte(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_set_typed(runtime, args, 'B'); }
+fn array_set_char(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_set_typed(runtime, args, 'C'); }
+fn array_set_short(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_set_typed(runtime, args, 'S'); }
+fn array_set_int(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_set_typed(runtime, args, 'I'); }
+fn array_set_long(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_set_typed(runtime, args, 'J'); }
+fn array_set_float(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_set_typed(runtime, args, 'F'); }
+fn array_set_double(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> { return array_set_typed(runtime, args, 'D'); }
+
+// Builds one level of a new array whose elements have the given type, filling in the levels below when there are more lengths
+fn new_array_level(runtime: &mut Runtime, element_type: &str, lengths: &[i32]) -> Result<Variable, RunnerError> {
+    let mut elements = Vec::new();
+    for _ in 0..lengths[0] {
+        elements.push(if lengths.len() > 1 {
+            try!(new_array_level(runtime, &element_type[1..], &lengths[1..]))
+        } else {
+            try!(default_value(runtime, element_type))
+        });
+    }
+    return construct_array_by_descriptor(runtime, element_type, Some(elements));
+}
+
+fn new_reflected_array(runtime: &mut Runtime, component_type: &Variable, lengths: &[i32]) -> Result<Variable, RunnerError> {
+    if component_type.is_null() {
+        let exception = try!(construct_object(runtime, "java/lang/NullPointerException"));
+        return Err(RunnerError::Exception(exception));
+    }
+    let component = try!(descriptor_of_class_object(runtime, component_type));
+    if component == "V" || lengths.is_empty() {
+        return Err(RunnerError::Exception(try!(construct_exception(runtime, "java/lang/IllegalArgumentException", "Array of void or without dimensions"))));
+    }
+    if let Some(length) = lengths.iter().find(|x| **x < 0) {
+        let exception = try!(construct_exception(runtime, "java/lang/NegativeArraySizeException", length.to_string().as_str()));
+        return Err(RunnerError::Exception(exception));
+    }
+    let element_type = format!("{}{}", "[".repeat(lengths.len() - 1), component);
+    return new_array_level(runtime, element_type.as_str(), lengths);
+}
+
+fn array_new_array(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: Array.newArray {} {}", args[0], args[1]);
+    let var = try!(new_reflected_array(runtime, &args[0], &[args[1].to_int()]));
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn array_multi_new_array(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: Array.multiNewArray {} {}", args[0], args[1]);
+    let lengths: Vec<i32> = try!(reflected_array(runtime, &args[1])).elements.borrow().iter().map(|x| x.to_int()).collect();
+    let var = try!(new_reflected_array(runtime, &args[0], &lengths));
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+// Proxy generates the class file in Java and only needs it loaded. The name comes with dots
+fn proxy_define_class0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let name = args[1].extract_string().replace('.', "/");
+    let offset = args[3].to_int();
+    let length = args[4].to_int();
+    runnerPrint!(runtime, true, 2, "BUILTIN: Proxy.defineClass0 {} {} {}", name, offset, length);
+    let array = args[2].to_arrayobj();
+    if array.is_null {
+        let exception = try!(construct_object(runtime, &"java/lang/NullPointerException"));
+        return Err(RunnerError::Exception(exception));
+    }
+    if offset < 0 || length < 0 || offset as i64 + length as i64 > array.elements.borrow().len() as i64 {
+        let exception = try!(construct_object(runtime, &"java/lang/ArrayIndexOutOfBoundsException"));
+        return Err(RunnerError::Exception(exception));
+    }
+    let data: Vec<u8> = array.elements.borrow()[offset as usize..(offset + length) as usize].iter().map(|x| x.to_byte() as u8).collect();
+    try!(define_class(runtime, name.as_str(), data.as_slice()));
+    let var = try!(get_class_object_from_name(runtime, name.as_str()));
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+pub fn register(m: &mut NativeTable) {
+    m.insert(("java/lang/Class", "registerNatives", "()V"), nop);
+    m.insert(("java/lang/Class", "isArray", "()Z"), class_is_array);
+    m.insert(("java/lang/Class", "isPrimitive", "()Z"), class_is_primitive);
+    m.insert(("java/lang/Class", "getPrimitiveClass", "(Ljava/lang/String;)Ljava/lang/Class;"), class_get_primitive_class);
+    m.insert(("java/lang/Class", "isAssignableFrom", "(Ljava/lang/Class;)Z"), class_is_assignable_from);
+    m.insert(("java/lang/Class", "getComponentType", "()Ljava/lang/Class;"), class_get_component_type);
+    m.insert(("java/lang/Class", "forName0", "(Ljava/lang/String;ZLjava/lang/ClassLoader;Ljava/lang/Class;)Ljava/lang/Class;"), class_for_name0);
+    m.insert(("java/lang/Class", "desiredAssertionStatus0", "(Ljava/lang/Class;)Z"), class_desired_assertion_status0);
+    m.insert(("java/lang/Class", "getDeclaredFields0", "(Z)[Ljava/lang/reflect/Field;"), class_get_declared_fields0);
+    m.insert(("java/lang/Class", "getDeclaredMethods0", "(Z)[Ljava/lang/reflect/Method;"), class_get_declared_methods0);
+    m.insert(("java/lang/Class", "getDeclaredConstructors0", "(Z)[Ljava/lang/reflect/Constructor;"), class_get_declared_constructors0);
+    m.insert(("java/lang/Class", "getModifiers", "()I"), class_get_modifiers);
+    m.insert(("java/lang/Class", "getSuperclass", "()Ljava/lang/Class;"), class_get_superclass);
+    m.insert(("java/lang/Class", "isInterface", "()Z"), class_is_interface);
+    m.insert(("java/lang/Class", "getInterfaces0", "()[Ljava/lang/Class;"), class_get_interfaces0);
+    m.insert(("java/lang/Class", "getDeclaringClass0", "()Ljava/lang/Class;"), class_get_declaring_class0);
+    m.insert(("java/lang/Class", "getEnclosingMethod0", "()[Ljava/lang/Object;"), class_get_enclosing_method0);
+    m.insert(("java/lang/Class", "getSimpleBinaryName", "()Ljava/lang/String;"), class_get_simple_binary_name);
+    m.insert(("java/lang/Class", "getGenericSignature0", "()Ljava/lang/String;"), class_get_generic_signature0);
+    m.insert(("java/lang/Class", "getRawAnnotations", "()[B"), class_get_raw_annotations);
+    m.insert(("java/lang/Class", "getRawTypeAnnotations", "()[B"), class_get_raw_type_annotations);
+    m.insert(("java/lang/Class", "getConstantPool", "()Lsun/reflect/ConstantPool;"), class_get_constant_pool);
+    m.insert(("java/lang/reflect/Field", "getTypeAnnotationBytes0", "()[B"), field_get_type_annotation_bytes0);
+    m.insert(("java/lang/reflect/Executable", "getTypeAnnotationBytes0", "()[B"), executable_get_type_annotation_bytes0);
+    m.insert(("java/lang/System", "arraycopy", "(Ljava/lang/Object;ILjava/lang/Object;II)V"), system_arraycopy);
+    m.insert(("java/lang/System", "registerNatives", "()V"), nop);
+    m.insert(("java/lang/System", "initProperties", "(Ljava/util/Properties;)Ljava/util/Properties;"), system_init_properties);
+    m.insert(("java/lang/ProcessEnvironment", "environ", "()[[B"), process_environment_environ);
+    m.insert(("java/lang/System", "setIn0", "(Ljava/io/InputStream;)V"), system_set_in0);
+    m.insert(("java/lang/System", "setOut0", "(Ljava/io/PrintStream;)V"), system_set_out0);
+    m.insert(("java/lang/System", "setErr0", "(Ljava/io/PrintStream;)V"), system_set_err0);
+    m.insert(("java/lang/System", "loadLibrary", "(Ljava/lang/String;)V"), system_load_library);
+    m.insert(("java/lang/Shutdown", "halt0", "(I)V"), shutdown_halt0);
+    m.insert(("java/lang/Shutdown", "beforeHalt", "()V"), nop);
+    m.insert(("java/lang/Shutdown", "runAllFinalizers", "()V"), nop);
+    m.insert(("java/lang/ApplicationShutdownHooks", "runHooks", "()V"), application_shutdown_hooks_run_hooks);
+    m.insert(("java/lang/Runtime", "availableProcessors", "()I"), runtime_available_processors);
+    m.insert(("java/lang/Runtime", "freeMemory", "()J"), runtime_free_memory);
+    m.insert(("java/lang/Runtime", "gc", "()V"), runtime_gc);
+    m.insert(("java/lang/Object", "registerNatives", "()V"), nop);
+    m.insert(("java/lang/Object", "notifyAll", "()V"), object_notify_all);
+    m.insert(("java/lang/String", "intern", "()Ljava/lang/String;"), string_intern_native);
+    m.insert(("java/lang/Float", "floatToRawIntBits", "(F)I"), float_float_to_raw_int_bits);
+    m.insert(("java/lang/Float", "intBitsToFloat", "(I)F"), float_int_bits_to_float);
+    m.insert(("java/lang/Double", "doubleToRawLongBits", "(D)J"), double_double_to_raw_long_bits);
+    m.insert(("java/lang/Double", "longBitsToDouble", "(J)D"), double_long_bits_to_double);
+    m.insert(("java/lang/SecurityManager", "checkPermission", "(Ljava/security/Permission;)V"), nop);
+    m.insert(("java/lang/Object", "hashCode", "()I"), object_hash_code);
+    m.insert(("java/lang/System", "identityHashCode", "(Ljava/lang/Object;)I"), system_identity_hash_code);
+    m.insert(("java/lang/System", "currentTimeMillis", "()J"), system_current_time_millis);
+    m.insert(("java/lang/System", "nanoTime", "()J"), system_nano_time);
+    m.insert(("java/lang/Object", "getClass", "()Ljava/lang/Class;"), object_get_class);
+    m.insert(("java/lang/Object", "clone", "()Ljava/lang/Object;"), object_clone);
+    m.insert(("java/lang/Throwable", "fillInStackTrace", "(I)Ljava/lang/Throwable;"), throwable_fill_in_stack_trace);
+    m.insert(("java/lang/ClassLoader", "registerNatives", "()V"), nop);
+    m.insert(("java/lang/Thread", "registerNatives", "()V"), nop);
+    m.insert(("java/lang/Thread", "isAlive", "()Z"), thread_is_alive);
+    m.insert(("java/lang/Thread", "start0", "()V"), thread_start0);
+    m.insert(("java/lang/Thread", "setPriority0", "(I)V"), thread_set_priority0);
+    m.insert(("java/lang/Thread", "sleep", "(J)V"), thread_sleep);
+    m.insert(("java/lang/Thread", "currentThread", "()Ljava/lang/Thread;"), thread_current_thread);
+    m.insert(("java/lang/reflect/Array", "getLength", "(Ljava/lang/Object;)I"), array_get_length);
+    m.insert(("java/lang/reflect/Array", "get", "(Ljava/lang/Object;I)Ljava/lang/Object;"), array_get);
+    m.insert(("java/lang/reflect/Array", "getBoolean", "(Ljava/lang/Object;I)Z"), array_get_boolean);
+    m.insert(("java/lang/reflect/Array", "getByte", "(Ljava/lang/Object;I)B"), array_get_byte);
+    m.insert(("java/lang/reflect/Array", "getChar", "(Ljava/lang/Object;I)C"), array_get_char);
+    m.insert(("java/lang/reflect/Array", "getShort", "(Ljava/lang/Object;I)S"), array_get_short);
+    m.insert(("java/lang/reflect/Array", "getInt", "(Ljava/lang/Object;I)I"), array_get_int);
+    m.insert(("java/lang/reflect/Array", "getLong", "(Ljava/lang/Object;I)J"), array_get_long);
+    m.insert(("java/lang/reflect/Array", "getFloat", "(Ljava/lang/Object;I)F"), array_get_float);
+    m.insert(("java/lang/reflect/Array", "getDouble", "(Ljava/lang/Object;I)D"), array_get_double);
+    m.insert(("java/lang/reflect/Array", "set", "(Ljava/lang/Object;ILjava/lang/Object;)V"), array_set);
+    m.insert(("java/lang/reflect/Array", "setBoolean", "(Ljava/lang/Object;IZ)V"), array_set_boolean);
+    m.insert(("java/lang/reflect/Array", "setByte", "(Ljava/lang/Object;IB)V"), array_set_byte);
+    m.insert(("java/lang/reflect/Array", "setChar", "(Ljava/lang/Object;IC)V"), array_set_char);
+    m.insert(("java/lang/reflect/Array", "setShort", "(Ljava/lang/Object;IS)V"), array_set_short);
+    m.insert(("java/lang/reflect/Array", "setInt", "(Ljava/lang/Object;II)V"), array_set_int);
+    m.insert(("java/lang/reflect/Array", "setLong", "(Ljava/lang/Object;IJ)V"), array_set_long);
+    m.insert(("java/lang/reflect/Array", "setFloat", "(Ljava/lang/Object;IF)V"), array_set_float);
+    m.insert(("java/lang/reflect/Array", "setDouble", "(Ljava/lang/Object;ID)V"), array_set_double);
+    m.insert(("java/lang/reflect/Array", "newArray", "(Ljava/lang/Class;I)Ljava/lang/Object;"), array_new_array);
+    m.insert(("java/lang/reflect/Array", "multiNewArray", "(Ljava/lang/Class;[I)Ljava/lang/Object;"), array_multi_new_array);
+    m.insert(("java/lang/reflect/Proxy", "defineClass0", "(Ljava/lang/ClassLoader;Ljava/lang/String;[BII)Ljava/lang/Class;"), proxy_define_class0);
 }
@@ -1,52 +1,43 @@
 use reader::jvm::construction::*;
 use reader::jvm::interpreter::*;
 use reader::runner::*;
-use std::rc::Rc;
+use reader::builtins::*;
 
-pub fn try_builtin(class_name: &Rc<String>, method_name: &Rc<String>, descriptor: &Rc<String>, args: &Vec<Variable>, runtime: &mut Runtime) -> Result<bool, RunnerError> {
-    match (class_name.as_str(), method_name.as_str(), descriptor.as_str()) {
-        ("java/net/InetAddress", "init", "()V") => {}
-        ("java/net/InetAddressImplFactory", "isIPv6Supported", "()Z") => { runtime.push_on_stack(Variable::Boolean(false)); }
-        ("java/util/concurrent/atomic/AtomicLong", "VMSupportsCS8", "()Z") => { runtime.push_on_stack(Variable::Boolean(false)); }
-        ("java/io/FileInputStream", "initIDs", "()V") => {},
-        ("java/io/FileOutputStream", "initIDs", "()V") => {},
-        ("java/io/FileOutputStream", "writeBytes", "([BIIZ)V") => {
-            let fos = args[0].clone().to_ref();
-            let bytes = args[1].clone().to_arrayobj();
-            let offset = args[2].to_int();
-            let length = args[3].to_int();
-            let _append = args[4].to_bool();
+fn inet_address_impl_factory_is_ipv6_supported(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runtime.push_on_stack(Variable::Boolean(false));
+    return Ok(());
+}
+
+fn atomic_long_vmsupports_cs8(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runtime.push_on_stack(Variable::Boolean(false));
+    return Ok(());
+}
 
-            let file_descriptor = try!(get_field(runtime, &fos, &"java/io/FileOutputStream", "fd")).to_ref();
-            let file_descriptor_id = try!(get_field(runtime, &file_descriptor, &"java/io/FileDescriptor", "fd")).to_int();
+fn access_controller_do_privileged(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let action = args[0].clone().to_ref();
+    runnerPrint!(runtime, true, 2, "BUILTIN: doPrivileged {}", action.as_ref().unwrap());
+    try!(invoke_nested(runtime, action.unwrap().type_ref().clone(), args.clone(), "run", "()Ljava/lang/Object;", false));
+    return Ok(());
+}
 
-            let stream = match file_descriptor_id {
-                1 => &mut runtime.stdout,
-                2 => &mut runtime.stderr,
-                _ => return Ok(true)
-            };
+fn access_controller_do_privileged_2(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let action = args[0].clone().to_ref();
+    runnerPrint!(runtime, true, 2, "BUILTIN: doPrivileged (ExceptionAction) {}", action.as_ref().unwrap());
+    try!(invoke_nested(runtime, action.unwrap().type_ref().clone(), args.clone(), "run", "()Ljava/lang/Object;", false));
+    return Ok(());
+}
+
+fn access_controller_get_stack_access_control_context(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let ret = try!(construct_null_object_by_name(runtime, &"java/security/AccessControlContext"));
+    runtime.push_on_stack(ret);
+    return Ok(());
+}
 
-            let data = bytes.elements.borrow();
-            for n in offset..offset+length {
-                stream.push(data[n as usize].to_byte() as char);
-            }
-        },
-        ("java/io/FileDescriptor", "initIDs", "()V") => {},
-        ("java/security/AccessController", "doPrivileged", "(Ljava/security/PrivilegedAction;)Ljava/lang/Object;") => {
-            let action = args[0].clone().to_ref();
-            runnerPrint!(runtime, true, 2, "BUILTIN: doPrivileged {}", action.as_ref().unwrap());
-            try!(invoke_nested(runtime, action.unwrap().type_ref().clone(), args.clone(), "run", "()Ljava/lang/Object;", false));
-        }
-        ("java/security/AccessController", "doPrivileged", "(Ljava/security/PrivilegedExceptionAction;)Ljava/lang/Object;") => {
-            let action = args[0].clone().to_ref();
-            runnerPrint!(runtime, true, 2, "BUILTIN: doPrivileged (ExceptionAction) {}", action.as_ref().unwrap());
-            try!(invoke_nested(runtime, action.unwrap().type_ref().clone(), args.clone(), "run", "()Ljava/lang/Object;", false));
-        }
-        ("java/security/AccessController", "getStackAccessControlContext", "()Ljava/security/AccessControlContext;") => {
-            let ret = try!(construct_null_object_by_name(runtime, &"java/security/AccessControlContext"));
-            runtime.push_on_stack(ret);
-        }
-        _ => return Ok(false)
-    };
-    return Ok(true);
+pub fn register(m: &mut NativeTable) {
+    m.insert(("java/net/InetAddress", "init", "()V"), nop);
+    m.insert(("java/net/InetAddressImplFactory", "isIPv6Supported", "()Z"), inet_address_impl_factory_is_ipv6_supported);
+    m.insert(("java/util/concurrent/atomic/AtomicLong", "VMSupportsCS8", "()Z"), atomic_long_vmsupports_cs8);
+    m.insert(("java/security/AccessController", "doPrivileged", "(Ljava/security/PrivilegedAction;)Ljava/lang/Object;"), access_controller_do_privileged);
+    m.insert(("java/security/AccessController", "doPrivileged", "(Ljava/security/PrivilegedExceptionAction;)Ljava/lang/Object;"), access_controller_do_privileged_2);
+    m.insert(("java/security/AccessController", "getStackAccessControlContext", "()Ljava/security/AccessControlContext;"), access_controller_get_stack_access_control_context);
 }
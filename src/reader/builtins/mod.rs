@@ -1,22 +1,80 @@
+mod java_io;
 mod java_lang;
 mod java_other;
 mod sun;
 
 use reader::runner::*;
+use reader::types::method::Method;
+use std::collections::HashMap;
+use std::fmt;
 use std::rc::Rc;
 
-pub fn try_builtin(class_name: &Rc<String>, method_name: &Rc<String>, descriptor: &Rc<String>, args: &Vec<Variable>, runtime: &mut Runtime) -> Result<bool, RunnerError> {
-    runnerPrint!(runtime, true, 4, "try_builtin {} {} {}", class_name, method_name, descriptor);
+pub type NativeFn = fn(&mut Runtime, &Vec<Variable>) -> Result<(), RunnerError>;
 
-    if try!(java_lang::try_builtin(class_name, method_name, descriptor, args, runtime)) {
-        return Ok((true));
+pub type NativeTable = HashMap<(&'static str, &'static str, &'static str), NativeFn>;
+
+#[derive(Clone)]
+pub enum Native {
+    Builtin(NativeFn),
+    Registered(NativeMethod),
+}
+
+impl fmt::Debug for Native {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            &Native::Builtin(_) => write!(f, "Builtin"),
+            &Native::Registered(_) => write!(f, "Registered"),
+        };
     }
-    if try!(java_other::try_builtin(class_name, method_name, descriptor, args, runtime)) {
-        return Ok((true));
+}
+
+lazy_static! {
+    static ref builtins: NativeTable = {
+        let mut m = HashMap::new();
+        java_io::register(&mut m);
+        java_lang::register(&mut m);
+        java_other::register(&mut m);
+        sun::register(&mut m);
+        m
+    };
+}
+
+pub fn nop(_runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return Ok(());
+}
+
+pub fn resolve_native(runtime: &Runtime, class_name: &str, method_name: &str, descriptor: &str) -> Option<Native> {
+    let maybe_registered = runtime.registered_native(class_name, method_name, descriptor);
+    if maybe_registered.is_some() {
+        return Some(Native::Registered(maybe_registered.unwrap().clone()));
     }
-    if try!(sun::try_builtin(class_name, method_name, descriptor, args, runtime)) {
-        return Ok((true));
+    return builtins.get(&(class_name, method_name, descriptor)).map(|x| Native::Builtin(*x));
+}
+
+// Static methods may be overridden by a native even when they have bytecode, instance methods only when declared native
+pub fn link_native(runtime: &Runtime, class_name: &str, method: &Method) {
+    *method.native.borrow_mut() = if method.is_native() || method.is_static() {
+        resolve_native(runtime, class_name, method.name.as_str(), method.descriptor.as_str())
+    } else {
+        None
+    };
+}
+
+pub fn link_natives(runtime: &Runtime, class: &Rc<Class>) {
+    for method in class.methods.iter() {
+        link_native(runtime, class.name.as_str(), method);
     }
+}
 
-    return Ok(false);
+pub fn call_native(runtime: &mut Runtime, native: &Native, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return match native {
+        &Native::Builtin(function) => function(runtime, args),
+        &Native::Registered(ref function) => {
+            let ret = try!(function(runtime, args));
+            if ret.is_some() {
+                runtime.push_on_stack(ret.unwrap());
+            }
+            Ok(())
+        }
+    };
 }
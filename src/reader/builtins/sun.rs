@@ -1,8 +1,11 @@
 use reader::runner::*;
+use reader::class_reader::*;
 use reader::jvm::class_objects::*;
+use reader::jvm::construction::*;
+use reader::jvm::interpreter::*;
 use reader::util::*;
 use std;
-use std::rc::Rc;
+use reader::builtins::*;
 
 fn get_at_index<F, G>(runtime: &mut Runtime, args: &Vec<Variable>, desc: &str, validator: F)-> Result<(), RunnerError>
     where F: Fn(&Variable) -> G
@@ -16,14 +19,14 @@ fn get_at_index<F, G>(runtime: &mut Runtime, args: &Vec<Variable>, desc: &str, v
     return Ok(());
 }
 
-fn compare_and_swap<F, G>(runtime: &mut Runtime, args: &Vec<Variable>, desc: &str, extractor: F, second_offset: usize) -> Result<(), RunnerError>
+fn compare_and_swap<F, G>(runtime: &mut Runtime, args: &Vec<Variable>, desc: &str, extractor: F) -> Result<(), RunnerError>
     where F: Fn(&Variable) -> G,
           G: std::cmp::PartialEq
 {
     let obj = args[1].clone().to_ref();
-    let offset = args[2].to_long(); // 2 slots :(
-    let expected = extractor(&args[4].clone());
-    let swap = args[5 + second_offset].clone();
+    let offset = args[2].to_long();
+    let expected = extractor(&args[3].clone());
+    let swap = args[4].clone();
 
     let current = extractor(&obj.as_ref().unwrap().get_member_at_offset(offset as usize).unwrap());
     //runnerPrint!(runtime, true, 2, "BUILTIN: {} {} {} {} {} {}", desc, obj.as_ref().unwrap(), offset, current, expected, swap);
@@ -50,87 +53,454 @@ fn deallocate(ptr: *mut u8, count: usize) {
     unsafe {std::mem::drop(Vec::from_raw_parts(ptr, 0, count)) };
 }
 
-pub fn try_builtin(class_name: &Rc<String>, method_name: &Rc<String>, descriptor: &Rc<String>, args: &Vec<Variable>, runtime: &mut Runtime) -> Result<bool, RunnerError> {
-    match (class_name.as_str(), method_name.as_str(), descriptor.as_str()) {
-        ("sun/misc/Signal", "findSignal", "(Ljava/lang/String;)I") => {
-            let signal = args[0].clone().extract_string();
-            runnerPrint!(runtime, true, 2, "BUILTIN: TODO findSignal {}", signal);
-            runtime.push_on_stack(Variable::Int(0));
+fn signal_find_signal(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let signal = args[0].clone().extract_string();
+    runnerPrint!(runtime, true, 2, "BUILTIN: TODO findSignal {}", signal);
+    runtime.push_on_stack(Variable::Int(0));
+    return Ok(());
+}
+
+fn signal_handle0(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: TODO signal handle0");
+    runtime.push_on_stack(Variable::Long(0));
+    return Ok(());
+}
+
+fn unsafe_array_base_offset(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: arrayBaseOffset");
+    runtime.push_on_stack(Variable::Int(0));
+    return Ok(());
+}
+
+fn unsafe_object_field_offset(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let obj = args[1].clone().to_ref();
+    let slot = try!(get_field(runtime, &obj, &"java/lang/reflect/Field", "slot")).to_int();
+
+    runnerPrint!(runtime, true, 2, "BUILTIN: objectFieldOffset {} {}", obj.unwrap(), slot);
+    runtime.push_on_stack(Variable::Long(slot as i64));
+    return Ok(());
+}
+
+fn unsafe_array_index_scale(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: arrayIndexScale");
+    runtime.push_on_stack(Variable::Int(1));
+    return Ok(());
+}
+
+fn unsafe_address_size(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: addressSize");
+    runtime.push_on_stack(Variable::Int(4));
+    return Ok(());
+}
+
+fn unsafe_page_size(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: pageSize");
+    runtime.push_on_stack(Variable::Int(4096));
+    return Ok(());
+}
+
+fn unsafe_allocate_memory(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let size = args[1].to_long();
+    runnerPrint!(runtime, true, 2, "BUILTIN: allocateMemory {}", size);
+    // TODO: abstract these memory allocations so everything is SAFE? Or continue to allow java to have raw memory access.
+    let ptr = allocate(size as usize + 8);
+    unsafe { *(ptr as *mut i64) = size; }
+    runtime.push_on_stack(Variable::Long(ptr as i64 + 8));
+    return Ok(());
+}
+
+fn unsafe_free_memory(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let ptr = args[1].to_long() - 8;
+    let size = unsafe {*(ptr as *mut i64)} as usize;
+    runnerPrint!(runtime, true, 2, "BUILTIN: freeMemory {} {}", ptr, size);
+    deallocate(ptr as *const usize as *const _ as *mut _, size);
+    return Ok(());
+}
+
+fn unsafe_put_long(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let ptr = args[1].to_long();
+    let value = args[2].to_long();
+    runnerPrint!(runtime, true, 2, "BUILTIN: putLong {} {}", ptr, value);
+    unsafe { *(ptr as *mut u64) = value as u64; }
+    return Ok(());
+}
+
+fn unsafe_get_byte(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let ptr = args[1].to_long();
+    let byte = unsafe { *(ptr as *mut i8) };
+    runnerPrint!(runtime, true, 2, "BUILTIN: getByte {} {}", ptr, byte);
+    runtime.push_on_stack(Variable::Byte(byte));
+    return Ok(());
+}
+
+fn unsafe_get_object_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getObjectVolatile", Variable::to_ref);
+}
+
+fn unsafe_get_int_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getIntVolatile", Variable::to_int);
+}
+
+fn unsafe_get_boolean_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getBooleanVolatile", Variable::to_bool);
+}
+
+fn unsafe_get_byte_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getByteVolatile", Variable::to_byte);
+}
+
+fn unsafe_get_short_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getShortVolatile", Variable::to_short);
+}
+
+fn unsafe_get_char_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getCharVolatile", Variable::to_char);
+}
+
+fn unsafe_get_long_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getLongVolatile", Variable::to_long);
+}
+
+fn unsafe_get_float_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getFloatVolatile", Variable::to_float);
+}
+
+fn unsafe_get_double_volatile(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return get_at_index(runtime, args, "getDoubleVolatile", Variable::to_double);
+}
+
+fn unsafe_compare_and_swap_object(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return compare_and_swap(runtime, args, "compareAndSwapObject", Variable::to_ref);
+}
+
+fn unsafe_compare_and_swap_int(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return compare_and_swap(runtime, args, "compareAndSwapInt", Variable::to_int);
+}
+
+fn unsafe_compare_and_swap_long(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return compare_and_swap(runtime, args, "compareAndSwapLong", Variable::to_long);
+}
+
+fn reflection_get_caller_class(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class = runtime.previous_frames[runtime.previous_frames.len()-1].class.clone().unwrap();
+    let var = try!(get_class_object_from_descriptor(runtime, type_name_to_descriptor(&class.name).as_str()));
+    runnerPrint!(runtime, true, 2, "BUILTIN: getCallerClass {}", var);
+    runtime.push_on_stack(var);
+    return Ok(());
+}
+
+fn class_access_flags(runtime: &mut Runtime, class_obj: &Variable) -> Result<u16, RunnerError> {
+    return Ok(try!(class_of(runtime, class_obj)).map(|x| x.cr.access_flags).unwrap_or(ACC_PUBLIC | ACC_FINAL | ACC_ABSTRACT));
+}
+
+fn reflection_get_class_access_flags(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let flags = match array_element_class_object(&args[0]) {
+        Some(element) => array_modifiers(try!(class_access_flags(runtime, &element))),
+        None => try!(class_access_flags(runtime, &args[0])),
+    };
+    runnerPrint!(runtime, true, 2, "BUILTIN: getClassAccessFlags {} {}", args[0], flags);
+    runtime.push_on_stack(Variable::Int(flags as i32));
+    return Ok(());
+}
+
+fn illegal_argument(runtime: &mut Runtime, message: &str) -> RunnerError {
+    return match construct_exception(runtime, "java/lang/IllegalArgumentException", message) {
+        Ok(exception) => RunnerError::Exception(exception),
+        Err(e) => e,
+    };
+}
+
+// The class, name and descriptor a Method or Constructor object's slot refers to
+fn reflected_executable(runtime: &mut Runtime, executable: &Variable, class_name: &str) -> Result<(Rc<Class>, Rc<String>, Rc<String>), RunnerError> {
+    let clazz = try!(get_field(runtime, &executable.to_ref(), class_name, "clazz"));
+    let slot = try!(get_field(runtime, &executable.to_ref(), class_name, "slot")).to_int() as usize;
+    let class = try!(try!(class_of(runtime, &clazz)).ok_or(RunnerError::ClassInvalid("Reflected executable of a class without methods")));
+    let (name, descriptor) = {
+        let method = try!(class.cr.methods.get(slot).ok_or(RunnerError::ClassInvalid("Reflected executable slot out of range")));
+        (try!(class.cr.constant_pool.get_str(method.name_index)), try!(class.cr.constant_pool.get_str(method.descriptor_index)))
+    };
+    let class = try!(load_class(runtime, class.name.as_str()));
+    return Ok((class, name, descriptor));
+}
+
+// Unboxes and checks the Object[] passed to invoke or newInstance against the parameter types
+fn reflected_arguments(runtime: &mut Runtime, descriptor: &str, array: &Variable) -> Result<Vec<Variable>, RunnerError> {
+    let parameters = try!(parameter_descriptors(descriptor));
+    let given = if array.is_null() {Vec::new()} else {array.to_arrayobj().elements.borrow().clone()};
+    if given.len() != parameters.len() {
+        return Err(illegal_argument(runtime, "wrong number of arguments"));
+    }
+    let mut arguments = Vec::new();
+    for (parameter, value) in parameters.iter().zip(given.iter()) {
+        let type_char = parameter.chars().next().unwrap();
+        let argument = match type_char {
+            'L' | '[' => {
+                if !try!(is_assignable_value(runtime, parameter.as_str(), value)) {
+                    return Err(illegal_argument(runtime, "argument type mismatch"));
+                }
+                value.clone()
+            }
+            _ => match try!(unbox_primitive(runtime, type_char, value)) {
+                Some(unboxed) => unboxed,
+                None => return Err(illegal_argument(runtime, "argument type mismatch")),
+            }
+        };
+        arguments.push(argument);
+    }
+    return Ok(arguments);
+}
+
+// Anything the target throws reaches the caller wrapped, the way reflection reports it
+fn invocation_target_exception(runtime: &mut Runtime, error: RunnerError) -> RunnerError {
+    return match error {
+        RunnerError::Exception(target) => {
+            let wrapped = construct_object(runtime, "java/lang/reflect/InvocationTargetException")
+                .and_then(|x| put_field(runtime, &x.to_ref(), "target", target).map(|_| x));
+            match wrapped {
+                Ok(exception) => RunnerError::Exception(exception),
+                Err(e) => e,
+            }
+        }
+        other => other,
+    };
+}
+
+fn native_method_accessor_invoke0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let (class, name, descriptor) = try!(reflected_executable(runtime, &args[0], "java/lang/reflect/Method"));
+    runnerPrint!(runtime, true, 2, "BUILTIN: invoke0 {} {} {}", class.name, name, descriptor);
+    let is_static = class.get_method(name.as_str(), descriptor.as_str()).map(|x| x.is_static()).unwrap_or(false);
+    let mut arguments = Vec::new();
+    let mut target = class.clone();
+    if !is_static {
+        let receiver = args[1].clone();
+        if receiver.is_null() {
+            let exception = try!(construct_object(runtime, "java/lang/NullPointerException"));
+            return Err(RunnerError::Exception(exception));
+        }
+        if !try!(is_assignable_value(runtime, generate_class_descriptor(&class).as_str(), &receiver)) {
+            return Err(illegal_argument(runtime, "object is not an instance of declaring class"));
+        }
+        let receiver_class = if receiver.is_reference() {receiver.to_ref().map(|x| x.type_ref())} else {None};
+        // Private methods aren't overridden, anything else dispatches on the receiver like invokevirtual
+        let is_private = class.get_method(name.as_str(), descriptor.as_str()).map(|x| x.access_flags & ACC_PRIVATE != 0).unwrap_or(false);
+        if !is_private {
+            if let Some((found, _)) = receiver_class.and_then(|x| Class::find_method(&x, name.as_str(), descriptor.as_str())) {
+                target = found;
+            }
+        }
+        arguments.push(receiver);
+    }
+    arguments.extend(try!(reflected_arguments(runtime, descriptor.as_str(), &args[2])));
+
+    if let Err(e) = invoke_nested(runtime, target, arguments, name.as_str(), descriptor.as_str(), false) {
+        return Err(invocation_target_exception(runtime, e));
+    }
+    let return_char = descriptor.splitn(2, ')').nth(1).and_then(|x| x.chars().next()).unwrap_or('V');
+    let ret = match return_char {
+        'V' => try!(construct_null_object_by_name(runtime, "Ljava/lang/Object;")),
+        'L' | '[' => runtime.current_frame.operand_stack.pop_typed(return_char),
+        _ => {
+            let value = runtime.current_frame.operand_stack.pop_typed(return_char);
+            try!(box_primitive(runtime, return_char, &value))
+        }
+    };
+    runtime.push_on_stack(ret);
+    return Ok(());
+}
+
+fn native_constructor_accessor_new_instance0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let (class, name, descriptor) = try!(reflected_executable(runtime, &args[0], "java/lang/reflect/Constructor"));
+    runnerPrint!(runtime, true, 2, "BUILTIN: newInstance0 {} {}", class.name, descriptor);
+    if class.cr.access_flags & (ACC_ABSTRACT | ACC_INTERFACE) != 0 {
+        let exception = try!(construct_exception(runtime, "java/lang/InstantiationException", class.name.as_str()));
+        return Err(RunnerError::Exception(exception));
+    }
+    let obj = try!(construct_object(runtime, class.name.as_str()));
+    let mut arguments = vec!(obj.clone());
+    arguments.extend(try!(reflected_arguments(runtime, descriptor.as_str(), &args[1])));
+    if let Err(e) = invoke_nested(runtime, class, arguments, name.as_str(), descriptor.as_str(), false) {
+        return Err(invocation_target_exception(runtime, e));
+    }
+    runtime.push_on_stack(obj);
+    return Ok(());
+}
+
+// The entry asked of a sun.reflect.ConstantPool, whose constantPoolOop is the Class object it was made for
+fn constant_pool_entry(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(Rc<Class>, ConstantPoolItem), RunnerError> {
+    let index = args[2].to_int();
+    let class = try!(try!(class_of(runtime, &args[1])).ok_or(RunnerError::ClassInvalid("ConstantPool without a class")));
+    runnerPrint!(runtime, true, 2, "BUILTIN: ConstantPool {} {}", class.name, index);
+    let entry = if index > 0 && index <= 0xFFFF {class.cr.constant_pool.pool.get(&(index as u16)).cloned()} else {None};
+    return match entry {
+        Some(entry) => Ok((class, entry)),
+        None => Err(RunnerError::Exception(try!(construct_exception(runtime, "java/lang/IllegalArgumentException", "Constant pool index out of bounds")))),
+    };
+}
+
+fn wrong_constant_type(runtime: &mut Runtime) -> Result<(), RunnerError> {
+    return Err(RunnerError::Exception(try!(construct_exception(runtime, "java/lang/IllegalArgumentException", "Wrong type at constant pool index"))));
+}
+
+fn constant_pool_get_size0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let class = try!(try!(class_of(runtime, &args[1])).ok_or(RunnerError::ClassInvalid("ConstantPool without a class")));
+    let size = class.cr.constant_pool.pool.keys().max().map(|x| *x as i32 + 1).unwrap_or(1);
+    runtime.push_on_stack(Variable::Int(size));
+    return Ok(());
+}
+
+fn constant_pool_get_class_at0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return match try!(constant_pool_entry(runtime, args)) {
+        (class, ConstantPoolItem::CONSTANT_Class{index}) => {
+            let name = try!(class.cr.constant_pool.get_str(index));
+            let var = try!(get_class_object_from_name(runtime, name.as_str()));
+            runtime.push_on_stack(var);
+            Ok(())
         }
-        ("sun/misc/Signal", "handle0", "(IJ)J") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: TODO signal handle0");
-            runtime.push_on_stack(Variable::Long(0));
+        _ => wrong_constant_type(runtime),
+    };
+}
+
+fn constant_pool_get_int_at0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return match try!(constant_pool_entry(runtime, args)) {
+        (_, ConstantPoolItem::CONSTANT_Integer{value}) => { runtime.push_on_stack(Variable::Int(value as i32)); Ok(()) }
+        _ => wrong_constant_type(runtime),
+    };
+}
+
+fn constant_pool_get_long_at0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return match try!(constant_pool_entry(runtime, args)) {
+        (_, ConstantPoolItem::CONSTANT_Long{value}) => { runtime.push_on_stack(Variable::Long(value as i64)); Ok(()) }
+        _ => wrong_constant_type(runtime),
+    };
+}
+
+fn constant_pool_get_float_at0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return match try!(constant_pool_entry(runtime, args)) {
+        (_, ConstantPoolItem::CONSTANT_Float{value}) => { runtime.push_on_stack(Variable::Float(value)); Ok(()) }
+        _ => wrong_constant_type(runtime),
+    };
+}
+
+fn constant_pool_get_double_at0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return match try!(constant_pool_entry(runtime, args)) {
+        (_, ConstantPoolItem::CONSTANT_Double{value}) => { runtime.push_on_stack(Variable::Double(value)); Ok(()) }
+        _ => wrong_constant_type(runtime),
+    };
+}
+
+fn constant_pool_get_string_at0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return match try!(constant_pool_entry(runtime, args)) {
+        (class, ConstantPoolItem::CONSTANT_String{index}) => {
+            let units = try!(class.cr.constant_pool.get_utf16(index));
+            let string = try!(make_string_utf16(runtime, &units));
+            let var = try!(string_intern(runtime, &string));
+            runtime.push_on_stack(var);
+            Ok(())
         }
-        ("sun/misc/Unsafe", "registerNatives", "()V") => {return Ok(true)},
-        ("sun/misc/Unsafe", "arrayBaseOffset", "(Ljava/lang/Class;)I") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: arrayBaseOffset");
-            runtime.push_on_stack(Variable::Int(0));
-        },
-        ("sun/misc/Unsafe", "objectFieldOffset", "(Ljava/lang/reflect/Field;)J") => {
-            let obj = args[1].clone().to_ref();
-            let slot = try!(get_field(runtime, &obj, &"java/lang/reflect/Field", "slot")).to_int();
-
-            runnerPrint!(runtime, true, 2, "BUILTIN: objectFieldOffset {} {}", obj.unwrap(), slot);
-            runtime.push_on_stack(Variable::Long(slot as i64));
-        },
-        ("sun/misc/Unsafe", "arrayIndexScale", "(Ljava/lang/Class;)I") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: arrayIndexScale");
-            runtime.push_on_stack(Variable::Int(1));
-        },
-        ("sun/misc/Unsafe", "addressSize", "()I") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: addressSize");
-            runtime.push_on_stack(Variable::Int(4));
-        },
-        ("sun/misc/Unsafe", "pageSize", "()I") => {
-            runnerPrint!(runtime, true, 2, "BUILTIN: pageSize");
-            runtime.push_on_stack(Variable::Int(4096));
-        },
-        ("sun/misc/Unsafe", "allocateMemory", "(J)J") => {
-            let size = args[1].to_long();
-            runnerPrint!(runtime, true, 2, "BUILTIN: allocateMemory {}", size);
-            // TODO: abstract these memory allocations so everything is SAFE? Or continue to allow java to have raw memory access.
-            let ptr = allocate(size as usize + 8);
-            unsafe { *(ptr as *mut i64) = size; }
-            runtime.push_on_stack(Variable::Long(ptr as i64 + 8));
-        },
-        ("sun/misc/Unsafe", "freeMemory", "(J)V") => {
-            let ptr = args[1].to_long() - 8;
-            let size = unsafe {*(ptr as *mut i64)} as usize;
-            runnerPrint!(runtime, true, 2, "BUILTIN: freeMemory {} {}", ptr, size);
-            deallocate(ptr as *const usize as *const _ as *mut _, size);
-        },
-        ("sun/misc/Unsafe", "putLong", "(JJ)V") => {
-            let ptr = args[1].to_long();
-            let value = args[3].to_long();
-            runnerPrint!(runtime, true, 2, "BUILTIN: putLong {} {}", ptr, value);
-            unsafe { *(ptr as *mut u64) = value as u64; }
-        },
-        ("sun/misc/Unsafe", "getByte", "(J)B") => {
-            let ptr = args[1].to_long();
-            let byte = unsafe { *(ptr as *mut u8) };
-            runnerPrint!(runtime, true, 2, "BUILTIN: getByte {} {}", ptr, byte);
-            runtime.push_on_stack(Variable::Byte(byte));
-        },
-        ("sun/misc/Unsafe", "getObjectVolatile", "(Ljava/lang/Object;J)Ljava/lang/Object;") => { try!(get_at_index(runtime, args, "getObjectVolatile", Variable::to_ref)); }
-        ("sun/misc/Unsafe", "getIntVolatile", "(Ljava/lang/Object;J)I") => { try!(get_at_index(runtime, args, "getIntVolatile", Variable::to_int)); }
-        ("sun/misc/Unsafe", "getBooleanVolatile", "(Ljava/lang/Object;J)Z") => { try!(get_at_index(runtime, args, "getBooleanVolatile", Variable::to_bool)); }
-        ("sun/misc/Unsafe", "getByteVolatile", "(Ljava/lang/Object;J)B") => { try!(get_at_index(runtime, args, "getByteVolatile", Variable::to_byte)); }
-        ("sun/misc/Unsafe", "getShortVolatile", "(Ljava/lang/Object;J)S") => { try!(get_at_index(runtime, args, "getShortVolatile", Variable::to_short)); }
-        ("sun/misc/Unsafe", "getCharVolatile", "(Ljava/lang/Object;J)C") => { try!(get_at_index(runtime, args, "getCharVolatile", Variable::to_char)); }
-        ("sun/misc/Unsafe", "getLongVolatile", "(Ljava/lang/Object;J)J") => { try!(get_at_index(runtime, args, "getLongVolatile", Variable::to_long)); }
-        ("sun/misc/Unsafe", "getFloatVolatile", "(Ljava/lang/Object;J)F") => { try!(get_at_index(runtime, args, "getFloatVolatile", Variable::to_float)); }
-        ("sun/misc/Unsafe", "getDoubleVolatile", "(Ljava/lang/Object;J)D") => { try!(get_at_index(runtime, args, "getDoubleVolatile", Variable::to_double)); }
-        ("sun/misc/Unsafe", "compareAndSwapObject", "(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Z") => { try!(compare_and_swap(runtime, args, "compareAndSwapObject", Variable::to_ref, 0));}
-        ("sun/misc/Unsafe", "compareAndSwapInt", "(Ljava/lang/Object;JII)Z") => { try!(compare_and_swap(runtime, args, "compareAndSwapInt", Variable::to_int, 0));}
-        ("sun/misc/Unsafe", "compareAndSwapLong", "(Ljava/lang/Object;JJJ)Z") => { try!(compare_and_swap(runtime, args, "compareAndSwapLong", Variable::to_long, 1));}
-        ("sun/misc/VM", "initialize", "()V") => {}
-        ("sun/reflect/Reflection", "getCallerClass", "()Ljava/lang/Class;") => {
-            let class = runtime.previous_frames[runtime.previous_frames.len()-1].class.clone().unwrap();
-            let var = try!(get_class_object_from_descriptor(runtime, type_name_to_descriptor(&class.name).as_str()));
-            runnerPrint!(runtime, true, 2, "BUILTIN: getCallerClass {}", var);
+        _ => wrong_constant_type(runtime),
+    };
+}
+
+fn constant_pool_get_utf8_at0(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    return match try!(constant_pool_entry(runtime, args)) {
+        (_, ConstantPoolItem::CONSTANT_Utf8(_, units)) => {
+            let var = try!(make_string_utf16(runtime, &units));
             runtime.push_on_stack(var);
+            Ok(())
         }
-        _ => return Ok(false)
+        _ => wrong_constant_type(runtime),
     };
-    return Ok(true);
+}
+
+// Performance counters live in an ordinary heap buffer, as nothing outside this process reads them
+fn perf_buffer(runtime: &mut Runtime, bytes: &[u8], capacity: i32) -> Result<Variable, RunnerError> {
+    let class = try!(load_class(runtime, "java/nio/ByteBuffer"));
+    try!(invoke_nested(runtime, class, vec!(Variable::Int(capacity)), "allocate", "(I)Ljava/nio/ByteBuffer;", false));
+    let buffer = runtime.current_frame.operand_stack.pop_ref();
+    let array = try!(get_field(runtime, &buffer.to_ref(), "java/nio/ByteBuffer", "hb")).to_arrayobj();
+    let mut elements = array.elements.borrow_mut();
+    for (n, byte) in bytes.iter().take(capacity as usize).enumerate() {
+        elements[n] = Variable::Byte(*byte as i8);
+    }
+    return Ok(buffer.clone());
+}
+
+fn perf_create_long(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let value = args[4].to_long();
+    runnerPrint!(runtime, true, 2, "BUILTIN: createLong {} {}", args[1].extract_string(), value);
+    let buffer = try!(perf_buffer(runtime, &value.to_ne_bytes(), 8));
+    runtime.push_on_stack(buffer);
+    return Ok(());
+}
+
+fn perf_create_byte_array(runtime: &mut Runtime, args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let bytes: Vec<u8> = args[4].to_arrayobj().elements.borrow().iter().map(|x| x.to_byte() as u8).collect();
+    let max_length = args[5].to_int();
+    runnerPrint!(runtime, true, 2, "BUILTIN: createByteArray {} {}", args[1].extract_string(), max_length);
+    if (bytes.len() as i32) > max_length {
+        return Err(illegal_argument(runtime, "value is longer than maxLength"));
+    }
+    let buffer = try!(perf_buffer(runtime, &bytes, max_length));
+    runtime.push_on_stack(buffer);
+    return Ok(());
+}
+
+fn perf_high_res_counter(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    let nanos = runtime.clock.nano_time();
+    runnerPrint!(runtime, true, 2, "BUILTIN: highResCounter {}", nanos);
+    runtime.push_on_stack(Variable::Long(nanos));
+    return Ok(());
+}
+
+// highResCounter counts nanoseconds
+fn perf_high_res_frequency(runtime: &mut Runtime, _args: &Vec<Variable>) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 2, "BUILTIN: highResFrequency");
+    runtime.push_on_stack(Variable::Long(1_000_000_000));
+    return Ok(());
+}
+
+pub fn register(m: &mut NativeTable) {
+    m.insert(("sun/misc/Perf", "registerNatives", "()V"), nop);
+    m.insert(("sun/misc/Perf", "createLong", "(Ljava/lang/String;IIJ)Ljava/nio/ByteBuffer;"), perf_create_long);
+    m.insert(("sun/misc/Perf", "createByteArray", "(Ljava/lang/String;II[BI)Ljava/nio/ByteBuffer;"), perf_create_byte_array);
+    m.insert(("sun/misc/Perf", "highResCounter", "()J"), perf_high_res_counter);
+    m.insert(("sun/misc/Perf", "highResFrequency", "()J"), perf_high_res_frequency);
+    m.insert(("sun/misc/Signal", "findSignal", "(Ljava/lang/String;)I"), signal_find_signal);
+    m.insert(("sun/misc/Signal", "handle0", "(IJ)J"), signal_handle0);
+    m.insert(("sun/misc/Unsafe", "registerNatives", "()V"), nop);
+    m.insert(("sun/misc/Unsafe", "arrayBaseOffset", "(Ljava/lang/Class;)I"), unsafe_array_base_offset);
+    m.insert(("sun/misc/Unsafe", "objectFieldOffset", "(Ljava/lang/reflect/Field;)J"), unsafe_object_field_offset);
+    m.insert(("sun/misc/Unsafe", "arrayIndexScale", "(Ljava/lang/Class;)I"), unsafe_array_index_scale);
+    m.insert(("sun/misc/Unsafe", "addressSize", "()I"), unsafe_address_size);
+    m.insert(("sun/misc/Unsafe", "pageSize", "()I"), unsafe_page_size);
+    m.insert(("sun/misc/Unsafe", "allocateMemory", "(J)J"), unsafe_allocate_memory);
+    m.insert(("sun/misc/Unsafe", "freeMemory", "(J)V"), unsafe_free_memory);
+    m.insert(("sun/misc/Unsafe", "putLong", "(JJ)V"), unsafe_put_long);
+    m.insert(("sun/misc/Unsafe", "getByte", "(J)B"), unsafe_get_byte);
+    m.insert(("sun/misc/Unsafe", "getObjectVolatile", "(Ljava/lang/Object;J)Ljava/lang/Object;"), unsafe_get_object_volatile);
+    m.insert(("sun/misc/Unsafe", "getIntVolatile", "(Ljava/lang/Object;J)I"), unsafe_get_int_volatile);
+    m.insert(("sun/misc/Unsafe", "getBooleanVolatile", "(Ljava/lang/Object;J)Z"), unsafe_get_boolean_volatile);
+    m.insert(("sun/misc/Unsafe", "getByteVolatile", "(Ljava/lang/Object;J)B"), unsafe_get_byte_volatile);
+    m.insert(("sun/misc/Unsafe", "getShortVolatile", "(Ljava/lang/Object;J)S"), unsafe_get_short_volatile);
+    m.insert(("sun/misc/Unsafe", "getCharVolatile", "(Ljava/lang/Object;J)C"), unsafe_get_char_volatile);
+    m.insert(("sun/misc/Unsafe", "getLongVolatile", "(Ljava/lang/Object;J)J"), unsafe_get_long_volatile);
+    m.insert(("sun/misc/Unsafe", "getFloatVolatile", "(Ljava/lang/Object;J)F"), unsafe_get_float_volatile);
+    m.insert(("sun/misc/Unsafe", "getDoubleVolatile", "(Ljava/lang/Object;J)D"), unsafe_get_double_volatile);
+    m.insert(("sun/misc/Unsafe", "compareAndSwapObject", "(Ljava/lang/Object;JLjava/lang/Object;Ljava/lang/Object;)Z"), unsafe_compare_and_swap_object);
+    m.insert(("sun/misc/Unsafe", "compareAndSwapInt", "(Ljava/lang/Object;JII)Z"), unsafe_compare_and_swap_int);
+    m.insert(("sun/misc/Unsafe", "compareAndSwapLong", "(Ljava/lang/Object;JJJ)Z"), unsafe_compare_and_swap_long);
+    m.insert(("sun/misc/VM", "initialize", "()V"), nop);
+    m.insert(("sun/reflect/ConstantPool", "getSize0", "(Ljava/lang/Object;)I"), constant_pool_get_size0);
+    m.insert(("sun/reflect/ConstantPool", "getClassAt0", "(Ljava/lang/Object;I)Ljava/lang/Class;"), constant_pool_get_class_at0);
+    m.insert(("sun/reflect/ConstantPool", "getClassAtIfLoaded0", "(Ljava/lang/Object;I)Ljava/lang/Class;"), constant_pool_get_class_at0);
+    m.insert(("sun/reflect/ConstantPool", "getIntAt0", "(Ljava/lang/Object;I)I"), constant_pool_get_int_at0);
+    m.insert(("sun/reflect/ConstantPool", "getLongAt0", "(Ljava/lang/Object;I)J"), constant_pool_get_long_at0);
+    m.insert(("sun/reflect/ConstantPool", "getFloatAt0", "(Ljava/lang/Object;I)F"), constant_pool_get_float_at0);
+    m.insert(("sun/reflect/ConstantPool", "getDoubleAt0", "(Ljava/lang/Object;I)D"), constant_pool_get_double_at0);
+    m.insert(("sun/reflect/ConstantPool", "getStringAt0", "(Ljava/lang/Object;I)Ljava/lang/String;"), constant_pool_get_string_at0);
+    m.insert(("sun/reflect/ConstantPool", "getUTF8At0", "(Ljava/lang/Object;I)Ljava/lang/String;"), constant_pool_get_utf8_at0);
+    m.insert(("sun/reflect/NativeMethodAccessorImpl", "invoke0", "(Ljava/lang/reflect/Method;Ljava/lang/Object;[Ljava/lang/Object;)Ljava/lang/Object;"), native_method_accessor_invoke0);
+    m.insert(("sun/reflect/NativeConstructorAccessorImpl", "newInstance0", "(Ljava/lang/reflect/Constructor;[Ljava/lang/Object;)Ljava/lang/Object;"), native_constructor_accessor_new_instance0);
+    m.insert(("sun/reflect/Reflection", "getClassAccessFlags", "(Ljava/lang/Class;)I"), reflection_get_class_access_flags);
+    m.insert(("sun/reflect/Reflection", "getCallerClass", "()Ljava/lang/Class;"), reflection_get_caller_class);
 }
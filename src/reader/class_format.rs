@@ -0,0 +1,170 @@
+use reader::class_reader::*;
+use std::rc::Rc;
+
+// Format checks from JVMS 4.8. Every failure carries the byte offset of the structure at fault.
+
+pub fn format_error<T>(offset: usize, message: String) -> Result<T, ClassReadError> {
+    return Err(ClassReadError::ClassFormatError(offset, message));
+}
+
+pub fn utf8_at(pool: &ConstantPool, index: u16, offset: usize, what: &str) -> Result<Rc<String>, ClassReadError> {
+    return match pool.pool.get(&index) {
+        Some(&ConstantPoolItem::CONSTANT_Utf8(ref s, _)) => Ok(s.clone()),
+        _ => format_error(offset, format!("{} index {} is not a Utf8 constant", what, index)),
+    };
+}
+
+pub fn class_at(pool: &ConstantPool, index: u16, offset: usize, what: &str) -> Result<(), ClassReadError> {
+    return match pool.pool.get(&index) {
+        Some(&ConstantPoolItem::CONSTANT_Class{..}) => Ok(()),
+        _ => format_error(offset, format!("{} index {} is not a Class constant", what, index)),
+    };
+}
+
+fn name_and_type_at(pool: &ConstantPool, index: u16, offset: usize) -> Result<(Rc<String>, Rc<String>), ClassReadError> {
+    return match pool.pool.get(&index) {
+        Some(&ConstantPoolItem::CONSTANT_NameAndType{name_index, descriptor_index}) =>
+            Ok((try!(utf8_at(pool, name_index, offset, "Name")), try!(utf8_at(pool, descriptor_index, offset, "Descriptor")))),
+        _ => format_error(offset, format!("Index {} is not a NameAndType constant", index)),
+    };
+}
+
+// Unqualified names (JVMS 4.2.2), where only methods may be the special <init> and <clinit>
+pub fn is_unqualified_name(name: &str, method: bool) -> bool {
+    if method && (name == "<init>" || name == "<clinit>") {
+        return true;
+    }
+    return !name.is_empty() && !name.contains(|c| c == '.' || c == ';' || c == '[' || c == '/' || (method && (c == '<' || c == '>')));
+}
+
+// Binary names in internal form or array descriptors (JVMS 4.2.1, 4.4.1)
+pub fn is_class_name(name: &str) -> bool {
+    if name.starts_with('[') {
+        return is_field_descriptor(name);
+    }
+    return name.split('/').all(|x| is_unqualified_name(x, false));
+}
+
+// Length of the field type at the start of the descriptor, if there is one
+fn field_type_length(descriptor: &str) -> Option<usize> {
+    let dimensions = descriptor.bytes().take_while(|x| *x == b'[').count();
+    if dimensions > 255 {
+        return None;
+    }
+    let rest = &descriptor[dimensions..];
+    return match rest.bytes().next() {
+        Some(b'B') | Some(b'C') | Some(b'D') | Some(b'F') | Some(b'I') | Some(b'J') | Some(b'S') | Some(b'Z') => Some(dimensions + 1),
+        Some(b'L') => rest.find(';')
+            .filter(|end| rest[1..*end].split('/').all(|x| is_unqualified_name(x, false)))
+            .map(|end| dimensions + end + 1),
+        _ => None,
+    };
+}
+
+pub fn is_field_descriptor(descriptor: &str) -> bool {
+    return field_type_length(descriptor) == Some(descriptor.len());
+}
+
+pub fn is_method_descriptor(descriptor: &str) -> bool {
+    if !descriptor.starts_with('(') {
+        return false;
+    }
+    let mut rest = &descriptor[1..];
+    while !rest.starts_with(')') {
+        match field_type_length(rest) {
+            Some(length) => rest = &rest[length..],
+            None => return false,
+        }
+    }
+    return &rest[1..] == "V" || is_field_descriptor(&rest[1..]);
+}
+
+fn check_member_ref(pool: &ConstantPool, class_index: u16, name_and_type_index: u16, method: bool, offset: usize) -> Result<(), ClassReadError> {
+    try!(class_at(pool, class_index, offset, "Class"));
+    let (name, descriptor) = try!(name_and_type_at(pool, name_and_type_index, offset));
+    if !is_unqualified_name(name.as_str(), method) {
+        return format_error(offset, format!("Invalid member name '{}'", name));
+    }
+    if method && !is_method_descriptor(descriptor.as_str()) || !method && !is_field_descriptor(descriptor.as_str()) {
+        return format_error(offset, format!("Invalid descriptor '{}' for '{}'", descriptor, name));
+    }
+    return Ok(());
+}
+
+// Cross references between constant pool entries (JVMS 4.4), checked once the whole pool has been read
+pub fn check_constant_pool(pool: &ConstantPool, offsets: &Vec<(u16, usize)>) -> Result<(), ClassReadError> {
+    for &(index, offset) in offsets {
+        match pool.pool.get(&index) {
+            Some(&ConstantPoolItem::CONSTANT_Class{index}) => {
+                let name = try!(utf8_at(pool, index, offset, "Class name"));
+                if !is_class_name(name.as_str()) {
+                    return format_error(offset, format!("Invalid class name '{}'", name));
+                }
+            }
+            Some(&ConstantPoolItem::CONSTANT_String{index}) => { try!(utf8_at(pool, index, offset, "String")); }
+            Some(&ConstantPoolItem::CONSTANT_Fieldref{class_index, name_and_type_index}) =>
+                try!(check_member_ref(pool, class_index, name_and_type_index, false, offset)),
+            Some(&ConstantPoolItem::CONSTANT_Methodref{class_index, name_and_type_index}) |
+            Some(&ConstantPoolItem::CONSTANT_InterfaceMethodref{class_index, name_and_type_index}) =>
+                try!(check_member_ref(pool, class_index, name_and_type_index, true, offset)),
+            Some(&ConstantPoolItem::CONSTANT_NameAndType{..}) => { try!(name_and_type_at(pool, index, offset)); }
+            Some(&ConstantPoolItem::CONSTANT_MethodHandle{reference_kind, reference_index}) => {
+                let valid = match (reference_kind, pool.pool.get(&reference_index)) {
+                    (1...4, Some(&ConstantPoolItem::CONSTANT_Fieldref{..})) => true,
+                    (5...8, Some(&ConstantPoolItem::CONSTANT_Methodref{..})) => true,
+                    (6 | 7, Some(&ConstantPoolItem::CONSTANT_InterfaceMethodref{..})) => true,
+                    (9, Some(&ConstantPoolItem::CONSTANT_InterfaceMethodref{..})) => true,
+                    _ => false,
+                };
+                if !valid {
+                    return format_error(offset, format!("Method handle of kind {} can't refer to index {}", reference_kind, reference_index));
+                }
+            }
+            Some(&ConstantPoolItem::CONSTANT_MethodType{descriptor_index}) => {
+                let descriptor = try!(utf8_at(pool, descriptor_index, offset, "Method type"));
+                if !is_method_descriptor(descriptor.as_str()) {
+                    return format_error(offset, format!("Invalid method type descriptor '{}'", descriptor));
+                }
+            }
+            Some(&ConstantPoolItem::CONSTANT_InvokeDynamic{name_and_type_index, ..}) => {
+                let (name, descriptor) = try!(name_and_type_at(pool, name_and_type_index, offset));
+                if !is_unqualified_name(name.as_str(), true) || !is_method_descriptor(descriptor.as_str()) {
+                    return format_error(offset, format!("Invalid invokedynamic call site '{}' '{}'", name, descriptor));
+                }
+            }
+            _ => {}
+        }
+    }
+    return Ok(());
+}
+
+// Field and method names and descriptors (JVMS 4.5, 4.6)
+pub fn check_member(pool: &ConstantPool, field: &FieldItem, method: bool, offset: usize) -> Result<(), ClassReadError> {
+    let name = try!(utf8_at(pool, field.name_index, offset, "Member name"));
+    let descriptor = try!(utf8_at(pool, field.descriptor_index, offset, "Member descriptor"));
+    if !is_unqualified_name(name.as_str(), method) {
+        return format_error(offset, format!("Invalid member name '{}'", name));
+    }
+    if method && !is_method_descriptor(descriptor.as_str()) || !method && !is_field_descriptor(descriptor.as_str()) {
+        return format_error(offset, format!("Invalid descriptor '{}' for '{}'", descriptor, name));
+    }
+    return Ok(());
+}
+
+// A ConstantValue must hold the constant type its field's descriptor calls for (JVMS 4.7.2)
+pub fn check_constant_value(pool: &ConstantPool, descriptor: &str, index: u16, offset: usize) -> Result<(), ClassReadError> {
+    let valid = match (descriptor, pool.pool.get(&index)) {
+        ("J", Some(&ConstantPoolItem::CONSTANT_Long{..})) => true,
+        ("F", Some(&ConstantPoolItem::CONSTANT_Float{..})) => true,
+        ("D", Some(&ConstantPoolItem::CONSTANT_Double{..})) => true,
+        ("I", Some(&ConstantPoolItem::CONSTANT_Integer{..})) | ("S", Some(&ConstantPoolItem::CONSTANT_Integer{..})) |
+        ("C", Some(&ConstantPoolItem::CONSTANT_Integer{..})) | ("B", Some(&ConstantPoolItem::CONSTANT_Integer{..})) |
+        ("Z", Some(&ConstantPoolItem::CONSTANT_Integer{..})) => true,
+        ("Ljava/lang/String;", Some(&ConstantPoolItem::CONSTANT_String{..})) => true,
+        _ => false,
+    };
+    if !valid {
+        return format_error(offset, format!("ConstantValue index {} doesn't match field type {}", index, descriptor));
+    }
+    return Ok(());
+}
@@ -8,11 +8,12 @@ unused_variables,
 extern crate byteorder;
 
 pub use reader::types::constant_pool::*;
-use std::char;
+pub use reader::attributes::{InnerClassItem, ElementValue, Annotation, TypeAnnotationTarget, TypeAnnotation, MethodParameter, BootstrapMethod};
+use reader::attributes::*;
+use reader::class_format::*;
 use std::str;
 use std::mem::transmute;
 use std::io;
-use std::io::BufReader;
 use std::io::Read;
 use std::string::String;
 use std::rc::Rc;
@@ -33,13 +34,15 @@ pub enum ClassReadError {
     Parse2(String),
     NativeMethod(String),
     UTF8Error(String),
-    UnsupportedVersion(f32)
+    UnsupportedVersion(f32),
+    ClassFormatError(usize, String)
 }
 
 #[derive(Clone, Debug, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum ConstantPoolItem {
-    CONSTANT_Utf8(Rc<String>),
+    // Decoded text and the UTF-16 units it came from, which only differ when there are lone surrogates
+    CONSTANT_Utf8(Rc<String>, Rc<Vec<u16>>),
     CONSTANT_Class{index: u16},
     CONSTANT_Integer{value: u32},
     CONSTANT_Long{value: u64},
@@ -84,6 +87,21 @@ pub enum AttributeItem {
     Code(Code),
     Signature{index: u16},
     Exceptions{indicies: Vec<u16>},
+    InnerClasses{classes: Vec<InnerClassItem>},
+    EnclosingMethod{class_index: u16, method_index: u16},
+    Synthetic,
+    Deprecated,
+    // The annotation attributes keep their raw bytes too, which is what reflection hands to the JDK's parser.
+    // Malformed contents don't stop the class loading, as in HotSpot, and are kept only as raw bytes (None)
+    RuntimeVisibleAnnotations{annotations: Option<Vec<Annotation>>, info: Vec<u8>},
+    RuntimeInvisibleAnnotations{annotations: Option<Vec<Annotation>>, info: Vec<u8>},
+    RuntimeVisibleParameterAnnotations{parameters: Option<Vec<Vec<Annotation>>>, info: Vec<u8>},
+    RuntimeInvisibleParameterAnnotations{parameters: Option<Vec<Vec<Annotation>>>, info: Vec<u8>},
+    RuntimeVisibleTypeAnnotations{annotations: Option<Vec<TypeAnnotation>>, info: Vec<u8>},
+    RuntimeInvisibleTypeAnnotations{annotations: Option<Vec<TypeAnnotation>>, info: Vec<u8>},
+    AnnotationDefault{value: Option<ElementValue>, info: Vec<u8>},
+    MethodParameters{parameters: Vec<MethodParameter>},
+    BootstrapMethods{methods: Vec<BootstrapMethod>},
     Unknown{name_index: u16, info: Vec<u8>}
 }
 
@@ -92,12 +110,16 @@ pub const ACC_PRIVATE: u16 = 0x0002;
 pub const ACC_PROTECTED: u16 = 0x0004;
 pub const ACC_STATIC: u16 = 0x0008;
 pub const ACC_FINAL: u16 = 0x0010;
+pub const ACC_SUPER: u16 = 0x0020;
 pub const ACC_VOLATILE: u16 = 0x0040;
 pub const ACC_TRANSIENT: u16 = 0x0080;
 pub const ACC_NATIVE: u16 = 0x0100;
+pub const ACC_INTERFACE: u16 = 0x0200;
 pub const ACC_ABSTRACT: u16 = 0x400;
 pub const ACC_SYNTHETIC: u16 = 0x1000;
 pub const ACC_ENUM: u16 = 0x4000;
+// Method flags reflection reports, everything up to synthetic except the unused 0x0200
+pub const ACC_RECOGNISED_METHOD: u16 = 0x1DFF;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct FieldItem {
@@ -111,6 +133,44 @@ impl FieldItem {
     pub fn new() -> FieldItem {
         FieldItem { access_flags: 0, name_index: 0, descriptor_index: 0, attributes: Vec::new() }
     }
+
+    pub fn is_synthetic(&self) -> bool {
+        return self.attributes.contains(&AttributeItem::Synthetic);
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        return self.attributes.contains(&AttributeItem::Deprecated);
+    }
+
+    pub fn annotations(&self, visible: bool) -> &[Annotation] {
+        return annotations_in(&self.attributes, visible);
+    }
+
+    pub fn type_annotations(&self, visible: bool) -> &[TypeAnnotation] {
+        return type_annotations_in(&self.attributes, visible);
+    }
+
+    pub fn parameter_annotations(&self, visible: bool) -> &[Vec<Annotation>] {
+        return self.attributes.iter().filter_map(|x| match (x, visible) {
+            (&AttributeItem::RuntimeVisibleParameterAnnotations{parameters: Some(ref parameters), ..}, true) => Some(&parameters[..]),
+            (&AttributeItem::RuntimeInvisibleParameterAnnotations{parameters: Some(ref parameters), ..}, false) => Some(&parameters[..]),
+            _ => None
+        }).next().unwrap_or(&[]);
+    }
+
+    pub fn annotation_default(&self) -> Option<&ElementValue> {
+        return self.attributes.iter().filter_map(|x| match x {
+            &AttributeItem::AnnotationDefault{value: Some(ref value), ..} => Some(value),
+            _ => None
+        }).next();
+    }
+
+    pub fn method_parameters(&self) -> &[MethodParameter] {
+        return self.attributes.iter().filter_map(|x| match x {
+            &AttributeItem::MethodParameters{ref parameters} => Some(&parameters[..]),
+            _ => None
+        }).next().unwrap_or(&[]);
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -147,6 +207,44 @@ impl ClassResult {
         return self.constant_pool.get_class_name(self.this_class_index);
     }
 
+    pub fn inner_classes(&self) -> &[InnerClassItem] {
+        return self.attributes.iter().filter_map(|x| match x {
+            &AttributeItem::InnerClasses{ref classes} => Some(&classes[..]),
+            _ => None
+        }).next().unwrap_or(&[]);
+    }
+
+    // Class index and, when the class is declared inside one, the NameAndType of the method
+    pub fn enclosing_method(&self) -> Option<(u16, Option<u16>)> {
+        return self.attributes.iter().filter_map(|x| match x {
+            &AttributeItem::EnclosingMethod{class_index, method_index} => Some((class_index, if method_index == 0 {None} else {Some(method_index)})),
+            _ => None
+        }).next();
+    }
+
+    pub fn is_synthetic(&self) -> bool {
+        return self.attributes.contains(&AttributeItem::Synthetic);
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        return self.attributes.contains(&AttributeItem::Deprecated);
+    }
+
+    pub fn annotations(&self, visible: bool) -> &[Annotation] {
+        return annotations_in(&self.attributes, visible);
+    }
+
+    pub fn type_annotations(&self, visible: bool) -> &[TypeAnnotation] {
+        return type_annotations_in(&self.attributes, visible);
+    }
+
+    pub fn bootstrap_methods(&self) -> &[BootstrapMethod] {
+        return self.attributes.iter().filter_map(|x| match x {
+            &AttributeItem::BootstrapMethods{ref methods} => Some(&methods[..]),
+            _ => None
+        }).next().unwrap_or(&[]);
+    }
+
     pub fn get_code(&self, target_method_name: &str, target_descriptor: &str) -> Result<Code, ClassReadError> {
         let debug = false;
         let class_name = try!(self.constant_pool.get_class_name(self.this_class_index));
@@ -186,6 +284,51 @@ impl From<io::Error> for ClassReadError {
     }
 }
 
+// Counts the bytes read so format errors can say where they are
+pub struct OffsetReader<R> {
+    inner: R,
+    pub offset: usize,
+}
+
+impl<R: Read> OffsetReader<R> {
+    pub fn new(inner: R) -> OffsetReader<R> {
+        return OffsetReader { inner: inner, offset: 0 };
+    }
+
+    // For reading a slice that started at the given offset of the whole file
+    pub fn at(inner: R, offset: usize) -> OffsetReader<R> {
+        return OffsetReader { inner: inner, offset: offset };
+    }
+}
+
+impl<R: Read> Read for OffsetReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = try!(self.inner.read(buf));
+        self.offset += count;
+        return Ok(count);
+    }
+}
+
+// Reads a declared length of bytes, only allocating as much as the input really has
+fn read_bytes<R: Read>(reader: &mut OffsetReader<R>, length: u64) -> Result<Vec<u8>, ClassReadError> {
+    let start = reader.offset;
+    let mut buf = Vec::new();
+    try!(reader.by_ref().take(length).read_to_end(&mut buf));
+    if (buf.len() as u64) < length {
+        return format_error(start, format!("Truncated, {} bytes declared but only {} present", length, buf.len()));
+    }
+    return Ok(buf);
+}
+
+// Running out of input part way through a structure is a format error at the point it happened
+fn truncated<R>(reader: &OffsetReader<R>, err: ClassReadError) -> ClassReadError {
+    return match err {
+        ClassReadError::Io(ref e) if e.kind() == io::ErrorKind::UnexpectedEof =>
+            ClassReadError::ClassFormatError(reader.offset, String::from("Truncated class file")),
+        _ => err,
+    };
+}
+
 fn base(n: u16) -> u16 {
     let mut val = n;
     let mut out = 1;
@@ -196,7 +339,7 @@ fn base(n: u16) -> u16 {
     return out;
 }
 
-fn read_exception(reader: &mut Read) -> Result<ExceptionItem, ClassReadError> {
+fn read_exception<R: Read>(reader: &mut OffsetReader<R>) -> Result<ExceptionItem, ClassReadError> {
     let start_pc = try!(reader.read_u16::<BigEndian>());
     let end_pc = try!(reader.read_u16::<BigEndian>());
     let handler_pc = try!(reader.read_u16::<BigEndian>());
@@ -204,26 +347,49 @@ fn read_exception(reader: &mut Read) -> Result<ExceptionItem, ClassReadError> {
     return Ok(ExceptionItem {start_pc: start_pc, end_pc: end_pc, handler_pc: handler_pc, catch_type: catch_type});
 }
 
-fn read_attribute(cp: &ConstantPool, reader: &mut Read) -> Result<AttributeItem, ClassReadError> {
+// Parses an attribute's contents on their own, so the raw bytes can be kept and must be used up exactly
+fn parse_info<T, F>(info: &[u8], start: usize, parse: F) -> Result<T, ClassReadError>
+    where F: FnOnce(&mut OffsetReader<&[u8]>) -> Result<T, ClassReadError> {
+    let mut reader = OffsetReader::at(info, start);
+    let result = try!(parse(&mut reader).map_err(|e| truncated(&reader, e)));
+    if reader.offset != start + info.len() {
+        return format_error(reader.offset, format!("{} bytes left over in attribute", start + info.len() - reader.offset));
+    }
+    return Ok(result);
+}
+
+// None when the contents are malformed, which is left for reflection to report as HotSpot does
+fn parse_annotation_info<T, F>(name: &str, info: &[u8], start: usize, parse: F) -> Option<T>
+    where F: FnOnce(&mut OffsetReader<&[u8]>) -> Result<T, ClassReadError> {
+    return match parse_info(info, start, parse) {
+        Ok(result) => Some(result),
+        Err(err) => {
+            debugPrint!(true, 4, "Keeping malformed {} attribute raw: {:?}", name, err);
+            None
+        }
+    };
+}
+
+fn read_attribute<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>, in_code: bool) -> Result<AttributeItem, ClassReadError> {
+    let offset = reader.offset;
     let name_index = try!(reader.read_u16::<BigEndian>());
     let length = try!(reader.read_u32::<BigEndian>());
-    let attribute_name = try!(cp.get_str(name_index));
-    match attribute_name.as_str() {
+    let attribute_name = try!(utf8_at(cp, name_index, offset, "Attribute name"));
+    let start = reader.offset;
+    let attribute = match attribute_name.as_str() {
         "ConstantValue" => {
-            if length != 2 {
-                return Err(ClassReadError::Parse);
-            } else {
-                let index = try!(reader.read_u16::<BigEndian>());
-                debugPrint!(true, 4, "ConstantValue attribute with index of {}", index);
-                return Ok(AttributeItem::ConstantValue {index: index});
-            }
+            let index = try!(reader.read_u16::<BigEndian>());
+            debugPrint!(true, 4, "ConstantValue attribute with index of {}", index);
+            AttributeItem::ConstantValue {index: index}
         }
-        "Code" => {
+        "Code" if !in_code => {
             let max_stack = try!(reader.read_u16::<BigEndian>());
             let max_locals = try!(reader.read_u16::<BigEndian>());
             let code_length = try!(reader.read_u32::<BigEndian>());
-            let mut code = Vec::new();
-            try!(reader.take(code_length as u64).read_to_end(&mut code));
+            if code_length == 0 || code_length > 65535 {
+                return format_error(offset, format!("Code length {} is outside 1 to 65535", code_length));
+            }
+            let code = try!(read_bytes(reader, code_length as u64));
             let exception_table_length =  try!(reader.read_u16::<BigEndian>());
             let mut exceptions = Vec::new();
             for _ in 0..exception_table_length {
@@ -232,93 +398,183 @@ fn read_attribute(cp: &ConstantPool, reader: &mut Read) -> Result<AttributeItem,
             let attributes_count = try!(reader.read_u16::<BigEndian>());
             let mut attributes = Vec::new();
             for _ in 0..attributes_count {
-                attributes.push(try!(read_attribute(cp, reader)));
+                attributes.push(try!(read_attribute(cp, reader, true)));
             }
             debugPrint!(true, 4, "Code attribute with {}B of code, {} exceptions and {} attributes", code_length, exception_table_length, attributes_count);
 
-            return Ok(AttributeItem::Code(Code {
+            AttributeItem::Code(Code {
                 max_stack: max_stack, max_locals: max_locals, code: code, exceptions: exceptions, attributes: attributes
-            }))
+            })
         }
+        "Code" => return format_error(offset, String::from("Code attribute inside another Code attribute")),
         "Exceptions" => {
             let num_exceptions = try!(reader.read_u16::<BigEndian>());
             let mut indicies = Vec::new();
             for _ in 0..num_exceptions {
-                indicies.push(try!(reader.read_u16::<BigEndian>()));
+                let index = try!(reader.read_u16::<BigEndian>());
+                try!(class_at(cp, index, offset, "Exception"));
+                indicies.push(index);
             }
             debugPrint!(true, 4, "Exceptions attribute with {} indicies", num_exceptions);
 
-            return Ok(AttributeItem::Exceptions {indicies: indicies})
-        }
-        "LineNumberTable" => {
-            let mut info = Vec::new();
-            try!(reader.take(length as u64).read_to_end(&mut info));
-            debugPrint!(true, 4, "Ignoring 'LineNumberTable' attribute");
-            return Ok(AttributeItem::Unknown {name_index: name_index, info: info});
+            AttributeItem::Exceptions {indicies: indicies}
         }
         "Signature" => {
             let signature_index = try!(reader.read_u16::<BigEndian>());
+            try!(utf8_at(cp, signature_index, offset, "Signature"));
             debugPrint!(true, 4, "Signature attribute with index {}", signature_index);
-            return Ok(AttributeItem::Signature {index: signature_index});
+            AttributeItem::Signature {index: signature_index}
+        }
+        "InnerClasses" => AttributeItem::InnerClasses {classes: try!(read_inner_classes(cp, reader))},
+        "EnclosingMethod" => {
+            let class_index = try!(reader.read_u16::<BigEndian>());
+            let method_index = try!(reader.read_u16::<BigEndian>());
+            try!(class_at(cp, class_index, offset, "Enclosing class"));
+            if method_index != 0 {
+                match cp.pool.get(&method_index) {
+                    Some(&ConstantPoolItem::CONSTANT_NameAndType{..}) => {}
+                    _ => return format_error(offset, format!("Enclosing method index {} is not a NameAndType constant", method_index)),
+                }
+            }
+            AttributeItem::EnclosingMethod {class_index: class_index, method_index: method_index}
+        }
+        "Synthetic" => AttributeItem::Synthetic,
+        "Deprecated" => AttributeItem::Deprecated,
+        "RuntimeVisibleAnnotations" | "RuntimeInvisibleAnnotations" => {
+            let info = try!(read_bytes(reader, length as u64));
+            let annotations = parse_annotation_info(&attribute_name, &info, start, |r| read_annotations(cp, r));
+            if attribute_name.as_str() == "RuntimeVisibleAnnotations" {
+                AttributeItem::RuntimeVisibleAnnotations {annotations: annotations, info: info}
+            } else {
+                AttributeItem::RuntimeInvisibleAnnotations {annotations: annotations, info: info}
+            }
+        }
+        "RuntimeVisibleParameterAnnotations" | "RuntimeInvisibleParameterAnnotations" => {
+            let info = try!(read_bytes(reader, length as u64));
+            let parameters = parse_annotation_info(&attribute_name, &info, start, |r| read_parameter_annotations(cp, r));
+            if attribute_name.as_str() == "RuntimeVisibleParameterAnnotations" {
+                AttributeItem::RuntimeVisibleParameterAnnotations {parameters: parameters, info: info}
+            } else {
+                AttributeItem::RuntimeInvisibleParameterAnnotations {parameters: parameters, info: info}
+            }
+        }
+        "RuntimeVisibleTypeAnnotations" | "RuntimeInvisibleTypeAnnotations" => {
+            let info = try!(read_bytes(reader, length as u64));
+            let annotations = parse_annotation_info(&attribute_name, &info, start, |r| read_type_annotations(cp, r));
+            if attribute_name.as_str() == "RuntimeVisibleTypeAnnotations" {
+                AttributeItem::RuntimeVisibleTypeAnnotations {annotations: annotations, info: info}
+            } else {
+                AttributeItem::RuntimeInvisibleTypeAnnotations {annotations: annotations, info: info}
+            }
         }
+        "AnnotationDefault" => {
+            let info = try!(read_bytes(reader, length as u64));
+            let value = parse_annotation_info(&attribute_name, &info, start, |r| read_element_default(cp, r));
+            AttributeItem::AnnotationDefault {value: value, info: info}
+        }
+        "MethodParameters" => AttributeItem::MethodParameters {parameters: try!(read_method_parameters(cp, reader))},
+        "BootstrapMethods" => AttributeItem::BootstrapMethods {methods: try!(read_bootstrap_methods(cp, reader))},
         _ => {
-            let mut info = Vec::new();
-            try!(reader.take(length as u64).read_to_end(&mut info));
+            let info = try!(read_bytes(reader, length as u64));
             debugPrint!(true, 4, "Unknown attribute with name {} data {:?}", attribute_name, info);
-            return Ok(AttributeItem::Unknown {name_index: name_index, info: info});
+            AttributeItem::Unknown {name_index: name_index, info: info}
         }
+    };
+    if (reader.offset - start) as u64 != length as u64 {
+        return format_error(offset, format!("{} attribute declares {} bytes but holds {}", attribute_name, length, reader.offset - start));
     }
+    return Ok(attribute);
 }
 
-fn read_field(cp: &ConstantPool, reader: &mut Read) -> Result<FieldItem, ClassReadError> {
+fn read_field<R: Read>(cp: &ConstantPool, reader: &mut OffsetReader<R>, method: bool) -> Result<FieldItem, ClassReadError> {
+    let offset = reader.offset;
     let mut field = FieldItem::new();
     field.access_flags = try!(reader.read_u16::<BigEndian>());
     field.name_index = try!(reader.read_u16::<BigEndian>());
     field.descriptor_index = try!(reader.read_u16::<BigEndian>());
+    try!(check_member(cp, &field, method, offset));
 
     debugPrint!(true, 4, "Field with name {} descriptor index {}", try!(cp.get_str(field.name_index)), field.descriptor_index);
     let attributes_count = try!(reader.read_u16::<BigEndian>());
     debugPrint!(true, 4, "Field has {} attributes", attributes_count);
     for _ in 0..attributes_count {
-        field.attributes.push(try!(read_attribute(cp, reader)));
+        let attribute_offset = reader.offset;
+        let attribute = try!(read_attribute(cp, reader, false));
+        match attribute {
+            AttributeItem::ConstantValue{index} if !method => {
+                let descriptor = try!(cp.get_str(field.descriptor_index));
+                try!(check_constant_value(cp, descriptor.as_str(), index, attribute_offset));
+            }
+            AttributeItem::Code(_) if !method => return format_error(attribute_offset, String::from("Code attribute on a field")),
+            _ => {}
+        }
+        field.attributes.push(attribute);
     }
     return Ok(field);
 }
 
-fn string_from_utf8(buf: &Vec<u8>) -> Result<String, ClassReadError> {
-    let mut ret = String::new();
-    let mut iter = buf.iter();
-    let mut maybe_x;
-    while {maybe_x = iter.next(); maybe_x.is_some()} {
-        let x = *maybe_x.unwrap() as u32;
-        if x < 128 {
-            ret.push((x as u8) as char);
-        } else if x & 0xE0 == 0xC0 {
-            let y = *iter.next().unwrap() as u32;
-            ret.push(try!(char::from_u32((y & 0x3F) | (x & 0x1F) << 6).ok_or(ClassReadError::UTF8Error(format!("Invalid 2 byte code {} {}", x, y)))));
-        } else if x & 0xF0 == 0xE0 {
-            let y = *iter.next().unwrap() as u32;
-            let z = *iter.next().unwrap() as u32;
-            ret.push(try!(char::from_u32((z & 0x3F) | (y & 0x3F) << 6 | (x & 0xF) << 12).ok_or(ClassReadError::UTF8Error(format!("Invalid 3 byte code {} {} {}", x, y, z)))));
-        } else if x == 0xED {
-            let v = *iter.next().unwrap() as u32;
-            let w = *iter.next().unwrap() as u32;
-            let head2 = *iter.next().unwrap() as u32;
-            if head2 != 0xED {
-                return Err(ClassReadError::UTF8Error(format!("Invalid second header byte in 2x3B UTF string {}", head2)));
+fn continuation(buf: &[u8], index: usize) -> Result<u16, ClassReadError> {
+    return match buf.get(index) {
+        Some(x) if x & 0xC0 == 0x80 => Ok((x & 0x3F) as u16),
+        Some(x) => Err(ClassReadError::UTF8Error(format!("Expected a continuation byte at {}, found {:#x}", index, x))),
+        None => Err(ClassReadError::UTF8Error(format!("Sequence truncated at {}", index))),
+    };
+}
+
+// Modified UTF-8 (JVMS 4.4.7) encodes each UTF-16 unit separately, so supplementary characters arrive as two
+// three byte surrogates. NUL is always the two byte form and nothing longer than three bytes is allowed.
+pub fn decode_modified_utf8(buf: &[u8]) -> Result<Vec<u16>, ClassReadError> {
+    let mut ret = Vec::with_capacity(buf.len());
+    let mut i = 0;
+    while i < buf.len() {
+        let x = buf[i];
+        match x {
+            0x01...0x7F => {
+                ret.push(x as u16);
+                i += 1;
             }
-            let y = *iter.next().unwrap() as u32;
-            let z = *iter.next().unwrap() as u32;
-            ret.push(try!(char::from_u32((z & 0x3F) | (y & 0xF) << 6 | (w & 0x3F) << 10 | (v & 0xF) << 16 | 0x10000)
-                .ok_or(ClassReadError::UTF8Error(format!("Invalid 2x3B code {} {} {} {} {} {}", x, v, w, head2, y, z)))));
-        } else {
-            return Err(ClassReadError::UTF8Error(format!("Invalid code byte {}", x)));
+            0xC0...0xDF => {
+                let unit = (x as u16 & 0x1F) << 6 | try!(continuation(buf, i + 1));
+                if unit != 0 && unit < 0x80 {
+                    return Err(ClassReadError::UTF8Error(format!("Overlong two byte sequence at {}", i)));
+                }
+                ret.push(unit);
+                i += 2;
+            }
+            0xE0...0xEF => {
+                let unit = (x as u16 & 0xF) << 12 | try!(continuation(buf, i + 1)) << 6 | try!(continuation(buf, i + 2));
+                if unit < 0x800 {
+                    return Err(ClassReadError::UTF8Error(format!("Overlong three byte sequence at {}", i)));
+                }
+                ret.push(unit);
+                i += 3;
+            }
+            _ => return Err(ClassReadError::UTF8Error(format!("Invalid byte {:#x} at {}", x, i))),
         }
     }
     return Ok(ret);
 }
 
-fn read_constant_pool(reader: &mut Read, entry_count: &mut u16) -> Result<ConstantPoolItem, ClassReadError> {
+pub fn encode_modified_utf8(units: &[u16]) -> Vec<u8> {
+    let mut ret = Vec::with_capacity(units.len());
+    for &unit in units {
+        match unit {
+            0x01...0x7F => ret.push(unit as u8),
+            0 | 0x80...0x7FF => {
+                ret.push(0xC0 | (unit >> 6) as u8);
+                ret.push(0x80 | (unit & 0x3F) as u8);
+            }
+            _ => {
+                ret.push(0xE0 | (unit >> 12) as u8);
+                ret.push(0x80 | (unit >> 6 & 0x3F) as u8);
+                ret.push(0x80 | (unit & 0x3F) as u8);
+            }
+        }
+    }
+    return ret;
+}
+
+fn read_constant_pool<R: Read>(reader: &mut OffsetReader<R>, entry_count: &mut u16) -> Result<ConstantPoolItem, ClassReadError> {
     let debug = false;
     let tag = try!(reader.read_u8());
     *entry_count = 1;
@@ -326,11 +582,12 @@ fn read_constant_pool(reader: &mut Read, entry_count: &mut u16) -> Result<Consta
         1 => {
             // CONSTANT_Utf8
             let length = try!(reader.read_u16::<BigEndian>());
-            let mut buf: Vec<u8> = Vec::new();
-            try!(reader.take(length as u64).read_to_end(&mut buf));
-            let string = try!(string_from_utf8(&buf));
+            let start = reader.offset;
+            let buf = try!(read_bytes(reader, length as u64));
+            let units = try!(decode_modified_utf8(&buf).or_else(|e| format_error(start, format!("Malformed modified UTF-8 {:?}", e))));
+            let string = String::from_utf16_lossy(&units);
             debugPrint!(debug, 4, "UTF8 {} '{}'", length, string);
-            return Ok(ConstantPoolItem::CONSTANT_Utf8(Rc::new(string)));
+            return Ok(ConstantPoolItem::CONSTANT_Utf8(Rc::new(string), Rc::new(units)));
         },
         3 => {
             // CONSTANT_Integer
@@ -418,19 +675,23 @@ fn read_constant_pool(reader: &mut Read, entry_count: &mut u16) -> Result<Consta
         }
         _ => {
             debugPrint!(debug, 4, "unknown tag: {}", tag);
-            return Err(ClassReadError::Parse);
+            return format_error(reader.offset - 1, format!("Unknown constant pool tag {}", tag));
         }
     }
 }
 
-pub fn read_stage_1<T: Read>(buf_reader: &mut BufReader<T>) -> Result<ClassResult, ClassReadError> {
+pub fn read_stage_1<T: Read>(reader: &mut OffsetReader<T>) -> Result<ClassResult, ClassReadError> {
+    return read_header(reader).map_err(|e| truncated(reader, e));
+}
+
+fn read_header<T: Read>(buf_reader: &mut OffsetReader<T>) -> Result<ClassResult, ClassReadError> {
     let magic = try!(buf_reader.read_u32::<BigEndian>());
     let minor = try!(buf_reader.read_u16::<BigEndian>());
     let major = try!(buf_reader.read_u16::<BigEndian>());
     let version = (major as f32) + ((minor as f32) / (base(minor) as f32));
 
     if magic != 0xCAFEBABE {
-        return Err(ClassReadError::Parse);
+        return format_error(0, format!("Bad magic number {:#x}", magic));
     }
 
     if major < 45 || major > 52 {
@@ -441,57 +702,84 @@ pub fn read_stage_1<T: Read>(buf_reader: &mut BufReader<T>) -> Result<ClassResul
     debugPrint!(true, 4, "cp: {}", cp_count);
 
     if cp_count == 0 {
-        return Err(ClassReadError::Parse);
+        return format_error(8, String::from("Constant pool count is zero"));
     }
 
     let mut ret = ClassResult::new();
+    let mut offsets = Vec::new();
 
     let mut i = 1;
     while i < cp_count {
         debugPrint!(true, 5, "{}", i);
         let mut entry_count : u16 = 1;
+        offsets.push((i, buf_reader.offset));
         ret.constant_pool.pool.insert(i, try!(read_constant_pool(buf_reader, &mut entry_count)));
+        if cp_count - i < entry_count {
+            return format_error(offsets[offsets.len() - 1].1, format!("Eight byte constant at {} is the last entry", i));
+        }
         i += entry_count;
     }
+    try!(check_constant_pool(&ret.constant_pool, &offsets));
 
     ret.access_flags = try!(buf_reader.read_u16::<BigEndian>());
     debugPrint!(true, 4, "access_flags: {}", ret.access_flags);
+    let offset = buf_reader.offset;
     ret.this_class_index = try!(buf_reader.read_u16::<BigEndian>());
+    try!(class_at(&ret.constant_pool, ret.this_class_index, offset, "This class"));
     return Ok(ret);
 }
 
-pub fn read_stage_2<T: Read>(buf_reader: &mut BufReader<T>, ret: &mut ClassResult) -> Result<(), ClassReadError> {
+// Reads everything after this_class and then checks nothing is left over
+pub fn read_stage_2<T: Read>(reader: &mut OffsetReader<T>, ret: &mut ClassResult) -> Result<(), ClassReadError> {
+    return read_body(reader, ret).map_err(|e| truncated(reader, e));
+}
+
+fn read_body<T: Read>(buf_reader: &mut OffsetReader<T>, ret: &mut ClassResult) -> Result<(), ClassReadError> {
+    let offset = buf_reader.offset;
     ret.super_class_index = try!(buf_reader.read_u16::<BigEndian>());
     debugPrint!(true, 4, "class_indexes: {} {}", ret.this_class_index, ret.super_class_index);
+    if ret.super_class_index != 0 {
+        try!(class_at(&ret.constant_pool, ret.super_class_index, offset, "Super class"));
+    } else if try!(ret.name()).as_str() != "java/lang/Object" {
+        return format_error(offset, String::from("Only java/lang/Object may have no super class"));
+    }
 
     let interfaces_count = try!(buf_reader.read_u16::<BigEndian>());
     debugPrint!(true, 4, "Interface count: {}", interfaces_count);
     for _ in 0..interfaces_count {
-        ret.interfaces.push(try!(buf_reader.read_u16::<BigEndian>()));
+        let offset = buf_reader.offset;
+        let index = try!(buf_reader.read_u16::<BigEndian>());
+        try!(class_at(&ret.constant_pool, index, offset, "Interface"));
+        ret.interfaces.push(index);
     }
 
     let fields_count = try!(buf_reader.read_u16::<BigEndian>());
     debugPrint!(true, 4, "Fields count: {}", fields_count);
     for _ in 0..fields_count {
-        ret.fields.push(try!(read_field(&ret.constant_pool, buf_reader)));
+        ret.fields.push(try!(read_field(&ret.constant_pool, buf_reader, false)));
     }
 
     let methods_count = try!(buf_reader.read_u16::<BigEndian>());
     debugPrint!(true, 4, "Methods count: {}", methods_count);
     for _ in 0..methods_count {
-        ret.methods.push(try!(read_field(&ret.constant_pool, buf_reader)));
+        ret.methods.push(try!(read_field(&ret.constant_pool, buf_reader, true)));
     }
 
     let attributes_count = try!(buf_reader.read_u16::<BigEndian>());
     debugPrint!(true, 4, "Attributes count: {}", attributes_count);
     for _ in 0..attributes_count {
-        let attribute = try!(read_attribute(&ret.constant_pool, buf_reader));
+        let offset = buf_reader.offset;
+        let attribute = try!(read_attribute(&ret.constant_pool, buf_reader, false));
         match attribute {
             AttributeItem::Signature{index} => {ret.signature = Some(index);},
-            AttributeItem::Code(c) => { ret.code = Some(c); },
+            AttributeItem::Code(_) => return format_error(offset, String::from("Code attribute on a class")),
             _ => { ret.attributes.push(attribute); }
         }
     }
 
+    let offset = buf_reader.offset;
+    if try!(buf_reader.read(&mut [0])) != 0 {
+        return format_error(offset, String::from("Extra bytes after the end of the class"));
+    }
     return Ok(());
 }
@@ -0,0 +1,240 @@
+use reader::class_reader::*;
+use reader::jvm::construction::*;
+use reader::jvm::interpreter::invoke_nested;
+use reader::runner::*;
+use reader::util::*;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+fn find_field_descriptor(class: &Rc<Class>, field_name: &str) -> Result<Option<Rc<String>>, RunnerError> {
+    let mut maybe_class = Some(class.clone());
+    while maybe_class.is_some() {
+        let class = maybe_class.unwrap();
+        for field in &class.cr.fields {
+            if field.access_flags & ACC_STATIC != 0 {
+                continue;
+            }
+            let name_string = try!(class.cr.constant_pool.get_str(field.name_index));
+            if *name_string == field_name {
+                return Ok(Some(try!(class.cr.constant_pool.get_str(field.descriptor_index))));
+            }
+        }
+        maybe_class = class.super_class.borrow().clone();
+    }
+    return Ok(None);
+}
+
+fn check_type(runtime: &mut Runtime, descriptor: &str, value: &Variable) -> Result<bool, RunnerError> {
+    let type_char = try!(descriptor.chars().nth(0).ok_or(RunnerError::ClassInvalid("Type specifier blank")));
+    return Ok(match type_char {
+        'B' => match value { &Variable::Byte(_) | &Variable::Int(_) => true, _ => false },
+        'C' => match value { &Variable::Char(_) | &Variable::Int(_) => true, _ => false },
+        'S' => match value { &Variable::Short(_) | &Variable::Int(_) => true, _ => false },
+        'Z' => match value { &Variable::Boolean(_) | &Variable::Int(_) => true, _ => false },
+        'I' => match value { &Variable::Int(_) => true, _ => false },
+        'J' => match value { &Variable::Long(_) => true, _ => false },
+        'F' => match value { &Variable::Float(_) => true, _ => false },
+        'D' => match value { &Variable::Double(_) => true, _ => false },
+        '[' | 'L' => match value {
+            // Fields nothing has been stored in yet, which read back as null
+            &Variable::UnresolvedReference(ref _x) => true,
+            _ => try!(is_assignable_value(runtime, descriptor, value)),
+        },
+        _ => return Err(RunnerError::ClassInvalid2(format!("Type specifier invalid {}", descriptor)))
+    });
+}
+
+#[derive(Clone, Debug)]
+pub struct ObjectHandle {
+    obj: Rc<Object>,
+}
+
+impl ObjectHandle {
+    pub fn new(runtime: &mut Runtime, class_name: &str, descriptor: &str, args: &Vec<Variable>) -> Result<ObjectHandle, RunnerError> {
+        let parameters = try!(parameter_descriptors(descriptor));
+        if parameters.len() != args.len() {
+            return Err(RunnerError::ClassInvalid2(format!("Constructor {} of {} takes {} arguments, {} given", descriptor, class_name, parameters.len(), args.len())));
+        }
+        for (parameter, arg) in parameters.iter().zip(args.iter()) {
+            if !try!(check_type(runtime, parameter.as_str(), arg)) {
+                return Err(RunnerError::ClassInvalid2(format!("Argument {} does not match parameter type {} of {} {}", arg, parameter, class_name, descriptor)));
+            }
+        }
+
+        let var = try!(construct_object(runtime, class_name));
+        let obj = var.to_ref().unwrap();
+
+        let mut locals = vec!(var.clone());
+        locals.extend(args.iter().cloned());
+        try!(invoke_nested(runtime, obj.type_ref(), locals, "<init>", descriptor, false));
+
+        return Ok(ObjectHandle { obj: obj });
+    }
+
+    pub fn from_variable(var: &Variable) -> Option<ObjectHandle> {
+        return match var {
+            &Variable::Reference(ref _class, ref obj) => obj.as_ref().map(|x| ObjectHandle { obj: x.clone() }),
+            &Variable::InterfaceReference(ref obj) => Some(ObjectHandle { obj: obj.clone() }),
+            _ => None
+        };
+    }
+
+    pub fn to_variable(&self) -> Variable {
+        return Variable::Reference(self.obj.type_ref(), Some(self.obj.clone()));
+    }
+
+    pub fn class_name(&self) -> String {
+        return self.obj.type_ref().name.clone();
+    }
+
+    pub fn get_field(&self, runtime: &mut Runtime, field_name: &str) -> Result<Variable, RunnerError> {
+        let class = self.obj.type_ref();
+        if try!(find_field_descriptor(&class, field_name)).is_none() {
+            return Err(RunnerError::ClassInvalid2(format!("Class {} has no field {}", class.name, field_name)));
+        }
+        return get_field(runtime, &Some(self.obj.clone()), class.name.as_str(), field_name);
+    }
+
+    pub fn set_field(&self, runtime: &mut Runtime, field_name: &str, value: Variable) -> Result<(), RunnerError> {
+        let class = self.obj.type_ref();
+        let descriptor = try!(try!(find_field_descriptor(&class, field_name))
+            .ok_or(RunnerError::ClassInvalid2(format!("Class {} has no field {}", class.name, field_name))));
+        if !try!(check_type(runtime, descriptor.as_str(), &value)) {
+            return Err(RunnerError::ClassInvalid2(format!("Value {} does not match type {} of field {} in {}", value, descriptor, field_name, class.name)));
+        }
+        return put_field(runtime, &Some(self.obj.clone()), field_name, value);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ArrayHandle {
+    array: Rc<ArrayObject>,
+}
+
+impl ArrayHandle {
+    pub fn new_primitive(runtime: &mut Runtime, element_descriptor: &str, length: usize) -> Result<ArrayHandle, RunnerError> {
+        let element = try!(initialise_variable(runtime, element_descriptor));
+        if element_descriptor.len() != 1 || !element.is_primitive() {
+            return Err(RunnerError::ClassInvalid2(format!("{} is not a primitive type", element_descriptor)));
+        }
+        let var = try!(construct_primitive_array(runtime, element_descriptor, Some(vec![element; length])));
+        return Ok(ArrayHandle { array: var.to_arrayobj() });
+    }
+
+    pub fn new_object(runtime: &mut Runtime, class_name: &str, length: usize) -> Result<ArrayHandle, RunnerError> {
+        let class = try!(load_class(runtime, class_name));
+        let null_object = try!(construct_null_object(runtime, class.clone()));
+        let var = try!(construct_array(runtime, class, Some(vec![null_object; length])));
+        return Ok(ArrayHandle { array: var.to_arrayobj() });
+    }
+
+    pub fn from_variable(var: &Variable) -> Option<ArrayHandle> {
+        return match var {
+            &Variable::ArrayReference(ref array) if !array.is_null => Some(ArrayHandle { array: array.clone() }),
+            _ => None
+        };
+    }
+
+    pub fn to_variable(&self) -> Variable {
+        return Variable::ArrayReference(self.array.clone());
+    }
+
+    pub fn element_descriptor(&self) -> String {
+        return self.array.element_type_str.clone();
+    }
+
+    pub fn is_primitive(&self) -> bool {
+        return self.array.element_type_ref.is_none();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.array.elements.borrow().len();
+    }
+
+    pub fn get(&self, index: usize) -> Option<Variable> {
+        return self.array.elements.borrow().get(index).map(|x| x.clone());
+    }
+
+    pub fn set(&self, runtime: &mut Runtime, index: usize, value: Variable) -> Result<(), RunnerError> {
+        if index >= self.len() {
+            return Err(RunnerError::ClassInvalid2(format!("Index {} out of bounds for array of length {}", index, self.len())));
+        }
+        let descriptor = self.element_descriptor();
+        if !try!(check_type(runtime, descriptor.as_str(), &value)) {
+            return Err(RunnerError::ClassInvalid2(format!("Value {} does not match array element type {}", value, descriptor)));
+        }
+        self.array.elements.borrow_mut()[index] = value;
+        return Ok(());
+    }
+
+    pub fn to_vec(&self) -> Vec<Variable> {
+        return self.array.elements.borrow().clone();
+    }
+}
+
+pub fn make_int_array(runtime: &mut Runtime, values: &[i32]) -> Result<Variable, RunnerError> {
+    let data = values.iter().map(|x| Variable::Int(*x)).collect();
+    return construct_primitive_array(runtime, "I", Some(data));
+}
+
+pub fn extract_int_array(var: &Variable) -> Result<Vec<i32>, RunnerError> {
+    let array = try!(ArrayHandle::from_variable(var).ok_or(RunnerError::ClassInvalid2(format!("{} is not an array", var))));
+    if array.element_descriptor() != "I" {
+        return Err(RunnerError::ClassInvalid2(format!("{} is not an int array", var)));
+    }
+    return Ok(array.to_vec().iter().map(|x| x.to_int()).collect());
+}
+
+// Fails rather than replacing lone surrogates, use extract_string_utf16 for those
+pub fn extract_string(runtime: &mut Runtime, var: &Variable) -> Result<String, RunnerError> {
+    let units = try!(extract_string_utf16(runtime, var));
+    return String::from_utf16(&units).map_err(|_| RunnerError::ClassInvalid2(format!("{} has a lone surrogate", var)));
+}
+
+pub fn extract_string_utf16(runtime: &mut Runtime, var: &Variable) -> Result<Vec<u16>, RunnerError> {
+    if !var.is_reference() || var.to_ref_type().name != "java/lang/String" {
+        return Err(RunnerError::ClassInvalid2(format!("{} is not a string", var)));
+    }
+    return extract_utf16_from_string(runtime, &var.to_ref());
+}
+
+pub fn make_hash_map(runtime: &mut Runtime, values: &HashMap<String, String>) -> Result<Variable, RunnerError> {
+    let map = try!(ObjectHandle::new(runtime, "java/util/HashMap", "()V", &Vec::new()));
+    let map_var = map.to_variable();
+    let map_class = map_var.to_ref_type();
+    for (key, value) in values {
+        let key_var = try!(make_string(runtime, key.as_str()));
+        let value_var = try!(make_string(runtime, value.as_str()));
+        try!(invoke_nested(runtime, map_class.clone(), vec!(map_var.clone(), key_var, value_var), "put", "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;", false));
+        runtime.current_frame.operand_stack.pop_ref();
+    }
+    return Ok(map_var);
+}
+
+pub fn extract_hash_map(runtime: &mut Runtime, var: &Variable) -> Result<HashMap<String, String>, RunnerError> {
+    let map = try!(ObjectHandle::from_variable(var).ok_or(RunnerError::ClassInvalid2(format!("{} is not an object", var))));
+    let map_class = try!(load_class(runtime, "java/util/HashMap"));
+    if Class::find_superclass(map.obj.type_ref(), Rc::new(String::from("java/util/HashMap"))).is_none() {
+        return Err(RunnerError::ClassInvalid2(format!("{} is not a java.util.HashMap", var)));
+    }
+
+    let mut ret = HashMap::new();
+    let table = try!(get_field(runtime, &Some(map.obj.clone()), map_class.name.as_str(), "table"));
+    if table.is_null() {
+        return Ok(ret);
+    }
+    let buckets = table.to_arrayobj().elements.borrow().clone();
+    for bucket in buckets {
+        let mut node = bucket;
+        while !node.is_null() {
+            let node_obj = node.to_ref();
+            let key = try!(get_field(runtime, &node_obj, "java/util/HashMap$Node", "key"));
+            let value = try!(get_field(runtime, &node_obj, "java/util/HashMap$Node", "value"));
+            let key_string = try!(extract_string(runtime, &key));
+            let value_string = try!(extract_string(runtime, &value));
+            ret.insert(key_string, value_string);
+            node = try!(get_field(runtime, &node_obj, "java/util/HashMap$Node", "next"));
+        }
+    }
+    return Ok(ret);
+}
@@ -1,6 +1,8 @@
+use reader::class_reader::{ACC_ABSTRACT, ACC_FINAL, ACC_PRIVATE, ACC_PROTECTED, ACC_PUBLIC};
 use reader::jvm::construction::*;
 use reader::runner::*;
 use reader::util::*;
+use std::rc::Rc;
 
 pub fn get_primitive_class_object(runtime: &mut Runtime, descriptor: String) -> Result<Variable, RunnerError> {
     if descriptor.len() > 1 {
@@ -46,29 +48,25 @@ pub fn get_class_object_from_descriptor(runtime: &mut Runtime, descriptor: &str)
     statics.borrow_mut().insert(String::from("initted"), Variable::Boolean(true));
     let obj = var.to_ref();
 
-    let subtype = try!(parse_single_type_descriptor(runtime, descriptor, false));
     let mut is_primitive = false;
     let mut is_array = false;
     let mut is_unresolved = false;
-    match subtype {
-        Variable::UnresolvedReference(ref _type_string) => {
-            is_unresolved = true;
-        },
-        Variable::Reference(ref class, ref _x) => {
-            let null_obj = try!(construct_null_object(runtime, class.clone()));
-            try!(put_field(runtime, &obj, "__class", null_obj));
-        },
-        Variable::ArrayReference(ref array_obj) => {
-            is_array = true;
-            let component_type;
-            if array_obj.element_type_ref.is_some() {
-                component_type = try!(get_class_object_from_descriptor(runtime, array_obj.element_type_str.clone().as_str()));
-            } else {
-                component_type = try!(get_primitive_class_object(runtime, array_obj.element_type_str.clone()));
-            }
-            try!(put_field(runtime, &obj, "__componentType", component_type));
-        },
-        _ => { is_primitive = true; }
+    if descriptor.starts_with('[') {
+        // The component's own Class object loads it when needed, so arrays of unloaded classes are still arrays
+        is_array = true;
+        let component_type = try!(get_class_object_for_type(runtime, &descriptor[1..]));
+        try!(put_field(runtime, &obj, "__componentType", component_type));
+    } else {
+        match try!(parse_single_type_descriptor(runtime, descriptor, false)) {
+            Variable::UnresolvedReference(ref _type_string) => {
+                is_unresolved = true;
+            },
+            Variable::Reference(ref class, ref _x) => {
+                let null_obj = try!(construct_null_object(runtime, class.clone()));
+                try!(put_field(runtime, &obj, "__class", null_obj));
+            },
+            _ => { is_primitive = true; }
+        }
     }
     try!(put_field(runtime, &obj, "__is_primitive", Variable::Boolean(is_primitive)));
     try!(put_field(runtime, &obj, "__is_array", Variable::Boolean(is_array)));
@@ -76,3 +74,68 @@ pub fn get_class_object_from_descriptor(runtime: &mut Runtime, descriptor: &str)
 
     return Ok(var);
 }
+
+// Class object for a name as CONSTANT_Class entries hold it, where arrays are already descriptors
+pub fn get_class_object_from_name(runtime: &mut Runtime, name: &str) -> Result<Variable, RunnerError> {
+    if name.starts_with('[') {
+        return get_class_object_from_descriptor(runtime, name);
+    }
+    return get_class_object_from_descriptor(runtime, format!("L{};", name).as_str());
+}
+
+// Class object for any field type or void, where primitives share the objects getPrimitiveClass hands out
+pub fn get_class_object_for_type(runtime: &mut Runtime, descriptor: &str) -> Result<Variable, RunnerError> {
+    if descriptor.len() == 1 {
+        return get_primitive_class_object(runtime, String::from(descriptor));
+    }
+    return get_class_object_from_descriptor(runtime, descriptor);
+}
+
+// The field type descriptor a Class object stands for, V for void
+pub fn descriptor_of_class_object(runtime: &mut Runtime, class_obj: &Variable) -> Result<String, RunnerError> {
+    let obj = class_obj.to_ref();
+    let name_var = try!(get_field(runtime, &obj, "java/lang/Class", "name"));
+    let name = try!(extract_from_string(runtime, &name_var.to_ref())).replace('.', "/");
+    if obj.as_ref().unwrap().get_member(&String::from("__is_primitive")).map(|x| x.to_bool()).unwrap_or(false) {
+        return Ok(type_name_to_descriptor(&name));
+    }
+    if name.starts_with('[') {
+        return Ok(name);
+    }
+    return Ok(format!("L{};", name));
+}
+
+// The class behind a Class object, None for primitives and arrays. Classes not loaded when the object was made are loaded now.
+pub fn class_of(runtime: &mut Runtime, class_obj: &Variable) -> Result<Option<Rc<Class>>, RunnerError> {
+    let obj = class_obj.to_ref();
+    match obj.as_ref().unwrap().get_member(&String::from("__class")) {
+        Some(Variable::Reference(class, _)) => return Ok(Some(class)),
+        _ => {}
+    }
+    if !obj.as_ref().unwrap().get_member(&String::from("__is_unresolved")).map(|x| x.to_bool()).unwrap_or(false) {
+        return Ok(None);
+    }
+    let name_var = try!(get_field(runtime, &obj, "java/lang/Class", "name"));
+    let name = try!(extract_from_string(runtime, &name_var.to_ref())).replace('.', "/");
+    let class = try!(load_class(runtime, name.as_str()));
+    let null_obj = try!(construct_null_object(runtime, class.clone()));
+    try!(put_field(runtime, &obj, "__class", null_obj));
+    try!(put_field(runtime, &obj, "__is_unresolved", Variable::Boolean(false)));
+    return Ok(Some(class));
+}
+
+// The Class object of an array's innermost element type, None when it isn't an array
+pub fn array_element_class_object(class_obj: &Variable) -> Option<Variable> {
+    let mut element = None;
+    let mut current = class_obj.clone();
+    while current.to_ref().unwrap().get_member(&String::from("__is_array")).map(|x| x.to_bool()).unwrap_or(false) {
+        current = current.to_ref().unwrap().get_member(&String::from("__componentType")).unwrap();
+        element = Some(current.clone());
+    }
+    return element;
+}
+
+// Arrays are as visible as their element type, primitives counting as public, and are always final and abstract
+pub fn array_modifiers(element_modifiers: u16) -> u16 {
+    return (element_modifiers & (ACC_PUBLIC | ACC_PRIVATE | ACC_PROTECTED)) | ACC_FINAL | ACC_ABSTRACT;
+}
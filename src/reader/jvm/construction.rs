@@ -11,12 +11,9 @@ pub fn initialise_variable(runtime: &mut Runtime, descriptor_string: &str) -> Re
     return Ok(variable);
 }
 
-pub fn construct_char_array(runtime: &mut Runtime, s: &str) -> Variable {
-    let mut v : Vec<Variable> = Vec::new();
-    for c in s.chars() {
-        v.push(Variable::Char(c));
-    }
-    runtime.free_mem -= v.len() as i64 + size_of::<(ArrayObject)>() as i64;
+pub fn construct_char_array(runtime: &mut Runtime, units: &[u16]) -> Variable {
+    let v : Vec<Variable> = units.iter().map(|x| Variable::Char(*x)).collect();
+    runtime.free_mem -= (v.len() * size_of::<u16>()) as i64 + size_of::<(ArrayObject)>() as i64;
     let array_object = ArrayObject {
         is_null: false,
         element_type_ref: None,
@@ -44,6 +41,36 @@ pub fn construct_array_by_name(runtime: &mut Runtime, name: &str, data: Option<V
     return construct_array(runtime, class, data);
 }
 
+// Arrays of arrays have no element class, element_type_str holds the element descriptor instead
+pub fn construct_array_by_descriptor(runtime: &mut Runtime, element_type: &str, data: Option<Vec<Variable>>) -> Result<Variable, RunnerError> {
+    if element_type.starts_with('L') {
+        return construct_array_by_name(runtime, &element_type[1..element_type.len()-1], data);
+    }
+    return construct_primitive_array(runtime, element_type, data);
+}
+
+// A new array with the same type and elements, the shallow copy clone() makes
+pub fn construct_array_clone(runtime: &mut Runtime, array: &ArrayObject) -> Variable {
+    let elements = array.elements.borrow().clone();
+    runtime.free_mem -= size_of::<(ArrayObject)>() as i64 + elements.len() as i64;
+    let array_object = ArrayObject {
+        is_null: array.is_null,
+        element_type_ref: array.element_type_ref.clone(),
+        element_type_str: array.element_type_str.clone(),
+        elements: RefCell::new(elements),
+        code: runtime.get_next_object_code()
+    };
+    return Variable::ArrayReference(Rc::new(array_object));
+}
+
+// Zero, false or null, what a field or array element of the type starts as
+pub fn default_value(runtime: &mut Runtime, descriptor: &str) -> Result<Variable, RunnerError> {
+    if descriptor.starts_with('[') {
+        return construct_array_by_descriptor(runtime, &descriptor[1..], None);
+    }
+    return parse_single_type_descriptor(runtime, descriptor, descriptor.starts_with('L'));
+}
+
 pub fn construct_primitive_array(runtime: &mut Runtime, element_type: &str, data: Option<Vec<Variable>>) -> Result<Variable, RunnerError> {
     // TODO
     runtime.free_mem -= size_of::<(ArrayObject)>() as i64 + data.as_ref().map(|x| x.len()).unwrap_or(0) as i64;
@@ -99,3 +126,10 @@ pub fn construct_object(runtime: &mut Runtime, name: &str) -> Result<Variable, R
         }
     }
 }
+
+pub fn construct_exception(runtime: &mut Runtime, name: &str, message: &str) -> Result<Variable, RunnerError> {
+    let exception = try!(construct_object(runtime, name));
+    let message_var = try!(make_string(runtime, message));
+    try!(put_field_specific_class_name(runtime, &exception.to_ref(), "java/lang/Throwable", "detailMessage", message_var));
+    return Ok(exception);
+}
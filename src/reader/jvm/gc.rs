@@ -24,11 +24,11 @@ fn mark_var(collectable_objects: &mut Vec<Weak<Object>>, var: &Variable) {
 }
 
 fn mark_frame(collectable_objects: &mut Vec<Weak<Object>>, frame: &Frame) {
-    for var in &frame.local_variables {
+    for var in frame.local_variables.references() {
         mark_var(collectable_objects, var);
     }
 
-    for var in &frame.operand_stack {
+    for var in frame.operand_stack.references() {
         mark_var(collectable_objects, var);
     }
 }
@@ -1,30 +1,35 @@
-extern crate byteorder;
-
-use self::byteorder::{BigEndian, ReadBytesExt};
+use reader::builtins::call_native;
 use reader::class_reader::*;
 use reader::jvm::construction::*;
 use reader::jvm::class_objects::*;
 use reader::runner::*;
 use reader::util::*;
 use std;
-use std::io::Cursor;
+use std::cmp::Ordering;
 use std::ops::BitAnd;
 use std::ops::BitOr;
 use std::ops::BitXor;
 use std::rc::Rc;
 
-fn load<F>(desc: &str, index: u8, runtime: &mut Runtime, _t: F) -> Result<(), RunnerError> { // TODO: Type checking
-    let loaded = runtime.current_frame.local_variables[index as usize].clone();
+fn load<T: SlotValue>(desc: &str, index: usize, runtime: &mut Runtime) -> Result<(), RunnerError> {
+    let loaded: T = runtime.current_frame.local_variables.get(index);
+    runnerPrint!(runtime, true, 2, "{} {} {}", desc, index, loaded);
+    runtime.current_frame.operand_stack.push(loaded);
+    return Ok(());
+}
+
+fn load_ref(desc: &str, index: usize, runtime: &mut Runtime) -> Result<(), RunnerError> {
+    let loaded = runtime.current_frame.local_variables.get_ref(index);
     runnerPrint!(runtime, true, 2, "{} {} {}", desc, index, loaded);
-    runtime.push_on_stack(loaded);
+    runtime.current_frame.operand_stack.push_ref(loaded);
     return Ok(());
 }
 
-fn aload<F, G>(desc: &str, runtime: &mut Runtime, _t: F, converter: G) -> Result<(), RunnerError>
+fn aload<G>(desc: &str, runtime: &mut Runtime, converter: G) -> Result<(), RunnerError>
     where G: Fn(Variable) -> Variable
 { // TODO: Type checking
-    let index = runtime.pop_from_stack().unwrap().to_int();
-    let var = runtime.pop_from_stack().unwrap();
+    let index = runtime.current_frame.operand_stack.pop::<i32>();
+    let var = runtime.current_frame.operand_stack.pop_ref();
     let array_obj = var.to_arrayobj();
     runnerPrint!(runtime, true, 2, "{} {} {}", desc, index, var);
     if array_obj.is_null {
@@ -44,23 +49,26 @@ fn aload<F, G>(desc: &str, runtime: &mut Runtime, _t: F, converter: G) -> Result
     return Ok(());
 }
 
-fn store<F>(desc: &str, index: u8, runtime: &mut Runtime, _t: F) -> Result<(), RunnerError> { // TODO: Type checking
-    let popped = runtime.pop_from_stack().unwrap();
+fn store<T: SlotValue>(desc: &str, index: usize, runtime: &mut Runtime) -> Result<(), RunnerError> {
+    let popped: T = runtime.current_frame.operand_stack.pop();
     runnerPrint!(runtime, true, 2, "{}_{} {}", desc, index, popped);
-    while runtime.current_frame.local_variables.len() <= index as usize {
-        runtime.current_frame.local_variables.push(Variable::Int(0));
-    }
-    runtime.current_frame.local_variables[index as usize] = popped;
+    runtime.current_frame.local_variables.set(index, popped);
     return Ok(());
 }
 
+fn store_ref(desc: &str, index: usize, runtime: &mut Runtime) -> Result<(), RunnerError> {
+    let popped = runtime.current_frame.operand_stack.pop_ref();
+    runnerPrint!(runtime, true, 2, "{}_{} {}", desc, index, popped);
+    runtime.current_frame.local_variables.set_ref(index, popped);
+    return Ok(());
+}
 
-fn astore<F>(desc: &str, runtime: &mut Runtime, converter: F) -> Result<(), RunnerError>
+fn astore<F>(desc: &str, runtime: &mut Runtime, type_char: char, converter: F) -> Result<(), RunnerError>
     where F: Fn(&Variable) -> Variable
 { // TODO: Type checking
-    let value = runtime.pop_from_stack().unwrap();
-    let index = runtime.pop_from_stack().unwrap().to_int();
-    let var = runtime.pop_from_stack().unwrap();
+    let value = runtime.current_frame.operand_stack.pop_typed(type_char);
+    let index = runtime.current_frame.operand_stack.pop::<i32>();
+    let var = runtime.current_frame.operand_stack.pop_ref();
     let array_obj = var.to_arrayobj();
     runnerPrint!(runtime, true, 2, "{} {} {}", desc, index, var);
     if array_obj.is_null {
@@ -78,164 +86,180 @@ fn astore<F>(desc: &str, runtime: &mut Runtime, converter: F) -> Result<(), Runn
     return Ok(());
 }
 
+fn check_array_size(runtime: &mut Runtime, count: i32) -> Result<(), RunnerError> {
+    if count < 0 {
+        let exception = try!(construct_exception(runtime, "java/lang/NegativeArraySizeException", count.to_string().as_str()));
+        return Err(RunnerError::Exception(exception));
+    }
+    return Ok(());
+}
+
 fn and<F>(a: F, b: F) -> <F as std::ops::BitAnd>::Output where F: BitAnd { a&b }
 fn or<F>(a: F, b: F) -> <F as std::ops::BitOr>::Output where F: BitOr { a|b }
 fn xor<F>(a: F, b: F) -> <F as std::ops::BitXor>::Output where F: BitXor { a^b }
 
-fn maths_instr<F, G, H, K>(desc: &str, runtime: &mut Runtime, creator: F, extractor: G, operation: H)
+fn maths_instr<K, H>(desc: &str, runtime: &mut Runtime, operation: H)
     where
-        F: Fn(K) -> Variable,
-        G: Fn(&Variable) -> K,
+        K: SlotValue,
         H: Fn(K, K) -> K
 {
-    let popped1 = runtime.pop_from_stack().unwrap();
-    let popped2 = runtime.pop_from_stack().unwrap();
+    let popped1: K = runtime.current_frame.operand_stack.pop();
+    let popped2: K = runtime.current_frame.operand_stack.pop();
     runnerPrint!(runtime, true, 2, "{} {} {}", desc, popped1, popped2);
-    runtime.push_on_stack(creator(operation(extractor(&popped2), extractor(&popped1))));
+    runtime.current_frame.operand_stack.push(operation(popped2, popped1));
+}
+
+// Integer division and remainder, which throw on a zero divisor
+fn divide_instr<K, H>(desc: &str, runtime: &mut Runtime, operation: H) -> Result<(), RunnerError>
+    where
+        K: SlotValue + PartialEq + Default,
+        H: Fn(K, K) -> K
+{
+    let popped1: K = runtime.current_frame.operand_stack.pop();
+    let popped2: K = runtime.current_frame.operand_stack.pop();
+    runnerPrint!(runtime, true, 2, "{} {} {}", desc, popped1, popped2);
+    if popped1 == K::default() {
+        let exception = try!(construct_exception(runtime, "java/lang/ArithmeticException", "/ by zero"));
+        return Err(RunnerError::Exception(exception));
+    }
+    runtime.current_frame.operand_stack.push(operation(popped2, popped1));
+    return Ok(());
 }
 
-fn maths_instr_2<F, G, H, I, J, K, L>(desc: &str, runtime: &mut Runtime, creator: F, extractor1: G, extractor2: H, operation: I)
+fn shift_instr<K, H>(desc: &str, runtime: &mut Runtime, operation: H)
     where
-        F: Fn(L) -> Variable,
-        G: Fn(&Variable) -> J,
-        H: Fn(&Variable) -> K,
-        I: Fn(K, J) -> L
+        K: SlotValue,
+        H: Fn(K, i32) -> K
 {
-    let popped1 = runtime.pop_from_stack().unwrap();
-    let popped2 = runtime.pop_from_stack().unwrap();
+    let popped1: i32 = runtime.current_frame.operand_stack.pop();
+    let popped2: K = runtime.current_frame.operand_stack.pop();
     runnerPrint!(runtime, true, 2, "{} {} {}", desc, popped1, popped2);
-    runtime.push_on_stack(creator(operation(extractor2(&popped2), extractor1(&popped1))));
+    runtime.current_frame.operand_stack.push(operation(popped2, popped1));
 }
 
-fn single_pop_instr<F, G, H, I, J>(desc: &str, runtime: &mut Runtime, creator: F, extractor: G, operation: H)
+fn cast<I, J, H>(desc: &str, runtime: &mut Runtime, operation: H)
     where
-        F: Fn(J) -> Variable,
-        G: Fn(&Variable) -> I,
+        I: SlotValue,
+        J: SlotValue,
         H: Fn(I) -> J
 {
-    let popped = runtime.pop_from_stack().unwrap();
+    let popped: I = runtime.current_frame.operand_stack.pop();
     runnerPrint!(runtime, true, 2, "{} {}", desc, popped);
-    runtime.push_on_stack(creator(operation(extractor(&popped))));
+    runtime.current_frame.operand_stack.push(operation(popped));
 }
 
-fn vreturn<F, K>(desc: &str, runtime: &mut Runtime, extractor: F) -> Result<bool, RunnerError> where F: Fn(&Variable) -> K {
-    let popped = runtime.pop_from_stack().unwrap();
+fn vreturn<K: SlotValue>(desc: &str, runtime: &mut Runtime) -> Result<Control, RunnerError> {
+    let popped: K = runtime.current_frame.operand_stack.pop();
     runnerPrint!(runtime, true, 1, "{} {}", desc, popped);
-    extractor(&popped); // Type check
     runtime.current_frame = runtime.previous_frames.pop().unwrap();
-    runtime.push_on_stack(popped);
-    return Err(RunnerError::Return);
+    runtime.current_frame.operand_stack.push(popped);
+    return Ok(Control::Return);
 }
 
+fn areturn(desc: &str, runtime: &mut Runtime) -> Result<Control, RunnerError> {
+    let popped = runtime.current_frame.operand_stack.pop_ref();
+    runnerPrint!(runtime, true, 1, "{} {}", desc, popped);
+    runtime.current_frame = runtime.previous_frames.pop().unwrap();
+    runtime.current_frame.operand_stack.push_ref(popped);
+    return Ok(Control::Return);
+}
+
+// Arguments are one value each with the receiver first, the same as run_method and ObjectHandle::new take them
 pub fn invoke_nested(runtime: &mut Runtime, class: Rc<Class>, args: Vec<Variable>, method_name: &str, method_descriptor: &str, allow_not_found: bool) -> Result<(), RunnerError>{
-    let maybe_code = class.cr.get_code(method_name, method_descriptor);
-    if maybe_code.is_err() {
+    let maybe_method = class.get_method(method_name, method_descriptor);
+    if maybe_method.is_none() {
         if allow_not_found { return Ok(()) }
-            else { try!(Err(maybe_code.err().unwrap())) }
-    } else {
-        let new_frame = Frame {
-            class: Some(class.clone()),
-            constant_pool: class.cr.constant_pool.clone(),
-            operand_stack: Vec::new(),
-            local_variables: args.clone(),
-            name: String::from(class.name.clone() + method_name),
-            code: maybe_code.unwrap(),
-            return_pos: 0,
-        };
+        return Err(RunnerError::ClassInvalid2(format!("Could not find method '{}' '{}' in class '{}'", method_name, method_descriptor, class.name)));
+    }
+    let method = maybe_method.unwrap();
+    let expected = try!(argument_types(method_descriptor)).len() + if method.is_static() {0} else {1};
+    if args.len() != expected {
+        return Err(RunnerError::ClassInvalid2(format!("Method '{}' '{}' in class '{}' takes {} arguments, {} given", method_name, method_descriptor, class.name, expected, args.len())));
+    }
+
+    let maybe_native = method.native.borrow().clone();
+    if maybe_native.is_some() {
+        runnerPrint!(runtime, true, 1, "INVOKE manual native {} {} on {}", method_name, method_descriptor, class.name);
+        return call_native(runtime, maybe_native.as_ref().unwrap(), &args);
+    }
+    if method.is_native() {
+        return Err(try!(unsatisfied_link_error(runtime, class.name.as_str(), method_name, method_descriptor)));
+    }
 
-        runnerPrint!(runtime, true, 1, "INVOKE manual {} {} on {}", method_name, method_descriptor, class.name);
-        runtime.previous_frames.push(runtime.current_frame.clone());
-        runtime.current_frame = new_frame;
-        return do_run_method(runtime);
+    if runtime.previous_frames.len() >= runtime.max_call_depth {
+        return Err(try!(stack_overflow_error(runtime)));
     }
+    let code = try!(method.code.as_ref().ok_or(RunnerError::ClassInvalid2(format!("Method '{}' '{}' in class '{}' has no code", method_name, method_descriptor, class.name))));
+    let new_frame = Frame::for_method(&class, &method, code, Slots::from_arguments(&args, code.max_locals as usize));
+
+    runnerPrint!(runtime, true, 1, "INVOKE manual {} {} on {}", method_name, method_descriptor, class.name);
+    let previous_frame = std::mem::replace(&mut runtime.current_frame, new_frame);
+    runtime.previous_frames.push(previous_frame);
+    return do_run_method(runtime);
 }
 
-fn invoke(desc: &str, runtime: &mut Runtime, index: u16, with_obj: bool, special: bool) -> Result<(), RunnerError> {
-    let (class_name, method_name, descriptor) = try!(runtime.current_frame.constant_pool.get_method(index)).clone();
-    runnerPrint!(runtime, true, 1, "{} {} {} {}", desc, class_name, method_name, descriptor);
+fn invoke(desc: &str, runtime: &mut Runtime, index: u16, with_obj: bool, special: bool) -> Result<Control, RunnerError> {
+    let resolved = try!(resolve_method(runtime, index));
+    runnerPrint!(runtime, true, 1, "{} {} {} {}", desc, resolved.class_name, resolved.method_name, resolved.descriptor);
 
-    return runtime.invoke(class_name, method_name, descriptor, with_obj, special);
+    return runtime.invoke_resolved(&resolved, with_obj, special);
 }
 
-fn fcmp(desc: &str, runtime: &mut Runtime, is_g: bool) -> Result<(), RunnerError> {
-    let pop2 = runtime.pop_from_stack().unwrap().to_float();
-    let pop1 = runtime.pop_from_stack().unwrap().to_float();
+fn fcmp<K: SlotValue + PartialOrd>(desc: &str, runtime: &mut Runtime, is_g: bool) -> Result<(), RunnerError> {
+    let pop2: K = runtime.current_frame.operand_stack.pop();
+    let pop1: K = runtime.current_frame.operand_stack.pop();
     runnerPrint!(runtime, true, 2, "{} {} {}", desc, pop1, pop2);
-    let ret;
-    if pop1.is_nan() || pop2.is_nan() {
-        ret = if is_g {1} else {-1}
-    } else if pop1 > pop2 {
-        ret = 1;
-    } else if pop1 == pop2 {
-        ret = 0;
-    } else {
-        ret = -1;
-    }
-    runtime.push_on_stack(Variable::Int(ret));
+    let ret = match pop1.partial_cmp(&pop2) {
+        None => if is_g {1} else {-1}, // NaN
+        Some(Ordering::Greater) => 1,
+        Some(Ordering::Equal) => 0,
+        Some(Ordering::Less) => -1,
+    };
+    runtime.current_frame.operand_stack.push::<i32>(ret);
     return Ok(());
 }
 
-fn ifcmp<F>(desc: &str, runtime: &mut Runtime, buf: &mut Cursor<&Vec<u8>>, cmp: F) -> Result<(), RunnerError>
+fn branch(runtime: &mut Runtime, taken: bool, pc: usize, target: usize) -> Control {
+    if taken {
+        runnerPrint!(runtime, true, 2, "BRANCHED from {} to {}", pc, target);
+        return Control::Jump(target);
+    }
+    return Control::Next;
+}
+
+fn ifcmp<F>(desc: &str, runtime: &mut Runtime, pc: usize, target: usize, cmp: F) -> Control
     where F: Fn(i32) -> bool
 {
-    let current_position = buf.position() - 1;
-    let branch_offset = try!(buf.read_u16::<BigEndian>()) as i16;
-    let popped = runtime.pop_from_stack().unwrap();
-    runnerPrint!(runtime, true, 2, "{} {} {}", desc, popped, branch_offset);
-    if cmp(popped.to_int()) {
-        let new_position = (current_position as i64 + branch_offset as i64) as u64;
-        runnerPrint!(runtime, true, 2, "BRANCHED from {} to {}", current_position, new_position);
-        buf.set_position(new_position);
-    }
-    return Ok(());
+    let popped: i32 = runtime.current_frame.operand_stack.pop();
+    runnerPrint!(runtime, true, 2, "{} {} {}", desc, popped, target);
+    let taken = cmp(popped);
+    return branch(runtime, taken, pc, target);
 }
 
-fn branch_if<F>(desc: &str, runtime: &mut Runtime, buf: &mut Cursor<&Vec<u8>>, current_position: u64, cmp: F) -> Result<(), RunnerError>
+fn branch_if<F>(desc: &str, runtime: &mut Runtime, pc: usize, target: usize, cmp: F) -> Control
     where F: Fn(&Variable) -> bool
 {
-    let branch_offset = try!(buf.read_u16::<BigEndian>()) as i16;
-    let var = runtime.pop_from_stack().unwrap();
+    let var = runtime.current_frame.operand_stack.pop_ref();
     let compare_result = cmp(&var);
-    runnerPrint!(runtime, true, 2, "{} {} {} {}", desc, var, branch_offset, compare_result);
-    if compare_result {
-        let new_pos = (current_position as i64 + branch_offset as i64) as u64;
-        runnerPrint!(runtime, true, 2, "BRANCHED from {} to {}", current_position, new_pos);
-        buf.set_position(new_pos);
-    }
-    return Ok(());
+    runnerPrint!(runtime, true, 2, "{} {} {} {}", desc, var, target, compare_result);
+    return branch(runtime, compare_result, pc, target);
 }
 
-fn icmp<F>(desc: &str, runtime: &mut Runtime, buf: &mut Cursor<&Vec<u8>>, cmp: F) -> Result<(), RunnerError>
+fn icmp<F>(desc: &str, runtime: &mut Runtime, pc: usize, target: usize, cmp: F) -> Control
     where F: Fn(i32, i32) -> bool
 {
-    let current_position = buf.position() - 1;
-    let branch_offset = try!(buf.read_u16::<BigEndian>()) as i16;
-    let popped2 = runtime.pop_from_stack().unwrap();
-    let popped1 = runtime.pop_from_stack().unwrap();
-    runnerPrint!(runtime, true, 2, "{} {} {} {}", desc, popped1, popped2, branch_offset);
-    if cmp(popped1.to_int(), popped2.to_int()) {
-        let new_position = (current_position as i64 + branch_offset as i64) as u64;
-        runnerPrint!(runtime, true, 2, "BRANCHED from {} to {}", current_position, new_position);
-        buf.set_position(new_position);
-    }
-    return Ok(());
-}
-
-fn cast<F>(desc: &str, runtime: &mut Runtime, mutator: F)
-    where F: Fn(&Variable) -> Variable
-{
-    let popped = runtime.pop_from_stack().unwrap();
-    runnerPrint!(runtime, true, 2, "{} {}", desc, popped);
-    runtime.push_on_stack(mutator(&popped));
+    let popped2: i32 = runtime.current_frame.operand_stack.pop();
+    let popped1: i32 = runtime.current_frame.operand_stack.pop();
+    runnerPrint!(runtime, true, 2, "{} {} {} {}", desc, popped1, popped2, target);
+    let taken = cmp(popped1, popped2);
+    return branch(runtime, taken, pc, target);
 }
 
-fn ifacmp(desc: &str, runtime: &mut Runtime, buf: &mut Cursor<&Vec<u8>>, should_match: bool) -> Result<(), RunnerError>
+fn ifacmp(desc: &str, runtime: &mut Runtime, pc: usize, target: usize, should_match: bool) -> Control
 {
-    let current_position = buf.position() - 1;
-    let branch_offset = try!(buf.read_u16::<BigEndian>()) as i16;
-    let popped2 = runtime.pop_from_stack().unwrap();
-    let popped1 = runtime.pop_from_stack().unwrap();
-    runnerPrint!(runtime, true, 2, "{} {} {} {}", desc, popped1, popped2, branch_offset);
+    let popped2 = runtime.current_frame.operand_stack.pop_ref();
+    let popped1 = runtime.current_frame.operand_stack.pop_ref();
+    runnerPrint!(runtime, true, 2, "{} {} {} {}", desc, popped1, popped2, target);
     let matching =
         if popped1.is_reference() && popped2.is_reference() {
             let obj1 = popped1.to_ref();
@@ -248,12 +272,7 @@ fn ifacmp(desc: &str, runtime: &mut Runtime, buf: &mut Cursor<&Vec<u8>>, should_
         } else {
             false
         };
-    if should_match == matching {
-        let new_position = (current_position as i64 + branch_offset as i64) as u64;
-        runnerPrint!(runtime, true, 2, "BRANCHED from {} to {}", current_position, new_position);
-        buf.set_position(new_position);
-    }
-    return Ok(());
+    return branch(runtime, should_match == matching, pc, target);
 }
 
 fn ldc(runtime: &mut Runtime, index: usize) -> Result<(), RunnerError> {
@@ -264,9 +283,9 @@ fn ldc(runtime: &mut Runtime, index: usize) -> Result<(), RunnerError> {
     } else {
         match maybe_cp_entry.as_ref().unwrap() {
             &ConstantPoolItem::CONSTANT_String { index } => {
-                let str = try!(runtime.current_frame.constant_pool.get_str(index));
-                runnerPrint!(runtime, true, 2, "LDC string {}", str);
-                let var = try!(make_string(runtime, str.as_str()));
+                let units = try!(runtime.current_frame.constant_pool.get_utf16(index));
+                runnerPrint!(runtime, true, 2, "LDC string {}", String::from_utf16_lossy(&units));
+                let var = try!(make_string_utf16(runtime, &units));
                 runtime.push_on_stack(var);
             }
             &ConstantPoolItem::CONSTANT_Class { index } => {
@@ -292,11 +311,11 @@ fn ldc(runtime: &mut Runtime, index: usize) -> Result<(), RunnerError> {
             }
             &ConstantPoolItem::CONSTANT_Integer { value } => {
                 runnerPrint!(runtime, true, 2, "LDC int {}", value as i32);
-                runtime.push_on_stack(Variable::Int(value as i32));
+                runtime.current_frame.operand_stack.push(value as i32);
             }
             &ConstantPoolItem::CONSTANT_Float { value } => {
                 runnerPrint!(runtime, true, 2, "LDC float {}", value as f32);
-                runtime.push_on_stack(Variable::Float(value as f32));
+                runtime.current_frame.operand_stack.push(value as f32);
             }
             _ => return Err(RunnerError::ClassInvalid2(format!("Unknown constant {:?}", maybe_cp_entry.as_ref().unwrap())))
         }
@@ -304,12 +323,11 @@ fn ldc(runtime: &mut Runtime, index: usize) -> Result<(), RunnerError> {
     return Ok(());
 }
 
-pub fn step(runtime: &mut Runtime, name: &str, buf: &mut Cursor<&Vec<u8>>) -> Result<bool, RunnerError> {
-    let current_position = buf.position();
-    let op_code = try!(buf.read_u8());
-    runnerPrint!(runtime, true, 3, "{} {} Op code {}", name, runtime.count, op_code);
+pub fn step(runtime: &mut Runtime, instruction: &Instruction, pc: usize) -> Result<Control, RunnerError> {
+    let op_code = instruction.op;
     runtime.count+=1;
     match op_code {
+        0 => {}
         1 => {
             runnerPrint!(runtime, true, 2, "ACONST_NULL");
             let obj = try!(construct_null_object_by_name(runtime, "java/lang/Object"));
@@ -318,38 +336,36 @@ pub fn step(runtime: &mut Runtime, name: &str, buf: &mut Cursor<&Vec<u8>>) -> Re
         2...8 => {
             let val = (op_code as i32) - 3;
             runnerPrint!(runtime, true, 2, "ICONST {}", val);
-            runtime.push_on_stack(Variable::Int(val));
+            runtime.current_frame.operand_stack.push(val);
         }
         9...10 => {
             let val = (op_code as i64) - 9;
             runnerPrint!(runtime, true, 2, "LCONST {}", val);
-            runtime.push_on_stack(Variable::Long(val));
+            runtime.current_frame.operand_stack.push(val);
         }
         11...13 => {
             let val = (op_code - 11) as f32;
             runnerPrint!(runtime, true, 2, "FCONST {}", val);
-            runtime.push_on_stack(Variable::Float(val));
+            runtime.current_frame.operand_stack.push(val);
+        }
+        14...15 => {
+            let val = (op_code - 14) as f64;
+            runnerPrint!(runtime, true, 2, "DCONST {}", val);
+            runtime.current_frame.operand_stack.push(val);
         }
         16 => {
-            let byte = try!(buf.read_u8()) as i8 as i32;
+            let byte = instruction.int();
             runnerPrint!(runtime, true, 2, "BIPUSH {}", byte);
-            runtime.push_on_stack(Variable::Int(byte));
+            runtime.current_frame.operand_stack.push(byte);
         }
         17 => {
-            let short = try!(buf.read_u16::<BigEndian>()) as i16 as i32;
+            let short = instruction.int();
             runnerPrint!(runtime, true, 2, "SIPUSH {}", short);
-            runtime.push_on_stack(Variable::Int(short));
-        }
-        18 => { // LDC
-            let index = try!(buf.read_u8());
-            try!(ldc(runtime, index as usize));
-        },
-        19 => {
-            let index = try!(buf.read_u16::<BigEndian>());
-            try!(ldc(runtime, index as usize));
+            runtime.current_frame.operand_stack.push(short);
         }
+        18...19 => try!(ldc(runtime, instruction.index() as usize)), // LDC, LDC_W
         20 => { // LDC2W
-            let index = try!(buf.read_u16::<BigEndian>());
+            let index = instruction.index();
             let maybe_cp_entry = runtime.current_frame.constant_pool.pool.get(&(index as u16)).map(|x| x.clone());
             if maybe_cp_entry.is_none() {
                 runnerPrint!(runtime, true, 1, "LDC2W failed at index {}", index);
@@ -358,157 +374,135 @@ pub fn step(runtime: &mut Runtime, name: &str, buf: &mut Cursor<&Vec<u8>>) -> Re
                 match maybe_cp_entry.as_ref().unwrap() {
                     &ConstantPoolItem::CONSTANT_Long { value } => {
                         runnerPrint!(runtime, true, 2, "LDC2W long {}", value);
-                        runtime.push_on_stack(Variable::Long(value as i64));
+                        runtime.current_frame.operand_stack.push(value as i64);
                     }
                     &ConstantPoolItem::CONSTANT_Double { value } => {
                         runnerPrint!(runtime, true, 2, "LDC2W double {}", value);
-                        runtime.push_on_stack(Variable::Double(value));
+                        runtime.current_frame.operand_stack.push(value);
                     }
                     _ => return Err(RunnerError::ClassInvalid2(format!("Invalid constant for LDC2W {:?}", maybe_cp_entry.as_ref().unwrap())))
                 }
             }
         },
-        21 => try!(load("ILOAD", try!(buf.read_u8()), runtime, Variable::Int)),
-        22 => try!(load("LLOAD", try!(buf.read_u8()), runtime, Variable::Long)),
-        23 => try!(load("FLOAD", try!(buf.read_u8()), runtime, Variable::Float)),
-        24 => try!(load("DLOAD", try!(buf.read_u8()), runtime, Variable::Double)),
-        25 => try!(load("ALOAD", try!(buf.read_u8()), runtime, Variable::Reference)),
-        26...29 => try!(load("ILOAD", op_code - 26, runtime, Variable::Int)),
-        30...33 => try!(load("LLOAD", op_code - 30, runtime, Variable::Long)),
-        34...37 => try!(load("FLOAD", op_code - 34, runtime, Variable::Float)),
-        38...41 => try!(load("DLOAD", op_code - 38, runtime, Variable::Double)),
-        42...45 => try!(load("ALOAD", op_code - 42, runtime, Variable::Reference)),
-        46 => try!(aload("IALOAD", runtime, Variable::Int, |x| x)),
-        47 => try!(aload("LALOAD", runtime, Variable::Long, |x| x)),
-        48 => try!(aload("FALOAD", runtime, Variable::Float, |x| x)),
-        49 => try!(aload("DALOAD", runtime, Variable::Double, |x| x)),
-        50 => try!(aload("AALOAD", runtime, Variable::Reference, |x| x)),
-        51 => try!(aload("BALOAD", runtime, Variable::Byte, |x| x)),
-        52 => try!(aload("CALOAD", runtime, Variable::Char, |x| Variable::Int(Variable::to_int(&x)))),
-        53 => try!(aload("SALOAD", runtime, Variable::Short, |x| x)),
-        54 => try!(store("ISTORE", try!(buf.read_u8()), runtime, Variable::Int)),
-        55 => try!(store("LSTORE", try!(buf.read_u8()), runtime, Variable::Long)),
-        56 => try!(store("FSTORE", try!(buf.read_u8()), runtime, Variable::Float)),
-        57 => try!(store("DSTORE", try!(buf.read_u8()), runtime, Variable::Double)),
-        58 => try!(store("ASTORE", try!(buf.read_u8()), runtime, Variable::Reference)),
-        59...62 => try!(store("ISTORE", op_code - 59, runtime, Variable::Int)),
-        63...66 => try!(store("LSTORE", op_code - 63, runtime, Variable::Long)),
-        67...70 => try!(store("FSTORE", op_code - 67, runtime, Variable::Float)),
-        71...74 => try!(store("DSTORE", op_code - 71, runtime, Variable::Double)),
-        75...78 => try!(store("ASTORE", op_code - 75, runtime, Variable::Reference)),
-        79 => try!(astore("IASTORE", runtime, |x| x.clone())),
-        80 => try!(astore("LASTORE", runtime, |x| x.clone())),
-        81 => try!(astore("FASTORE", runtime, |x| x.clone())),
-        82 => try!(astore("DASTORE", runtime, |x| x.clone())),
-        83 => try!(astore("AASTORE", runtime, |x| x.clone())),
-        84 => try!(astore("BASTORE", runtime, |x| Variable::Byte(x.to_int() as u8))),
-        85 => try!(astore("CASTORE", runtime, |x| Variable::Char(std::char::from_u32((x.to_int() as u32) & 0xFF).unwrap()))),
-        86 => try!(astore("SASTORE", runtime, |x| Variable::Short(x.to_int() as i16))),
+        21 => try!(load::<i32>("ILOAD", instruction.local(), runtime)),
+        22 => try!(load::<i64>("LLOAD", instruction.local(), runtime)),
+        23 => try!(load::<f32>("FLOAD", instruction.local(), runtime)),
+        24 => try!(load::<f64>("DLOAD", instruction.local(), runtime)),
+        25 => try!(load_ref("ALOAD", instruction.local(), runtime)),
+        26...29 => try!(load::<i32>("ILOAD", (op_code - 26) as usize, runtime)),
+        30...33 => try!(load::<i64>("LLOAD", (op_code - 30) as usize, runtime)),
+        34...37 => try!(load::<f32>("FLOAD", (op_code - 34) as usize, runtime)),
+        38...41 => try!(load::<f64>("DLOAD", (op_code - 38) as usize, runtime)),
+        42...45 => try!(load_ref("ALOAD", (op_code - 42) as usize, runtime)),
+        46 => try!(aload("IALOAD", runtime, |x| x)),
+        47 => try!(aload("LALOAD", runtime, |x| x)),
+        48 => try!(aload("FALOAD", runtime, |x| x)),
+        49 => try!(aload("DALOAD", runtime, |x| x)),
+        50 => try!(aload("AALOAD", runtime, |x| x)),
+        51 => try!(aload("BALOAD", runtime, |x| x)),
+        52 => try!(aload("CALOAD", runtime, |x| Variable::Int(Variable::to_int(&x)))),
+        53 => try!(aload("SALOAD", runtime, |x| x)),
+        54 => try!(store::<i32>("ISTORE", instruction.local(), runtime)),
+        55 => try!(store::<i64>("LSTORE", instruction.local(), runtime)),
+        56 => try!(store::<f32>("FSTORE", instruction.local(), runtime)),
+        57 => try!(store::<f64>("DSTORE", instruction.local(), runtime)),
+        58 => try!(store_ref("ASTORE", instruction.local(), runtime)),
+        59...62 => try!(store::<i32>("ISTORE", (op_code - 59) as usize, runtime)),
+        63...66 => try!(store::<i64>("LSTORE", (op_code - 63) as usize, runtime)),
+        67...70 => try!(store::<f32>("FSTORE", (op_code - 67) as usize, runtime)),
+        71...74 => try!(store::<f64>("DSTORE", (op_code - 71) as usize, runtime)),
+        75...78 => try!(store_ref("ASTORE", (op_code - 75) as usize, runtime)),
+        79 => try!(astore("IASTORE", runtime, 'I', |x| x.clone())),
+        80 => try!(astore("LASTORE", runtime, 'J', |x| x.clone())),
+        81 => try!(astore("FASTORE", runtime, 'F', |x| x.clone())),
+        82 => try!(astore("DASTORE", runtime, 'D', |x| x.clone())),
+        83 => try!(astore("AASTORE", runtime, 'L', |x| x.clone())),
+        84 => try!(astore("BASTORE", runtime, 'B', |x| Variable::Byte(x.to_int() as i8))),
+        85 => try!(astore("CASTORE", runtime, 'C', |x| Variable::Char(x.to_int() as u16))),
+        86 => try!(astore("SASTORE", runtime, 'S', |x| Variable::Short(x.to_int() as i16))),
         87 => {
-            let popped = runtime.pop_from_stack().unwrap();
-            runnerPrint!(runtime, true, 2, "POP {}", popped);
+            runnerPrint!(runtime, true, 2, "POP");
+            runtime.current_frame.operand_stack.drop_slots(1);
         }
         88 => {
-            let popped = runtime.pop_from_stack().unwrap();
-            if popped.is_type_1() {
-                let popped2 = runtime.pop_from_stack().unwrap();
-                runnerPrint!(runtime, true, 2, "POP2 {} {}", popped, popped2);
-            } else {
-                runnerPrint!(runtime, true, 2, "POP2 {}", popped);
-            }
-        }
-        89 => {
-            let stack_len = runtime.current_frame.operand_stack.len();
-            let peek = runtime.current_frame.operand_stack[stack_len - 1].clone();
-            runnerPrint!(runtime, true, 2, "DUP {}", peek);
-            runtime.push_on_stack(peek);
-        }
-        90 => {
-            let stack_len = runtime.current_frame.operand_stack.len();
-            let peek = runtime.current_frame.operand_stack[stack_len - 1].clone();
-            runnerPrint!(runtime, true, 2, "DUP_X1 {}", peek);
-            runtime.current_frame.operand_stack.insert(stack_len - 2, peek);
-        }
-        91 => {
-            let stack_len = runtime.current_frame.operand_stack.len();
-            let peek = runtime.current_frame.operand_stack[stack_len - 1].clone();
-            runnerPrint!(runtime, true, 2, "DUP_X2 {}", peek);
-            runtime.current_frame.operand_stack.insert(stack_len - 3, peek);
-        }
-        92 => {
-            let stack_len = runtime.current_frame.operand_stack.len();
-            let peek1 = runtime.current_frame.operand_stack[stack_len - 1].clone();
-            if peek1.is_type_1() {
-                let peek2 = runtime.current_frame.operand_stack[stack_len - 2].clone();
-                runnerPrint!(runtime, true, 2, "DUP2 {} {}", peek1, peek2);
-                runtime.push_on_stack(peek2);
-                runtime.push_on_stack(peek1);
-            } else {
-                runnerPrint!(runtime, true, 2, "DUP2 {}", peek1);
-                runtime.push_on_stack(peek1);
-            }
-        }
-        96 => maths_instr("IADD", runtime, Variable::Int, Variable::to_int, i32::wrapping_add),
-        97 => maths_instr("LADD", runtime, Variable::Long, Variable::to_long, i64::wrapping_add),
-        98 => maths_instr("FADD", runtime, Variable::Float, Variable::to_float, std::ops::Add::add),
-        99 => maths_instr("DADD", runtime, Variable::Double, Variable::to_double, std::ops::Add::add),
-        100 => maths_instr("ISUB", runtime, Variable::Int, Variable::to_int, i32::wrapping_sub),
-        101 => maths_instr("LSUB", runtime, Variable::Long, Variable::to_long, i64::wrapping_sub),
-        102 => maths_instr("FSUB", runtime, Variable::Float, Variable::to_float, std::ops::Sub::sub),
-        103 => maths_instr("DSUB", runtime, Variable::Double, Variable::to_double, std::ops::Sub::sub),
-        104 => maths_instr("IMUL", runtime, Variable::Int, Variable::to_int, i32::wrapping_mul),
-        105 => maths_instr("LMUL", runtime, Variable::Long, Variable::to_long, i64::wrapping_mul),
-        106 => maths_instr("FMUL", runtime, Variable::Float, Variable::to_float, std::ops::Mul::mul),
-        107 => maths_instr("DMUL", runtime, Variable::Double, Variable::to_double, std::ops::Mul::mul),
-        108 => maths_instr("IDIV", runtime, Variable::Int, Variable::to_int, i32::wrapping_div),
-        109 => maths_instr("LDIV", runtime, Variable::Long, Variable::to_long, i64::wrapping_div),
-        110 => maths_instr("FDIV", runtime, Variable::Float, Variable::to_float, std::ops::Div::div),
-        111 => maths_instr("DDIV", runtime, Variable::Double, Variable::to_double, std::ops::Div::div),
-        112 => maths_instr("IREM", runtime, Variable::Int, Variable::to_int, i32::wrapping_rem),
-        113 => maths_instr("LREM", runtime, Variable::Long, Variable::to_long, i64::wrapping_rem),
-        114 => maths_instr("FREM", runtime, Variable::Float, Variable::to_float, std::ops::Rem::rem),
-        115 => maths_instr("DREM", runtime, Variable::Double, Variable::to_double, std::ops::Rem::rem),
-        116 => single_pop_instr("INEG", runtime, Variable::Int, Variable::to_int, |x| 0 - x),
-        117 => single_pop_instr("LNEG", runtime, Variable::Long, Variable::to_long, |x| 0 - x),
-        118 => single_pop_instr("FNEG", runtime, Variable::Float, Variable::to_float, |x| 0.0 - x),
-        119 => single_pop_instr("DNEG", runtime, Variable::Double, Variable::to_double, |x| 0.0 - x),
-        120 => maths_instr("ISHL", runtime, Variable::Int, Variable::to_int, |x,y| x << y),
-        121 => maths_instr_2("LSHL", runtime, Variable::Long, Variable::to_int, Variable::to_long, |x,y| (x << y) as i64),
-        122 => maths_instr("ISHR", runtime, Variable::Int, Variable::to_int, |x,y| x >> y),
-        123 => maths_instr_2("LSHR", runtime, Variable::Long, Variable::to_int, Variable::to_long, |x,y| (x >> y) as i64),
-        124 => maths_instr("IUSHR", runtime, Variable::Int, Variable::to_int, |x,y| ((x as u32)>>y) as i32),
-        125 => maths_instr_2("LUSHR", runtime, Variable::Long, Variable::to_int, Variable::to_long, |x,y| ((x as u64)>>y) as i64),
-        126 => maths_instr("IAND", runtime, Variable::Int, Variable::to_int, and),
-        127 => maths_instr("LAND", runtime, Variable::Long, Variable::to_long, and),
-        128 => maths_instr("IOR", runtime, Variable::Int, Variable::to_int, or),
-        129 => maths_instr("LOR", runtime, Variable::Long, Variable::to_long, or),
-        130 => maths_instr("IXOR", runtime, Variable::Int, Variable::to_int, xor),
-        131 => maths_instr("LXOR", runtime, Variable::Long, Variable::to_long, xor),
+            runnerPrint!(runtime, true, 2, "POP2");
+            runtime.current_frame.operand_stack.drop_slots(2);
+        }
+        89...94 => {
+            // Slots copied and slots skipped: DUP, DUP_X1, DUP_X2, DUP2, DUP2_X1, DUP2_X2
+            let (count, skip) = [(1, 0), (1, 1), (1, 2), (2, 0), (2, 1), (2, 2)][(op_code - 89) as usize];
+            runnerPrint!(runtime, true, 2, "DUP {} {}", count, skip);
+            runtime.current_frame.operand_stack.dup(count, skip);
+        }
+        95 => {
+            runnerPrint!(runtime, true, 2, "SWAP");
+            runtime.current_frame.operand_stack.swap();
+        }
+        96 => maths_instr("IADD", runtime, i32::wrapping_add),
+        97 => maths_instr("LADD", runtime, i64::wrapping_add),
+        98 => maths_instr::<f32, _>("FADD", runtime, std::ops::Add::add),
+        99 => maths_instr::<f64, _>("DADD", runtime, std::ops::Add::add),
+        100 => maths_instr("ISUB", runtime, i32::wrapping_sub),
+        101 => maths_instr("LSUB", runtime, i64::wrapping_sub),
+        102 => maths_instr::<f32, _>("FSUB", runtime, std::ops::Sub::sub),
+        103 => maths_instr::<f64, _>("DSUB", runtime, std::ops::Sub::sub),
+        104 => maths_instr("IMUL", runtime, i32::wrapping_mul),
+        105 => maths_instr("LMUL", runtime, i64::wrapping_mul),
+        106 => maths_instr::<f32, _>("FMUL", runtime, std::ops::Mul::mul),
+        107 => maths_instr::<f64, _>("DMUL", runtime, std::ops::Mul::mul),
+        108 => try!(divide_instr("IDIV", runtime, i32::wrapping_div)),
+        109 => try!(divide_instr("LDIV", runtime, i64::wrapping_div)),
+        110 => maths_instr::<f32, _>("FDIV", runtime, std::ops::Div::div),
+        111 => maths_instr::<f64, _>("DDIV", runtime, std::ops::Div::div),
+        112 => try!(divide_instr("IREM", runtime, i32::wrapping_rem)),
+        113 => try!(divide_instr("LREM", runtime, i64::wrapping_rem)),
+        114 => maths_instr::<f32, _>("FREM", runtime, std::ops::Rem::rem),
+        115 => maths_instr::<f64, _>("DREM", runtime, std::ops::Rem::rem),
+        116 => cast("INEG", runtime, i32::wrapping_neg),
+        117 => cast("LNEG", runtime, i64::wrapping_neg),
+        118 => cast("FNEG", runtime, |x: f32| -x),
+        119 => cast("DNEG", runtime, |x: f64| -x),
+        // Wrapping shifts mask the distance to the low 5 or 6 bits, as Java does
+        120 => shift_instr("ISHL", runtime, |x: i32, y| x.wrapping_shl(y as u32)),
+        121 => shift_instr("LSHL", runtime, |x: i64, y| x.wrapping_shl(y as u32)),
+        122 => shift_instr("ISHR", runtime, |x: i32, y| x.wrapping_shr(y as u32)),
+        123 => shift_instr("LSHR", runtime, |x: i64, y| x.wrapping_shr(y as u32)),
+        124 => shift_instr("IUSHR", runtime, |x: i32, y| (x as u32).wrapping_shr(y as u32) as i32),
+        125 => shift_instr("LUSHR", runtime, |x: i64, y| (x as u64).wrapping_shr(y as u32) as i64),
+        126 => maths_instr::<i32, _>("IAND", runtime, and),
+        127 => maths_instr::<i64, _>("LAND", runtime, and),
+        128 => maths_instr::<i32, _>("IOR", runtime, or),
+        129 => maths_instr::<i64, _>("LOR", runtime, or),
+        130 => maths_instr::<i32, _>("IXOR", runtime, xor),
+        131 => maths_instr::<i64, _>("LXOR", runtime, xor),
         132 => {
-            let index = try!(buf.read_u8());
-            let constt = try!(buf.read_u8()) as i8;
-            runnerPrint!(runtime, true, 2, "IINC {} {}", index, constt);
-            let old_val = runtime.current_frame.local_variables[index as usize].to_int();
-            runtime.current_frame.local_variables[index as usize] = Variable::Int(old_val + constt as i32);
+            if let Operand::Iinc(index, constt) = instruction.operand {
+                runnerPrint!(runtime, true, 2, "IINC {} {}", index, constt);
+                let old_val: i32 = runtime.current_frame.local_variables.get(index);
+                runtime.current_frame.local_variables.set(index, old_val.wrapping_add(constt));
+            }
         }
-        133 => cast("I2L", runtime, |x| Variable::Long(x.to_int() as i64)),
-        134 => cast("I2F", runtime, |x| Variable::Float(x.to_int() as f32)),
-        135 => cast("I2D", runtime, |x| Variable::Double(x.to_int() as f64)),
-        136 => single_pop_instr("L2I", runtime, Variable::Int, Variable::to_long, |x| x as i32),
-        139 => cast("F2I", runtime, |x| Variable::Int(x.to_float() as i32)),
-        140 => cast("F2L", runtime, |x| Variable::Long(x.to_float() as i64)),
-        141 => cast("F2D", runtime, |x| Variable::Double(x.to_float() as f64)),
-        142 => cast("D2I", runtime, |x| Variable::Int(x.to_double() as i32)),
-        143 => cast("D2L", runtime, |x| Variable::Long(x.to_double() as i64)),
-        144 => cast("D2F", runtime, |x| Variable::Float(x.to_double() as f32)),
-        145 => cast("I2B", runtime, |x| Variable::Byte(x.to_int() as u8)),
-        146 => cast("I2C", runtime, |x| Variable::Char(std::char::from_u32(x.to_int() as u32).unwrap_or('\0'))),
-        147 => cast("I2S", runtime, |x| Variable::Short(x.to_int() as i16)),
+        133 => cast("I2L", runtime, |x: i32| x as i64),
+        134 => cast("I2F", runtime, |x: i32| x as f32),
+        135 => cast("I2D", runtime, |x: i32| x as f64),
+        136 => cast("L2I", runtime, |x: i64| x as i32),
+        137 => cast("L2F", runtime, |x: i64| x as f32),
+        138 => cast("L2D", runtime, |x: i64| x as f64),
+        // Float to integer casts saturate and give 0 for NaN, which matches JVMS 2.8.3
+        139 => cast("F2I", runtime, |x: f32| x as i32),
+        140 => cast("F2L", runtime, |x: f32| x as i64),
+        141 => cast("F2D", runtime, |x: f32| x as f64),
+        142 => cast("D2I", runtime, |x: f64| x as i32),
+        143 => cast("D2L", runtime, |x: f64| x as i64),
+        144 => cast("D2F", runtime, |x: f64| x as f32),
+        145 => cast("I2B", runtime, |x: i32| x as i8 as i32),
+        146 => cast("I2C", runtime, |x: i32| x as u16 as i32),
+        147 => cast("I2S", runtime, |x: i32| x as i16 as i32),
         148 => {
-            let pop2 = runtime.pop_from_stack().unwrap().to_long();
-            let pop1 = runtime.pop_from_stack().unwrap().to_long();
+            let pop2: i64 = runtime.current_frame.operand_stack.pop();
+            let pop1: i64 = runtime.current_frame.operand_stack.pop();
             runnerPrint!(runtime, true, 2, "LCMP {} {}", pop1, pop2);
-            let ret;
+            let ret: i32;
             if pop1 > pop2 {
                 ret = 1;
             } else if pop1 == pop2 {
@@ -516,168 +510,102 @@ pub fn step(runtime: &mut Runtime, name: &str, buf: &mut Cursor<&Vec<u8>>) -> Re
             } else {
                 ret = -1;
             }
-            runtime.push_on_stack(Variable::Int(ret));
-        }
-        149 => try!(fcmp("FCMPG", runtime, true)),
-        150 => try!(fcmp("FCMPL", runtime, false)),
-        153 => try!(ifcmp("IFEQ", runtime, buf, |x| x == 0)),
-        154 => try!(ifcmp("IFNE", runtime, buf, |x| x != 0)),
-        155 => try!(ifcmp("IFLT", runtime, buf, |x| x < 0)),
-        156 => try!(ifcmp("IFGE", runtime, buf, |x| x >= 0)),
-        157 => try!(ifcmp("IFGT", runtime, buf, |x| x > 0)),
-        158 => try!(ifcmp("IFLE", runtime, buf, |x| x <= 0)),
-        159 => try!(icmp("IF_ICMPEQ", runtime, buf, |x,y| x == y)),
-        160 => try!(icmp("IF_ICMPNE", runtime, buf, |x,y| x != y)),
-        161 => try!(icmp("IF_ICMPLT", runtime, buf, |x,y| x < y)),
-        162 => try!(icmp("IF_ICMPGE", runtime, buf, |x,y| x >= y)),
-        163 => try!(icmp("IF_ICMPGT", runtime, buf, |x,y| x > y)),
-        164 => try!(icmp("IF_ICMPLE", runtime, buf, |x,y| x <= y)),
-        165 => try!(ifacmp("IF_ACMPEQ", runtime, buf, true)),
-        166 => try!(ifacmp("IF_ACMPNEQ", runtime, buf, false)),
-        167 => {
-            let branch_offset = try!(buf.read_u16::<BigEndian>()) as i16;
-            let new_pos = (current_position as i64 + branch_offset as i64) as u64;
-            runnerPrint!(runtime, true, 2, "BRANCH from {} to {}", current_position, new_pos);
-            buf.set_position(new_pos);
-        }
-        170 => {
-            let pos = buf.position();
-            buf.set_position((pos + 3) & !3);
-            let default = try!(buf.read_u32::<BigEndian>());
-            let low = try!(buf.read_u32::<BigEndian>());
-            let high = try!(buf.read_u32::<BigEndian>());
-            let value_int = runtime.pop_from_stack().unwrap().to_int() as u32;
-            runnerPrint!(runtime, true, 2, "TABLESWITCH {} {} {} {}", default, low, high, value_int);
-            if value_int < low || value_int > high {
-                let new_pos = (current_position as i64 + default as i64) as u64;
-                runnerPrint!(runtime, true, 2, "No match so BRANCH from {} to {}", current_position, new_pos);
-                buf.set_position(new_pos);
-            } else {
-                let pos = buf.position();
-                buf.set_position(pos + (value_int - low) as u64 * 4);
-                let jump = try!(buf.read_u32::<BigEndian>());
-                let new_pos = (current_position as i64 + jump as i64) as u64;
-                runnerPrint!(runtime, true, 2, "Match so BRANCH from {} to {}", current_position, new_pos);
-                buf.set_position(new_pos);
-            }
-        }
-        171 => {
-            let pos = buf.position();
-            buf.set_position((pos + 3) & !3);
-            let default = try!(buf.read_u32::<BigEndian>());
-            let npairs = try!(buf.read_u32::<BigEndian>());
-            let value_int = runtime.pop_from_stack().unwrap().to_int();
-            runnerPrint!(runtime, true, 2, "LOOKUPSWITCH {} {} {}", default, npairs, value_int);
-            let mut matched = false;
-            for _i in 0..npairs { // TODO: Nonlinear search
-                let match_key = try!(buf.read_u32::<BigEndian>()) as i32;
-                let offset = try!(buf.read_u32::<BigEndian>()) as i32;
-                if match_key == value_int {
-                    let new_pos = (current_position as i64 + offset as i64) as u64;
-                    runnerPrint!(runtime, true, 2, "Matched so BRANCH from {} to {}", current_position, new_pos);
-                    buf.set_position(new_pos);
-                    matched = true;
-                    break;
-                }
-            }
-            if matched == false {
-                let new_pos = (current_position as i64 + default as i64) as u64;
-                runnerPrint!(runtime, true, 2, "No match so BRANCH from {} to {}", current_position, new_pos);
-                buf.set_position(new_pos);
+            runtime.current_frame.operand_stack.push(ret);
+        }
+        149 => try!(fcmp::<f32>("FCMPL", runtime, false)),
+        150 => try!(fcmp::<f32>("FCMPG", runtime, true)),
+        151 => try!(fcmp::<f64>("DCMPL", runtime, false)),
+        152 => try!(fcmp::<f64>("DCMPG", runtime, true)),
+        153 => return Ok(ifcmp("IFEQ", runtime, pc, instruction.target(), |x| x == 0)),
+        154 => return Ok(ifcmp("IFNE", runtime, pc, instruction.target(), |x| x != 0)),
+        155 => return Ok(ifcmp("IFLT", runtime, pc, instruction.target(), |x| x < 0)),
+        156 => return Ok(ifcmp("IFGE", runtime, pc, instruction.target(), |x| x >= 0)),
+        157 => return Ok(ifcmp("IFGT", runtime, pc, instruction.target(), |x| x > 0)),
+        158 => return Ok(ifcmp("IFLE", runtime, pc, instruction.target(), |x| x <= 0)),
+        159 => return Ok(icmp("IF_ICMPEQ", runtime, pc, instruction.target(), |x,y| x == y)),
+        160 => return Ok(icmp("IF_ICMPNE", runtime, pc, instruction.target(), |x,y| x != y)),
+        161 => return Ok(icmp("IF_ICMPLT", runtime, pc, instruction.target(), |x,y| x < y)),
+        162 => return Ok(icmp("IF_ICMPGE", runtime, pc, instruction.target(), |x,y| x >= y)),
+        163 => return Ok(icmp("IF_ICMPGT", runtime, pc, instruction.target(), |x,y| x > y)),
+        164 => return Ok(icmp("IF_ICMPLE", runtime, pc, instruction.target(), |x,y| x <= y)),
+        165 => return Ok(ifacmp("IF_ACMPEQ", runtime, pc, instruction.target(), true)),
+        166 => return Ok(ifacmp("IF_ACMPNEQ", runtime, pc, instruction.target(), false)),
+        167 | 200 => {
+            runnerPrint!(runtime, true, 2, "BRANCH from {} to {}", pc, instruction.target());
+            return Ok(Control::Jump(instruction.target()));
+        }
+        170...171 => {
+            if let Operand::Switch(ref switch) = instruction.operand {
+                let value_int: i32 = runtime.current_frame.operand_stack.pop();
+                let target = switch.target(value_int);
+                runnerPrint!(runtime, true, 2, "SWITCH {} BRANCH from {} to {}", value_int, pc, target);
+                return Ok(Control::Jump(target));
             }
         }
-        172 => { return vreturn("IRETURN", runtime, Variable::can_convert_to_int); }
-        173 => { return vreturn("LRETURN", runtime, Variable::to_long); }
-        174 => { return vreturn("FRETURN", runtime, Variable::to_float); }
-        175 => { return vreturn("DRETURN", runtime, Variable::to_double); }
-        176 => { return vreturn("ARETURN", runtime, Variable::is_ref_or_array); }
+        172 => { return vreturn::<i32>("IRETURN", runtime); }
+        173 => { return vreturn::<i64>("LRETURN", runtime); }
+        174 => { return vreturn::<f32>("FRETURN", runtime); }
+        175 => { return vreturn::<f64>("DRETURN", runtime); }
+        176 => { return areturn("ARETURN", runtime); }
         177 => { // return
             runnerPrint!(runtime, true, 1, "RETURN");
             runtime.current_frame = runtime.previous_frames.pop().unwrap();
-            return Err(RunnerError::Return);
+            return Ok(Control::Return);
         }
         178 => { // getstatic
-            let index = try!(buf.read_u16::<BigEndian>());
-            let (class_name, field_name, typ) = try!(runtime.current_frame.constant_pool.get_field(index));
-            runnerPrint!(runtime, true, 2, "GETSTATIC {} {} {}", class_name, field_name, typ);
-            let mut class_result = try!(load_class(runtime, class_name.as_str()));
-            loop {
-                {
-                    let statics = class_result.statics.borrow();
-                    let maybe_static_variable = statics.get(&*field_name);
-                    if maybe_static_variable.is_some() {
-                        runnerPrint!(runtime, true, 2, "GETSTATIC found {}", maybe_static_variable.unwrap());
-                        runtime.push_on_stack(maybe_static_variable.unwrap().clone());
-                        break;
-                    }
-                }
-                let maybe_super = class_result.super_class.borrow().clone();
-                if maybe_super.is_none() {
-                    return Err(RunnerError::ClassInvalid2(format!("Couldn't find static {} in {}", field_name.as_str(), class_name.as_str())));
-                }
-                class_result = maybe_super.unwrap();
-            }
+            let index = instruction.index();
+            let field = try!(resolve_static(runtime, index));
+            let value = try!(field.class.statics.borrow().get(field.field_name.as_str()).cloned().ok_or(RunnerError::ClassInvalid2(format!("Couldn't find static {} in {}", field.field_name, field.class_name))));
+            runnerPrint!(runtime, true, 2, "GETSTATIC {} {} {} found {}", field.class_name, field.field_name, field.descriptor, value);
+            runtime.push_on_stack(value);
         }
         179 => { // putstatic
-            let index = try!(buf.read_u16::<BigEndian>());
-            let value = runtime.pop_from_stack().unwrap();
-            let (class_name, field_name, typ) = try!(runtime.current_frame.constant_pool.get_field(index));
-            runnerPrint!(runtime, true, 2, "PUTSTATIC {} {} {} {}", class_name, field_name, typ, value);
-            try!(put_static(runtime, class_name.as_str(), field_name.as_str(), value));
+            let index = instruction.index();
+            let field = try!(resolve_static(runtime, index));
+            let value = runtime.current_frame.operand_stack.pop_typed(field.descriptor.chars().next().unwrap_or('L'));
+            runnerPrint!(runtime, true, 2, "PUTSTATIC {} {} {} {}", field.class_name, field.field_name, field.descriptor, value);
+            field.class.statics.borrow_mut().insert((*field.field_name).clone(), value);
         }
         180 => {
-            let field_index = try!(buf.read_u16::<BigEndian>());
-            let (class_name, field_name, typ) = try!(runtime.current_frame.constant_pool.get_field(field_index));
-            let var = runtime.pop_from_stack().unwrap();
+            let field_index = instruction.index();
+            let field = try!(resolve_field(runtime, field_index));
+            let var = runtime.current_frame.operand_stack.pop_ref();
             let obj = var.to_ref();
-            let f = try!(get_field(runtime, &obj, class_name.as_str(), field_name.as_str()));
-            runnerPrint!(runtime, true, 2, "GETFIELD class:'{}' field:'{}' type:'{}' object:'{}' result:'{}'", class_name, field_name, typ, obj.unwrap(), f);
+            let f = try!(get_field_at_offset(runtime, &obj, field.offset));
+            runnerPrint!(runtime, true, 2, "GETFIELD class:'{}' field:'{}' type:'{}' object:'{}' result:'{}'", field.class_name, field.field_name, field.descriptor, obj.unwrap(), f);
             runtime.push_on_stack(f);
         }
         181 => {
-            let field_index = try!(buf.read_u16::<BigEndian>());
-            let (class_name, field_name, typ) = try!(runtime.current_frame.constant_pool.get_field(field_index));
-            let value = runtime.pop_from_stack().unwrap();
-            let var = runtime.pop_from_stack().unwrap();
+            let field_index = instruction.index();
+            let field = try!(resolve_field(runtime, field_index));
+            let value = runtime.current_frame.operand_stack.pop_typed(field.descriptor.chars().next().unwrap_or('L'));
+            let var = runtime.current_frame.operand_stack.pop_ref();
             let obj = var.to_ref();
-            runnerPrint!(runtime, true, 2, "PUTFIELD {} {} {} {} {}", class_name, field_name, typ, obj.as_ref().unwrap(), value);
-            try!(put_field_specific_class_name(runtime, &obj, class_name.as_str(), field_name.as_str(), value));
-        }
-        182 => {
-            let index = try!(buf.read_u16::<BigEndian>());
-            try!(invoke("INVOKEVIRTUAL", runtime, index, true, false));
-        },
-        183 => {
-            let index = try!(buf.read_u16::<BigEndian>());
-            try!(invoke("INVOKESPECIAL", runtime, index, true, true));
-        },
-        184 => {
-            let index = try!(buf.read_u16::<BigEndian>());
-            try!(invoke("INVOKESTATIC", runtime, index, false, true));
-        }
-        185 => {
-            let index = try!(buf.read_u16::<BigEndian>());
-            let _count = try!(buf.read_u8());
-            let _zero = try!(buf.read_u8());
-            try!(invoke("INVOKEINTERFACE", runtime, index, true, false));
+            try!(put_field_at_offset(runtime, &obj, field.offset, value.clone()));
+            runnerPrint!(runtime, true, 2, "PUTFIELD {} {} {} {} {}", field.class_name, field.field_name, field.descriptor, obj.as_ref().unwrap(), value);
         }
+        182 => return invoke("INVOKEVIRTUAL", runtime, instruction.index(), true, false),
+        183 => return invoke("INVOKESPECIAL", runtime, instruction.index(), true, true),
+        184 => return invoke("INVOKESTATIC", runtime, instruction.index(), false, true),
+        185 => return invoke("INVOKEINTERFACE", runtime, instruction.index(), true, false),
         187 => {
-            let index = try!(buf.read_u16::<BigEndian>());
+            let index = instruction.index();
             let class_name = try!(runtime.current_frame.constant_pool.get_class_name(index));
             runnerPrint!(runtime, true, 2, "NEW {}", class_name);
             let var = try!(construct_object(runtime, class_name.as_str()));
             runtime.push_on_stack(var);
         }
         188 => {
-            let atype = try!(buf.read_u8());
-            let count = try!(runtime.pop_from_stack().ok_or(RunnerError::ClassInvalid("NEWARRAY POP fail"))).to_int();
+            let atype = instruction.int();
+            let count: i32 = runtime.current_frame.operand_stack.pop();
             runnerPrint!(runtime, true, 2, "NEWARRAY {} {}", atype, count);
+            try!(check_array_size(runtime, count));
 
             let var : Variable;
             let type_str : char;
             match atype {
                 4 => { var = Variable::Boolean(false); type_str = 'Z'; },
-                5 => { var = Variable::Char('\0'); type_str = 'C'; },
+                5 => { var = Variable::Char(0); type_str = 'C'; },
                 6 => { var = Variable::Float(0.0); type_str = 'F'; },
                 7 => { var = Variable::Double(0.0); type_str = 'D'; },
                 8 => { var = Variable::Byte(0); type_str = 'B'; },
@@ -695,21 +623,30 @@ pub fn step(runtime: &mut Runtime, name: &str, buf: &mut Cursor<&Vec<u8>>) -> Re
             runtime.push_on_stack(array_obj);
         }
         189 => {
-            let index = try!(buf.read_u16::<BigEndian>());
+            let index = instruction.index();
             let class_name = try!(runtime.current_frame.constant_pool.get_class_name(index));
-            try!(load_class(runtime, class_name.as_str()));
-            let class = runtime.classes.get(&*class_name).unwrap().clone();
-            let count = try!(runtime.pop_from_stack().ok_or(RunnerError::ClassInvalid("ANEWARRAY count fail"))).to_int();
+            let count: i32 = runtime.current_frame.operand_stack.pop();
             runnerPrint!(runtime, true, 2, "ANEWARRAY {} {}", class_name, count);
+            try!(check_array_size(runtime, count));
             let mut v : Vec<Variable> = Vec::new();
-            for _c in 0..count {
-                v.push(try!(construct_null_object(runtime, class.clone())));
-            }
-            let array_obj = try!(construct_array(runtime, class, Some(v)));
+            let array_obj = if class_name.starts_with('[') {
+                // Arrays of arrays start out holding null arrays of the element type
+                for _c in 0..count {
+                    v.push(try!(default_value(runtime, class_name.as_str())));
+                }
+                try!(construct_array_by_descriptor(runtime, class_name.as_str(), Some(v)))
+            } else {
+                try!(load_class(runtime, class_name.as_str()));
+                let class = runtime.classes.get(&*class_name).unwrap().clone();
+                for _c in 0..count {
+                    v.push(try!(construct_null_object(runtime, class.clone())));
+                }
+                try!(construct_array(runtime, class, Some(v)))
+            };
             runtime.push_on_stack(array_obj);
         }
         190 => {
-            let var = runtime.pop_from_stack().unwrap();
+            let var = runtime.current_frame.operand_stack.pop_ref();
             let array_obj = var.to_arrayobj();
             if array_obj.is_null {
                 let exception = try!(construct_object(runtime, &"java/lang/NullPointerException"));
@@ -717,11 +654,20 @@ pub fn step(runtime: &mut Runtime, name: &str, buf: &mut Cursor<&Vec<u8>>) -> Re
             }
             let len = array_obj.elements.borrow().len();
             runnerPrint!(runtime, true, 2, "ARRAYLEN {} {} {}", var, array_obj.element_type_str, len);
-            runtime.push_on_stack(Variable::Int(len as i32));
+            runtime.current_frame.operand_stack.push(len as i32);
+        }
+        191 => {
+            let var = runtime.current_frame.operand_stack.pop_ref();
+            runnerPrint!(runtime, true, 2, "ATHROW {}", var);
+            if var.is_null() {
+                let exception = try!(construct_object(runtime, &"java/lang/NullPointerException"));
+                return Err(RunnerError::Exception(exception));
+            }
+            return Err(RunnerError::Exception(var));
         }
         192 => {
-            let var = runtime.pop_from_stack().unwrap();
-            let index = try!(buf.read_u16::<BigEndian>());
+            let var = runtime.current_frame.operand_stack.pop_ref();
+            let index = instruction.index();
 
             runnerPrint!(runtime, true, 2, "CHECKCAST {} {}", var, index);
 
@@ -734,8 +680,8 @@ pub fn step(runtime: &mut Runtime, name: &str, buf: &mut Cursor<&Vec<u8>>) -> Re
             runtime.push_on_stack(var);
         }
         193 => {
-            let var = runtime.pop_from_stack().unwrap();
-            let index = try!(buf.read_u16::<BigEndian>());
+            let var = runtime.current_frame.operand_stack.pop_ref();
+            let index = instruction.index();
             let class_name = try!(runtime.current_frame.constant_pool.get_class_name(index));
 
             runnerPrint!(runtime, true, 2, "INSTANCEOF {} {}", var, class_name);
@@ -745,25 +691,25 @@ pub fn step(runtime: &mut Runtime, name: &str, buf: &mut Cursor<&Vec<u8>>) -> Re
             if var_ref.is_some() && Class::find_superclass(var_ref.unwrap().type_ref(), class_name).is_some() {
                 matches = 1;
             }
-            runtime.push_on_stack(Variable::Int(matches));
+            runtime.current_frame.operand_stack.push::<i32>(matches);
         }
         194 => {
-            let var = runtime.pop_from_stack().unwrap();
+            let var = runtime.current_frame.operand_stack.pop_ref();
             runnerPrint!(runtime, true, 2, "MONITORENTER {}", var);
             let _obj = var.to_ref();
             // TODO: Implement monitor
             runnerPrint!(runtime, true, 1, "WARNING: MonitorEnter not implemented");
         },
         195 => {
-            let var = runtime.pop_from_stack().unwrap();
+            let var = runtime.current_frame.operand_stack.pop_ref();
             runnerPrint!(runtime, true, 2, "MONITOREXIT {}", var);
             let _obj = var.to_ref();
             // TODO: Implement monitor
             runnerPrint!(runtime, true, 1, "WARNING: MonitorExit not implemented");
         },
-        198 => try!(branch_if("IFNULL", runtime, buf, current_position, |x| x.is_null())),
-        199 => try!(branch_if("IFNONNULL", runtime, buf, current_position, |x| !x.is_null())),
+        198 => return Ok(branch_if("IFNULL", runtime, pc, instruction.target(), |x| x.is_null())),
+        199 => return Ok(branch_if("IFNONNULL", runtime, pc, instruction.target(), |x| !x.is_null())),
         _ => return Err(RunnerError::UnknownOpCode(op_code))
     }
-    return Ok(false);
+    return Ok(Control::Next);
 }
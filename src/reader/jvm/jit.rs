@@ -0,0 +1,1116 @@
+extern crate cranelift_codegen;
+extern crate cranelift_frontend;
+extern crate cranelift_jit;
+extern crate cranelift_module;
+extern crate cranelift_native;
+
+use self::cranelift_codegen::Context;
+use self::cranelift_codegen::ir::{types, AbiParam, Block, Function, InstBuilder, MemFlagsData, Signature, Type, UserFuncName, Value};
+use self::cranelift_codegen::ir::condcodes::{FloatCC, IntCC};
+use self::cranelift_codegen::settings::{self, Configurable};
+use self::cranelift_frontend::{FunctionBuilder, FunctionBuilderContext, Switch as SwitchBuilder, Variable as Var};
+use self::cranelift_jit::{JITBuilder, JITModule};
+use self::cranelift_module::{default_libcall_names, FuncId, Linkage, Module};
+use reader::class_reader::*;
+use reader::runner::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::env;
+use std::mem;
+use std::rc::Rc;
+
+// Baseline compiler for hot methods. Only static methods over primitives are compiled: constants, locals,
+// arithmetic that can't throw, conversions, branches and static calls to other compilable methods. Anything
+// else leaves the method to the interpreter. Compiled code never throws, so it needs no way back into it, except
+// for running out of stack: then every compiled frame returns at once and the interpreter throws StackOverflowError.
+
+pub struct Jit {
+    pub enabled: bool,
+    pub invocation_threshold: u32,
+    pub back_edge_threshold: u32,
+    modules: Vec<JITModule>, // Kept so compiled code outlives the compilation that produced it
+    compiled: usize,
+    calls: Box<CallDepth>,
+}
+
+// Shared with compiled code, which counts remaining down on entry to each method and back up on return. Going
+// below zero sets overflowed, and compiled code returns without finishing whenever a call leaves it set.
+#[repr(C)]
+struct CallDepth {
+    remaining: Cell<i64>,
+    overflowed: Cell<i64>,
+}
+
+impl Jit {
+    pub fn new() -> Jit {
+        return Jit {
+            enabled: env::var_os("RJVM_NO_JIT").is_none(),
+            invocation_threshold: 500,
+            back_edge_threshold: 10000,
+            modules: Vec::new(),
+            compiled: 0,
+            calls: Box::new(CallDepth { remaining: Cell::new(0), overflowed: Cell::new(0) }),
+        };
+    }
+
+    pub fn compiled_count(&self) -> usize {
+        return self.compiled;
+    }
+
+    fn calls_address(&self) -> i64 {
+        return &*self.calls as *const CallDepth as i64;
+    }
+
+    // Compiled code entered from the interpreter gets whatever depth the interpreter hasn't used
+    fn start_calls(&self, frames: usize, max_depth: usize) {
+        self.calls.remaining.set(max_depth as i64 - frames as i64);
+        self.calls.overflowed.set(0);
+    }
+
+    fn overflowed(&self) -> bool {
+        return self.calls.overflowed.get() != 0;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Kind {
+    Int,
+    Long,
+    Float,
+    Double,
+}
+
+impl Kind {
+    fn from_descriptor(type_char: char) -> Option<Kind> {
+        return match type_char {
+            'B' | 'C' | 'I' | 'S' | 'Z' => Some(Kind::Int),
+            'J' => Some(Kind::Long),
+            'F' => Some(Kind::Float),
+            'D' => Some(Kind::Double),
+            _ => None
+        };
+    }
+
+    fn ty(&self) -> Type {
+        return match *self {
+            Kind::Int => types::I32,
+            Kind::Long => types::I64,
+            Kind::Float => types::F32,
+            Kind::Double => types::F64,
+        };
+    }
+
+    fn size(&self) -> usize {
+        return match *self { Kind::Long | Kind::Double => 2, _ => 1 };
+    }
+
+    fn push_bits(&self, stack: &mut OperandStack, bits: u64) {
+        match *self {
+            Kind::Int => stack.push(bits as u32 as i32),
+            Kind::Long => stack.push(bits as i64),
+            Kind::Float => stack.push(f32::from_bits(bits as u32)),
+            Kind::Double => stack.push(f64::from_bits(bits)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Compiled {
+    address: *const u8, // Native calling convention, used by other compiled methods
+    entry: *const u8,   // Takes arguments as interpreter slots and returns the result as bits, used by the interpreter
+    ret: Option<Kind>,
+}
+
+#[derive(Debug)]
+pub struct OsrEntry {
+    entry: *const u8, // Takes the local variable slots and returns the method result as bits
+}
+
+#[derive(Debug)]
+pub enum JitState {
+    Interpreted,
+    Compiled(Compiled),
+    Failed,
+}
+
+// Per method profile and compiled code
+#[derive(Debug)]
+pub struct MethodJit {
+    pub invocations: Cell<u32>,
+    pub back_edges: Cell<u32>,
+    pub state: RefCell<JitState>,
+    osr: RefCell<HashMap<usize, Option<Rc<OsrEntry>>>>, // Keyed by loop header, None if it couldn't be compiled
+}
+
+impl MethodJit {
+    pub fn new() -> MethodJit {
+        return MethodJit {
+            invocations: Cell::new(0),
+            back_edges: Cell::new(0),
+            state: RefCell::new(JitState::Interpreted),
+            osr: RefCell::new(HashMap::new()),
+        };
+    }
+
+    fn is_interpreted(&self) -> bool {
+        return match *self.state.borrow() { JitState::Interpreted => true, _ => false };
+    }
+}
+
+fn parse_signature(descriptor: &str) -> Option<(Vec<Kind>, Option<Kind>)> {
+    if !descriptor.starts_with('(') {
+        return None;
+    }
+    let close = match descriptor.find(')') { Some(x) => x, None => return None };
+    let mut params = Vec::new();
+    for type_char in descriptor[1..close].chars() {
+        match Kind::from_descriptor(type_char) {
+            Some(kind) => params.push(kind),
+            None => return None
+        }
+    }
+    return match &descriptor[close + 1..] {
+        "V" => Some((params, None)),
+        ret if ret.len() == 1 => Kind::from_descriptor(ret.chars().next().unwrap()).map(|x| (params, Some(x))),
+        _ => None
+    };
+}
+
+struct Analysis {
+    code: Rc<DecodedCode>,
+    params: Vec<Kind>,
+    ret: Option<Kind>,
+    stacks: Vec<Option<Vec<Kind>>>, // Operand stack on entry to each instruction, None if unreachable
+    block_starts: Vec<bool>,
+    calls: HashMap<usize, (Rc<Class>, Rc<Method>)>, // Static call targets by instruction index
+}
+
+// Removes entries from the top of a stack until they take exactly the given number of slots
+fn take_slots<T, F>(stack: &mut Vec<T>, slots: usize, size_of: F) -> Result<Vec<T>, String> where F: Fn(&T) -> usize {
+    let mut taken = Vec::new();
+    let mut total = 0;
+    while total < slots {
+        let entry = try!(stack.pop().ok_or(String::from("Stack underflow")));
+        total = total + size_of(&entry);
+        taken.insert(0, entry);
+    }
+    if total != slots {
+        return Err(String::from("Stack manipulation splits a long or double"));
+    }
+    return Ok(taken);
+}
+
+// DUP, DUP_X1, DUP_X2, DUP2, DUP2_X1 and DUP2_X2 as (slots duplicated, slots skipped)
+fn dup_form(op: u8) -> (usize, usize) {
+    return match op {
+        89 => (1, 0),
+        90 => (1, 1),
+        91 => (1, 2),
+        92 => (2, 0),
+        93 => (2, 1),
+        _ => (2, 2),
+    };
+}
+
+fn stack_manipulation<T, F>(op: u8, stack: &mut Vec<T>, size_of: F) -> Result<(), String> where T: Clone, F: Fn(&T) -> usize {
+    match op {
+        87 | 88 => { try!(take_slots(stack, (op - 86) as usize, &size_of)); }
+        89...94 => {
+            let (copied, skipped) = dup_form(op);
+            let top = try!(take_slots(stack, copied, &size_of));
+            let below = try!(take_slots(stack, skipped, &size_of));
+            stack.extend(top.iter().cloned());
+            stack.extend(below);
+            stack.extend(top);
+        }
+        _ => { // SWAP
+            let top = try!(take_slots(stack, 1, &size_of));
+            let below = try!(take_slots(stack, 1, &size_of));
+            stack.extend(top);
+            stack.extend(below);
+        }
+    }
+    return Ok(());
+}
+
+fn constant_kind(class: &Class, index: u16) -> Result<Kind, String> {
+    return match class.constant_pool.pool.get(&index) {
+        Some(&ConstantPoolItem::CONSTANT_Integer { value: _ }) => Ok(Kind::Int),
+        Some(&ConstantPoolItem::CONSTANT_Float { value: _ }) => Ok(Kind::Float),
+        Some(&ConstantPoolItem::CONSTANT_Long { value: _ }) => Ok(Kind::Long),
+        Some(&ConstantPoolItem::CONSTANT_Double { value: _ }) => Ok(Kind::Double),
+        _ => Err(format!("Constant {} isn't primitive", index))
+    };
+}
+
+fn pop_kind(stack: &mut Vec<Kind>, kind: Kind) -> Result<(), String> {
+    let popped = try!(stack.pop().ok_or(String::from("Stack underflow")));
+    if popped != kind {
+        return Err(format!("Expected {:?} on the stack but found {:?}", kind, popped));
+    }
+    return Ok(());
+}
+
+// Kind of the typed load, store, arithmetic and return instructions which come in int, long, float, double order
+fn typed_kind(op: u8, first: u8) -> Kind {
+    return [Kind::Int, Kind::Long, Kind::Float, Kind::Double][((op - first) % 4) as usize];
+}
+
+// Kind of the xLOAD_n and xSTORE_n instructions which come in groups of four per kind
+fn numbered_kind(op: u8, first: u8) -> Kind {
+    return [Kind::Int, Kind::Long, Kind::Float, Kind::Double][((op - first) / 4) as usize];
+}
+
+// Applies an instruction to the operand stack kinds, returns the instructions that may execute next
+fn analyse_instruction(runtime: &Runtime, class: &Class, analysis: &mut Analysis, index: usize, stack: &mut Vec<Kind>) -> Result<Vec<usize>, String> {
+    let instruction = &analysis.code.instructions[index];
+    let op = instruction.op;
+    match op {
+        0 => {}
+        2...8 | 16 | 17 => stack.push(Kind::Int),
+        9 | 10 => stack.push(Kind::Long),
+        11...13 => stack.push(Kind::Float),
+        14 | 15 => stack.push(Kind::Double),
+        18...20 => stack.push(try!(constant_kind(class, instruction.index()))),
+        21...24 => stack.push(typed_kind(op, 21)),
+        26...41 => stack.push(numbered_kind(op, 26)),
+        54...57 => try!(pop_kind(stack, typed_kind(op, 54))),
+        59...74 => try!(pop_kind(stack, numbered_kind(op, 59))),
+        87...95 => try!(stack_manipulation(op, stack, |x| x.size())),
+        96...107 | 110 | 111 => {
+            let kind = typed_kind(op, 96);
+            try!(pop_kind(stack, kind));
+            try!(pop_kind(stack, kind));
+            stack.push(kind);
+        }
+        116...119 => {
+            let kind = typed_kind(op, 116);
+            try!(pop_kind(stack, kind));
+            stack.push(kind);
+        }
+        120...125 => {
+            let kind = if op % 2 == 0 { Kind::Int } else { Kind::Long };
+            try!(pop_kind(stack, Kind::Int));
+            try!(pop_kind(stack, kind));
+            stack.push(kind);
+        }
+        126...131 => {
+            let kind = if op % 2 == 0 { Kind::Int } else { Kind::Long };
+            try!(pop_kind(stack, kind));
+            try!(pop_kind(stack, kind));
+            stack.push(kind);
+        }
+        132 => {}
+        133...147 => {
+            let (from, to) = match op {
+                133 => (Kind::Int, Kind::Long),
+                134 => (Kind::Int, Kind::Float),
+                135 => (Kind::Int, Kind::Double),
+                136 => (Kind::Long, Kind::Int),
+                137 => (Kind::Long, Kind::Float),
+                138 => (Kind::Long, Kind::Double),
+                139 => (Kind::Float, Kind::Int),
+                140 => (Kind::Float, Kind::Long),
+                141 => (Kind::Float, Kind::Double),
+                142 => (Kind::Double, Kind::Int),
+                143 => (Kind::Double, Kind::Long),
+                144 => (Kind::Double, Kind::Float),
+                _ => (Kind::Int, Kind::Int),
+            };
+            try!(pop_kind(stack, from));
+            stack.push(to);
+        }
+        148...152 => {
+            let kind = match op { 148 => Kind::Long, 149 | 150 => Kind::Float, _ => Kind::Double };
+            try!(pop_kind(stack, kind));
+            try!(pop_kind(stack, kind));
+            stack.push(Kind::Int);
+        }
+        153...164 => {
+            try!(pop_kind(stack, Kind::Int));
+            if op >= 159 {
+                try!(pop_kind(stack, Kind::Int));
+            }
+            return Ok(vec!(instruction.target(), index + 1));
+        }
+        167 | 200 => return Ok(vec!(instruction.target())),
+        170 | 171 => {
+            try!(pop_kind(stack, Kind::Int));
+            return match instruction.operand {
+                Operand::Switch(ref switch) => Ok(match **switch {
+                    Switch::Table { default, ref targets, .. } => targets.iter().cloned().chain(Some(default)).collect(),
+                    Switch::Lookup { default, ref pairs } => pairs.iter().map(|x| x.1).chain(Some(default)).collect(),
+                }),
+                _ => Err(String::from("Switch without targets"))
+            };
+        }
+        172...175 => {
+            let kind = typed_kind(op, 172);
+            try!(pop_kind(stack, kind));
+            if analysis.ret != Some(kind) {
+                return Err(String::from("Return doesn't match descriptor"));
+            }
+            return Ok(Vec::new());
+        }
+        177 => {
+            if analysis.ret.is_some() {
+                return Err(String::from("Return doesn't match descriptor"));
+            }
+            return Ok(Vec::new());
+        }
+        184 => {
+            let (class_name, method_name, descriptor) = try!(class.constant_pool.get_method(instruction.index()).map_err(|x| format!("{:?}", x)));
+            // Loading a class runs its initialiser, which compiled code can't do, so only call into loaded classes
+            let callee_class = try!(runtime.classes.get(class_name.as_str()).ok_or(format!("Class {} not loaded", class_name)));
+            let (declaring_class, callee) = try!(Class::find_method(callee_class, method_name.as_str(), descriptor.as_str()).ok_or(format!("No method {} {}", method_name, descriptor)));
+            if !callee.is_static() || callee.is_native() || callee.code.is_none() || callee.native.borrow().is_some() {
+                return Err(format!("Can't call {} {}", method_name, descriptor));
+            }
+            if let JitState::Failed = *callee.jit.state.borrow() {
+                return Err(format!("Callee {} {} can't be compiled", method_name, descriptor));
+            }
+            let (params, ret) = try!(parse_signature(descriptor.as_str()).ok_or(format!("Unsupported call {}", descriptor)));
+            for kind in params.iter().rev() {
+                try!(pop_kind(stack, *kind));
+            }
+            if let Some(kind) = ret {
+                stack.push(kind);
+            }
+            analysis.calls.insert(index, (declaring_class, callee));
+        }
+        _ => return Err(format!("Unsupported instruction {}", op))
+    }
+    return Ok(vec!(index + 1));
+}
+
+fn analyse(runtime: &Runtime, class: &Class, method: &Method) -> Result<Analysis, String> {
+    if !method.is_static() || method.is_native() {
+        return Err(String::from("Only static methods are compiled"));
+    }
+    let code = try!(method.code.clone().ok_or(String::from("No code")));
+    let (params, ret) = try!(parse_signature(method.descriptor.as_str()).ok_or(String::from("Non primitive signature")));
+    let count = code.instructions.len();
+    let mut analysis = Analysis {
+        code: code,
+        params: params,
+        ret: ret,
+        stacks: vec![None; count],
+        block_starts: vec![false; count],
+        calls: HashMap::new(),
+    };
+    if count == 0 {
+        return Err(String::from("No instructions"));
+    }
+
+    analysis.stacks[0] = Some(Vec::new());
+    analysis.block_starts[0] = true;
+    let mut pending = vec!(0);
+    while let Some(index) = pending.pop() {
+        let mut stack = analysis.stacks[index].clone().unwrap();
+        let successors = try!(analyse_instruction(runtime, class, &mut analysis, index, &mut stack));
+        let branches = successors.len() != 1 || successors[0] != index + 1;
+        for successor in successors {
+            if successor >= count {
+                return Err(String::from("Execution falls off the end of the code"));
+            }
+            if branches {
+                analysis.block_starts[successor] = true;
+            }
+            match analysis.stacks[successor] {
+                Some(ref existing) if *existing != stack => return Err(format!("Inconsistent stack at {}", successor)),
+                Some(_) => continue,
+                None => {}
+            }
+            analysis.stacks[successor] = Some(stack.clone());
+            pending.push(successor);
+        }
+    }
+    return Ok(analysis);
+}
+
+enum Entry<'a> {
+    Method,                        // Arguments in the native calling convention
+    Osr(usize, &'a [(usize, Kind)]), // Locals in interpreter slots, entering at a loop header
+}
+
+struct Translator<'a> {
+    b: FunctionBuilder<'a>,
+    locals: HashMap<(usize, Kind), Var>,
+    stack_vars: HashMap<(usize, Kind), Var>,
+    stack: Vec<(Kind, Value)>,
+    returns_bits: bool,
+    ret: Option<Kind>,
+    calls: i64,    // Address of the CallDepth
+    counted: bool, // Whether this function counts against the call depth, which entries from the interpreter don't
+}
+
+impl<'a> Translator<'a> {
+    fn var(b: &mut FunctionBuilder, vars: &mut HashMap<(usize, Kind), Var>, index: usize, kind: Kind) -> Var {
+        return *vars.entry((index, kind)).or_insert_with(|| b.declare_var(kind.ty()));
+    }
+
+    fn load(&mut self, index: usize, kind: Kind) {
+        let var = Translator::var(&mut self.b, &mut self.locals, index, kind);
+        let value = self.b.use_var(var);
+        self.stack.push((kind, value));
+    }
+
+    fn store_value(&mut self, index: usize, kind: Kind, value: Value) {
+        let var = Translator::var(&mut self.b, &mut self.locals, index, kind);
+        self.b.def_var(var, value);
+    }
+
+    // Immediates of narrow types are kept zero extended
+    fn int(&mut self, value: i32) -> Value {
+        return self.b.ins().iconst(types::I32, value as u32 as i64);
+    }
+
+    fn push(&mut self, kind: Kind, value: Value) {
+        self.stack.push((kind, value));
+    }
+
+    fn pop(&mut self) -> Value {
+        return self.stack.pop().unwrap().1;
+    }
+
+    fn pop2(&mut self) -> (Value, Value) {
+        let b = self.pop();
+        let a = self.pop();
+        return (a, b);
+    }
+
+    // The stack lives in SSA values inside a block and in variables across block boundaries
+    fn spill(&mut self) {
+        for depth in 0..self.stack.len() {
+            let (kind, value) = self.stack[depth];
+            let var = Translator::var(&mut self.b, &mut self.stack_vars, depth, kind);
+            self.b.def_var(var, value);
+        }
+    }
+
+    fn reload(&mut self, kinds: &Vec<Kind>) {
+        self.stack.clear();
+        for (depth, kind) in kinds.iter().enumerate() {
+            let var = Translator::var(&mut self.b, &mut self.stack_vars, depth, *kind);
+            let value = self.b.use_var(var);
+            self.stack.push((*kind, value));
+        }
+    }
+
+    fn to_bits(&mut self, kind: Kind, value: Value) -> Value {
+        return match kind {
+            Kind::Int => self.b.ins().uextend(types::I64, value),
+            Kind::Long => value,
+            Kind::Float => {
+                let bits = self.b.ins().bitcast(types::I32, MemFlagsData::new(), value);
+                self.b.ins().uextend(types::I64, bits)
+            }
+            Kind::Double => self.b.ins().bitcast(types::I64, MemFlagsData::new(), value),
+        };
+    }
+
+    fn emit_return(&mut self, kind: Option<Kind>) {
+        let value = kind.map(|_| self.pop());
+        if self.counted {
+            let address = self.b.ins().iconst(types::I64, self.calls);
+            let remaining = self.b.ins().load(types::I64, MemFlagsData::new().with_notrap(), address, 0);
+            let remaining = self.b.ins().iadd_imm_s(remaining, 1);
+            self.b.ins().store(MemFlagsData::new().with_notrap(), remaining, address, 0);
+        }
+        if self.returns_bits {
+            let bits = match (kind, value) {
+                (Some(kind), Some(value)) => self.to_bits(kind, value),
+                _ => self.b.ins().iconst(types::I64, 0),
+            };
+            self.b.ins().return_(&[bits]);
+        } else {
+            let values: Vec<Value> = value.into_iter().collect();
+            self.b.ins().return_(&values);
+        }
+    }
+
+    // Returns a zero in place of a result once the stack has overflowed, which nothing ever reads
+    fn bail(&mut self) {
+        let zero = match (self.returns_bits, self.ret) {
+            (true, _) => Some(self.b.ins().iconst(types::I64, 0)),
+            (false, Some(Kind::Int)) => Some(self.int(0)),
+            (false, Some(Kind::Long)) => Some(self.b.ins().iconst(types::I64, 0)),
+            (false, Some(Kind::Float)) => Some(self.b.ins().f32const(0.0)),
+            (false, Some(Kind::Double)) => Some(self.b.ins().f64const(0.0)),
+            (false, None) => None,
+        };
+        let values: Vec<Value> = zero.into_iter().collect();
+        self.b.ins().return_(&values);
+    }
+
+    // Continues in a new block if the condition is zero, otherwise bails out
+    fn bail_if(&mut self, condition: Value) {
+        let exhausted = self.b.create_block();
+        let next = self.b.create_block();
+        self.b.ins().brif(condition, exhausted, &[], next, &[]);
+        self.b.switch_to_block(exhausted);
+        self.bail();
+        self.b.switch_to_block(next);
+    }
+
+    // Counts this frame against the remaining depth, flagging an overflow and leaving if there is none left
+    fn enter(&mut self) {
+        let address = self.b.ins().iconst(types::I64, self.calls);
+        let remaining = self.b.ins().load(types::I64, MemFlagsData::new().with_notrap(), address, 0);
+        let remaining = self.b.ins().iadd_imm_s(remaining, -1);
+        self.b.ins().store(MemFlagsData::new().with_notrap(), remaining, address, 0);
+        let exhausted = self.b.ins().icmp_imm_s(IntCC::SignedLessThan, remaining, 0);
+        let flag = self.b.ins().uextend(types::I64, exhausted);
+        self.b.ins().store(MemFlagsData::new().with_notrap(), flag, address, 8);
+        self.bail_if(exhausted);
+    }
+
+    // Three way compare producing -1, 0 or 1, unordered floats give nan_result
+    fn compare(&mut self, kind: Kind, nan_result: i32) {
+        let (a, b) = self.pop2();
+        let (greater, equal, less) = if kind == Kind::Long {
+            (self.b.ins().icmp(IntCC::SignedGreaterThan, a, b), self.b.ins().icmp(IntCC::Equal, a, b), self.b.ins().icmp(IntCC::SignedLessThan, a, b))
+        } else {
+            (self.b.ins().fcmp(FloatCC::GreaterThan, a, b), self.b.ins().fcmp(FloatCC::Equal, a, b), self.b.ins().fcmp(FloatCC::LessThan, a, b))
+        };
+        let one = self.int(1);
+        let zero = self.int(0);
+        let minus_one = self.int(-1);
+        let unordered = self.int(nan_result);
+        let result = self.b.ins().select(less, minus_one, unordered);
+        let result = self.b.ins().select(equal, zero, result);
+        let result = self.b.ins().select(greater, one, result);
+        self.push(Kind::Int, result);
+    }
+
+    fn constant(&mut self, class: &Class, index: u16) {
+        let (kind, value) = match class.constant_pool.pool.get(&index) {
+            Some(&ConstantPoolItem::CONSTANT_Integer { value }) => (Kind::Int, self.int(value as i32)),
+            Some(&ConstantPoolItem::CONSTANT_Float { value }) => (Kind::Float, self.b.ins().f32const(value)),
+            Some(&ConstantPoolItem::CONSTANT_Long { value }) => (Kind::Long, self.b.ins().iconst(types::I64, value as i64)),
+            Some(&ConstantPoolItem::CONSTANT_Double { value }) => (Kind::Double, self.b.ins().f64const(value)),
+            _ => unreachable!(),
+        };
+        self.push(kind, value);
+    }
+
+    fn call(&mut self, module: &mut JITModule, batch: &HashMap<*const Method, FuncId>, callee: &Rc<Method>) -> Result<(), String> {
+        let (params, ret) = parse_signature(callee.descriptor.as_str()).unwrap();
+        let split = self.stack.len() - params.len();
+        let args: Vec<Value> = self.stack.split_off(split).into_iter().map(|x| x.1).collect();
+        let call = match batch.get(&(&**callee as *const Method)) {
+            Some(id) => {
+                let func = module.declare_func_in_func(*id, &mut *self.b.func);
+                self.b.ins().call(func, &args)
+            }
+            None => {
+                let address = match *callee.jit.state.borrow() {
+                    JitState::Compiled(ref compiled) => compiled.address,
+                    _ => return Err(format!("Callee {} isn't compiled", callee.name))
+                };
+                let sig = self.b.import_signature(native_signature(module, &params, ret));
+                let target = self.b.ins().iconst(types::I64, address as i64);
+                self.b.ins().call_indirect(sig, target, &args)
+            }
+        };
+        let result = ret.map(|_| self.b.inst_results(call)[0]);
+        let address = self.b.ins().iconst(types::I64, self.calls);
+        let overflowed = self.b.ins().load(types::I64, MemFlagsData::new().with_notrap(), address, 8);
+        self.bail_if(overflowed);
+        if let (Some(kind), Some(value)) = (ret, result) {
+            self.push(kind, value);
+        }
+        return Ok(());
+    }
+
+    // Returns true if the instruction ends the current block
+    fn translate(&mut self, module: &mut JITModule, class: &Class, analysis: &Analysis, blocks: &Vec<Option<Block>>, batch: &HashMap<*const Method, FuncId>, index: usize) -> Result<bool, String> {
+        let instruction = &analysis.code.instructions[index];
+        let op = instruction.op;
+        match op {
+            0 => {}
+            2...8 => { let v = self.int(op as i32 - 3); self.push(Kind::Int, v); }
+            9 | 10 => { let v = self.b.ins().iconst(types::I64, op as i64 - 9); self.push(Kind::Long, v); }
+            11...13 => { let v = self.b.ins().f32const((op - 11) as f32); self.push(Kind::Float, v); }
+            14 | 15 => { let v = self.b.ins().f64const((op - 14) as f64); self.push(Kind::Double, v); }
+            16 | 17 => { let v = self.int(instruction.int()); self.push(Kind::Int, v); }
+            18...20 => self.constant(class, instruction.index()),
+            21...24 => self.load(instruction.local(), typed_kind(op, 21)),
+            26...41 => self.load(((op - 26) % 4) as usize, numbered_kind(op, 26)),
+            54...57 => { let v = self.pop(); self.store_value(instruction.local(), typed_kind(op, 54), v); }
+            59...74 => { let v = self.pop(); self.store_value(((op - 59) % 4) as usize, numbered_kind(op, 59), v); }
+            87...95 => try!(stack_manipulation(op, &mut self.stack, |x| x.0.size())),
+            96...111 => {
+                let kind = typed_kind(op, 96);
+                let (a, b) = self.pop2();
+                let float = kind == Kind::Float || kind == Kind::Double;
+                let v = match (op - 96) / 4 {
+                    0 if float => self.b.ins().fadd(a, b),
+                    0 => self.b.ins().iadd(a, b),
+                    1 if float => self.b.ins().fsub(a, b),
+                    1 => self.b.ins().isub(a, b),
+                    2 if float => self.b.ins().fmul(a, b),
+                    2 => self.b.ins().imul(a, b),
+                    _ => self.b.ins().fdiv(a, b),
+                };
+                self.push(kind, v);
+            }
+            126...131 => {
+                let kind = if op % 2 == 0 { Kind::Int } else { Kind::Long };
+                let (a, b) = self.pop2();
+                let v = match op {
+                    126 | 127 => self.b.ins().band(a, b),
+                    128 | 129 => self.b.ins().bor(a, b),
+                    _ => self.b.ins().bxor(a, b),
+                };
+                self.push(kind, v);
+            }
+            116...119 => {
+                let kind = typed_kind(op, 116);
+                let a = self.pop();
+                let v = if kind == Kind::Int || kind == Kind::Long { self.b.ins().ineg(a) } else { self.b.ins().fneg(a) };
+                self.push(kind, v);
+            }
+            120...125 => { // Shift distances are masked to the width of the shifted value, as in Java
+                let kind = if op % 2 == 0 { Kind::Int } else { Kind::Long };
+                let (a, b) = self.pop2();
+                let v = match op {
+                    120 | 121 => self.b.ins().ishl(a, b),
+                    122 | 123 => self.b.ins().sshr(a, b),
+                    _ => self.b.ins().ushr(a, b),
+                };
+                self.push(kind, v);
+            }
+            132 => {
+                let (local, increment) = match instruction.operand { Operand::Iinc(local, increment) => (local, increment), _ => (0, 0) };
+                self.load(local, Kind::Int);
+                let a = self.pop();
+                let increment = self.int(increment);
+                let v = self.b.ins().iadd(a, increment);
+                self.store_value(local, Kind::Int, v);
+            }
+            133...147 => {
+                let a = self.pop();
+                let (kind, v) = match op {
+                    133 => (Kind::Long, self.b.ins().sextend(types::I64, a)),
+                    134 => (Kind::Float, self.b.ins().fcvt_from_sint(types::F32, a)),
+                    135 => (Kind::Double, self.b.ins().fcvt_from_sint(types::F64, a)),
+                    136 => (Kind::Int, self.b.ins().ireduce(types::I32, a)),
+                    137 => (Kind::Float, self.b.ins().fcvt_from_sint(types::F32, a)),
+                    138 => (Kind::Double, self.b.ins().fcvt_from_sint(types::F64, a)),
+                    139 | 142 => (Kind::Int, self.b.ins().fcvt_to_sint_sat(types::I32, a)), // NaN gives 0 and out of range values saturate
+                    140 | 143 => (Kind::Long, self.b.ins().fcvt_to_sint_sat(types::I64, a)),
+                    141 => (Kind::Double, self.b.ins().fpromote(types::F64, a)),
+                    144 => (Kind::Float, self.b.ins().fdemote(types::F32, a)),
+                    145 => { let narrow = self.b.ins().ireduce(types::I8, a); (Kind::Int, self.b.ins().sextend(types::I32, narrow)) }
+                    146 => (Kind::Int, self.b.ins().band_imm_u(a, 0xffff)),
+                    _ => { let narrow = self.b.ins().ireduce(types::I16, a); (Kind::Int, self.b.ins().sextend(types::I32, narrow)) }
+                };
+                self.push(kind, v);
+            }
+            148 => self.compare(Kind::Long, 0),
+            149 => self.compare(Kind::Float, -1),
+            150 => self.compare(Kind::Float, 1),
+            151 => self.compare(Kind::Double, -1),
+            152 => self.compare(Kind::Double, 1),
+            153...164 => {
+                let cc = match (op - 153) % 6 {
+                    0 => IntCC::Equal,
+                    1 => IntCC::NotEqual,
+                    2 => IntCC::SignedLessThan,
+                    3 => IntCC::SignedGreaterThanOrEqual,
+                    4 => IntCC::SignedGreaterThan,
+                    _ => IntCC::SignedLessThanOrEqual,
+                };
+                let condition = if op >= 159 {
+                    let (a, b) = self.pop2();
+                    self.b.ins().icmp(cc, a, b)
+                } else {
+                    let a = self.pop();
+                    self.b.ins().icmp_imm_s(cc, a, 0)
+                };
+                self.spill();
+                self.b.ins().brif(condition, blocks[instruction.target()].unwrap(), &[], blocks[index + 1].unwrap(), &[]);
+                return Ok(true);
+            }
+            167 | 200 => {
+                self.spill();
+                self.b.ins().jump(blocks[instruction.target()].unwrap(), &[]);
+                return Ok(true);
+            }
+            170 | 171 => {
+                let key = self.pop();
+                self.spill();
+                let mut switch = SwitchBuilder::new();
+                let default = match instruction.operand {
+                    Operand::Switch(ref s) => match **s {
+                        Switch::Table { default, low, ref targets } => {
+                            for (offset, target) in targets.iter().enumerate() {
+                                switch.set_entry((low as i64 + offset as i64) as u32 as u128, blocks[*target].unwrap());
+                            }
+                            default
+                        }
+                        Switch::Lookup { default, ref pairs } => {
+                            for &(value, target) in pairs.iter() {
+                                switch.set_entry(value as u32 as u128, blocks[target].unwrap());
+                            }
+                            default
+                        }
+                    },
+                    _ => unreachable!(),
+                };
+                switch.emit(&mut self.b, key, blocks[default].unwrap());
+                return Ok(true);
+            }
+            172...175 => { self.emit_return(Some(typed_kind(op, 172))); return Ok(true); }
+            177 => { self.emit_return(None); return Ok(true); }
+            184 => try!(self.call(module, batch, &analysis.calls[&index].1)),
+            _ => return Err(format!("Unsupported instruction {}", op))
+        }
+        return Ok(false);
+    }
+}
+
+fn native_signature(module: &JITModule, params: &Vec<Kind>, ret: Option<Kind>) -> Signature {
+    let mut sig = module.make_signature();
+    for kind in params {
+        sig.params.push(AbiParam::new(kind.ty()));
+    }
+    if let Some(kind) = ret {
+        sig.returns.push(AbiParam::new(kind.ty()));
+    }
+    return sig;
+}
+
+// Signature of the interpreter facing entries: a pointer to 32 bit interpreter slots in, the result as bits out
+fn bits_signature(module: &JITModule) -> Signature {
+    let mut sig = module.make_signature();
+    sig.params.push(AbiParam::new(types::I64));
+    sig.returns.push(AbiParam::new(types::I64));
+    return sig;
+}
+
+fn new_module() -> Result<JITModule, String> {
+    let mut flags = settings::builder();
+    try!(flags.set("opt_level", "speed").map_err(|x| x.to_string()));
+    try!(flags.set("use_colocated_libcalls", "false").map_err(|x| x.to_string()));
+    try!(flags.set("is_pic", "false").map_err(|x| x.to_string()));
+    let isa_builder = try!(cranelift_native::builder().map_err(|x| x.to_string()));
+    let isa = try!(isa_builder.finish(settings::Flags::new(flags)).map_err(|x| x.to_string()));
+    return Ok(JITModule::new(JITBuilder::with_isa(isa, default_libcall_names())));
+}
+
+fn define_method(module: &mut JITModule, ctx: &mut Context, fctx: &mut FunctionBuilderContext, id: FuncId, class: &Class, analysis: &Analysis, entry: Entry, batch: &HashMap<*const Method, FuncId>, calls: i64) -> Result<(), String> {
+    ctx.func = Function::with_name_signature(UserFuncName::user(0, id.as_u32()), match entry {
+        Entry::Method => native_signature(module, &analysis.params, analysis.ret),
+        Entry::Osr(_, _) => bits_signature(module),
+    });
+    {
+        let mut t = Translator {
+            b: FunctionBuilder::new(&mut ctx.func, fctx),
+            locals: HashMap::new(),
+            stack_vars: HashMap::new(),
+            stack: Vec::new(),
+            returns_bits: match entry { Entry::Osr(_, _) => true, _ => false },
+            ret: analysis.ret,
+            calls: calls,
+            counted: match entry { Entry::Method => true, _ => false },
+        };
+        let count = analysis.code.instructions.len();
+        let mut blocks = Vec::new();
+        for index in 0..count {
+            blocks.push(if analysis.block_starts[index] && analysis.stacks[index].is_some() { Some(t.b.create_block()) } else { None });
+        }
+
+        let start = t.b.create_block();
+        t.b.append_block_params_for_function_params(start);
+        t.b.switch_to_block(start);
+        let params = t.b.block_params(start).to_vec();
+        match entry {
+            Entry::Method => {
+                t.enter();
+                let mut slot = 0;
+                for (kind, value) in analysis.params.iter().zip(params) {
+                    t.store_value(slot, *kind, value);
+                    slot = slot + kind.size();
+                }
+                t.b.ins().jump(blocks[0].unwrap(), &[]);
+            }
+            Entry::Osr(target, locals) => {
+                for &(slot, kind) in locals.iter() {
+                    let value = t.b.ins().load(kind.ty(), MemFlagsData::new().with_notrap(), params[0], (slot * 4) as i32);
+                    t.store_value(slot, kind, value);
+                }
+                t.b.ins().jump(try!(blocks[target].ok_or(String::from("Loop header isn't a block"))), &[]);
+            }
+        }
+
+        let mut terminated = true;
+        for index in 0..count {
+            let stack = match analysis.stacks[index] { Some(ref stack) => stack, None => continue };
+            if let Some(block) = blocks[index] {
+                if !terminated {
+                    t.spill();
+                    t.b.ins().jump(block, &[]);
+                }
+                t.b.switch_to_block(block);
+                t.reload(stack);
+            }
+            terminated = try!(t.translate(module, class, analysis, &blocks, batch, index));
+        }
+        t.b.seal_all_blocks();
+        t.b.finalize(module.target_config());
+    }
+    try!(module.define_function(id, ctx).map_err(|x| format!("{:?}", x)));
+    module.clear_context(ctx);
+    return Ok(());
+}
+
+// Wraps a compiled method so the interpreter can call it without knowing its native signature
+fn define_entry(module: &mut JITModule, ctx: &mut Context, fctx: &mut FunctionBuilderContext, id: FuncId, target: FuncId, analysis: &Analysis) -> Result<(), String> {
+    ctx.func = Function::with_name_signature(UserFuncName::user(0, id.as_u32()), bits_signature(module));
+    {
+        let mut b = FunctionBuilder::new(&mut ctx.func, fctx);
+        let start = b.create_block();
+        b.append_block_params_for_function_params(start);
+        b.switch_to_block(start);
+        let slots = b.block_params(start)[0];
+        let mut args = Vec::new();
+        let mut slot = 0;
+        for kind in analysis.params.iter() {
+            args.push(b.ins().load(kind.ty(), MemFlagsData::new().with_notrap(), slots, (slot * 4) as i32));
+            slot = slot + kind.size();
+        }
+        let func = module.declare_func_in_func(target, &mut *b.func);
+        let call = b.ins().call(func, &args);
+        let results = b.inst_results(call).to_vec();
+        let mut t = Translator { b: b, locals: HashMap::new(), stack_vars: HashMap::new(), stack: Vec::new(), returns_bits: true, ret: analysis.ret, calls: 0, counted: false };
+        if let Some(kind) = analysis.ret {
+            t.push(kind, results[0]);
+        }
+        t.emit_return(analysis.ret);
+        t.b.seal_all_blocks();
+        t.b.finalize(module.target_config());
+    }
+    try!(module.define_function(id, ctx).map_err(|x| format!("{:?}", x)));
+    module.clear_context(ctx);
+    return Ok(());
+}
+
+fn fail(runtime: &Runtime, method: &Method, reason: String) {
+    runnerPrint!(runtime, true, 2, "JIT: not compiling {} {}: {}", method.name, method.descriptor, reason);
+    *method.jit.state.borrow_mut() = JitState::Failed;
+}
+
+// Compiles a method along with the static methods it calls that aren't compiled yet
+pub fn compile(runtime: &mut Runtime, class: &Rc<Class>, method: &Rc<Method>) -> bool {
+    if !method.jit.is_interpreted() {
+        return match *method.jit.state.borrow() { JitState::Compiled(_) => true, _ => false };
+    }
+
+    let mut batch: Vec<(Rc<Class>, Rc<Method>, Analysis)> = Vec::new();
+    let mut pending = vec!((class.clone(), method.clone()));
+    while let Some((c, m)) = pending.pop() {
+        if batch.iter().any(|x| Rc::ptr_eq(&x.1, &m)) {
+            continue;
+        }
+        match analyse(runtime, &c, &m) {
+            Ok(analysis) => {
+                for &(ref callee_class, ref callee) in analysis.calls.values() {
+                    if callee.jit.is_interpreted() {
+                        pending.push((callee_class.clone(), callee.clone()));
+                    }
+                }
+                batch.push((c, m, analysis));
+            }
+            Err(reason) => {
+                fail(runtime, &m, reason.clone());
+                if !Rc::ptr_eq(&m, method) {
+                    fail(runtime, method, format!("calls {} which can't be compiled", m.name));
+                }
+                return false;
+            }
+        }
+    }
+
+    let result = compile_batch(&batch, runtime.jit.calls_address());
+    match result {
+        Ok((module, compiled)) => {
+            for (&(_, ref m, _), c) in batch.iter().zip(compiled) {
+                runnerPrint!(runtime, true, 2, "JIT: compiled {} {}", m.name, m.descriptor);
+                *m.jit.state.borrow_mut() = JitState::Compiled(c);
+                runtime.jit.compiled = runtime.jit.compiled + 1;
+            }
+            runtime.jit.modules.push(module);
+            return true;
+        }
+        Err(reason) => {
+            fail(runtime, method, reason);
+            return false;
+        }
+    }
+}
+
+fn compile_batch(batch: &Vec<(Rc<Class>, Rc<Method>, Analysis)>, calls: i64) -> Result<(JITModule, Vec<Compiled>), String> {
+    let mut module = try!(new_module());
+    let mut ids = HashMap::new();
+    let mut entry_ids = Vec::new();
+    for &(ref c, ref m, ref analysis) in batch.iter() {
+        let name = format!("{}.{}{}", c.name, m.name, m.descriptor);
+        let id = try!(module.declare_function(name.as_str(), Linkage::Local, &native_signature(&module, &analysis.params, analysis.ret)).map_err(|x| format!("{:?}", x)));
+        let entry_id = try!(module.declare_function((name + "#entry").as_str(), Linkage::Local, &bits_signature(&module)).map_err(|x| format!("{:?}", x)));
+        ids.insert(&**m as *const Method, id);
+        entry_ids.push((id, entry_id));
+    }
+
+    let mut ctx = module.make_context();
+    let mut fctx = FunctionBuilderContext::new();
+    for (&(ref c, _, ref analysis), &(id, entry_id)) in batch.iter().zip(entry_ids.iter()) {
+        try!(define_method(&mut module, &mut ctx, &mut fctx, id, c, analysis, Entry::Method, &ids, calls));
+        try!(define_entry(&mut module, &mut ctx, &mut fctx, entry_id, id, analysis));
+    }
+    try!(module.finalize_definitions().map_err(|x| format!("{:?}", x)));
+
+    let mut compiled = Vec::new();
+    for (&(_, _, ref analysis), &(id, entry_id)) in batch.iter().zip(entry_ids.iter()) {
+        compiled.push(Compiled {
+            address: module.get_finalized_function(id),
+            entry: module.get_finalized_function(entry_id),
+            ret: analysis.ret,
+        });
+    }
+    return Ok((module, compiled));
+}
+
+// Local variable slots and the kinds they're accessed as, which an entry at a loop header reads from the frame
+fn local_kinds(code: &DecodedCode) -> Vec<(usize, Kind)> {
+    let mut locals = Vec::new();
+    for instruction in code.instructions.iter() {
+        let op = instruction.op;
+        let local = match op {
+            21...24 => Some((instruction.local(), typed_kind(op, 21))),
+            54...57 => Some((instruction.local(), typed_kind(op, 54))),
+            26...41 => Some((((op - 26) % 4) as usize, numbered_kind(op, 26))),
+            59...74 => Some((((op - 59) % 4) as usize, numbered_kind(op, 59))),
+            132 => match instruction.operand { Operand::Iinc(index, _) => Some((index, Kind::Int)), _ => None },
+            _ => None
+        };
+        if let Some(local) = local {
+            if !locals.contains(&local) {
+                locals.push(local);
+            }
+        }
+    }
+    return locals;
+}
+
+// Compiles an entry into the middle of a method at a loop header
+fn compile_osr(runtime: &mut Runtime, class: &Rc<Class>, method: &Rc<Method>, target: usize) -> Result<OsrEntry, String> {
+    if !compile(runtime, class, method) {
+        return Err(String::from("Method can't be compiled"));
+    }
+    let analysis = try!(analyse(runtime, class, method));
+    if analysis.stacks.get(target).and_then(|x| x.as_ref()).map(|x| !x.is_empty()).unwrap_or(true) {
+        return Err(String::from("Loop header has values on the stack"));
+    }
+    let locals = local_kinds(&analysis.code);
+
+    let mut module = try!(new_module());
+    let name = format!("{}.{}{}#osr{}", class.name, method.name, method.descriptor, target);
+    let id = try!(module.declare_function(name.as_str(), Linkage::Local, &bits_signature(&module)).map_err(|x| format!("{:?}", x)));
+    let mut ctx = module.make_context();
+    let mut fctx = FunctionBuilderContext::new();
+    let calls = runtime.jit.calls_address();
+    try!(define_method(&mut module, &mut ctx, &mut fctx, id, class, &analysis, Entry::Osr(target, &locals), &HashMap::new(), calls));
+    try!(module.finalize_definitions().map_err(|x| format!("{:?}", x)));
+
+    let entry = OsrEntry { entry: module.get_finalized_function(id) };
+    runtime.jit.modules.push(module);
+    return Ok(entry);
+}
+
+fn call_bits(entry: *const u8, slots: *const u32) -> u64 {
+    let function: extern "C" fn(*const u32) -> u64 = unsafe { mem::transmute(entry) };
+    return function(slots);
+}
+
+// Called by the interpreter on invocation of a static method with its arguments on top of the stack. Runs
+// compiled code on those slots directly, then pops them and pushes the result if there is any.
+pub fn invoke_compiled(runtime: &mut Runtime, class: &Rc<Class>, method: &Rc<Method>, arg_slots: usize) -> Result<bool, RunnerError> {
+    if !runtime.jit.enabled {
+        return Ok(false);
+    }
+    let invocations = method.jit.invocations.get().saturating_add(1);
+    method.jit.invocations.set(invocations);
+    if invocations >= runtime.jit.invocation_threshold && method.jit.is_interpreted() {
+        compile(runtime, class, method);
+    }
+
+    let (entry, ret) = match *method.jit.state.borrow() {
+        JitState::Compiled(ref compiled) => (compiled.entry, compiled.ret),
+        _ => return Ok(false)
+    };
+    runtime.jit.start_calls(runtime.previous_frames.len(), runtime.max_call_depth);
+    let bits = call_bits(entry, runtime.current_frame.operand_stack.slots_ptr(arg_slots));
+    runtime.current_frame.operand_stack.drop_slots(arg_slots);
+    if runtime.jit.overflowed() {
+        return Err(try!(stack_overflow_error(runtime)));
+    }
+    if let Some(kind) = ret {
+        kind.push_bits(&mut runtime.current_frame.operand_stack, bits);
+    }
+    return Ok(true);
+}
+
+// Called by the interpreter on a backward branch. Once a loop is hot the rest of the method runs compiled from
+// the loop header, in which case the frame is popped and its result pushed as if it had returned.
+pub fn back_edge(runtime: &mut Runtime, target: usize) -> Result<Control, RunnerError> {
+    if !runtime.jit.enabled {
+        return Ok(Control::Jump(target));
+    }
+    let (class, method) = match (runtime.current_frame.class.clone(), runtime.current_frame.method.clone()) {
+        (Some(class), Some(method)) => (class, method),
+        _ => return Ok(Control::Jump(target))
+    };
+    let back_edges = method.jit.back_edges.get().saturating_add(1);
+    method.jit.back_edges.set(back_edges);
+    if back_edges < runtime.jit.back_edge_threshold {
+        return Ok(Control::Jump(target));
+    }
+    if let JitState::Failed = *method.jit.state.borrow() {
+        return Ok(Control::Jump(target));
+    }
+
+    let existing = method.jit.osr.borrow().get(&target).cloned();
+    let entry = match existing {
+        Some(entry) => entry,
+        None => {
+            let entry = match compile_osr(runtime, &class, &method, target) {
+                Ok(entry) => Some(Rc::new(entry)),
+                Err(reason) => {
+                    runnerPrint!(runtime, true, 2, "JIT: no loop entry for {} {} at {}: {}", method.name, method.descriptor, target, reason);
+                    None
+                }
+            };
+            method.jit.osr.borrow_mut().insert(target, entry.clone());
+            entry
+        }
+    };
+    let entry = match entry {
+        Some(entry) => entry,
+        None => return Ok(Control::Jump(target))
+    };
+
+    // The loop entry runs as part of this frame, so it doesn't count against the depth itself
+    runtime.jit.start_calls(runtime.previous_frames.len() + 1, runtime.max_call_depth);
+    let bits = call_bits(entry.entry, runtime.current_frame.local_variables.as_ptr());
+    if runtime.jit.overflowed() {
+        return Err(try!(stack_overflow_error(runtime)));
+    }
+    let ret = parse_signature(method.descriptor.as_str()).and_then(|x| x.1);
+    runtime.current_frame = runtime.previous_frames.pop().unwrap();
+    if let Some(kind) = ret {
+        kind.push_bits(&mut runtime.current_frame.operand_stack, bits);
+    }
+    return Ok(Control::Return);
+}
@@ -1,4 +1,5 @@
 pub mod class_objects;
 pub mod construction;
 pub mod interpreter;
+pub mod jit;
 pub mod gc;
\ No newline at end of file
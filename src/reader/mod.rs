@@ -1,15 +1,25 @@
 #[macro_use]
 pub mod class_reader;
+mod class_format;
+mod attributes;
 #[macro_use]
 pub mod runner;
+pub mod handles;
 mod util;
 mod builtins;
 mod jvm;
 mod types {
+    pub mod capture;
     pub mod class;
+    pub mod clock;
     pub mod constant_pool;
+    pub mod files;
     pub mod frame;
+    pub mod instruction;
+    pub mod method;
     pub mod objects;
+    pub mod resolved;
     pub mod runtime;
+    pub mod slots;
     pub mod variable;
 }
\ No newline at end of file
@@ -1,21 +1,29 @@
 extern crate rand;
 extern crate zip;
+use reader::builtins::link_natives;
 use reader::class_reader::*;
 use reader::jvm::construction::*;
 use reader::jvm::interpreter::*;
+use reader::jvm::jit::back_edge;
+pub use reader::types::capture::*;
 pub use reader::types::class::*;
+pub use reader::types::clock::*;
+pub use reader::types::files::*;
 pub use reader::types::frame::*;
+pub use reader::types::instruction::*;
+pub use reader::types::method::*;
 pub use reader::types::objects::*;
+pub use reader::types::resolved::*;
 pub use reader::types::runtime::*;
+pub use reader::types::slots::*;
 pub use reader::types::variable::*;
-pub use reader::util::make_string;
+pub use reader::util::{make_string, make_string_utf16};
 use reader::util::*;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io;
 use std::io::BufReader;
 use std::io::Read;
-use std::io::Cursor;
 use std::rc::Rc;
 use std::path::PathBuf;
 
@@ -42,8 +50,16 @@ pub enum RunnerError {
     UnknownOpCode(u8),
     ClassNotLoaded(String),
     Exception(Variable),
+    ExitCode(i32), // System.exit or Runtime.halt, after any shutdown hooks have run
+}
+
+// What the dispatch loop does after an instruction
+#[derive(Debug, PartialEq)]
+pub enum Control {
+    Next,
+    Jump(usize),
+    Invoke,
     Return,
-    Invoke
 }
 
 impl From<io::Error> for RunnerError {
@@ -60,98 +76,104 @@ impl From<ClassReadError> for RunnerError {
 
 pub fn do_run_method(runtime: &mut Runtime) -> Result<(), RunnerError> {
     let start_frames = runtime.previous_frames.len();
+    let mut pc = runtime.current_frame.pc;
 
     loop {
         let code = runtime.current_frame.code.clone();
-        let mut buf = Cursor::new(&code.code);
-        let name_string = runtime.current_frame.name.clone();
-
-        buf.set_position(runtime.current_frame.return_pos);
 
         loop {
-            let current_position = buf.position();
-            let result = step(runtime, name_string.as_str(), &mut buf);
-            if result.is_err() {
-                let mut caught = false;
-                let err = result.err().unwrap();
-                match &err {
-                    &RunnerError::Exception(ref exception) => {
-                        runnerPrint!(runtime, true, 3, "Exception {}", exception);
-                        loop {
-                            for e in &runtime.current_frame.code.exceptions.clone() {
-                                if current_position >= e.start_pc as u64 && current_position <= e.end_pc as u64 {
-                                    if e.catch_type > 0 {
-                                        let class_name = try!(runtime.current_frame.constant_pool.get_class_name(e.catch_type));
-                                        if exception.to_ref().unwrap().type_ref().name != *class_name {
-                                            continue;
-                                        }
-                                    }
-
-                                    runnerPrint!(runtime, true, 3, "Caught exception and branching to {}", e.handler_pc);
-
-                                    caught = true;
-                                    runtime.push_on_stack(exception.clone());
-                                    runtime.current_frame.return_pos = e.handler_pc as u64;
-                                    break;
-                                }
-                            }
-
-                            if caught == true || runtime.previous_frames.len() == 0 {
-                                break;
-                            }
-
-                            runtime.current_frame = runtime.previous_frames.pop().unwrap();
-                        }
-                    },
-                    &RunnerError::Invoke => {
-                        let len = runtime.previous_frames.len();
-                        runtime.previous_frames[len - 1].return_pos = buf.position();
-                        break;
-                    },
-                    &RunnerError::Return => {
-                        if runtime.previous_frames.len() < start_frames {
-                            return Ok(());
-                        }
-                        break;
+            let instruction = try!(code.instructions.get(pc).ok_or(RunnerError::InvalidPc));
+            let result = match step(runtime, instruction, pc) {
+                Ok(Control::Jump(target)) if target <= pc => back_edge(runtime, target),
+                result => result
+            };
+            match result {
+                Ok(Control::Next) => pc = pc + 1,
+                Ok(Control::Jump(target)) => pc = target,
+                Ok(Control::Invoke) => {
+                    let len = runtime.previous_frames.len();
+                    runtime.previous_frames[len - 1].pc = pc + 1;
+                    pc = 0;
+                    break;
+                }
+                Ok(Control::Return) => {
+                    if runtime.previous_frames.len() < start_frames {
+                        return Ok(());
                     }
-                    _ => {}
+                    pc = runtime.current_frame.pc;
+                    break;
                 }
-
-                if caught == false {
-                    runnerPrint!(runtime, true, 3, "Uncaught");
-                    return Err(err);
-                } else {
+                Err(RunnerError::Exception(exception)) => {
+                    runnerPrint!(runtime, true, 3, "Exception {}", exception);
+                    pc = try!(unwind(runtime, exception, pc, start_frames));
                     break;
                 }
+                Err(err) => return Err(err)
             }
         }
     }
 }
 
-fn do_find_class<T : Read>(debug: bool, name: &str, reader: T) -> Option<ClassResult> {
-    let mut buf_reader = BufReader::new(reader);
+// Finds the handler for an exception, popping frames until one catches it or the frames this run started with are exhausted
+fn unwind(runtime: &mut Runtime, exception: Variable, mut pc: usize, start_frames: usize) -> Result<usize, RunnerError> {
+    let exception_class = exception.to_ref().unwrap().type_ref();
+    loop {
+        let code = runtime.current_frame.code.clone();
+        for handler in code.handlers.iter() {
+            if pc < handler.start || pc >= handler.end {
+                continue;
+            }
+            if handler.catch_type > 0 {
+                let class_name = try!(runtime.current_frame.constant_pool.get_class_name(handler.catch_type));
+                if Class::find_superclass(exception_class.clone(), class_name).is_none() {
+                    continue;
+                }
+            }
+
+            runnerPrint!(runtime, true, 3, "Caught exception and branching to {}", handler.handler);
+            runtime.current_frame.operand_stack.clear();
+            runtime.push_on_stack(exception);
+            return Ok(handler.handler);
+        }
 
-    let maybe_class_result = read_stage_1(&mut buf_reader);
-    if maybe_class_result.is_err() {
-        debugPrint!(debug, 3, "Couldn't read headers of class file {:?}", maybe_class_result.unwrap_err());
-        return None;
+        if runtime.previous_frames.len() == 0 {
+            break;
+        }
+        runtime.current_frame = runtime.previous_frames.pop().unwrap();
+        if runtime.previous_frames.len() < start_frames {
+            break;
+        }
+        pc = runtime.current_frame.pc - 1;
     }
 
-    let mut class_result = maybe_class_result.unwrap();
+    runnerPrint!(runtime, true, 3, "Uncaught");
+    return Err(RunnerError::Exception(exception));
+}
+
+// None when the file holds some other class, which isn't an error as the search carries on
+fn do_find_class<T : Read>(debug: bool, name: &str, reader: T) -> Result<Option<ClassResult>, ClassReadError> {
+    let mut buf_reader = OffsetReader::new(BufReader::new(reader));
+
+    let mut class_result = try!(read_stage_1(&mut buf_reader));
 
     if class_result.name().map(|x| *x != name).unwrap_or(true) {
         debugPrint!(debug, 3, "Name mismatch {:?}, {}", class_result.name(), name);
-        return None;
+        return Ok(None);
     }
 
-    let maybe_read = read_stage_2(&mut buf_reader, &mut class_result);
+    try!(read_stage_2(&mut buf_reader, &mut class_result));
 
-    if maybe_read.is_err() {
-        debugPrint!(debug, 3, "Failed to read rest of file {:?}", maybe_read.unwrap_err());
-        return None;
-    }
+    return Ok(Some(class_result));
+}
 
-    return Some(class_result);
+// Malformed classes throw ClassFormatError once the class is found, anything else unreadable is skipped
+fn class_read_error(runtime: &mut Runtime, name: &str, err: ClassReadError) -> Result<(), RunnerError> {
+    runnerPrint!(runtime, true, 3, "Couldn't read class file for {} {:?}", name, err);
+    if let ClassReadError::ClassFormatError(offset, message) = err {
+        let exception = try!(construct_exception(runtime, "java/lang/ClassFormatError", format!("{} (at byte {} of {})", message, offset, name).as_str()));
+        return Err(RunnerError::Exception(exception));
+    }
+    return Ok(());
 }
 
 fn find_class(runtime: &mut Runtime, base_name: &str) -> Result<ClassResult, RunnerError> {
@@ -160,7 +182,7 @@ fn find_class(runtime: &mut Runtime, base_name: &str) -> Result<ClassResult, Run
     name = name.replace('.', "/");
     runnerPrint!(runtime, debug, 3, "Finding class {}", name);
 
-    for class_path in runtime.class_paths.iter() {
+    for class_path in runtime.class_paths.clone().iter() {
         let mut direct_path = PathBuf::from(class_path);
 
         for sub in name.split('/') {
@@ -174,13 +196,14 @@ fn find_class(runtime: &mut Runtime, base_name: &str) -> Result<ClassResult, Run
             continue;
         }
 
-        let maybe_class = do_find_class(debug, name.as_str(), maybe_file.unwrap());
-
-        if maybe_class.is_some() {
-            return Ok(maybe_class.unwrap());
+        match do_find_class(debug, name.as_str(), maybe_file.unwrap()) {
+            Ok(Some(class)) => return Ok(class),
+            Ok(None) => {}
+            Err(err) => try!(class_read_error(runtime, name.as_str(), err)),
         }
     }
 
+    let mut found = None;
     for jar in runtime.jars.iter_mut() {
         let maybe_zip_file = jar.by_name((name.clone() + ".class").as_str());
         if maybe_zip_file.is_err() {
@@ -188,12 +211,16 @@ fn find_class(runtime: &mut Runtime, base_name: &str) -> Result<ClassResult, Run
             continue;
         }
 
-        let maybe_class = do_find_class(debug, name.as_str(), maybe_zip_file.unwrap());
-
-        if maybe_class.is_some() {
-            return Ok(maybe_class.unwrap());
+        found = Some(do_find_class(debug, name.as_str(), maybe_zip_file.unwrap()));
+        if let Some(Ok(Some(_))) = found {
+            break;
         }
     }
+    match found {
+        Some(Ok(Some(class))) => return Ok(class),
+        Some(Err(err)) => try!(class_read_error(runtime, name.as_str(), err)),
+        _ => {}
+    }
 
     return Err(RunnerError::ClassNotLoaded(String::from(name)));
 }
@@ -214,10 +241,36 @@ pub fn load_class(runtime: &mut Runtime, name: &str) -> Result<Rc<Class>, Runner
     return Ok(class_obj);
 }
 
+// Loads a class from bytes made at run time, such as the proxies built by java.lang.reflect.Proxy
+pub fn define_class(runtime: &mut Runtime, name: &str, data: &[u8]) -> Result<Rc<Class>, RunnerError> {
+    let mut buf_reader = OffsetReader::new(data);
+    let read = read_stage_1(&mut buf_reader)
+        .and_then(|mut class_result| read_stage_2(&mut buf_reader, &mut class_result).map(|_| class_result));
+    let class_result = match read {
+        Ok(class_result) => class_result,
+        Err(err) => {
+            try!(class_read_error(runtime, name, err));
+            let exception = try!(construct_exception(runtime, "java/lang/ClassFormatError", name));
+            return Err(RunnerError::Exception(exception));
+        }
+    };
+    if class_result.name().map(|x| *x != name).unwrap_or(true) {
+        let exception = try!(construct_exception(runtime, "java/lang/NoClassDefFoundError", format!("{} (wrong name)", name).as_str()));
+        return Err(RunnerError::Exception(exception));
+    }
+    if runtime.classes.contains_key(name) {
+        let exception = try!(construct_exception(runtime, "java/lang/LinkageError", format!("duplicate class definition for name: {}", name).as_str()));
+        return Err(RunnerError::Exception(exception));
+    }
+    runnerPrint!(runtime, true, 2, "Defining class {} from {} bytes", name, data.len());
+    return bootstrap_class_and_dependencies(runtime, name, &class_result);
+}
+
 fn bootstrap_class_and_dependencies(runtime: &mut Runtime, name: &str, class_result: &ClassResult) -> Result<Rc<Class>, RunnerError>  {
     let debug = true;
 
-    let core_class = Rc::new(Class::new(&String::from(name), class_result));
+    let core_class = Rc::new(try!(Class::new(&String::from(name), class_result)));
+    link_natives(runtime, &core_class);
     let mut class_chain : Vec<Rc<Class>> = Vec::new();
     let mut member_count : usize = 0;
 
@@ -250,7 +303,8 @@ fn bootstrap_class_and_dependencies(runtime: &mut Runtime, name: &str, class_res
 
         runnerPrint!(runtime, debug, 2, "Finding super class {} not already loaded", super_class_name);
         let class_result = try!(find_class(runtime, super_class_name.as_str()));
-        let new_class = Rc::new(Class::new(&super_class_name, &class_result));
+        let new_class = Rc::new(try!(Class::new(&super_class_name, &class_result)));
+        link_natives(runtime, &new_class);
         runtime.classes.insert(super_class_name, new_class.clone());
         *class.super_class.borrow_mut() = Some(new_class.clone());
 
@@ -312,7 +366,7 @@ pub fn parse_single_type_descriptor(runtime: &mut Runtime, descriptor: &str, res
     let mut class : Option<Rc<Class>> = None;
     match maybe_type_specifier.unwrap() {
         'B' => variable = Variable::Byte(0),
-        'C' => variable = Variable::Char('\0'),
+        'C' => variable = Variable::Char(0),
         'D' => variable = Variable::Double(0.0),
         'F' => variable = Variable::Float(0.0),
         'I' => variable = Variable::Int(0),
@@ -380,9 +434,6 @@ pub fn parse_function_type_descriptor(runtime: &mut Runtime, descriptor: &str) -
         }
         runnerPrint!(runtime, debug, 3, "Found parameter {}", type_string);
         let param = try!(parse_single_type_descriptor(runtime, type_string.as_str(), true));
-        if !param.is_type_1() {
-            parameters.push(param.clone());
-        }
         parameters.push(param);
         runnerPrint!(runtime, debug, 3, "Parameters now {:?}", parameters);
     }
@@ -398,10 +449,12 @@ pub fn parse_function_type_descriptor(runtime: &mut Runtime, descriptor: &str) -
 
 fn execute_method(runtime: &mut Runtime, class: &str, method: &str, descriptor: &str, _args: &Vec<Variable>, ret: bool) -> Result<Variable, RunnerError> {
     //runtime.add_arguments(arguments);
-    let _ignore = runtime.invoke(Rc::new(String::from(class)), Rc::new(String::from(method)), Rc::new(String::from(descriptor)), false, false);
-    try!(do_run_method(runtime));
+    let control = try!(runtime.invoke(Rc::new(String::from(class)), Rc::new(String::from(method)), Rc::new(String::from(descriptor)), false, false));
+    if control == Control::Invoke {
+        try!(do_run_method(runtime));
+    }
     if ret {
-        return Ok(runtime.pop_from_stack().unwrap().clone());
+        return Ok(runtime.current_frame.operand_stack.pop_typed(descriptor.chars().last().unwrap_or('V')));
     } else {
         return Ok(Variable::Int(0));
     }
@@ -410,12 +463,16 @@ fn execute_method(runtime: &mut Runtime, class: &str, method: &str, descriptor:
 pub fn run(class_paths: &Vec<String>, jars: Vec<zip::ZipArchive<File>>, class: &ClassResult) -> Result<(), RunnerError> {
     println!("Running");
     let mut runtime = Runtime::new(class_paths.clone(), jars);
-    runtime.current_frame.constant_pool = class.constant_pool.clone();
-
-    try!(bootstrap_class_and_dependencies(&mut runtime, String::new().as_str(), class));
 
+    let main_class = try!(bootstrap_class_and_dependencies(&mut runtime, String::new().as_str(), class));
     let main_code = try!(class.get_code(&"main", &"([Ljava/lang/String;)V"));
-    runtime.current_frame.code = main_code;
+    runtime.current_frame.constant_pool = main_class.constant_pool.clone();
+    runtime.current_frame.method = main_class.get_method(&"main", &"([Ljava/lang/String;)V");
+    runtime.current_frame.class = Some(main_class);
+    let decoded = try!(decode(&main_code));
+    runtime.current_frame.local_variables = Slots::new(decoded.max_locals as usize);
+    runtime.current_frame.operand_stack = OperandStack::new(decoded.max_stack as usize);
+    runtime.current_frame.code = Rc::new(decoded);
 
     try!(do_run_method(&mut runtime));
 
@@ -426,36 +483,63 @@ pub fn get_runtime(class_paths: &Vec<String>, jars: Vec<zip::ZipArchive<File>>,
     let mut runtime = Runtime::new(class_paths.clone(), jars);
 
     if initialise {
-        let execution_result = execute_method(&mut runtime, "java/lang/System", "initializeSystemClass", "()V", &Vec::new(), false);
-        execution_result.expect("Failed to initialize system");
+        initialise_system(&mut runtime).expect("Failed to initialize system");
     }
 
     return runtime;
 }
 
+// Runs a class's public static void main(String[]) the way the java launcher would
+pub fn run_main(runtime: &mut Runtime, class_name: &str, arguments: &[String]) -> Result<(), RunnerError> {
+    runtime.reset_frames();
+    let class = try!(load_class(runtime, class_name));
+    let mut strings = Vec::new();
+    for argument in arguments {
+        strings.push(try!(make_string(runtime, argument.as_str())));
+    }
+    let args = try!(construct_array_by_name(runtime, "java/lang/String", Some(strings)));
+    return invoke_nested(runtime, class, vec!(args), "main", "([Ljava/lang/String;)V", false);
+}
+
+// Runs the shutdown hooks, as the JVM does once main returns without System.exit being called
+pub fn shutdown_system(runtime: &mut Runtime) -> Result<(), RunnerError> {
+    runtime.reset_frames();
+    try!(execute_method(runtime, "java/lang/Shutdown", "shutdown", "()V", &Vec::new(), false));
+    return Ok(());
+}
+
+// Sets up System's properties and streams, which reads whatever properties the runtime has been given
+pub fn initialise_system(runtime: &mut Runtime) -> Result<(), RunnerError> {
+    try!(execute_method(runtime, "java/lang/System", "initializeSystemClass", "()V", &Vec::new(), false));
+    return Ok(());
+}
+
 pub fn run_method(runtime: &mut Runtime, class_result: &ClassResult, method: &str, arguments: &Vec<Variable>, return_descriptor: String) -> Result<Variable, RunnerError> {
     println!("Running method {} with {} arguments", method, arguments.len());
 
     runtime.reset_frames();
-    runtime.current_frame.constant_pool = class_result.constant_pool.clone();
 
     let name = try!(class_result.name());
     let class = try!(bootstrap_class_and_dependencies(runtime, name.as_str(), class_result));
 
-    runtime.current_frame.class = Some(class);
-    runtime.add_arguments(arguments);
+    runtime.current_frame.constant_pool = class.constant_pool.clone();
 
     let method_descriptor = generate_method_descriptor(&arguments, return_descriptor.clone(), true);
     runnerPrint!(runtime, true, 1, "Finding method {} with descriptor {}", method, method_descriptor);
     let code = try!(class_result.get_code(method, method_descriptor.as_str()));
+    runtime.current_frame.method = class.get_method(method, method_descriptor.as_str());
+    runtime.current_frame.class = Some(class);
 
     println!("Running method");
-    runtime.current_frame.code = code;
+    let decoded = try!(decode(&code));
+    runtime.current_frame.local_variables = Slots::from_arguments(arguments, decoded.max_locals as usize);
+    runtime.current_frame.operand_stack = OperandStack::new(decoded.max_stack as usize);
+    runtime.current_frame.code = Rc::new(decoded);
     try!(do_run_method(runtime));
 
     if return_descriptor == "V" {
         return Ok(Variable::Int(0));
     } else {
-        return Ok(runtime.pop_from_stack().unwrap().clone());
+        return Ok(runtime.current_frame.operand_stack.pop_typed(return_descriptor.chars().next().unwrap_or('L')));
     }
 }
@@ -0,0 +1,31 @@
+use std::cell::RefCell;
+use std::io;
+use std::io::Write;
+use std::rc::Rc;
+
+// An in-memory sink for a standard stream, cloned so the runtime keeps a handle to read it back
+#[derive(Clone)]
+pub struct Capture {
+    bytes: Rc<RefCell<Vec<u8>>>,
+}
+
+impl Capture {
+    pub fn new() -> Capture {
+        return Capture {bytes: Rc::new(RefCell::new(Vec::new()))};
+    }
+
+    pub fn contents(&self) -> String {
+        return String::from_utf8_lossy(&self.bytes.borrow()).into_owned();
+    }
+}
+
+impl Write for Capture {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.bytes.borrow_mut().extend_from_slice(data);
+        return Ok(data.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return Ok(());
+    }
+}
@@ -1,6 +1,8 @@
 use reader::class_reader::*;
 use reader::jvm::interpreter::invoke_nested;
 use reader::runner::RunnerError;
+use reader::types::method::*;
+use reader::types::resolved::ResolutionCache;
 use reader::types::variable::*;
 use reader::types::runtime::Runtime;
 use std::collections::HashMap;
@@ -11,25 +13,70 @@ use std::rc::Rc;
 pub struct Class {
     pub name: String,
     pub cr: ClassResult,
+    pub constant_pool: Rc<ConstantPool>,
     pub initialising: RefCell<bool>,
     pub initialised: RefCell<bool>,
     pub statics: RefCell<HashMap<String, Variable>>,
     pub super_class: RefCell<Option<Rc<Class>>>,
     member_offset: RefCell<HashMap<Rc<String>, usize>>,
     pub total_size: RefCell<usize>,
+    pub methods: Vec<Rc<Method>>,
+    method_lookup: HashMap<String, Vec<usize>>,
+    pub resolved: ResolutionCache,
 }
 impl Class {
-    pub fn new(name: &String, cr: &ClassResult) -> Class {
-        return Class {
+    pub fn new(name: &String, cr: &ClassResult) -> Result<Class, RunnerError> {
+        let mut methods = Vec::new();
+        let mut method_lookup : HashMap<String, Vec<usize>> = HashMap::new();
+        for method_item in &cr.methods {
+            let method = try!(Method::new(&cr.constant_pool, method_item));
+            method_lookup.entry((*method.name).clone()).or_insert(Vec::new()).push(methods.len());
+            methods.push(Rc::new(method));
+        }
+
+        return Ok(Class {
             name: name.clone(),
             initialising: RefCell::new(false),
             initialised: RefCell::new(false),
             cr: cr.clone(),
+            constant_pool: Rc::new(cr.constant_pool.clone()),
             statics: RefCell::new(HashMap::new()),
             super_class: RefCell::new(None),
             member_offset: RefCell::new(HashMap::new()),
-            total_size: RefCell::new(0)
-        };
+            total_size: RefCell::new(0),
+            methods: methods,
+            method_lookup: method_lookup,
+            resolved: ResolutionCache::new(cr.constant_pool.pool.keys().max().map(|x| *x as usize + 1).unwrap_or(0)),
+        });
+    }
+
+    pub fn get_method(&self, name: &str, descriptor: &str) -> Option<Rc<Method>> {
+        let maybe_indices = self.method_lookup.get(name);
+        if maybe_indices.is_none() {
+            return None;
+        }
+        for index in maybe_indices.unwrap() {
+            if *self.methods[*index].descriptor == descriptor {
+                return Some(self.methods[*index].clone());
+            }
+        }
+        return None;
+    }
+
+    pub fn find_method(class: &Rc<Class>, name: &str, descriptor: &str) -> Option<(Rc<Class>, Rc<Method>)> {
+        let mut class = class.clone();
+        loop {
+            let maybe_method = class.get_method(name, descriptor);
+            if maybe_method.is_some() {
+                return Some((class, maybe_method.unwrap()));
+            }
+
+            let maybe_super_class = class.super_class.borrow().clone();
+            if maybe_super_class.is_none() {
+                return None;
+            }
+            class = maybe_super_class.unwrap();
+        }
     }
 
     pub fn initialise(runtime: &mut Runtime, class: &Rc<Class>) -> Result<(), RunnerError> {
@@ -0,0 +1,98 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// Where System.currentTimeMillis, System.nanoTime, Thread.sleep and sun.misc.Perf get their time from
+pub trait Clock {
+    // Milliseconds since the Unix epoch
+    fn current_millis(&self) -> i64;
+    // Nanoseconds from an arbitrary fixed point, which never goes backwards
+    fn nano_time(&self) -> i64;
+    fn sleep(&self, millis: i64);
+}
+
+pub struct HostClock {
+    start: Instant,
+}
+
+impl HostClock {
+    pub fn new() -> HostClock {
+        return HostClock {start: Instant::now()};
+    }
+}
+
+impl Clock for HostClock {
+    fn current_millis(&self) -> i64 {
+        return match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_secs() as i64 * 1000 + since.subsec_nanos() as i64 / 1_000_000,
+            Err(before) => -(before.duration().as_secs() as i64 * 1000 + before.duration().subsec_nanos() as i64 / 1_000_000),
+        };
+    }
+
+    fn nano_time(&self) -> i64 {
+        let elapsed = self.start.elapsed();
+        return elapsed.as_secs() as i64 * 1_000_000_000 + elapsed.subsec_nanos() as i64;
+    }
+
+    fn sleep(&self, millis: i64) {
+        thread::sleep(Duration::from_millis(millis as u64));
+    }
+}
+
+// Time that only moves when told to, by advance, by sleeping or by a fixed step on every reading.
+// Cloned so a test keeps a handle to the clock it gave the runtime
+#[derive(Clone)]
+pub struct VirtualClock {
+    epoch_millis: i64,
+    nanos: Rc<Cell<i64>>,
+    step_nanos: Rc<Cell<i64>>,
+}
+
+impl VirtualClock {
+    // Starts with currentTimeMillis at epoch_millis and nanoTime at 0
+    pub fn new(epoch_millis: i64) -> VirtualClock {
+        return VirtualClock {
+            epoch_millis: epoch_millis,
+            nanos: Rc::new(Cell::new(0)),
+            step_nanos: Rc::new(Cell::new(0)),
+        };
+    }
+
+    pub fn advance_nanos(&self, nanos: i64) {
+        self.nanos.set(self.nanos.get() + nanos);
+    }
+
+    pub fn advance_millis(&self, millis: i64) {
+        self.advance_nanos(millis * 1_000_000);
+    }
+
+    // How far each reading of the clock moves it on, so loops waiting for time to pass finish
+    pub fn set_step_nanos(&self, nanos: i64) {
+        self.step_nanos.set(nanos);
+    }
+
+    pub fn elapsed_nanos(&self) -> i64 {
+        return self.nanos.get();
+    }
+
+    fn read(&self) -> i64 {
+        let nanos = self.nanos.get();
+        self.advance_nanos(self.step_nanos.get());
+        return nanos;
+    }
+}
+
+impl Clock for VirtualClock {
+    fn current_millis(&self) -> i64 {
+        return self.epoch_millis + self.read() / 1_000_000;
+    }
+
+    fn nano_time(&self) -> i64 {
+        return self.read();
+    }
+
+    fn sleep(&self, millis: i64) {
+        self.advance_millis(millis);
+    }
+}
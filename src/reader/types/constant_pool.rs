@@ -19,7 +19,7 @@ impl ConstantPool {
             return Err(ClassReadError::Parse);
         } else {
             match *maybe_cp_entry.unwrap() {
-                ConstantPoolItem::CONSTANT_Utf8(ref s) => {
+                ConstantPoolItem::CONSTANT_Utf8(ref s, _) => {
                     return Ok(s.clone());
                 }
                 _ => {
@@ -30,6 +30,19 @@ impl ConstantPool {
         }
     }
 
+    // Exact UTF-16 units of a string constant, for building java.lang.String values
+    pub fn get_utf16(&self, index: u16) -> Result<Rc<Vec<u16>>, ClassReadError> {
+        match self.pool.get(&index) {
+            Some(&ConstantPoolItem::CONSTANT_Utf8(_, ref units)) => {
+                return Ok(units.clone());
+            }
+            maybe_cp_entry => {
+                debugPrint!(true, 2, "Constant pool item at index {} is not UTF8, actually {:?}", index, maybe_cp_entry);
+                return Err(ClassReadError::Parse);
+            }
+        }
+    }
+
     pub fn get_class_name(&self, index:u16) -> Result<Rc<String>, ClassReadError> {
         let maybe_cp_entry = self.pool.get(&index);
         if maybe_cp_entry.is_none() {
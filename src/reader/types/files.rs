@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Component, Path, PathBuf};
+
+// Host files opened by java.io streams, keyed by the number stored in their FileDescriptor
+pub struct Files {
+    pub root: Option<PathBuf>,
+    open: HashMap<i32, File>,
+    next_fd: i32,
+}
+
+impl Files {
+    pub fn new() -> Files {
+        return Files {
+            root: None,
+            open: HashMap::new(),
+            next_fd: 3, // 0 to 2 are the standard streams
+        };
+    }
+
+    // Where a Java path lives on the host. Under a root, absolute paths start at the root, and
+    // paths climbing out of it with .. or through symlinks are refused
+    pub fn resolve(&self, path: &str) -> Option<PathBuf> {
+        if self.root.is_none() {
+            return Some(PathBuf::from(path));
+        }
+        let mut relative = PathBuf::new();
+        for component in Path::new(path).components() {
+            match component {
+                Component::Normal(name) => relative.push(name),
+                Component::ParentDir => {
+                    if !relative.pop() {
+                        return None;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let host_path = self.root.as_ref().unwrap().join(relative);
+        if !self.inside_root(&host_path) {
+            return None;
+        }
+        return Some(host_path);
+    }
+
+    // Follows symlinks in the deepest part of the path that exists, as the rest can't hold any yet.
+    // A symlink that leads nowhere is refused, since creating the file would follow it
+    fn inside_root(&self, path: &Path) -> bool {
+        let root = match self.root.as_ref().and_then(|x| x.canonicalize().ok()) {
+            Some(root) => root,
+            None => return false,
+        };
+        let mut existing = path;
+        while existing.symlink_metadata().is_err() {
+            existing = match existing.parent() {
+                Some(parent) => parent,
+                None => return false,
+            };
+        }
+        return match existing.canonicalize() {
+            Ok(real) => real.starts_with(&root),
+            Err(_) => false,
+        };
+    }
+
+    // How Java code sees a host path, None when it is outside the root
+    pub fn java_path(&self, host_path: &Path) -> Option<String> {
+        let root = match self.root {
+            Some(ref root) => root,
+            None => return Some(host_path.to_string_lossy().into_owned()),
+        };
+        return match (root.canonicalize(), host_path.canonicalize()) {
+            (Ok(root), Ok(real)) => real.strip_prefix(&root).ok().map(|x| Path::new("/").join(x).to_string_lossy().into_owned()),
+            _ => None,
+        };
+    }
+
+    pub fn open(&mut self, file: File) -> i32 {
+        let fd = self.next_fd;
+        self.next_fd += 1;
+        self.open.insert(fd, file);
+        return fd;
+    }
+
+    pub fn get(&mut self, fd: i32) -> Option<&mut File> {
+        return self.open.get_mut(&fd);
+    }
+
+    pub fn close(&mut self, fd: i32) -> bool {
+        return self.open.remove(&fd).is_some();
+    }
+}
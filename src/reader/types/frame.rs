@@ -5,22 +5,40 @@ use std::rc::Rc;
 #[derive(Clone, Debug)]
 pub struct Frame {
     pub class: Option<Rc<Class>>,
-    pub constant_pool: ConstantPool,
-    pub local_variables: Vec<Variable>,
-    pub operand_stack: Vec<Variable>,
-    pub return_pos: u64,
-    pub code: Code,
-    pub name: String
+    pub method: Option<Rc<Method>>,
+    pub constant_pool: Rc<ConstantPool>,
+    pub local_variables: Slots,
+    pub operand_stack: OperandStack,
+    pub pc: usize,
+    pub code: Rc<DecodedCode>,
 }
 impl Frame {
     pub fn new() -> Frame {
         Frame {
             class: None,
-            constant_pool: ConstantPool::new(),
-            operand_stack: Vec::new(),
-            local_variables: Vec::new(),
-            return_pos: 0,
-            code: Code::new(),
-            name: String::new()}
+            method: None,
+            constant_pool: Rc::new(ConstantPool::new()),
+            operand_stack: OperandStack::new(0),
+            local_variables: Slots::new(0),
+            pc: 0,
+            code: Rc::new(DecodedCode::new())}
+    }
+
+    // Frames share their class's constant pool and method's code, nothing is copied on invocation
+    pub fn for_method(class: &Rc<Class>, method: &Rc<Method>, code: &Rc<DecodedCode>, local_variables: Slots) -> Frame {
+        Frame {
+            class: Some(class.clone()),
+            method: Some(method.clone()),
+            constant_pool: class.constant_pool.clone(),
+            operand_stack: OperandStack::new(code.max_stack as usize),
+            local_variables: local_variables,
+            pc: 0,
+            code: code.clone()}
+    }
+
+    pub fn name(&self) -> String {
+        let class_name = self.class.as_ref().map(|x| x.name.as_str()).unwrap_or("");
+        let method_name = self.method.as_ref().map(|x| x.name.as_str()).unwrap_or("");
+        return format!("{}/{}", class_name, method_name);
     }
 }
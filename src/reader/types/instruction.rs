@@ -0,0 +1,213 @@
+extern crate byteorder;
+
+use self::byteorder::{BigEndian, ReadBytesExt};
+use reader::class_reader::*;
+use std::io::Cursor;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Switch {
+    Table { default: usize, low: i32, targets: Vec<usize> },
+    Lookup { default: usize, pairs: Vec<(i32, usize)> }, // Sorted by key
+}
+
+impl Switch {
+    pub fn target(&self, value: i32) -> usize {
+        return match self {
+            &Switch::Table { default, low, ref targets } => {
+                let offset = value as i64 - low as i64;
+                if offset < 0 || offset >= targets.len() as i64 { default } else { targets[offset as usize] }
+            }
+            &Switch::Lookup { default, ref pairs } => {
+                pairs.binary_search_by_key(&value, |x| x.0).map(|x| pairs[x].1).unwrap_or(default)
+            }
+        };
+    }
+}
+
+// Operands are decoded once, branch targets are instruction indices rather than byte offsets
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operand {
+    None,
+    Int(i32),
+    Local(usize),
+    Index(u16),
+    Iinc(usize, i32),
+    Branch(usize),
+    Switch(Rc<Switch>),
+    MultiNewArray(u16, u8),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instruction {
+    pub op: u8,
+    pub operand: Operand,
+}
+
+impl Instruction {
+    pub fn int(&self) -> i32 {
+        return match self.operand { Operand::Int(x) => x, _ => 0 };
+    }
+
+    pub fn local(&self) -> usize {
+        return match self.operand { Operand::Local(x) => x, _ => 0 };
+    }
+
+    pub fn index(&self) -> u16 {
+        return match self.operand { Operand::Index(x) | Operand::MultiNewArray(x, _) => x, _ => 0 };
+    }
+
+    pub fn target(&self) -> usize {
+        return match self.operand { Operand::Branch(x) => x, _ => 0 };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Handler {
+    pub start: usize,
+    pub end: usize,
+    pub handler: usize,
+    pub catch_type: u16,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedCode {
+    pub max_stack: u16,
+    pub max_locals: u16,
+    pub instructions: Vec<Instruction>,
+    pub offsets: Vec<u32>, // Bytecode offset of each instruction
+    pub handlers: Vec<Handler>,
+}
+
+impl DecodedCode {
+    pub fn new() -> DecodedCode {
+        DecodedCode { max_stack: 0, max_locals: 0, instructions: Vec::new(), offsets: Vec::new(), handlers: Vec::new() }
+    }
+}
+
+fn read_operand(buf: &mut Cursor<&[u8]>, op: u8, position: i64) -> Result<Operand, ClassReadError> {
+    let operand = match op {
+        16 => Operand::Int(try!(buf.read_i8()) as i32),
+        17 => Operand::Int(try!(buf.read_i16::<BigEndian>()) as i32),
+        18 => Operand::Index(try!(buf.read_u8()) as u16),
+        19 | 20 | 178...184 | 187 | 189 | 192 | 193 => Operand::Index(try!(buf.read_u16::<BigEndian>())),
+        21...25 | 54...58 | 169 => Operand::Local(try!(buf.read_u8()) as usize),
+        132 => {
+            let index = try!(buf.read_u8()) as usize;
+            Operand::Iinc(index, try!(buf.read_i8()) as i32)
+        }
+        153...168 | 198 | 199 => Operand::Branch((position + try!(buf.read_i16::<BigEndian>()) as i64) as usize),
+        200 | 201 => Operand::Branch((position + try!(buf.read_i32::<BigEndian>()) as i64) as usize),
+        170 | 171 => {
+            let pos = buf.position();
+            buf.set_position((pos + 3) & !3);
+            let default = (position + try!(buf.read_i32::<BigEndian>()) as i64) as usize;
+            if op == 170 {
+                let low = try!(buf.read_i32::<BigEndian>());
+                let high = try!(buf.read_i32::<BigEndian>());
+                if high < low {
+                    return Err(ClassReadError::Parse2(format!("Tableswitch at {} has high {} below low {}", position, high, low)));
+                }
+                let mut targets = Vec::new();
+                for _i in (low as i64)..(high as i64 + 1) {
+                    targets.push((position + try!(buf.read_i32::<BigEndian>()) as i64) as usize);
+                }
+                Operand::Switch(Rc::new(Switch::Table { default: default, low: low, targets: targets }))
+            } else {
+                let npairs = try!(buf.read_i32::<BigEndian>());
+                let mut pairs = Vec::new();
+                for _i in 0..npairs {
+                    let key = try!(buf.read_i32::<BigEndian>());
+                    pairs.push((key, (position + try!(buf.read_i32::<BigEndian>()) as i64) as usize));
+                }
+                pairs.sort_by_key(|x| x.0);
+                Operand::Switch(Rc::new(Switch::Lookup { default: default, pairs: pairs }))
+            }
+        }
+        185 | 186 => {
+            let index = try!(buf.read_u16::<BigEndian>());
+            try!(buf.read_u16::<BigEndian>());
+            Operand::Index(index)
+        }
+        188 => Operand::Int(try!(buf.read_u8()) as i32),
+        197 => {
+            let index = try!(buf.read_u16::<BigEndian>());
+            Operand::MultiNewArray(index, try!(buf.read_u8()))
+        }
+        _ => Operand::None
+    };
+    return Ok(operand);
+}
+
+// Decodes bytecode into instructions, WIDE is folded into the instruction it modifies
+pub fn decode(code: &Code) -> Result<DecodedCode, ClassReadError> {
+    let mut buf = Cursor::new(code.code.as_slice());
+    let mut instructions = Vec::new();
+    let mut offsets = Vec::new();
+    let mut index_of_offset = vec![None; code.code.len() + 1];
+
+    while (buf.position() as usize) < code.code.len() {
+        let position = buf.position();
+        index_of_offset[position as usize] = Some(instructions.len());
+        offsets.push(position as u32);
+
+        let mut op = try!(buf.read_u8());
+        let operand;
+        if op == 196 {
+            op = try!(buf.read_u8());
+            let index = try!(buf.read_u16::<BigEndian>()) as usize;
+            operand = match op {
+                21...25 | 54...58 | 169 => Operand::Local(index),
+                132 => Operand::Iinc(index, try!(buf.read_i16::<BigEndian>()) as i32),
+                _ => return Err(ClassReadError::Parse2(format!("Invalid wide instruction {} at {}", op, position)))
+            };
+        } else {
+            operand = try!(read_operand(&mut buf, op, position as i64));
+        }
+        instructions.push(Instruction { op: op, operand: operand });
+    }
+    index_of_offset[code.code.len()] = Some(instructions.len());
+
+    let to_index = |offset: usize| -> Result<usize, ClassReadError> {
+        return index_of_offset.get(offset).and_then(|x| *x).ok_or(ClassReadError::Parse2(format!("Offset {} is not the start of an instruction", offset)));
+    };
+
+    for instruction in instructions.iter_mut() {
+        let operand = match instruction.operand {
+            Operand::Branch(target) => Operand::Branch(try!(to_index(target))),
+            Operand::Switch(ref switch) => {
+                let switch = match **switch {
+                    Switch::Table { default, low, ref targets } => {
+                        let mut new_targets = Vec::new();
+                        for target in targets {
+                            new_targets.push(try!(to_index(*target)));
+                        }
+                        Switch::Table { default: try!(to_index(default)), low: low, targets: new_targets }
+                    }
+                    Switch::Lookup { default, ref pairs } => {
+                        let mut new_pairs = Vec::new();
+                        for &(key, target) in pairs {
+                            new_pairs.push((key, try!(to_index(target))));
+                        }
+                        Switch::Lookup { default: try!(to_index(default)), pairs: new_pairs }
+                    }
+                };
+                Operand::Switch(Rc::new(switch))
+            }
+            _ => continue
+        };
+        instruction.operand = operand;
+    }
+
+    let mut handlers = Vec::new();
+    for e in code.exceptions.iter() {
+        handlers.push(Handler {
+            start: try!(to_index(e.start_pc as usize)),
+            end: try!(to_index(e.end_pc as usize)),
+            handler: try!(to_index(e.handler_pc as usize)),
+            catch_type: e.catch_type,
+        });
+    }
+
+    return Ok(DecodedCode { max_stack: code.max_stack, max_locals: code.max_locals, instructions: instructions, offsets: offsets, handlers: handlers });
+}
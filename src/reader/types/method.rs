@@ -0,0 +1,50 @@
+use reader::builtins::Native;
+use reader::class_reader::*;
+use reader::jvm::jit::MethodJit;
+use reader::types::instruction::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct Method {
+    pub name: Rc<String>,
+    pub descriptor: Rc<String>,
+    pub access_flags: u16,
+    pub code: Option<Rc<DecodedCode>>,
+    pub native: RefCell<Option<Native>>,
+    pub jit: MethodJit,
+}
+
+impl Method {
+    pub fn new(cp: &ConstantPool, method: &FieldItem) -> Result<Method, ClassReadError> {
+        let mut code = None;
+        for attribute in method.attributes.iter() {
+            if let &AttributeItem::Code(ref c) = attribute {
+                code = Some(Rc::new(try!(decode(c))));
+                break;
+            }
+        }
+        return Ok(Method {
+            name: try!(cp.get_str(method.name_index)),
+            descriptor: try!(cp.get_str(method.descriptor_index)),
+            access_flags: method.access_flags,
+            code: code,
+            native: RefCell::new(None),
+            jit: MethodJit::new(),
+        });
+    }
+
+    pub fn is_native(&self) -> bool {
+        return self.access_flags & ACC_NATIVE != 0;
+    }
+
+    pub fn is_static(&self) -> bool {
+        return self.access_flags & ACC_STATIC != 0;
+    }
+}
+
+impl PartialEq for Method {
+    fn eq(&self, other: &Self) -> bool {
+        return self.name == other.name && self.descriptor == other.descriptor && self.access_flags == other.access_flags && self.code == other.code;
+    }
+}
@@ -54,6 +54,13 @@ impl Object {
         self.members.borrow_mut()[offset] = var;
     }
 
+    // A new object of the same class holding the same member values, what clone() makes
+    pub fn shallow_copy(&self, runtime: &mut Runtime) -> Rc<Object> {
+        let obj = Object::new(runtime, &self.type_ref, self.members.borrow().len());
+        obj.members.borrow_mut().clone_from_slice(&self.members.borrow());
+        return obj;
+    }
+
     pub fn deep_compare(&self, other:&Self) -> bool {
         if self.type_ref != other.type_ref {
             return false;
@@ -140,6 +147,12 @@ pub fn put_field_specific_class(runtime: &mut Runtime, obj: &Option<Rc<Object>>,
     return Ok(());
 }
 
+pub fn put_field_at_offset(runtime: &mut Runtime, obj: &Option<Rc<Object>>, offset: usize, value: Variable) -> Result<(), RunnerError> {
+    try!(null_check(runtime, obj));
+    obj.as_ref().unwrap().put_member_at_offset(offset, value);
+    return Ok(());
+}
+
 pub fn get_field(runtime: &mut Runtime, obj: &Option<Rc<Object>>, class_name: &str, field_name: &str) -> Result<Variable, RunnerError> {
     try!(null_check(runtime, obj));
 
@@ -153,12 +166,18 @@ pub fn get_field(runtime: &mut Runtime, obj: &Option<Rc<Object>>, class_name: &s
         panic!("TODO, class doesn't contain field");
     }
 
-    let member = obj.as_ref().unwrap().get_member_at_offset(maybe_offset.unwrap()).unwrap();
+    return get_field_at_offset(runtime, obj, maybe_offset.unwrap());
+}
+
+pub fn get_field_at_offset(runtime: &mut Runtime, obj: &Option<Rc<Object>>, offset: usize) -> Result<Variable, RunnerError> {
+    try!(null_check(runtime, obj));
+
+    let member = obj.as_ref().unwrap().get_member_at_offset(offset).unwrap();
     if !member.is_unresolved() {
         return Ok(member.clone());
     }
     let var = try!(construct_null_object_by_name(runtime, member.get_unresolved_type_name().clone().as_str()));
-    obj.as_ref().unwrap().put_member_at_offset(maybe_offset.unwrap(), var.clone());
+    obj.as_ref().unwrap().put_member_at_offset(offset, var.clone());
     return Ok(var);
 }
 
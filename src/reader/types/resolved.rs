@@ -0,0 +1,174 @@
+use reader::runner::*;
+use reader::types::method::Method;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct ResolvedField {
+    pub class_name: Rc<String>,
+    pub field_name: Rc<String>,
+    pub descriptor: Rc<String>,
+    pub offset: usize,
+}
+
+#[derive(Debug)]
+pub struct ResolvedStatic {
+    pub class_name: Rc<String>,
+    pub field_name: Rc<String>,
+    pub descriptor: Rc<String>,
+    pub class: Rc<Class>, // Class actually holding the static, may be a super class of class_name
+}
+
+#[derive(Debug)]
+pub struct ResolvedMethod {
+    pub class_name: Rc<String>,
+    pub method_name: Rc<String>,
+    pub descriptor: Rc<String>,
+    pub class: Rc<Class>,
+    pub target: Option<(Rc<Class>, Rc<Method>)>, // Declaring class and method found from class, None if only a receiver can provide it
+    pub arg_types: Vec<char>,
+    pub arg_slots: usize,
+}
+
+#[derive(Clone)]
+enum Resolved {
+    Field(Rc<ResolvedField>),
+    Static(Rc<ResolvedStatic>),
+    Method(Rc<ResolvedMethod>),
+}
+
+// Resolved constant pool entries of a class, indexed by constant pool index
+#[derive(Clone)]
+pub struct ResolutionCache {
+    entries: RefCell<Vec<Option<Resolved>>>,
+}
+
+impl ResolutionCache {
+    pub fn new(size: usize) -> ResolutionCache {
+        return ResolutionCache { entries: RefCell::new(vec![None; size]) };
+    }
+
+    fn get(&self, index: u16) -> Option<Resolved> {
+        return self.entries.borrow().get(index as usize).and_then(|x| x.clone());
+    }
+
+    fn put(&self, index: u16, resolved: Resolved) {
+        let mut entries = self.entries.borrow_mut();
+        if entries.len() <= index as usize {
+            entries.resize(index as usize + 1, None);
+        }
+        entries[index as usize] = Some(resolved);
+    }
+}
+
+// Entries refer back to classes, so don't recurse into them
+impl fmt::Debug for ResolutionCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let resolved = self.entries.borrow().iter().filter(|x| x.is_some()).count();
+        write!(f, "ResolutionCache {} resolved", resolved)
+    }
+}
+
+impl PartialEq for ResolutionCache {
+    fn eq(&self, _other: &Self) -> bool {
+        return true;
+    }
+}
+
+// Leading descriptor character of each argument of a method descriptor, arrays are '['
+pub fn argument_types(descriptor: &str) -> Result<Vec<char>, RunnerError> {
+    let mut iter = descriptor.chars();
+    if iter.next() != Some('(') {
+        return Err(RunnerError::ClassInvalid2(format!("Function type {} invalid", descriptor)));
+    }
+
+    let mut types = Vec::new();
+    loop {
+        let mut type_char = try!(iter.next().ok_or(RunnerError::ClassInvalid2(format!("Failed to parse {}", descriptor))));
+        if type_char == ')' {
+            return Ok(types);
+        }
+
+        let mut is_array = false;
+        while type_char == '[' {
+            is_array = true;
+            type_char = try!(iter.next().ok_or(RunnerError::ClassInvalid2(format!("Failed to parse {}", descriptor))));
+        }
+
+        match type_char {
+            'L' => {
+                if !iter.by_ref().any(|x| x == ';') {
+                    return Err(RunnerError::ClassInvalid2(format!("Failed to parse {}", descriptor)));
+                }
+            }
+            'B' | 'C' | 'D' | 'F' | 'I' | 'J' | 'S' | 'Z' => {}
+            _ => return Err(RunnerError::ClassInvalid2(format!("Failed to parse {}", descriptor)))
+        }
+        types.push(if is_array {'['} else {type_char});
+    }
+}
+
+// Number of local variable slots taken by arguments, long and double take two
+pub fn argument_slot_count(types: &Vec<char>) -> usize {
+    return types.iter().map(|x| match *x { 'J' | 'D' => 2, _ => 1 }).sum();
+}
+
+pub fn resolve_field(runtime: &mut Runtime, index: u16) -> Result<Rc<ResolvedField>, RunnerError> {
+    let current_class = runtime.current_frame.class.clone();
+    if let Some(Resolved::Field(resolved)) = current_class.as_ref().and_then(|x| x.resolved.get(index)) {
+        return Ok(resolved);
+    }
+
+    let (class_name, field_name, descriptor) = try!(runtime.current_frame.constant_pool.get_field(index));
+    let class = try!(load_class(runtime, class_name.as_str()));
+    let offset = try!(class.find_member_offset(&field_name).ok_or(RunnerError::ClassInvalid2(format!("Couldn't find field '{}' in class '{}'", field_name, class_name))));
+    let resolved = Rc::new(ResolvedField { class_name: class_name, field_name: field_name, descriptor: descriptor, offset: offset });
+
+    current_class.map(|x| x.resolved.put(index, Resolved::Field(resolved.clone())));
+    return Ok(resolved);
+}
+
+pub fn resolve_static(runtime: &mut Runtime, index: u16) -> Result<Rc<ResolvedStatic>, RunnerError> {
+    let current_class = runtime.current_frame.class.clone();
+    if let Some(Resolved::Static(resolved)) = current_class.as_ref().and_then(|x| x.resolved.get(index)) {
+        return Ok(resolved);
+    }
+
+    let (class_name, field_name, descriptor) = try!(runtime.current_frame.constant_pool.get_field(index));
+    let mut class = try!(load_class(runtime, class_name.as_str()));
+    while !class.statics.borrow().contains_key(field_name.as_str()) {
+        let maybe_super = class.super_class.borrow().clone();
+        if maybe_super.is_none() {
+            return Err(RunnerError::ClassInvalid2(format!("Couldn't find static {} in {}", field_name, class_name)));
+        }
+        class = maybe_super.unwrap();
+    }
+    let resolved = Rc::new(ResolvedStatic { class_name: class_name, field_name: field_name, descriptor: descriptor, class: class });
+
+    current_class.map(|x| x.resolved.put(index, Resolved::Static(resolved.clone())));
+    return Ok(resolved);
+}
+
+pub fn resolve_method_by_name(runtime: &mut Runtime, class_name: Rc<String>, method_name: Rc<String>, descriptor: Rc<String>) -> Result<ResolvedMethod, RunnerError> {
+    let arg_types = try!(argument_types(descriptor.as_str()));
+    let arg_slots = argument_slot_count(&arg_types);
+    // Arrays have no class file, the methods they can be sent are Object's
+    let class = try!(load_class(runtime, if class_name.starts_with('[') {"java/lang/Object"} else {class_name.as_str()}));
+    try!(parse_function_type_descriptor(runtime, descriptor.as_str())); // Loads parameter and return classes
+    let target = Class::find_method(&class, method_name.as_str(), descriptor.as_str());
+    return Ok(ResolvedMethod { class_name: class_name, method_name: method_name, descriptor: descriptor, class: class, target: target, arg_types: arg_types, arg_slots: arg_slots });
+}
+
+pub fn resolve_method(runtime: &mut Runtime, index: u16) -> Result<Rc<ResolvedMethod>, RunnerError> {
+    let current_class = runtime.current_frame.class.clone();
+    if let Some(Resolved::Method(resolved)) = current_class.as_ref().and_then(|x| x.resolved.get(index)) {
+        return Ok(resolved);
+    }
+
+    let (class_name, method_name, descriptor) = try!(runtime.current_frame.constant_pool.get_method(index));
+    let resolved = Rc::new(try!(resolve_method_by_name(runtime, class_name, method_name, descriptor)));
+
+    current_class.map(|x| x.resolved.put(index, Resolved::Method(resolved.clone())));
+    return Ok(resolved);
+}
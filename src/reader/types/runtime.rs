@@ -1,12 +1,21 @@
 extern crate rand;
 extern crate zip;
-use reader::class_reader::*;
 use reader::runner::*;
 use reader::builtins::*;
+use reader::jvm::construction::*;
+use reader::jvm::jit;
+use reader::jvm::jit::Jit;
 use std::collections::HashMap;
+use std::env;
+use std::mem;
 use std::rc::Rc;
 use std::rc::Weak;
 use std::fs::File;
+use std::io;
+use std::io::{Cursor, Read, Write};
+use std::path::PathBuf;
+
+pub type NativeMethod = Rc<dyn Fn(&mut Runtime, &Vec<Variable>) -> Result<Option<Variable>, RunnerError>>;
 
 pub struct Runtime {
     pub previous_frames: Vec<Frame>,
@@ -16,14 +25,23 @@ pub struct Runtime {
     pub classes: HashMap<String, Rc<Class>>,
     pub count: i64,
     pub current_thread: Option<Variable>,
-    pub string_interns: HashMap<String, Variable>,
-    pub properties: HashMap<String, Variable>,
+    pub string_interns: HashMap<Vec<u16>, Variable>,
+    pub properties: HashMap<String, String>,
+    pub environment: HashMap<String, String>,
     pub class_objects: HashMap<String, Variable>,
     pub object_count: i32,
-    pub stdout: String,
-    pub stderr: String,
+    pub stdin: Box<dyn Read>,
+    pub stdout: Box<dyn Write>,
+    pub stderr: Box<dyn Write>,
+    stdout_capture: Option<Capture>,
+    stderr_capture: Option<Capture>,
+    pub files: Files,
+    pub clock: Box<dyn Clock>,
     pub free_mem: i64,
-    pub objects: Vec<Weak<Object>>
+    pub objects: Vec<Weak<Object>>,
+    pub natives: HashMap<String, HashMap<String, HashMap<String, NativeMethod>>>, // Keyed by class, method name then descriptor so &str lookups don't allocate
+    pub jit: Jit,
+    pub max_call_depth: usize, // Frames deep Java code may call before getting a StackOverflowError
 }
 impl Runtime {
     pub fn new(class_paths: Vec<String>, jars: Vec<zip::ZipArchive<File>>) -> Runtime {
@@ -37,15 +55,147 @@ impl Runtime {
             current_thread: None,
             string_interns: HashMap::new(),
             properties: HashMap::new(),
+            environment: env::vars_os().map(|(k, v)| (k.to_string_lossy().into_owned(), v.to_string_lossy().into_owned())).collect(),
             class_objects: HashMap::new(),
             object_count: rand::random::<i32>(),
-            stdout: String::new(),
-            stderr: String::new(),
+            stdin: Box::new(io::empty()),
+            stdout: Box::new(io::stdout()),
+            stderr: Box::new(io::stderr()),
+            stdout_capture: None,
+            stderr_capture: None,
+            files: Files::new(),
+            clock: Box::new(HostClock::new()),
             free_mem: 4 * 1024 * 1024,
-            objects: Vec::new()
+            objects: Vec::new(),
+            natives: HashMap::new(),
+            jit: Jit::new(),
+            max_call_depth: 4096,
         };
     }
 
+    // Natives get one value per argument, long and double included, with the receiver first for instance methods
+    pub fn register_native<F>(&mut self, class_name: &str, method_name: &str, descriptor: &str, native: F)
+        where F: Fn(&mut Runtime, &Vec<Variable>) -> Result<Option<Variable>, RunnerError> + 'static
+    {
+        self.natives.entry(String::from(class_name)).or_default()
+            .entry(String::from(method_name)).or_default()
+            .insert(String::from(descriptor), Rc::new(native));
+        self.relink_native(class_name, method_name, descriptor);
+    }
+
+    pub fn unregister_native(&mut self, class_name: &str, method_name: &str, descriptor: &str) -> bool {
+        let removed = self.natives.get_mut(class_name)
+            .and_then(|x| x.get_mut(method_name))
+            .and_then(|x| x.remove(descriptor))
+            .is_some();
+        self.relink_native(class_name, method_name, descriptor);
+        return removed;
+    }
+
+    pub fn registered_native(&self, class_name: &str, method_name: &str, descriptor: &str) -> Option<&NativeMethod> {
+        return self.natives.get(class_name).and_then(|x| x.get(method_name)).and_then(|x| x.get(descriptor));
+    }
+
+    fn relink_native(&mut self, class_name: &str, method_name: &str, descriptor: &str) {
+        let maybe_method = self.classes.get(class_name).and_then(|x| x.get_method(method_name, descriptor));
+        if maybe_method.is_some() {
+            link_native(self, class_name, &maybe_method.unwrap());
+        }
+    }
+
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.max_call_depth = depth;
+    }
+
+    // The JIT can also be turned off by setting RJVM_NO_JIT, which helps when debugging the interpreter
+    pub fn set_jit_enabled(&mut self, enabled: bool) {
+        self.jit.enabled = enabled;
+    }
+
+    // Invocations and backward branches a method takes before it is compiled
+    pub fn set_jit_thresholds(&mut self, invocations: u32, back_edges: u32) {
+        self.jit.invocation_threshold = invocations;
+        self.jit.back_edge_threshold = back_edges;
+    }
+
+    // What System.in reads, which is empty until a source is given
+    pub fn set_stdin<R: Read + 'static>(&mut self, input: R) {
+        self.stdin = Box::new(input);
+    }
+
+    pub fn set_stdin_bytes(&mut self, bytes: &[u8]) {
+        self.set_stdin(Cursor::new(bytes.to_vec()));
+    }
+
+    pub fn set_stdin_host(&mut self) {
+        self.set_stdin(io::stdin());
+    }
+
+    // Runs System.initializeSystemClass, for runtimes made without initialisation so they could be configured first
+    pub fn initialise(&mut self) -> Result<(), RunnerError> {
+        return initialise_system(self);
+    }
+
+    pub fn shutdown(&mut self) -> Result<(), RunnerError> {
+        return shutdown_system(self);
+    }
+
+    // Added to or replacing the defaults when System is initialised, so has to be set before then
+    pub fn set_property(&mut self, key: &str, value: &str) {
+        self.properties.insert(String::from(key), String::from(value));
+    }
+
+    // The environment starts as a copy of the host's and is read by the first System.getenv
+    pub fn set_env(&mut self, key: &str, value: &str) {
+        self.environment.insert(String::from(key), String::from(value));
+    }
+
+    pub fn remove_env(&mut self, key: &str) {
+        self.environment.remove(key);
+    }
+
+    // Where System.out and System.err go, the host's own streams until changed
+    pub fn set_stdout<W: Write + 'static>(&mut self, output: W) {
+        self.stdout = Box::new(output);
+        self.stdout_capture = None;
+    }
+
+    pub fn set_stderr<W: Write + 'static>(&mut self, output: W) {
+        self.stderr = Box::new(output);
+        self.stderr_capture = None;
+    }
+
+    // Keeps System.out and System.err in memory, read back with captured_stdout and captured_stderr
+    pub fn capture_output(&mut self) {
+        let (stdout, stderr) = (Capture::new(), Capture::new());
+        self.stdout = Box::new(stdout.clone());
+        self.stderr = Box::new(stderr.clone());
+        self.stdout_capture = Some(stdout);
+        self.stderr_capture = Some(stderr);
+    }
+
+    pub fn captured_stdout(&self) -> String {
+        return self.stdout_capture.as_ref().map(|x| x.contents()).unwrap_or(String::new());
+    }
+
+    pub fn captured_stderr(&self) -> String {
+        return self.stderr_capture.as_ref().map(|x| x.contents()).unwrap_or(String::new());
+    }
+
+    // Confines java.io to a directory on the host, which Java code then sees as /
+    pub fn set_file_root(&mut self, root: Option<PathBuf>) {
+        self.files.root = root;
+    }
+
+    // Where Java code reads the time from, the host's clock until changed
+    pub fn set_clock<C: Clock + 'static>(&mut self, clock: C) {
+        self.clock = Box::new(clock);
+    }
+
+    pub fn jit_compiled_count(&self) -> usize {
+        return self.jit.compiled_count();
+    }
+
     pub fn reset_frames(&mut self) {
         self.previous_frames = vec!(Frame::new());
         self.current_frame = Frame::new();
@@ -58,108 +208,85 @@ impl Runtime {
     }
 
     pub fn push_on_stack(&mut self, var: Variable) {
-        if !var.is_type_1() {
-            self.current_frame.operand_stack.push(var.clone());
-        }
-        self.current_frame.operand_stack.push(var);
-    }
-
-    pub fn pop_from_stack(&mut self) -> Option<Variable> {
-        let maybe_var = self.current_frame.operand_stack.pop();
-        maybe_var.as_ref().map(|x| {if !x.is_type_1() {self.current_frame.operand_stack.pop();}});
-        return maybe_var;
-    }
-    
-    pub fn add_arguments(&mut self, arguments: &Vec<Variable>) {
-        for arg in arguments {
-            match arg {
-                &Variable::Long(ref _x) => {
-                    self.current_frame.local_variables.push(arg.clone());
-                    self.current_frame.local_variables.push(arg.clone());
-                },
-                &Variable::Double(ref _x) => {
-                    self.current_frame.local_variables.push(arg.clone());
-                    self.current_frame.local_variables.push(arg.clone());
-                },
-                _ => {
-                    self.current_frame.local_variables.push(arg.clone());
-                }
-            }
-        }
+        self.current_frame.operand_stack.push_variable(var);
     }
 
-    pub fn invoke(&mut self, class_name: Rc<String>, method_name: Rc<String>, descriptor: Rc<String>, with_obj: bool, special: bool) -> Result<(), RunnerError> {
-        let mut code : Option<Code>;
-        let new_frame : Option<Frame>;
-        let new_method_name : Option<String>;
-        let current_op_stack_size = self.current_frame.operand_stack.len();
+    pub fn invoke(&mut self, class_name: Rc<String>, method_name: Rc<String>, descriptor: Rc<String>, with_obj: bool, special: bool) -> Result<Control, RunnerError> {
+        let resolved = try!(resolve_method_by_name(self, class_name, method_name, descriptor));
+        return self.invoke_resolved(&resolved, with_obj, special);
+    }
 
-        new_method_name = Some((*class_name).clone() + "/" + method_name.as_str());
-        let (parameters, _return_type) = try!(parse_function_type_descriptor(self, descriptor.as_str()));
+    pub fn invoke_resolved(&mut self, resolved: &ResolvedMethod, with_obj: bool, special: bool) -> Result<Control, RunnerError> {
         let extra_parameter = if with_obj {1} else {0};
-        let new_local_variables = self.current_frame.operand_stack.split_off(current_op_stack_size - parameters.len() - extra_parameter);
-
-        let mut class = try!(load_class(self, class_name.as_str()));
+        let arg_slots = resolved.arg_slots + extra_parameter;
+        let method_name = &resolved.method_name;
+        let descriptor = &resolved.descriptor;
 
+        let mut maybe_method = resolved.target.clone();
         if with_obj {
-
-            if new_local_variables[0].is_null() {
-                return Err(RunnerError::ClassInvalid2(format!("NULL obj ref on local var stack for method on {}", class_name)));
+            let receiver = self.current_frame.operand_stack.peek_ref(arg_slots - 1);
+            if receiver.is_null() {
+                return Err(RunnerError::ClassInvalid2(format!("NULL obj ref on local var stack for method on {}", resolved.class_name)));
             }
 
-            if new_local_variables[0].is_reference() {
-                let obj = new_local_variables[0].to_ref();
-
-                if !special {
-                    class = obj.as_ref().unwrap().type_ref();
-                }
-
-                // Find method
-                while {
-                    code = class.cr.get_code(method_name.as_str(), descriptor.as_str()).ok();
-                    code.is_none()
-                } {
-                    let new_class = class.super_class.borrow().clone();
-                    if new_class.is_none() {
-                        if try!(try_builtin(&class_name, &method_name, &descriptor, &new_local_variables, self)) {
-                            return Ok(());
-                        }
-
-                        return Err(RunnerError::ClassInvalid2(format!("Could not find super class of object '{}' that matched method '{}' '{}'", obj.unwrap(), method_name, descriptor)))
-                    }
-                    class = new_class.unwrap();
-                }
-            } else if new_local_variables[0].is_array_reference() {
-                let arrayobj = new_local_variables[0].to_arrayobj();
-                // TODO, other "Object" methods like clone?
-                if try!(try_builtin(&class_name, &method_name, &descriptor, &new_local_variables, self)) {
-                    return Ok(());
+            if receiver.is_reference() {
+                let class = receiver.to_ref().unwrap().type_ref();
+                if !special && !Rc::ptr_eq(&class, &resolved.class) {
+                    maybe_method = Class::find_method(&class, method_name.as_str(), descriptor.as_str());
                 }
-
-                return Err(RunnerError::ClassInvalid2(format!("Could not find super class of array '{}' that matched method '{}' '{}'", arrayobj, method_name, descriptor)))
-            } else {
-                panic!("Tried to invoke method on {}", new_local_variables[0]);
+            } else if !receiver.is_array_reference() {
+                panic!("Tried to invoke method on {}", receiver);
             }
-        } else {
-            if try!(try_builtin(&class_name, &method_name, &descriptor, &new_local_variables, self)) {
-                return Ok(());
+        }
+
+        if maybe_method.is_none() {
+            let maybe_native = resolve_native(self, resolved.class_name.as_str(), method_name.as_str(), descriptor.as_str());
+            if maybe_native.is_some() {
+                let args = self.current_frame.operand_stack.take_arguments(arg_slots, with_obj, &resolved.arg_types);
+                try!(call_native(self, maybe_native.as_ref().unwrap(), &args));
+                return Ok(Control::Next);
             }
+            return Err(RunnerError::ClassInvalid2(format!("Could not find method '{}' '{}' in class '{}' or its super classes", method_name, descriptor, resolved.class_name)));
+        }
+        let (method_class, method) = maybe_method.unwrap();
 
-            code = Some(try!(class.cr.get_code(method_name.as_str(), descriptor.as_str())));
+        let maybe_native = method.native.borrow().clone();
+        if maybe_native.is_some() {
+            runnerPrint!(self, true, 2, "NATIVE {} {} {}", method_class.name, method_name, descriptor);
+            let args = self.current_frame.operand_stack.take_arguments(arg_slots, with_obj, &resolved.arg_types);
+            try!(call_native(self, maybe_native.as_ref().unwrap(), &args));
+            return Ok(Control::Next);
+        }
+        if method.is_native() {
+            return Err(try!(unsatisfied_link_error(self, &method_class.name, method_name, descriptor)));
+        }
+
+        if method.is_static() && try!(jit::invoke_compiled(self, &method_class, &method, arg_slots)) {
+            return Ok(Control::Next);
+        }
+        if self.previous_frames.len() >= self.max_call_depth {
+            return Err(try!(stack_overflow_error(self)));
         }
 
-        new_frame = Some(Frame {
-            class: Some(class.clone()),
-            constant_pool: class.cr.constant_pool.clone(),
-            operand_stack: Vec::new(),
-            local_variables: new_local_variables,
-            name: new_method_name.unwrap(),
-            code: code.unwrap(),
-            return_pos: 0,
-        });
+        let code = try!(method.code.as_ref().ok_or(RunnerError::ClassInvalid2(format!("Method '{}' '{}' in class '{}' has no code", method_name, descriptor, method_class.name))));
+        let new_local_variables = self.current_frame.operand_stack.take_slots(arg_slots, code.max_locals as usize);
+        let new_frame = Frame::for_method(&method_class, &method, code, new_local_variables);
 
-        self.previous_frames.push(self.current_frame.clone());
-        self.current_frame = new_frame.unwrap();
-        return Err(RunnerError::Invoke);
+        let previous_frame = mem::replace(&mut self.current_frame, new_frame);
+        self.previous_frames.push(previous_frame);
+        return Ok(Control::Invoke);
     }
+}
+
+pub fn unsatisfied_link_error(runtime: &mut Runtime, class_name: &str, method_name: &str, descriptor: &str) -> Result<RunnerError, RunnerError> {
+    runnerPrint!(runtime, true, 1, "No native registered for {} {} {}", class_name, method_name, descriptor);
+    let message = format!("{}.{}{}", class_name.replace('/', "."), method_name, descriptor);
+    let exception = try!(construct_exception(runtime, "java/lang/UnsatisfiedLinkError", message.as_str()));
+    return Ok(RunnerError::Exception(exception));
+}
+
+pub fn stack_overflow_error(runtime: &mut Runtime) -> Result<RunnerError, RunnerError> {
+    runnerPrint!(runtime, true, 1, "Call depth over {}", runtime.max_call_depth);
+    let exception = try!(construct_object(runtime, "java/lang/StackOverflowError"));
+    return Ok(RunnerError::Exception(exception));
 }
\ No newline at end of file
@@ -0,0 +1,278 @@
+use reader::runner::*;
+use std::fmt;
+
+// Primitive values held in JVMS slots, long and double take two with the low word first
+pub trait SlotValue: Copy + fmt::Display {
+    const SIZE: usize;
+    fn read(values: &[u32], index: usize) -> Self;
+    fn write(values: &mut [u32], index: usize, value: Self);
+}
+
+impl SlotValue for i32 {
+    const SIZE: usize = 1;
+    fn read(values: &[u32], index: usize) -> i32 { values[index] as i32 }
+    fn write(values: &mut [u32], index: usize, value: i32) { values[index] = value as u32; }
+}
+
+impl SlotValue for f32 {
+    const SIZE: usize = 1;
+    fn read(values: &[u32], index: usize) -> f32 { f32::from_bits(values[index]) }
+    fn write(values: &mut [u32], index: usize, value: f32) { values[index] = value.to_bits(); }
+}
+
+impl SlotValue for i64 {
+    const SIZE: usize = 2;
+    fn read(values: &[u32], index: usize) -> i64 { (values[index] as u64 | (values[index + 1] as u64) << 32) as i64 }
+    fn write(values: &mut [u32], index: usize, value: i64) {
+        values[index] = value as u32;
+        values[index + 1] = (value as u64 >> 32) as u32;
+    }
+}
+
+impl SlotValue for f64 {
+    const SIZE: usize = 2;
+    fn read(values: &[u32], index: usize) -> f64 { f64::from_bits(i64::read(values, index) as u64) }
+    fn write(values: &mut [u32], index: usize, value: f64) { i64::write(values, index, value.to_bits() as i64); }
+}
+
+// Fixed size array of 32 bit slots. Primitives are stored raw, references live in a separate map the GC scans.
+#[derive(Clone, Debug)]
+pub struct Slots {
+    values: Vec<u32>,
+    refs: Vec<Option<Variable>>,
+}
+
+impl Slots {
+    pub fn new(size: usize) -> Slots {
+        return Slots { values: vec![0; size], refs: vec![None; size] };
+    }
+
+    // Lays out arguments given one value each, long and double take two slots
+    pub fn from_arguments(arguments: &Vec<Variable>, size: usize) -> Slots {
+        let needed: usize = arguments.iter().map(|x| if x.is_type_1() {1} else {2}).sum();
+        let mut slots = Slots::new(size.max(needed));
+        let mut index = 0;
+        for arg in arguments {
+            index = index + slots.set_variable(index, arg.clone());
+        }
+        return slots;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.values.len();
+    }
+
+    pub fn get<T: SlotValue>(&self, index: usize) -> T {
+        return T::read(&self.values, index);
+    }
+
+    pub fn set<T: SlotValue>(&mut self, index: usize, value: T) {
+        T::write(&mut self.values, index, value);
+        for i in index..index + T::SIZE {
+            if self.refs[i].is_some() {
+                self.refs[i] = None;
+            }
+        }
+    }
+
+    pub fn get_ref(&self, index: usize) -> Variable {
+        return self.refs[index].clone().unwrap_or_else(|| panic!("Slot {} doesn't hold a reference", index));
+    }
+
+    pub fn peek_ref(&self, index: usize) -> &Variable {
+        return self.refs[index].as_ref().unwrap_or_else(|| panic!("Slot {} doesn't hold a reference", index));
+    }
+
+    pub fn set_ref(&mut self, index: usize, value: Variable) {
+        self.values[index] = 0;
+        self.refs[index] = Some(value);
+    }
+
+    // Reads a value as the variant for the given descriptor type. Booleans stay ints as no instruction makes them.
+    pub fn get_typed(&self, index: usize, type_char: char) -> Variable {
+        let int: i32 = self.get(index);
+        return match type_char {
+            'B' => Variable::Byte(int as i8),
+            'C' => Variable::Char(int as u16),
+            'I' | 'Z' => Variable::Int(int),
+            'S' => Variable::Short(int as i16),
+            'J' => Variable::Long(self.get(index)),
+            'F' => Variable::Float(self.get(index)),
+            'D' => Variable::Double(self.get(index)),
+            _ => self.get_ref(index),
+        };
+    }
+
+    // Writes a value according to its variant and returns the number of slots it took
+    pub fn set_variable(&mut self, index: usize, value: Variable) -> usize {
+        match value {
+            Variable::Long(x) => self.set(index, x),
+            Variable::Double(x) => self.set(index, x),
+            Variable::Float(x) => self.set(index, x),
+            Variable::Boolean(_) | Variable::Byte(_) | Variable::Char(_) | Variable::Short(_) | Variable::Int(_) => self.set(index, value.to_int()),
+            _ => {
+                self.set_ref(index, value);
+                return 1;
+            }
+        }
+        return if value.is_type_1() {1} else {2};
+    }
+
+    fn copy(&mut self, from: usize, to: usize) {
+        self.values[to] = self.values[from];
+        self.refs[to] = self.refs[from].clone();
+    }
+
+    fn take(&mut self, from: usize, to: usize) {
+        self.values[to] = self.values[from];
+        self.refs[to] = self.refs[from].take();
+    }
+
+    pub fn references<'a>(&'a self) -> impl Iterator<Item = &'a Variable> + 'a {
+        return self.refs.iter().filter_map(|x| x.as_ref());
+    }
+
+    // Raw slots for compiled code, which only ever reads primitives
+    pub fn as_ptr(&self) -> *const u32 {
+        return self.values.as_ptr();
+    }
+}
+
+// Operand stack over the same slot layout, sized from max_stack. It only grows past that when natives push
+// results onto a frame that didn't account for them.
+#[derive(Clone, Debug)]
+pub struct OperandStack {
+    slots: Slots,
+    top: usize,
+}
+
+impl OperandStack {
+    pub fn new(size: usize) -> OperandStack {
+        return OperandStack { slots: Slots::new(size), top: 0 };
+    }
+
+    pub fn len(&self) -> usize {
+        return self.top;
+    }
+
+    fn reserve(&mut self, count: usize) {
+        while self.slots.len() < self.top + count {
+            self.slots.values.push(0);
+            self.slots.refs.push(None);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.drop_slots(self.top);
+    }
+
+    pub fn push<T: SlotValue>(&mut self, value: T) {
+        self.reserve(T::SIZE);
+        T::write(&mut self.slots.values, self.top, value);
+        self.top = self.top + T::SIZE;
+    }
+
+    pub fn pop<T: SlotValue>(&mut self) -> T {
+        self.top = self.top - T::SIZE;
+        return T::read(&self.slots.values, self.top);
+    }
+
+    pub fn push_ref(&mut self, value: Variable) {
+        self.reserve(1);
+        self.slots.set_ref(self.top, value);
+        self.top = self.top + 1;
+    }
+
+    pub fn pop_ref(&mut self) -> Variable {
+        self.top = self.top - 1;
+        return self.slots.refs[self.top].take().unwrap_or_else(|| panic!("Stack slot {} doesn't hold a reference", self.top));
+    }
+
+    pub fn push_variable(&mut self, value: Variable) {
+        self.reserve(2);
+        let size = self.slots.set_variable(self.top, value);
+        self.top = self.top + size;
+    }
+
+    pub fn pop_typed(&mut self, type_char: char) -> Variable {
+        return match type_char {
+            'J' | 'D' => {
+                self.top = self.top - 2;
+                self.slots.get_typed(self.top, type_char)
+            }
+            'B' | 'C' | 'F' | 'I' | 'S' | 'Z' => {
+                self.top = self.top - 1;
+                self.slots.get_typed(self.top, type_char)
+            }
+            _ => self.pop_ref()
+        };
+    }
+
+    // Reference held the given number of slots below the top, 0 being the top slot
+    pub fn peek_ref(&self, depth: usize) -> &Variable {
+        return self.slots.peek_ref(self.top - 1 - depth);
+    }
+
+    pub fn drop_slots(&mut self, count: usize) {
+        for _i in 0..count {
+            self.top = self.top - 1;
+            self.slots.refs[self.top] = None;
+        }
+    }
+
+    // DUP family: copies the top slots below the ones under them
+    pub fn dup(&mut self, count: usize, skip: usize) {
+        self.reserve(count);
+        let start = self.top - count - skip;
+        for i in (start..self.top).rev() {
+            self.slots.take(i, i + count);
+        }
+        for i in 0..count {
+            self.slots.copy(self.top + i, start + i);
+        }
+        self.top = self.top + count;
+    }
+
+    pub fn swap(&mut self) {
+        self.slots.values.swap(self.top - 1, self.top - 2);
+        self.slots.refs.swap(self.top - 1, self.top - 2);
+    }
+
+    // Pops the top slots into the start of a new local variable array
+    pub fn take_slots(&mut self, count: usize, size: usize) -> Slots {
+        let mut locals = Slots::new(size.max(count));
+        let start = self.top - count;
+        for i in 0..count {
+            locals.values[i] = self.slots.values[start + i];
+            locals.refs[i] = self.slots.refs[start + i].take();
+        }
+        self.top = start;
+        return locals;
+    }
+
+    // Pops the top slots as arguments for a native, one value each with the receiver first
+    pub fn take_arguments(&mut self, count: usize, with_obj: bool, types: &Vec<char>) -> Vec<Variable> {
+        let mut slot = self.top - count;
+        let mut args = Vec::new();
+        if with_obj {
+            args.push(self.slots.get_ref(slot));
+            slot = slot + 1;
+        }
+        for type_char in types {
+            let var = self.slots.get_typed(slot, *type_char);
+            slot = slot + if var.is_type_1() {1} else {2};
+            args.push(var);
+        }
+        self.drop_slots(count);
+        return args;
+    }
+
+    pub fn references<'a>(&'a self) -> impl Iterator<Item = &'a Variable> + 'a {
+        return self.slots.refs[..self.top].iter().filter_map(|x| x.as_ref());
+    }
+
+    // Raw slots starting the given number of slots below the top, for compiled code
+    pub fn slots_ptr(&self, depth: usize) -> *const u32 {
+        return unsafe { self.slots.as_ptr().add(self.top - depth) };
+    }
+}
@@ -6,8 +6,8 @@ use std::rc::Rc;
 
 #[derive(Clone, PartialEq)]
 pub enum Variable {
-    Byte(u8),
-    Char(char),
+    Byte(i8),
+    Char(u16),
     Double(f64),
     Float(f32),
     Int(i32),
@@ -34,8 +34,11 @@ impl Variable {
             }
         }
     }
-    pub fn to_char(&self) -> char {
+    pub fn to_char(&self) -> u16 {
         match self {
+            &Variable::Int(ref x) => {
+                return *x as u16;
+            },
             &Variable::Char(ref x) => {
                 return *x;
             },
@@ -44,10 +47,10 @@ impl Variable {
             }
         }
     }
-    pub fn to_byte(&self) -> u8 {
+    pub fn to_byte(&self) -> i8 {
         match self {
             &Variable::Int(ref x) => {
-                return *x as u8;
+                return *x as i8;
             },
             &Variable::Byte(ref x) => {
                 return *x;
@@ -4,36 +4,49 @@ use std;
 use std::rc::Rc;
 
 pub fn make_string(runtime: &mut Runtime, val: &str) -> Result<Variable, RunnerError> {
+    let units: Vec<u16> = val.encode_utf16().collect();
+    return make_string_utf16(runtime, &units);
+}
+
+// Builds a string from exact UTF-16 units, which may include lone surrogates a Rust string can't hold
+pub fn make_string_utf16(runtime: &mut Runtime, units: &[u16]) -> Result<Variable, RunnerError> {
     let var = try!(construct_object(runtime, &"java/lang/String"));
     let obj = var.to_ref();
-    let array = construct_char_array(runtime,val);
+    let array = construct_char_array(runtime, units);
     try!(put_field(runtime, &obj, &"value", array));
     return Ok(var);
 }
 
 pub fn string_intern(runtime: &mut Runtime, var: &Variable) -> Result<Variable, RunnerError> {
     let obj = var.to_ref();
-    let string = try!(extract_from_string(runtime, &obj));
-    if !runtime.string_interns.contains_key(&string) {
-        runtime.string_interns.insert(string.clone(), var.clone());
+    let units = try!(extract_utf16_from_string(runtime, &obj));
+    if !runtime.string_interns.contains_key(&units) {
+        runtime.string_interns.insert(units.clone(), var.clone());
     }
-    return Ok(runtime.string_interns.get(&string).unwrap().clone());
+    return Ok(runtime.string_interns.get(&units).unwrap().clone());
 }
 
-pub fn extract_from_char_array(runtime: &mut Runtime, var: &Variable) -> Result<String, RunnerError> {
+pub fn extract_utf16_from_char_array(runtime: &mut Runtime, var: &Variable) -> Result<Vec<u16>, RunnerError> {
     let array = var.to_arrayobj();
     if array.is_null {
         let exception = try!(construct_object(runtime, &"java/lang/NullPointerException"));
         return Err(RunnerError::Exception(exception));
     } else {
-        let mut res = String::new();
-        for c in array.elements.borrow().iter() {
-            res.push(c.to_char());
-        }
-        return Ok(res);
+        return Ok(array.elements.borrow().iter().map(|c| c.to_char()).collect());
     }
 }
 
+// Surrogate pairs become one character, lone surrogates are replaced as Rust strings can't represent them
+pub fn extract_from_char_array(runtime: &mut Runtime, var: &Variable) -> Result<String, RunnerError> {
+    let units = try!(extract_utf16_from_char_array(runtime, var));
+    return Ok(String::from_utf16_lossy(&units));
+}
+
+pub fn extract_utf16_from_string(runtime: &mut Runtime, obj: &Option<Rc<Object>>) -> Result<Vec<u16>, RunnerError> {
+    let field = try!(get_field(runtime, obj, "java/lang/String", "value"));
+    return extract_utf16_from_char_array(runtime, &field);
+}
+
 pub fn extract_from_string(runtime: &mut Runtime, obj: &Option<Rc<Object>>) -> Result<String, RunnerError> {
     let field = try!(get_field(runtime, obj, "java/lang/String", "value"));
     let string = try!(extract_from_char_array(runtime, &field));
@@ -45,12 +58,8 @@ pub fn string_to_string(obj: &Object) -> String {
     let array = value_array.unwrap().to_arrayobj();
     if array.is_null { return String::from("");}
     let vec = array.elements.borrow();
-    let mut ret = String::new();
-    for v in vec.iter() {
-        ret.push(v.to_char());
-    }
-
-    return ret;
+    let units: Vec<u16> = vec.iter().map(|v| v.to_char()).collect();
+    return String::from_utf16_lossy(&units);
 }
 
 pub fn type_name_to_descriptor(name: &String) -> String {
@@ -63,6 +72,7 @@ pub fn type_name_to_descriptor(name: &String) -> String {
         "long" => "J",
         "short" => "S",
         "boolean" => "Z",
+        "void" => "V",
         _ => {
             let mut ret = String::from("L");
             ret.push_str(name.as_str());
@@ -113,6 +123,7 @@ pub fn descriptor_to_type_name(string: &str) -> Result<String, RunnerError> {
                     'J' => "long",
                     'S' => "short",
                     'Z' => "boolean",
+                    'V' => "void",
                     _ => return Err(RunnerError::ClassInvalid2(format!("Type specifier invalid {}", string)))
                 })
             }
@@ -144,3 +155,168 @@ pub fn generate_method_descriptor(args: &Vec<Variable>, return_descriptor: Strin
     ret.push_str(return_descriptor.as_str());
     return ret;
 }
+
+pub fn parameter_descriptors(descriptor: &str) -> Result<Vec<String>, RunnerError> {
+    let mut iter = descriptor.chars();
+    if iter.next().map(|x| x != '(').unwrap_or(true) {
+        return Err(RunnerError::ClassInvalid2(format!("Function type {} invalid", descriptor)));
+    }
+
+    let mut parameters = Vec::new();
+    let mut type_char : char;
+    while {type_char = try!(iter.next().ok_or(RunnerError::ClassInvalid2(format!("Failed to parse {}", descriptor)))); type_char != ')'} {
+        let mut type_string = String::new();
+        while type_char == '[' {
+            type_string.push(type_char);
+            type_char = try!(iter.next().ok_or(RunnerError::ClassInvalid2(format!("Failed to parse {}", descriptor))));
+        }
+        type_string.push(type_char);
+        if type_char == 'L' {
+            type_string.push_str(iter.by_ref().take_while(|x| *x != ';').collect::<String>().as_str());
+            type_string.push(';');
+        }
+        parameters.push(type_string);
+    }
+    return Ok(parameters);
+}
+
+pub fn box_class_name(type_char: char) -> Option<&'static str> {
+    return match type_char {
+        'B' => Some("java/lang/Byte"),
+        'C' => Some("java/lang/Character"),
+        'D' => Some("java/lang/Double"),
+        'F' => Some("java/lang/Float"),
+        'I' => Some("java/lang/Integer"),
+        'J' => Some("java/lang/Long"),
+        'S' => Some("java/lang/Short"),
+        'Z' => Some("java/lang/Boolean"),
+        _ => None
+    };
+}
+
+// Wraps a primitive in a new box object, which is what reflection hands back rather than a cached valueOf
+pub fn box_primitive(runtime: &mut Runtime, type_char: char, value: &Variable) -> Result<Variable, RunnerError> {
+    let class_name = try!(box_class_name(type_char).ok_or(RunnerError::ClassInvalid2(format!("No box type for {}", type_char))));
+    let typed = match type_char {
+        'B' => Variable::Byte(value.to_byte()),
+        'C' => Variable::Char(value.to_char()),
+        'S' => Variable::Short(value.to_short()),
+        'Z' => Variable::Boolean(value.to_bool()),
+        _ => value.clone()
+    };
+    let var = try!(construct_object(runtime, class_name));
+    try!(put_field(runtime, &var.to_ref(), "value", typed));
+    return Ok(var);
+}
+
+// Unwraps a box to the given primitive type with the widening conversions of JLS 5.1.2, None when it can't be done
+pub fn unbox_primitive(runtime: &mut Runtime, type_char: char, value: &Variable) -> Result<Option<Variable>, RunnerError> {
+    if !value.is_reference() || value.is_null() {
+        return Ok(None);
+    }
+    let class_name = value.to_ref().unwrap().type_ref().name.clone();
+    let source = match "BCDFIJSZ".chars().find(|x| box_class_name(*x) == Some(class_name.as_str())) {
+        Some(source) => source,
+        None => return Ok(None),
+    };
+    let boxed = try!(get_field(runtime, &value.to_ref(), class_name.as_str(), "value"));
+    return Ok(widen_primitive(source, type_char, &boxed));
+}
+
+// Converts a primitive of one type to another when JLS 5.1.2 allows it without a cast
+pub fn widen_primitive(source: char, type_char: char, value: &Variable) -> Option<Variable> {
+    let widens = source == type_char || match (source, type_char) {
+        ('B', 'S') => true,
+        ('B', _) | ('S', _) | ('C', _) => "IJFD".contains(type_char),
+        ('I', _) => "JFD".contains(type_char),
+        ('J', _) => "FD".contains(type_char),
+        ('F', 'D') => true,
+        _ => false,
+    };
+    if !widens {
+        return None;
+    }
+    let integral = match source {
+        'B' => value.to_byte() as i64,
+        'C' => value.to_char() as i64,
+        'S' => value.to_short() as i64,
+        'I' => value.to_int() as i64,
+        'J' => value.to_long(),
+        _ => 0,
+    };
+    let real = match source {
+        'F' => value.to_float() as f64,
+        'D' => value.to_double(),
+        _ => integral as f64,
+    };
+    return Some(match type_char {
+        'Z' => Variable::Boolean(value.to_bool()),
+        'B' => Variable::Byte(integral as i8),
+        'C' => Variable::Char(integral as u16),
+        'S' => Variable::Short(integral as i16),
+        'I' => Variable::Int(integral as i32),
+        'J' => Variable::Long(integral),
+        'F' => Variable::Float(if source == 'J' {integral as f32} else {real as f32}),
+        _ => Variable::Double(real),
+    });
+}
+
+// Whether the class or any of its super classes lists the interface, directly or through another interface
+pub fn implements_interface(runtime: &mut Runtime, class: &Rc<Class>, name: &str) -> Result<bool, RunnerError> {
+    let mut class = class.clone();
+    loop {
+        for index in &class.cr.interfaces {
+            let interface_name = try!(class.cr.constant_pool.get_class_name(*index));
+            if interface_name.as_str() == name {
+                return Ok(true);
+            }
+            let interface = try!(load_class(runtime, interface_name.as_str()));
+            if try!(implements_interface(runtime, &interface, name)) {
+                return Ok(true);
+            }
+        }
+        let maybe_super_class = class.super_class.borrow().clone();
+        match maybe_super_class {
+            Some(super_class) => class = super_class,
+            None => return Ok(false),
+        }
+    }
+}
+
+// Whether a reference could be stored somewhere of the given field type, primitives never can
+pub fn is_assignable_value(runtime: &mut Runtime, descriptor: &str, value: &Variable) -> Result<bool, RunnerError> {
+    let source = match value {
+        &Variable::Reference(_, None) => return Ok(true),
+        &Variable::Reference(_, Some(ref obj)) => generate_class_descriptor(&obj.type_ref()),
+        &Variable::ArrayReference(ref array) if array.is_null => return Ok(true),
+        &Variable::ArrayReference(_) => value.get_descriptor(),
+        _ => return Ok(false),
+    };
+    return is_assignable_descriptor(runtime, descriptor, source.as_str());
+}
+
+// Whether the source reference type widens to the target one, arrays being covariant in their element type
+pub fn is_assignable_descriptor(runtime: &mut Runtime, target: &str, source: &str) -> Result<bool, RunnerError> {
+    if target == source || target == "Ljava/lang/Object;" {
+        return Ok(true);
+    }
+    if source.starts_with('[') {
+        if target.starts_with('[') {
+            let (target_element, source_element) = (&target[1..], &source[1..]);
+            if target_element.len() == 1 || source_element.len() == 1 {
+                return Ok(false);
+            }
+            return is_assignable_descriptor(runtime, target_element, source_element);
+        }
+        return Ok(target == "Ljava/lang/Cloneable;" || target == "Ljava/io/Serializable;");
+    }
+    if !source.starts_with('L') || !target.starts_with('L') {
+        return Ok(false);
+    }
+    let source_class = try!(load_class(runtime, &source[1..source.len()-1]));
+    let target_name = &target[1..target.len()-1];
+    if Class::find_superclass(source_class.clone(), Rc::new(String::from(target_name))).is_some() {
+        return Ok(true);
+    }
+    return implements_interface(runtime, &source_class, target_name);
+}
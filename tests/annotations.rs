@@ -0,0 +1,106 @@
+mod common;
+use common::*;
+
+#[test]
+fn annotations() {
+    let (mut runtime, class_path) = setup("annotations", r##"
+        import java.lang.annotation.*;
+        import java.lang.reflect.*;
+
+        @Retention(RetentionPolicy.RUNTIME)
+        @interface Tag {
+            String value();
+            int size() default 3;
+            long big() default 0;
+            String[] names() default {};
+        }
+
+        @Tag(value = "annotated", size = 7, big = 1L << 40)
+        public class annotations {
+            @Tag("field")
+            public int counted;
+
+            @Tag(value = "method", names = {"a", "b"})
+            public static void marked() {
+            }
+
+            public static void plain() {
+            }
+
+            public static boolean classAnnotation() {
+                Tag tag = annotations.class.getAnnotation(Tag.class);
+                return tag != null && tag.value().equals("annotated") && tag.size() == 7 && tag.big() == 1L << 40 &&
+                    tag.names().length == 0 && tag.annotationType() == Tag.class;
+            }
+
+            public static boolean methodAnnotation() throws NoSuchMethodException {
+                Tag tag = annotations.class.getMethod("marked").getAnnotation(Tag.class);
+                return tag != null && tag.value().equals("method") && tag.size() == 3 && tag.names().length == 2 &&
+                    tag.names()[1].equals("b") && annotations.class.getMethod("plain").getAnnotation(Tag.class) == null;
+            }
+
+            public static boolean fieldAnnotations() throws NoSuchFieldException {
+                Annotation[] all = annotations.class.getDeclaredField("counted").getDeclaredAnnotations();
+                return all.length == 1 && all[0] instanceof Tag && ((Tag) all[0]).value().equals("field") && ((Tag) all[0]).big() == 0;
+            }
+
+            public static boolean unannotated() {
+                return Runnable.class.getAnnotation(Tag.class) == null && Runnable.class.getAnnotations().length == 0;
+            }
+        }
+    "##, true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "classAnnotation"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "methodAnnotation"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "fieldAnnotations"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "unannotated"), true);
+}
+
+#[test]
+fn annotations_raw() {
+    let (mut runtime, class_path) = setup("annotations", r##"
+        import java.lang.annotation.*;
+        import sun.misc.JavaLangAccess;
+        import sun.misc.SharedSecrets;
+        import sun.reflect.ConstantPool;
+
+        @Retention(RetentionPolicy.RUNTIME)
+        @interface Tag {
+            String value();
+            int size() default 0;
+            long big() default 0;
+        }
+
+        @Tag(value = "annotated", size = 7, big = 1L << 40)
+        public class annotations {
+            private static int u2(byte[] raw, int at) {
+                return ((raw[at] & 0xFF) << 8) | (raw[at + 1] & 0xFF);
+            }
+
+            public static boolean rawClassAnnotations() {
+                JavaLangAccess access = SharedSecrets.getJavaLangAccess();
+                byte[] raw = access.getRawClassAnnotations(annotations.class);
+                ConstantPool pool = access.getConstantPool(annotations.class);
+                return u2(raw, 0) == 1 && pool.getUTF8At(u2(raw, 2)).equals("LTag;") && u2(raw, 4) == 3 &&
+                    pool.getUTF8At(u2(raw, 6)).equals("value") && pool.getUTF8At(u2(raw, 9)).equals("annotated") &&
+                    pool.getIntAt(u2(raw, 14)) == 7 && pool.getLongAt(u2(raw, 19)) == 1L << 40;
+            }
+
+            public static boolean unannotated() {
+                return SharedSecrets.getJavaLangAccess().getRawClassAnnotations(Runnable.class) == null;
+            }
+
+            public static boolean wrongType() {
+                byte[] raw = SharedSecrets.getJavaLangAccess().getRawClassAnnotations(annotations.class);
+                try {
+                    SharedSecrets.getJavaLangAccess().getConstantPool(annotations.class).getIntAt(u2(raw, 2));
+                } catch (IllegalArgumentException e) {
+                    return true;
+                }
+                return false;
+            }
+        }
+    "##, true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "rawClassAnnotations"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "unannotated"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "wrongType"), true);
+}
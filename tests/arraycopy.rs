@@ -0,0 +1,89 @@
+mod common;
+use common::*;
+
+#[test]
+fn arraycopy() {
+    let (mut runtime, class_path) = setup("arraycopy", r##"
+        public class arraycopy {
+            private static int digits(int[] values) {
+                int ret = 0;
+                for (int value : values) {
+                    ret = ret * 10 + value;
+                }
+                return ret;
+            }
+
+            public static int overlapForwards() {
+                int[] values = new int[] {1, 2, 3, 4, 5};
+                System.arraycopy(values, 0, values, 1, 4);
+                return digits(values);
+            }
+
+            public static int overlapBackwards() {
+                int[] values = new int[] {1, 2, 3, 4, 5};
+                System.arraycopy(values, 1, values, 0, 4);
+                return digits(values);
+            }
+
+            public static int bounds() {
+                int[] values = new int[4];
+                int failures = 0;
+                try { System.arraycopy(values, -1, values, 0, 1); } catch (ArrayIndexOutOfBoundsException e) { failures++; }
+                try { System.arraycopy(values, 0, values, -1, 1); } catch (ArrayIndexOutOfBoundsException e) { failures++; }
+                try { System.arraycopy(values, 0, values, 0, -1); } catch (ArrayIndexOutOfBoundsException e) { failures++; }
+                try { System.arraycopy(values, 3, values, 0, 2); } catch (ArrayIndexOutOfBoundsException e) { failures++; }
+                try { System.arraycopy(values, 0, values, 3, 2); } catch (ArrayIndexOutOfBoundsException e) { failures++; }
+                try { System.arraycopy(values, 0, values, 0, Integer.MAX_VALUE); } catch (ArrayIndexOutOfBoundsException e) { failures++; }
+                System.arraycopy(values, 4, values, 0, 0);
+                return failures;
+            }
+
+            public static int nulls() {
+                int failures = 0;
+                try { System.arraycopy(null, 0, new int[1], 0, 0); } catch (NullPointerException e) { failures++; }
+                try { System.arraycopy(new int[1], 0, null, 0, 0); } catch (NullPointerException e) { failures++; }
+                return failures;
+            }
+
+            public static int types() {
+                int failures = 0;
+                try { System.arraycopy(new int[1], 0, new long[1], 0, 1); } catch (ArrayStoreException e) { failures++; }
+                try { System.arraycopy(new int[1], 0, new Object[1], 0, 1); } catch (ArrayStoreException e) { failures++; }
+                try { System.arraycopy(new Object[1], 0, new int[1], 0, 0); } catch (ArrayStoreException e) { failures++; }
+                try { System.arraycopy("not an array", 0, new int[1], 0, 0); } catch (ArrayStoreException e) { failures++; }
+                try { System.arraycopy(new int[1], 0, new int[1][], 0, 0); } catch (ArrayStoreException e) { failures++; }
+                return failures;
+            }
+
+            public static int references() {
+                String[] strings = new String[] {"a", "b"};
+                Object[] objects = new Object[3];
+                System.arraycopy(strings, 0, objects, 1, 2);
+
+                Object[] mixed = new Object[] {"c", "d", new Object(), "e"};
+                String[] target = new String[4];
+                try {
+                    System.arraycopy(mixed, 0, target, 0, 4);
+                } catch (ArrayStoreException e) {
+                    return (objects[0] == null ? 1 : 0) + (objects[2] == strings[1] ? 10 : 0) +
+                        (target[1] == mixed[1] ? 100 : 0) + (target[2] == null && target[3] == null ? 1000 : 0);
+                }
+                return -1;
+            }
+
+            public static int nested() {
+                int[][] grid = new int[][] {new int[] {1}, new int[] {2}};
+                Object[] rows = new Object[2];
+                System.arraycopy(grid, 0, rows, 0, 2);
+                return ((int[]) rows[1])[0];
+            }
+        }
+    "##, false);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "overlapForwards"), 11234);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "overlapBackwards"), 23455);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "bounds"), 6);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "nulls"), 2);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "types"), 5);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "references"), 1111);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "nested"), 2);
+}
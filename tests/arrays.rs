@@ -27,3 +27,21 @@ fn arrays() {
     assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "arrayReturningFunctionTest"), 12);
     assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "arrayComparison"), 10);
 }
+
+#[test]
+fn negative_array_size() {
+    let (mut runtime, class_path) = setup("negative_array_size", r##"
+        public class negative_array_size {
+            public static int make(int n) {
+                int failures = 0;
+                try { failures += new int[n][].length; } catch (NegativeArraySizeException e) { failures += 100; }
+                try { failures += new Object[n].length; } catch (NegativeArraySizeException e) { failures += 100; }
+                try { failures += new int[n].length; } catch (NegativeArraySizeException e) { failures += 100; }
+                return failures;
+            }
+        }
+    "##, false);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "make", -1), 300);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "make", 2), 6);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "make", 0), 0);
+}
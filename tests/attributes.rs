@@ -0,0 +1,133 @@
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::{Annotation, ClassResult, ElementValue, FieldItem, TypeAnnotationTarget, read_class};
+use std::fs;
+
+fn read(class_path: &Path, name: &str) -> ClassResult {
+    return read_class(&fs::read(class_path.with_file_name(format!("{}.class", name))).unwrap()).unwrap();
+}
+
+fn member<'a>(class: &'a ClassResult, methods: bool, name: &str) -> &'a FieldItem {
+    let members = if methods {&class.methods} else {&class.fields};
+    return members.iter().find(|x| class.constant_pool.get_str(x.name_index).unwrap().as_str() == name).unwrap();
+}
+
+fn type_names(class: &ClassResult, annotations: &[Annotation]) -> Vec<String> {
+    return annotations.iter().map(|x| class.constant_pool.get_str(x.type_index).unwrap().to_string()).collect();
+}
+
+#[test]
+fn attributes() {
+    let (_runtime, class_path) = setup_with_options("annotated", r##"
+        import java.lang.annotation.*;
+        import java.util.List;
+        import java.util.function.IntSupplier;
+
+        @Retention(RetentionPolicy.RUNTIME)
+        @interface Tag {
+            String value() default "none";
+            int[] sizes() default {1, 2};
+            ElementType kind() default ElementType.FIELD;
+            Class<?> type() default Object.class;
+        }
+
+        @Retention(RetentionPolicy.CLASS)
+        @interface Hidden {
+        }
+
+        @Retention(RetentionPolicy.RUNTIME)
+        @Target(ElementType.TYPE_USE)
+        @interface Typed {
+        }
+
+        @Tag(value = "outer", sizes = {3})
+        @Hidden
+        @Deprecated
+        public class annotated {
+            @Tag static List<@Typed String> names;
+
+            class Inner {
+            }
+
+            public static int run(@Tag("count") final int count, @Hidden String label) {
+                IntSupplier supplier = () -> count + 1;
+                return supplier.getAsInt() + new Object() { }.hashCode();
+            }
+        }
+    "##, false, &["-parameters"]);
+
+    let class = read(class_path.as_path(), "annotated");
+    let cp = &class.constant_pool;
+    assert!(class.is_deprecated());
+    assert!(!class.is_synthetic());
+    assert_eq!(type_names(&class, class.annotations(true)), vec!("LTag;", "Ljava/lang/Deprecated;"));
+    assert_eq!(type_names(&class, class.annotations(false)), vec!("LHidden;"));
+
+    let tag = &class.annotations(true)[0];
+    assert_eq!(tag.elements.len(), 2);
+    assert_eq!(cp.get_str(tag.elements[0].0).unwrap().as_str(), "value");
+    match tag.elements[0].1 {
+        ElementValue::Const{tag: b's', index} => assert_eq!(cp.get_str(index).unwrap().as_str(), "outer"),
+        ref other => panic!("Unexpected value {:?}", other),
+    }
+    match tag.elements[1].1 {
+        ElementValue::Array(ref values) => assert_eq!(values.len(), 1),
+        ref other => panic!("Unexpected value {:?}", other),
+    }
+
+    let inner: Vec<String> = class.inner_classes().iter().map(|x| cp.get_class_name(x.inner_class_info_index).unwrap().to_string()).collect();
+    assert!(inner.contains(&String::from("annotated$Inner")));
+    assert!(inner.contains(&String::from("annotated$1")));
+
+    // The lambda needs LambdaMetafactory as its bootstrap method
+    assert_eq!(class.bootstrap_methods().len(), 1);
+    assert_eq!(class.bootstrap_methods()[0].arguments.len(), 3);
+
+    let names = member(&class, false, "names");
+    assert_eq!(type_names(&class, names.annotations(true)), vec!("LTag;"));
+    let typed = names.type_annotations(true);
+    assert_eq!(typed.len(), 1);
+    assert_eq!(typed[0].target, TypeAnnotationTarget::Empty);
+    assert_eq!(typed[0].type_path, vec!((3, 0)));
+
+    let run = member(&class, true, "run");
+    let parameters: Vec<String> = run.method_parameters().iter().map(|x| cp.get_str(x.name_index).unwrap().to_string()).collect();
+    assert_eq!(parameters, vec!("count", "label"));
+    assert_eq!(run.method_parameters()[0].access_flags, 0x0010);
+    assert_eq!(run.parameter_annotations(true).len(), 2);
+    assert_eq!(type_names(&class, &run.parameter_annotations(true)[0]), vec!("LTag;"));
+    assert!(run.parameter_annotations(true)[1].is_empty());
+    assert_eq!(type_names(&class, &run.parameter_annotations(false)[1]), vec!("LHidden;"));
+    assert!(run.annotation_default().is_none());
+
+    let anonymous = read(class_path.as_path(), "annotated$1");
+    let (enclosing, method) = anonymous.enclosing_method().unwrap();
+    assert_eq!(anonymous.constant_pool.get_class_name(enclosing).unwrap().as_str(), "annotated");
+    let (name, descriptor) = anonymous.constant_pool.get_name_and_type(method.unwrap()).unwrap();
+    assert_eq!((name.as_str(), descriptor.as_str()), ("run", "(ILjava/lang/String;)I"));
+    assert!(read(class_path.as_path(), "annotated$Inner").enclosing_method().is_none());
+
+    let annotation = read(class_path.as_path(), "Tag");
+    let tp = &annotation.constant_pool;
+    match member(&annotation, true, "value").annotation_default() {
+        Some(&ElementValue::Const{tag: b's', index}) => assert_eq!(tp.get_str(index).unwrap().as_str(), "none"),
+        other => panic!("Unexpected default {:?}", other),
+    }
+    match member(&annotation, true, "sizes").annotation_default() {
+        Some(&ElementValue::Array(ref values)) => assert_eq!(values.len(), 2),
+        other => panic!("Unexpected default {:?}", other),
+    }
+    match member(&annotation, true, "kind").annotation_default() {
+        Some(&ElementValue::Enum{type_name_index, const_name_index}) => {
+            assert_eq!(tp.get_str(type_name_index).unwrap().as_str(), "Ljava/lang/annotation/ElementType;");
+            assert_eq!(tp.get_str(const_name_index).unwrap().as_str(), "FIELD");
+        }
+        other => panic!("Unexpected default {:?}", other),
+    }
+    match member(&annotation, true, "type").annotation_default() {
+        Some(&ElementValue::Class{index}) => assert_eq!(tp.get_str(index).unwrap().as_str(), "Ljava/lang/Object;"),
+        other => panic!("Unexpected default {:?}", other),
+    }
+}
@@ -40,8 +40,9 @@ fn binary_converter() {
             }
         }
     "##, true);
+    runtime.capture_output();
     assert_eq!(run_method(&mut runtime, class_path.as_path(), "test", &Vec::new(), "Z"), Variable::Int(1));
-    assert_eq!(runtime.stdout,
+    assert_eq!(runtime.captured_stdout(),
 r##"-5: -101
 -5
 -5: 11111111111111111111111111111011
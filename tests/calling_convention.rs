@@ -0,0 +1,79 @@
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::ObjectHandle;
+
+#[test]
+fn calling_convention() {
+    let (mut runtime, class_path) = setup("calls", r##"
+        public class calls {
+            private long big;
+            private double ratio;
+            private int small;
+
+            public calls(long big, double ratio, int small) {
+                this.big = big;
+                this.ratio = ratio;
+                this.small = small;
+            }
+
+            private static native double combine(int a, long b, double c, long d);
+            private native long accumulate(long x, double y);
+
+            private static double spread(int a, long b, double c, Object o, long d) {
+                return a + b * 2 + c * 3 + (o == null ? 0 : 1000) + d * 4;
+            }
+
+            private double scale(long a, double b, int c) {
+                return big * a + ratio * b + small * c;
+            }
+
+            public static double staticCall(long b, double c) {
+                return spread(1, b, c, null, b - 1) + spread(-1, -b, -c, new Object(), 0);
+            }
+
+            public static double virtualCall(long a, double b) {
+                calls c = new calls(a, b, 3);
+                return c.scale(a, b, 5) + c.scale(-1, 0.5, 0);
+            }
+
+            public static double builtinCall(long b, double c) {
+                calls o = new calls(0, 0.0, 0);
+                return combine(2, b, c, b + 1) + o.accumulate(b, c) + o.accumulate(1, 1.5);
+            }
+        }
+    "##, false);
+
+    runtime.register_native("calls", "combine", "(IJDJ)D", |_runtime, args| {
+        assert_eq!(args.len(), 4);
+        assert!(args[0].is_type_1() && !args[1].is_type_1() && !args[2].is_type_1() && !args[3].is_type_1());
+        return Ok(Some(Variable::Double(args[0].to_int() as f64 + args[1].to_long() as f64 + args[2].to_double() * 10.0 + args[3].to_long() as f64 * 100.0)));
+    });
+    runtime.register_native("calls", "accumulate", "(JD)J", |runtime, args| {
+        assert_eq!(args.len(), 3);
+        let receiver = ObjectHandle::from_variable(&args[0]).unwrap();
+        let big = receiver.get_field(runtime, "big").unwrap().to_long() + args[1].to_long() + args[2].to_double() as i64;
+        receiver.set_field(runtime, "big", Variable::Long(big)).unwrap();
+        return Ok(Some(Variable::Long(big)));
+    });
+
+    let path = class_path.as_path();
+    let b = 1i64 << 40;
+    let result = run_method(&mut runtime, path, "staticCall", &vec!(Variable::Long(b), Variable::Double(0.25)), "D");
+    assert_eq!(result, Variable::Double((4 * b + 996) as f64));
+
+    let a = 1i64 << 20;
+    let result = run_method(&mut runtime, path, "virtualCall", &vec!(Variable::Long(a), Variable::Double(0.25)), "D");
+    assert_eq!(result, Variable::Double((a * a) as f64 + 0.0625 + 15.0 - a as f64 + 0.125));
+
+    let b = 1i64 << 30;
+    let result = run_method(&mut runtime, path, "builtinCall", &vec!(Variable::Long(b), Variable::Double(2.5)), "D");
+    let combined = 2.0 + b as f64 + 25.0 + (b + 1) as f64 * 100.0;
+    assert_eq!(result, Variable::Double(combined + (b + 2) as f64 + (b + 4) as f64));
+
+    let handle = ObjectHandle::new(&mut runtime, "calls", "(JDI)V", &vec!(Variable::Long(-b), Variable::Double(-0.5), Variable::Int(7))).unwrap();
+    assert_eq!(handle.get_field(&mut runtime, "big").unwrap(), Variable::Long(-b));
+    assert_eq!(handle.get_field(&mut runtime, "ratio").unwrap(), Variable::Double(-0.5));
+    assert_eq!(handle.get_field(&mut runtime, "small").unwrap(), Variable::Int(7));
+}
@@ -0,0 +1,382 @@
+extern crate rand;
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::{AttributeItem, ClassReadError, read_class};
+use self::rand::{Rng, SeedableRng, XorShiftRng};
+use std::fs;
+
+fn be16(x: u16) -> Vec<u8> {
+    return vec!((x >> 8) as u8, x as u8);
+}
+
+fn be32(x: u32) -> Vec<u8> {
+    return vec!((x >> 24) as u8, (x >> 16) as u8, (x >> 8) as u8, x as u8);
+}
+
+// Hand assembled class files, so each test can break exactly one structure and know its offset
+struct Builder {
+    pool: Vec<u8>,
+    count: u16,
+    body: Vec<u8>,
+}
+
+impl Builder {
+    fn new() -> Builder {
+        return Builder { pool: Vec::new(), count: 1, body: Vec::new() };
+    }
+
+    fn constant(&mut self, bytes: Vec<u8>) -> u16 {
+        let index = self.count;
+        self.count += if bytes[0] == 5 || bytes[0] == 6 {2} else {1};
+        self.pool.extend(bytes);
+        return index;
+    }
+
+    // Offset the next constant will have in the finished file
+    fn pool_offset(&self) -> usize {
+        return 10 + self.pool.len();
+    }
+
+    fn utf8(&mut self, s: &str) -> u16 {
+        let mut bytes = vec!(1);
+        bytes.extend(be16(s.len() as u16));
+        bytes.extend(s.bytes());
+        return self.constant(bytes);
+    }
+
+    fn class(&mut self, name: &str) -> u16 {
+        let name_index = self.utf8(name);
+        let mut bytes = vec!(7);
+        bytes.extend(be16(name_index));
+        return self.constant(bytes);
+    }
+
+    // Offset the next body bytes will have in the finished file
+    fn body_offset(&self) -> usize {
+        return self.pool_offset() + self.body.len();
+    }
+
+    fn header(&mut self, this_class: u16, super_class: u16) {
+        self.body.extend(be16(0x21));
+        self.body.extend(be16(this_class));
+        self.body.extend(be16(super_class));
+        self.body.extend(be16(0));
+    }
+
+    fn member(&mut self, name: u16, descriptor: u16, attributes: Vec<Vec<u8>>) {
+        self.body.extend(be16(0x08));
+        self.body.extend(be16(name));
+        self.body.extend(be16(descriptor));
+        self.body.extend(be16(attributes.len() as u16));
+        for attribute in attributes {
+            self.body.extend(attribute);
+        }
+    }
+
+    fn finish(&self) -> Vec<u8> {
+        let mut data = be32(0xCAFEBABE);
+        data.extend(be16(0));
+        data.extend(be16(52));
+        data.extend(be16(self.count));
+        data.extend(self.pool.clone());
+        data.extend(self.body.clone());
+        return data;
+    }
+}
+
+fn attribute(name: u16, info: Vec<u8>) -> Vec<u8> {
+    let mut bytes = be16(name);
+    bytes.extend(be32(info.len() as u32));
+    bytes.extend(info);
+    return bytes;
+}
+
+fn code(max_stack: u16, code: Vec<u8>, attributes: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut info = be16(max_stack);
+    info.extend(be16(1));
+    info.extend(be32(code.len() as u32));
+    info.extend(code);
+    info.extend(be16(0));
+    info.extend(be16(attributes.len() as u16));
+    for attribute in attributes {
+        info.extend(attribute);
+    }
+    return info;
+}
+
+fn format_error_at(data: &[u8]) -> (usize, String) {
+    return match read_class(data) {
+        Err(ClassReadError::ClassFormatError(offset, message)) => (offset, message),
+        other => panic!("Expected a format error, got {:?}", other.map(|_| ())),
+    };
+}
+
+// A class with a static method and a long constant, plus offsets to patch
+struct Sample {
+    builder: Builder,
+    field_offset: usize,
+    constant_value_offset: usize,
+    method_offset: usize,
+    code_offset: usize,
+}
+
+fn sample(field_descriptor: &str, method_name: &str, method_code: Vec<u8>) -> Sample {
+    let mut b = Builder::new();
+    let this_class = b.class("Sample");
+    let super_class = b.class("java/lang/Object");
+    let field_name = b.utf8("LIMIT");
+    let field_type = b.utf8(field_descriptor);
+    let constant_value = b.utf8("ConstantValue");
+    let long = b.constant(vec!(5, 0, 0, 0, 0, 0, 0, 0, 42));
+    let method = b.utf8(method_name);
+    let method_type = b.utf8("()V");
+    let code_name = b.utf8("Code");
+
+    b.header(this_class, super_class);
+    b.body.extend(be16(1));
+    let field_offset = b.body_offset();
+    let constant_value_offset = field_offset + 8;
+    b.member(field_name, field_type, vec!(attribute(constant_value, be16(long))));
+    b.body.extend(be16(1));
+    let method_offset = b.body_offset();
+    let code_offset = method_offset + 8;
+    b.member(method, method_type, vec!(attribute(code_name, method_code)));
+    b.body.extend(be16(0));
+    return Sample { builder: b, field_offset: field_offset, constant_value_offset: constant_value_offset, method_offset: method_offset, code_offset: code_offset };
+}
+
+#[test]
+fn class_format_members() {
+    assert!(read_class(&sample("J", "run", code(0, vec!(0xB1), Vec::new())).builder.finish()).is_ok());
+
+    let s = sample("I", "run", code(0, vec!(0xB1), Vec::new()));
+    let (offset, message) = format_error_at(&s.builder.finish());
+    assert_eq!(offset, s.constant_value_offset, "{}", message);
+
+    let s = sample("Ljava/lang/String", "run", code(0, vec!(0xB1), Vec::new()));
+    assert_eq!(format_error_at(&s.builder.finish()).0, s.field_offset);
+
+    let s = sample(&format!("{}J", "[".repeat(256)), "run", code(0, vec!(0xB1), Vec::new()));
+    assert_eq!(format_error_at(&s.builder.finish()).0, s.field_offset);
+
+    for name in ["a.b", "a/b", "<run>", ""].iter() {
+        let s = sample("J", name, code(0, vec!(0xB1), Vec::new()));
+        assert_eq!(format_error_at(&s.builder.finish()).0, s.method_offset, "{} was accepted", name);
+    }
+
+    let s = sample("J", "run", code(0, Vec::new(), Vec::new()));
+    assert_eq!(format_error_at(&s.builder.finish()).0, s.code_offset);
+
+    // "Code" is constant 12 in the sample
+    let s = sample("J", "run", code(0, vec!(0xB1), vec!(attribute(12, code(0, vec!(0xB1), Vec::new())))));
+    assert_eq!(format_error_at(&s.builder.finish()).0, s.code_offset + 6 + 2 + 2 + 4 + 1 + 2 + 2);
+
+    // Lengths must cover an attribute's contents exactly
+    let mut s = sample("J", "run", code(0, vec!(0xB1), Vec::new()));
+    let offset = s.constant_value_offset - s.builder.pool_offset();
+    s.builder.body[offset + 5] = 4;
+    let (offset, message) = format_error_at(&s.builder.finish());
+    assert_eq!(offset, s.constant_value_offset, "{}", message);
+}
+
+#[test]
+fn class_format_constant_pool() {
+    // Entries may refer forwards, but only to constants of the right kind
+    let mut b = Builder::new();
+    let methodref_offset = b.pool_offset();
+    b.constant(vec!(10, 0, 2, 0, 3));
+    b.class("java/lang/Object");
+    b.utf8("<init>");
+    assert_eq!(format_error_at(&b.finish()).0, methodref_offset);
+
+    let mut b = Builder::new();
+    b.constant(vec!(10, 0, 9, 0, 2));
+    assert_eq!(format_error_at(&b.finish()).0, 10);
+
+    let mut b = Builder::new();
+    let class = b.class("java/lang/Object");
+    let name = b.utf8("value");
+    let descriptor = b.utf8("I");
+    let mut name_and_type = vec!(12);
+    name_and_type.extend(be16(name));
+    name_and_type.extend(be16(descriptor));
+    let name_and_type = b.constant(name_and_type);
+    let mut fieldref = vec!(9);
+    fieldref.extend(be16(class));
+    fieldref.extend(be16(name_and_type));
+    let fieldref = b.constant(fieldref);
+    let handle_offset = b.pool_offset();
+    let mut handle = vec!(15, 6);
+    handle.extend(be16(fieldref));
+    b.constant(handle);
+    assert_eq!(format_error_at(&b.finish()).0, handle_offset);
+
+    let mut b = Builder::new();
+    b.class("java/lang/Object");
+    b.utf8("java//Object");
+    let class_offset = b.pool_offset();
+    b.constant(vec!(7, 0, 3));
+    assert_eq!(format_error_at(&b.finish()).0, class_offset);
+
+    let mut b = Builder::new();
+    b.class("java/lang/Object");
+    let tag_offset = b.pool_offset();
+    b.constant(vec!(2, 0, 0));
+    assert_eq!(format_error_at(&b.finish()).0, tag_offset);
+
+    // Eight byte constants take two entries, which must both fit in the pool
+    let mut b = Builder::new();
+    b.class("java/lang/Object");
+    let long_offset = b.pool_offset();
+    b.constant(vec!(6, 0, 0, 0, 0, 0, 0, 0, 0));
+    b.count -= 1;
+    assert_eq!(format_error_at(&b.finish()).0, long_offset);
+
+    let mut b = Builder::new();
+    let this_class = b.class("Orphan");
+    b.header(this_class, 0);
+    b.body.extend(vec!(0, 0, 0, 0, 0, 0));
+    assert_eq!(format_error_at(&b.finish()).0, b.pool_offset() + 4);
+}
+
+// A class with only the given class attributes, returning the offset of each one's contents
+fn with_attributes(attributes: Vec<(&str, Vec<u8>)>) -> (Vec<u8>, Vec<usize>) {
+    let mut b = Builder::new();
+    let this_class = b.class("Sample");
+    let super_class = b.class("java/lang/Object");
+    b.utf8("LSample;");
+    let names: Vec<u16> = attributes.iter().map(|x| b.utf8(x.0)).collect();
+    b.header(this_class, super_class);
+    b.body.extend(vec!(0, 0, 0, 0));
+    b.body.extend(be16(attributes.len() as u16));
+    let mut offsets = Vec::new();
+    for (name, (_, info)) in names.into_iter().zip(attributes.into_iter()) {
+        offsets.push(b.body_offset() + 6);
+        b.body.extend(attribute(name, info));
+    }
+    return (b.finish(), offsets);
+}
+
+#[test]
+fn class_format_attributes() {
+    // "LSample;" is constant 5 and "Sample" constant 1
+    let (data, _) = with_attributes(vec!(("Synthetic", Vec::new()), ("RuntimeVisibleAnnotations", vec!(0, 1, 0, 5, 0, 0))));
+    let class = read_class(&data).unwrap();
+    assert!(class.is_synthetic());
+    assert_eq!(class.annotations(true).len(), 1);
+    assert!(class.annotations(false).is_empty());
+
+    // Malformed annotations still load, keeping only their raw bytes
+    let mut nested = vec!(0, 1, 0, 5, 0, 1, 0, 5);
+    for _ in 0..300 {
+        nested.extend(vec!(b'[', 0, 1));
+    }
+    nested.extend(vec!(b'c', 0, 5));
+    let malformed = vec!(("RuntimeVisibleAnnotations", vec!(0, 1, 0, 1, 0, 0)),
+                         ("RuntimeInvisibleAnnotations", vec!(0, 1, 0, 5, 0, 0, 9)),
+                         ("RuntimeVisibleAnnotations", vec!(0, 1, 0, 5, 0, 1, 0, 5, b'I', 0, 1)),
+                         ("RuntimeVisibleAnnotations", nested));
+    for (name, info) in malformed {
+        let (data, _) = with_attributes(vec!((name, info.clone())));
+        let class = read_class(&data).unwrap();
+        assert!(class.annotations(true).is_empty() && class.annotations(false).is_empty());
+        match class.attributes[0] {
+            AttributeItem::RuntimeVisibleAnnotations{annotations: None, info: ref raw} |
+            AttributeItem::RuntimeInvisibleAnnotations{annotations: None, info: ref raw} => assert_eq!(raw, &info),
+            ref other => panic!("{} was kept as {:?}", name, other)
+        }
+    }
+
+    let (data, offsets) = with_attributes(vec!(("Synthetic", vec!(0))));
+    assert_eq!(format_error_at(&data).0, offsets[0] - 6);
+
+    let (data, offsets) = with_attributes(vec!(("BootstrapMethods", vec!(0, 1, 0, 5, 0, 0))));
+    assert_eq!(format_error_at(&data).0, offsets[0] + 2);
+}
+
+#[test]
+fn class_format_mutations() {
+    let (_runtime, class_path) = setup("formatted", r##"
+        public class formatted implements Runnable {
+            public static final long LIMIT = 1L << 40;
+            public static final String NAME = "formatted";
+            private double total;
+
+            public void run() {
+                try {
+                    total += Math.sqrt(LIMIT);
+                } catch (RuntimeException e) {
+                    total = -1;
+                }
+            }
+
+            public static int count(int[] values) throws Exception {
+                int sum = 0;
+                for (int v : values) {
+                    sum += v;
+                }
+                return sum;
+            }
+        }
+    "##, false);
+
+    let original = fs::read(class_path.as_path()).unwrap();
+    assert!(read_class(&original).is_ok());
+
+    let mut data = original.clone();
+    data[3] = 0xBF;
+    assert_eq!(format_error_at(&data).0, 0);
+
+    // Cutting the file short anywhere fails at or before the cut, and so does adding to it
+    for length in 0..original.len() {
+        let (offset, message) = format_error_at(&original[..length]);
+        assert!(offset <= length, "Cut at {} reported at {}: {}", length, offset, message);
+    }
+    let mut data = original.clone();
+    data.push(0);
+    assert_eq!(format_error_at(&data).0, original.len());
+
+    // Random damage must come back as a result, whatever it is
+    let mut rng: XorShiftRng = SeedableRng::from_seed([0x4a56_4d38, 4, 8, 0x0c1a_55f0]);
+    for _ in 0..20000 {
+        let mut data = original.clone();
+        for _ in 0..rng.gen_range(1, 4) {
+            let offset = rng.gen_range(8, data.len());
+            data[offset] = match rng.gen_range(0, 3) {
+                0 => 0,
+                1 => 0xFF,
+                _ => rng.gen(),
+            };
+        }
+        let _ = read_class(&data);
+    }
+}
+
+#[test]
+fn class_format_error_thrown() {
+    let (mut runtime, class_path) = setup("loader", r##"
+        public class loader {
+            public static int load() {
+                try {
+                    return new Broken().hashCode();
+                } catch (ClassFormatError e) {
+                    return e.getMessage().indexOf("at byte") > 0 ? 1 : 2;
+                }
+            }
+        }
+
+        class Broken {
+        }
+    "##, false);
+
+    let mut b = Builder::new();
+    let this_class = b.class("Broken");
+    let super_class = b.class("java/lang/Object");
+    b.header(this_class, super_class);
+    b.body.extend(vec!(0, 0, 0, 0, 0, 0, 0));
+    fs::write(class_path.with_file_name("Broken.class"), b.finish()).unwrap();
+
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "load"), 1);
+}
@@ -0,0 +1,106 @@
+mod common;
+use common::*;
+
+#[test]
+fn class_natives() {
+    let (mut runtime, class_path) = setup("class_natives", r##"
+        import java.io.Serializable;
+        import java.lang.reflect.Constructor;
+        import java.lang.reflect.Method;
+        import java.lang.reflect.Modifier;
+        import java.lang.reflect.ParameterizedType;
+        import java.util.AbstractList;
+
+        public class class_natives {
+            interface Shape extends Comparable<Shape> {
+            }
+
+            private static abstract class Square extends AbstractList<String> implements Shape, Serializable {
+            }
+
+            static class Counter {
+                private int start;
+
+                public Counter() { this(40); }
+                private Counter(int start) { this.start = start; }
+            }
+
+            static Class<?> local() {
+                class Local {
+                }
+                return Local.class;
+            }
+
+            static Object anonymous = new Object() { };
+
+            public static boolean interfaces() {
+                Class<?>[] interfaces = Square.class.getInterfaces();
+                Class<?>[] arrays = int[].class.getInterfaces();
+                return interfaces.length == 2 && interfaces[0] == Shape.class && interfaces[1] == Serializable.class &&
+                    Shape.class.getInterfaces()[0] == Comparable.class && int.class.getInterfaces().length == 0 &&
+                    arrays.length == 2 && arrays[0] == Cloneable.class && arrays[1] == Serializable.class;
+            }
+
+            public static boolean kinds() {
+                return Shape.class.isInterface() && !Square.class.isInterface() && !int.class.isInterface() &&
+                    Shape.class.getSuperclass() == null && Square.class.getSuperclass() == AbstractList.class &&
+                    int.class.getSuperclass() == null && String[].class.getSuperclass() == Object.class;
+            }
+
+            public static int constructors() throws Exception {
+                Constructor<Counter> make = Counter.class.getDeclaredConstructor(int.class);
+                make.setAccessible(true);
+                return Counter.class.getDeclaredConstructors().length * 100 + make.newInstance(7).start +
+                    Counter.class.getConstructor().newInstance().start;
+            }
+
+            public static int modifiers() {
+                return Square.class.getModifiers();
+            }
+
+            public static int arrayModifiers() {
+                return Square[][].class.getModifiers();
+            }
+
+            public static int primitiveArrayModifiers() {
+                return int[].class.getModifiers();
+            }
+
+            public static boolean nesting() {
+                Method method = local().getEnclosingMethod();
+                return Square.class.getDeclaringClass() == class_natives.class && class_natives.class.getDeclaringClass() == null &&
+                    local().getDeclaringClass() == null && local().getEnclosingClass() == class_natives.class &&
+                    method.getName().equals("local") && anonymous.getClass().getEnclosingMethod() == null &&
+                    anonymous.getClass().getEnclosingClass() == class_natives.class;
+            }
+
+            public static boolean names() {
+                return Square.class.getSimpleName().equals("Square") && local().getSimpleName().equals("Local") &&
+                    anonymous.getClass().getSimpleName().isEmpty() && anonymous.getClass().isAnonymousClass() &&
+                    Square.class.isMemberClass() && local().isLocalClass();
+            }
+
+            public static boolean generics() {
+                ParameterizedType superclass = (ParameterizedType) Square.class.getGenericSuperclass();
+                return superclass.getRawType() == AbstractList.class && superclass.getActualTypeArguments()[0] == String.class &&
+                    class_natives.class.getGenericSuperclass() == Object.class;
+            }
+
+            public static String describe() {
+                return Shape.class.toString() + ", " + Square.class + ", " + int.class;
+            }
+        }
+    "##, true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "interfaces"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "kinds"), true);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "constructors"), 200 + 7 + 40);
+    // private static abstract
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "modifiers"), 0x0002 | 0x0008 | 0x0400);
+    // private final abstract, then public final abstract
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "arrayModifiers"), 0x0002 | 0x0010 | 0x0400);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "primitiveArrayModifiers"), 0x0001 | 0x0010 | 0x0400);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "nesting"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "names"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "generics"), true);
+    assert_eq!(void_str_call(&mut runtime, class_path.as_path(), "describe"), "interface class_natives$Shape, class class_natives$Square, int");
+}
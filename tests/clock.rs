@@ -0,0 +1,93 @@
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::VirtualClock;
+
+#[test]
+fn virtual_clock() {
+    let (mut runtime, class_path) = setup("clocks", r##"
+        public class clocks {
+            public static long millis() {
+                return System.currentTimeMillis();
+            }
+
+            public static long nanos() {
+                return System.nanoTime();
+            }
+
+            public static long sleep(long millis) {
+                long start = System.nanoTime();
+                try {
+                    Thread.sleep(millis);
+                } catch (InterruptedException e) {
+                    return -1;
+                }
+                return System.nanoTime() - start;
+            }
+
+            public static boolean negativeSleep() {
+                try {
+                    Thread.sleep(-1);
+                } catch (IllegalArgumentException e) {
+                    return true;
+                } catch (InterruptedException e) {
+                }
+                return false;
+            }
+
+            public static long timeout(long millis) {
+                long deadline = System.currentTimeMillis() + millis;
+                long spins = 0;
+                while (System.currentTimeMillis() < deadline) {
+                    spins++;
+                }
+                return spins;
+            }
+        }
+    "##, false);
+    let clock = VirtualClock::new(1_500_000_000_000);
+    runtime.set_clock(clock.clone());
+
+    assert_eq!(void_long_call(&mut runtime, class_path.as_path(), "millis"), 1_500_000_000_000);
+    assert_eq!(void_long_call(&mut runtime, class_path.as_path(), "nanos"), 0);
+    clock.advance_millis(1500);
+    assert_eq!(void_long_call(&mut runtime, class_path.as_path(), "millis"), 1_500_000_001_500);
+    assert_eq!(void_long_call(&mut runtime, class_path.as_path(), "nanos"), 1_500_000_000);
+
+    assert_eq!(long_long_call(&mut runtime, class_path.as_path(), "sleep", 250), 250_000_000);
+    assert_eq!(clock.elapsed_nanos(), 1_750_000_000);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "negativeSleep"), true);
+
+    clock.set_step_nanos(1_000_000);
+    assert_eq!(long_long_call(&mut runtime, class_path.as_path(), "timeout", 10), 9);
+}
+
+#[test]
+fn perf_counter() {
+    let (mut runtime, class_path) = setup("perfs", r##"
+        import java.nio.ByteOrder;
+        import sun.misc.Perf;
+
+        public class perfs {
+            public static long frequency() {
+                return Perf.getPerf().highResFrequency();
+            }
+
+            public static long counter() {
+                return Perf.getPerf().highResCounter();
+            }
+
+            public static long created(long value) {
+                return Perf.getPerf().createLong("rjvm.test", 1, 1, value).order(ByteOrder.nativeOrder()).getLong(0);
+            }
+        }
+    "##, false);
+    let clock = VirtualClock::new(0);
+    clock.advance_nanos(12345);
+    runtime.set_clock(clock);
+
+    assert_eq!(void_long_call(&mut runtime, class_path.as_path(), "frequency"), 1_000_000_000);
+    assert_eq!(void_long_call(&mut runtime, class_path.as_path(), "counter"), 12345);
+    assert_eq!(long_long_call(&mut runtime, class_path.as_path(), "created", -7), -7);
+}
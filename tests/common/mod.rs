@@ -20,10 +20,17 @@ use std::io::Write;
 use std::process::Command;
 
 pub fn setup(classname: &str, source_body: &str, system_init: bool) -> (Runtime, PathBuf) {
+    return setup_with_options(classname, source_body, system_init, &[]);
+}
+
+pub fn setup_with_options(classname: &str, source_body: &str, system_init: bool, options: &[&str]) -> (Runtime, PathBuf) {
     let source = String::from(source_body);
 
     let mut hasher = DefaultHasher::new();
     source.hash(&mut hasher);
+    if !options.is_empty() {
+        options.hash(&mut hasher);
+    }
     let crc = hasher.finish();
     let mut temp_dir = env::temp_dir();
     temp_dir.push(format!("rjvm-test-{}", crc));
@@ -42,6 +49,7 @@ pub fn setup(classname: &str, source_body: &str, system_init: bool) -> (Runtime,
         file.write_all(source.as_bytes()).expect("Unable to write source file");
 
         let output = Command::new("javac")
+            .args(options)
             .args(&["-d", temp_dir.to_str().unwrap(), source_path.to_str().unwrap()])
             .output()
             .unwrap();
@@ -95,6 +103,10 @@ pub fn void_long_call(runtime: &mut Runtime, path: &Path, method: &str) -> i64 {
     return run_method(runtime, path, method, &Vec::new(), "J").to_long();
 }
 
+pub fn long_long_call(runtime: &mut Runtime, path: &Path, method: &str, arg: i64) -> i64 {
+    return run_method(runtime, path, method, &vec!(Variable::Long(arg)), "J").to_long();
+}
+
 pub fn long2_long_call(runtime: &mut Runtime, path: &Path, method: &str, arg: i64, arg2: i64) -> i64 {
     return run_method(runtime, path, method, &vec!(Variable::Long(arg), Variable::Long(arg2)), "J").to_long();
 }
@@ -111,6 +123,10 @@ pub fn double2_double_call(runtime: &mut Runtime, path: &Path, method: &str, arg
     return run_method(runtime, path, method, &vec!(Variable::Double(arg), Variable::Double(arg2)), "D").to_double();
 }
 
+pub fn double_int_double_call(runtime: &mut Runtime, path: &Path, method: &str, arg: f64, arg2: i32) -> f64 {
+    return run_method(runtime, path, method, &vec!(Variable::Double(arg), Variable::Int(arg2)), "D").to_double();
+}
+
 pub fn str_void_call(runtime: &mut Runtime, path: &Path, method: &str, arg: &str) {
     let argvar = make_string(runtime, arg).expect("Couldn't create string for argument");
     run_method(runtime, path, method, &vec!(argvar), "V");
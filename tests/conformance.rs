@@ -0,0 +1,1435 @@
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::{ArrayHandle, extract_int_array};
+
+// Expected values come from running the same class on HotSpot (OpenJDK 17)
+const INT_BINARY: [i32; 2816] = [
+    0, 0, 0, 195890671, 195890671, 0, 0, 0,
+    0, 0, 0, 1, -1, 0, 0, 0,
+    0, 0, 0, 0, 1, 1, -1, 1,
+    0, 0, 0, 0, 0, 0, 0, -1,
+    -1, 2, -2, 0, 0, 0, 0, 0,
+    0, 0, 2, 2, -7, 7, 0, 0,
+    0, 0, 0, 0, 0, -7, -7, 31,
+    -31, 0, 0, 0, 0, 0, 0, 0,
+    31, 31, 32, -32, 0, 0, 0, 0,
+    0, 0, 0, 32, 32, 33, -33, 0,
+    0, 0, 0, 0, 0, 0, 33, 33,
+    63, -63, 0, 0, 0, 0, 0, 0,
+    0, 63, 63, 64, -64, 0, 0, 0,
+    0, 0, 0, 0, 64, 64, 127, -127,
+    0, 0, 0, 0, 0, 0, 0, 127,
+    127, -128, 128, 0, 0, 0, 0, 0,
+    0, 0, -128, -128, 65535, -65535, 0, 0,
+    0, 0, 0, 0, 0, 65535, 65535, 305419896,
+    -305419896, 0, 0, 0, 0, 0, 0, 0,
+    305419896, 305419896, 2147483647, -2147483647, 0, 0, 0, 0,
+    0, 0, 0, 2147483647, 2147483647, -2147483648, -2147483648, 0,
+    0, 0, 0, 0, 0, 0, -2147483648, -2147483648,
+    1, 1, 0, 195890671, 195890671, 1, 1, 1,
+    0, 1, 1, 2, 0, 1, 1, 0,
+    2, 0, 0, 1, 1, 0, 0, 2,
+    -1, -1, 0, -2147483648, 0, 0, 1, -1,
+    -2, 3, -1, 2, 0, 1, 4, 0,
+    0, 0, 3, 3, -6, 8, -7, 0,
+    1, 33554432, 0, 0, 1, -7, -8, 32,
+    -30, 31, 0, 1, -2147483648, 0, 0, 1,
+    31, 30, 33, -31, 32, 0, 1, 1,
+    1, 1, 0, 33, 33, 34, -32, 33,
+    0, 1, 2, 0, 0, 1, 33, 32,
+    64, -62, 63, 0, 1, -2147483648, 0, 0,
+    1, 63, 62, 65, -63, 64, 0, 1,
+    1, 1, 1, 0, 65, 65, 128, -126,
+    127, 0, 1, -2147483648, 0, 0, 1, 127,
+    126, -127, 129, -128, 0, 1, 1, 1,
+    1, 0, -127, -127, 65536, -65534, 65535, 0,
+    1, -2147483648, 0, 0, 1, 65535, 65534, 305419897,
+    -305419895, 305419896, 0, 1, 16777216, 0, 0, 0,
+    305419897, 305419897, -2147483648, -2147483646, 2147483647, 0, 1, -2147483648,
+    0, 0, 1, 2147483647, 2147483646, -2147483647, -2147483647, -2147483648,
+    0, 1, 1, 1, 1, 0, -2147483647, -2147483647,
+    -1, -1, 0, 195890671, 195890671, -1, -1, -1,
+    0, -1, -1, 0, -2, -1, -1, 0,
+    -2, -1, 2147483647, 1, -1, -2, -2, 0,
+    1, 1, 0, -2147483648, -1, 1, -1, -1,
+    0, 1, -3, -2, 0, -1, -4, -1,
+    1073741823, 2, -1, -3, -8, 6, 7, 0,
+    -1, -33554432, -1, 127, -7, -1, 6, 30,
+    -32, -31, 0, -1, -2147483648, -1, 1, 31,
+    -1, -32, 31, -33, -32, 0, -1, -1,
+    -1, -1, 32, -1, -33, 32, -34, -33,
+    0, -1, -2, -1, 2147483647, 33, -1, -34,
+    62, -64, -63, 0, -1, -2147483648, -1, 1,
+    63, -1, -64, 63, -65, -64, 0, -1,
+    -1, -1, -1, 64, -1, -65, 126, -128,
+    -127, 0, -1, -2147483648, -1, 1, 127, -1,
+    -128, -129, 127, 128, 0, -1, -1, -1,
+    -1, -128, -1, 127, 65534, -65536, -65535, 0,
+    -1, -2147483648, -1, 1, 65535, -1, -65536, 305419895,
+    -305419897, -305419896, 0, -1, -16777216, -1, 255, 305419896,
+    -1, -305419897, 2147483646, -2147483648, -2147483647, 0, -1, -2147483648,
+    -1, 1, 2147483647, -1, -2147483648, 2147483647, 2147483647, -2147483648,
+    0, -1, -1, -1, -1, -2147483648, -1, 2147483647,
+    2, 2, 0, 195890671, 195890671, 2, 2, 2,
+    0, 2, 2, 3, 1, 2, 2, 0,
+    4, 1, 1, 0, 3, 3, 1, 3,
+    -2, -2, 0, 0, 0, 0, 2, -1,
+    -3, 4, 0, 4, 1, 0, 8, 0,
+    0, 2, 2, 0, -5, 9, -14, 0,
+    2, 67108864, 0, 0, 0, -5, -5, 33,
+    -29, 62, 0, 2, 0, 0, 0, 2,
+    31, 29, 34, -30, 64, 0, 2, 2,
+    2, 2, 0, 34, 34, 35, -31, 66,
+    0, 2, 4, 1, 1, 0, 35, 35,
+    65, -61, 126, 0, 2, 0, 0, 0,
+    2, 63, 61, 66, -62, 128, 0, 2,
+    2, 2, 2, 0, 66, 66, 129, -125,
+    254, 0, 2, 0, 0, 0, 2, 127,
+    125, -126, 130, -256, 0, 2, 2, 2,
+    2, 0, -126, -126, 65537, -65533, 131070, 0,
+    2, 0, 0, 0, 2, 65535, 65533, 305419898,
+    -305419894, 610839792, 0, 2, 33554432, 0, 0, 0,
+    305419898, 305419898, -2147483647, -2147483645, -2, 0, 2, 0,
+    0, 0, 2, 2147483647, 2147483645, -2147483646, -2147483646, 0,
+    0, 2, 2, 2, 2, 0, -2147483646, -2147483646,
+    -7, -7, 0, 195890671, 195890671, -7, -7, -7,
+    0, -7, -7, -6, -8, -7, -7, 0,
+    -14, -4, 2147483644, 1, -7, -8, -8, -6,
+    7, 7, 0, -2147483648, -1, 1, -7, -1,
+    6, -5, -9, -14, -3, -1, -28, -2,
+    1073741822, 0, -5, -5, -14, 0, 49, 1,
+    0, -234881024, -1, 127, -7, -7, 0, 24,
+    -38, -217, 0, -7, -2147483648, -1, 1, 25,
+    -1, -26, 25, -39, -224, 0, -7, -7,
+    -7, -7, 32, -7, -39, 26, -40, -231,
+    0, -7, -14, -4, 2147483644, 33, -7, -40,
+    56, -70, -441, 0, -7, -2147483648, -1, 1,
+    57, -1, -58, 57, -71, -448, 0, -7,
+    -7, -7, -7, 64, -7, -71, 120, -134,
+    -889, 0, -7, -2147483648, -1, 1, 121, -1,
+    -122, -135, 121, 896, 0, -7, -7, -7,
+    -7, -128, -7, 121, 65528, -65542, -458745, 0,
+    -7, -2147483648, -1, 1, 65529, -1, -65530, 305419889,
+    -305419903, -2137939272, 0, -7, -117440512, -1, 255, 305419896,
+    -7, -305419903, 2147483640, 2147483642, -2147483641, 0, -7, -2147483648,
+    -1, 1, 2147483641, -1, -2147483642, 2147483641, 2147483641, -2147483648,
+    0, -7, -7, -7, -7, -2147483648, -7, 2147483641,
+    31, 31, 0, 195890671, 195890671, 31, 31, 31,
+    0, 31, 31, 32, 30, 31, 31, 0,
+    62, 15, 15, 1, 31, 30, 30, 32,
+    -31, -31, 0, -2147483648, 0, 0, 31, -1,
+    -32, 33, 29, 62, 15, 1, 124, 7,
+    7, 2, 31, 29, 24, 38, -217, -4,
+    3, 1040187392, 0, 0, 25, -1, -26, 62,
+    0, 961, 1, 0, -2147483648, 0, 0, 31,
+    31, 0, 63, -1, 992, 0, 31, 31,
+    31, 31, 0, 63, 63, 64, -2, 1023,
+    0, 31, 62, 15, 15, 1, 63, 62,
+    94, -32, 1953, 0, 31, -2147483648, 0, 0,
+    31, 63, 32, 95, -33, 1984, 0, 31,
+    31, 31, 31, 0, 95, 95, 158, -96,
+    3937, 0, 31, -2147483648, 0, 0, 31, 127,
+    96, -97, 159, -3968, 0, 31, 31, 31,
+    31, 0, -97, -97, 65566, -65504, 2031585, 0,
+    31, -2147483648, 0, 0, 31, 65535, 65504, 305419927,
+    -305419865, 878082184, 0, 31, 520093696, 0, 0, 24,
+    305419903, 305419879, -2147483618, -2147483616, 2147483617, 0, 31, -2147483648,
+    0, 0, 31, 2147483647, 2147483616, -2147483617, -2147483617, -2147483648,
+    0, 31, 31, 31, 31, 0, -2147483617, -2147483617,
+    32, 32, 0, 195890671, 195890671, 32, 32, 32,
+    0, 32, 32, 33, 31, 32, 32, 0,
+    64, 16, 16, 0, 33, 33, 31, 33,
+    -32, -32, 0, 0, 0, 0, 32, -1,
+    -33, 34, 30, 64, 16, 0, 128, 8,
+    8, 0, 34, 34, 25, 39, -224, -4,
+    4, 1073741824, 0, 0, 32, -7, -39, 63,
+    1, 992, 1, 1, 0, 0, 0, 0,
+    63, 63, 64, 0, 1024, 1, 0, 32,
+    32, 32, 32, 32, 0, 65, -1, 1056,
+    0, 32, 64, 16, 16, 32, 33, 1,
+    95, -31, 2016, 0, 32, 0, 0, 0,
+    32, 63, 31, 96, -32, 2048, 0, 32,
+    32, 32, 32, 0, 96, 96, 159, -95,
+    4064, 0, 32, 0, 0, 0, 32, 127,
+    95, -96, 160, -4096, 0, 32, 32, 32,
+    32, 0, -96, -96, 65567, -65503, 2097120, 0,
+    32, 0, 0, 0, 32, 65535, 65503, 305419928,
+    -305419864, 1183502080, 0, 32, 536870912, 0, 0, 32,
+    305419896, 305419864, -2147483617, -2147483615, -32, 0, 32, 0,
+    0, 0, 32, 2147483647, 2147483615, -2147483616, -2147483616, 0,
+    0, 32, 32, 32, 32, 0, -2147483616, -2147483616,
+    33, 33, 0, 195890671, 195890671, 33, 33, 33,
+    0, 33, 33, 34, 32, 33, 33, 0,
+    66, 16, 16, 1, 33, 32, 32, 34,
+    -33, -33, 0, -2147483648, 0, 0, 33, -1,
+    -34, 35, 31, 66, 16, 1, 132, 8,
+    8, 0, 35, 35, 26, 40, -231, -4,
+    5, 1107296256, 0, 0, 33, -7, -40, 64,
+    2, 1023, 1, 2, -2147483648, 0, 0, 1,
+    63, 62, 65, 1, 1056, 1, 1, 33,
+    33, 33, 32, 33, 1, 66, 0, 1089,
+    1, 0, 66, 16, 16, 33, 33, 0,
+    96, -30, 2079, 0, 33, -2147483648, 0, 0,
+    33, 63, 30, 97, -31, 2112, 0, 33,
+    33, 33, 33, 0, 97, 97, 160, -94,
+    4191, 0, 33, -2147483648, 0, 0, 33, 127,
+    94, -95, 161, -4224, 0, 33, 33, 33,
+    33, 0, -95, -95, 65568, -65502, 2162655, 0,
+    33, -2147483648, 0, 0, 33, 65535, 65502, 305419929,
+    -305419863, 1488921976, 0, 33, 553648128, 0, 0, 32,
+    305419897, 305419865, -2147483616, -2147483614, 2147483615, 0, 33, -2147483648,
+    0, 0, 33, 2147483647, 2147483614, -2147483615, -2147483615, -2147483648,
+    0, 33, 33, 33, 33, 0, -2147483615, -2147483615,
+    63, 63, 0, 195890671, 195890671, 63, 63, 63,
+    0, 63, 63, 64, 62, 63, 63, 0,
+    126, 31, 31, 1, 63, 62, 62, 64,
+    -63, -63, 0, -2147483648, 0, 0, 63, -1,
+    -64, 65, 61, 126, 31, 1, 252, 15,
+    15, 2, 63, 61, 56, 70, -441, -9,
+    0, 2113929216, 0, 0, 57, -1, -58, 94,
+    32, 1953, 2, 1, -2147483648, 0, 0, 31,
+    63, 32, 95, 31, 2016, 1, 31, 63,
+    63, 63, 32, 63, 31, 96, 30, 2079,
+    1, 30, 126, 31, 31, 33, 63, 30,
+    126, 0, 3969, 1, 0, -2147483648, 0, 0,
+    63, 63, 0, 127, -1, 4032, 0, 63,
+    63, 63, 63, 0, 127, 127, 190, -64,
+    8001, 0, 63, -2147483648, 0, 0, 63, 127,
+    64, -65, 191, -8064, 0, 63, 63, 63,
+    63, 0, -65, -65, 65598, -65472, 4128705, 0,
+    63, -2147483648, 0, 0, 63, 65535, 65472, 305419959,
+    -305419833, 2061584264, 0, 63, 1056964608, 0, 0, 56,
+    305419903, 305419847, -2147483586, -2147483584, 2147483585, 0, 63, -2147483648,
+    0, 0, 63, 2147483647, 2147483584, -2147483585, -2147483585, -2147483648,
+    0, 63, 63, 63, 63, 0, -2147483585, -2147483585,
+    64, 64, 0, 195890671, 195890671, 64, 64, 64,
+    0, 64, 64, 65, 63, 64, 64, 0,
+    128, 32, 32, 0, 65, 65, 63, 65,
+    -64, -64, 0, 0, 0, 0, 64, -1,
+    -65, 66, 62, 128, 32, 0, 256, 16,
+    16, 0, 66, 66, 57, 71, -448, -9,
+    1, -2147483648, 0, 0, 64, -7, -71, 95,
+    33, 1984, 2, 2, 0, 0, 0, 0,
+    95, 95, 96, 32, 2048, 2, 0, 64,
+    64, 64, 0, 96, 96, 97, 31, 2112,
+    1, 31, 128, 32, 32, 0, 97, 97,
+    127, 1, 4032, 1, 1, 0, 0, 0,
+    0, 127, 127, 128, 0, 4096, 1, 0,
+    64, 64, 64, 64, 64, 0, 191, -63,
+    8128, 0, 64, 0, 0, 0, 64, 127,
+    63, -64, 192, -8192, 0, 64, 64, 64,
+    64, 0, -64, -64, 65599, -65471, 4194240, 0,
+    64, 0, 0, 0, 64, 65535, 65471, 305419960,
+    -305419832, -1927963136, 0, 64, 1073741824, 0, 0, 64,
+    305419896, 305419832, -2147483585, -2147483583, -64, 0, 64, 0,
+    0, 0, 64, 2147483647, 2147483583, -2147483584, -2147483584, 0,
+    0, 64, 64, 64, 64, 0, -2147483584, -2147483584,
+    127, 127, 0, 195890671, 195890671, 127, 127, 127,
+    0, 127, 127, 128, 126, 127, 127, 0,
+    254, 63, 63, 1, 127, 126, 126, 128,
+    -127, -127, 0, -2147483648, 0, 0, 127, -1,
+    -128, 129, 125, 254, 63, 1, 508, 31,
+    31, 2, 127, 125, 120, 134, -889, -18,
+    1, -33554432, 0, 0, 121, -1, -122, 158,
+    96, 3937, 4, 3, -2147483648, 0, 0, 31,
+    127, 96, 159, 95, 4064, 3, 31, 127,
+    127, 127, 32, 127, 95, 160, 94, 4191,
+    3, 28, 254, 63, 63, 33, 127, 94,
+    190, 64, 8001, 2, 1, -2147483648, 0, 0,
+    63, 127, 64, 191, 63, 8128, 1, 63,
+    127, 127, 127, 64, 127, 63, 254, 0,
+    16129, 1, 0, -2147483648, 0, 0, 127, 127,
+    0, -1, 255, -16256, 0, 127, 127, 127,
+    127, 0, -1, -1, 65662, -65408, 8322945, 0,
+    127, -2147483648, 0, 0, 127, 65535, 65408, 305420023,
+    -305419769, 133621128, 0, 127, 2130706432, 0, 0, 120,
+    305419903, 305419783, -2147483522, -2147483520, 2147483521, 0, 127, -2147483648,
+    0, 0, 127, 2147483647, 2147483520, -2147483521, -2147483521, -2147483648,
+    0, 127, 127, 127, 127, 0, -2147483521, -2147483521,
+    -128, -128, 0, 195890671, 195890671, -128, -128, -128,
+    0, -128, -128, -127, -129, -128, -128, 0,
+    -256, -64, 2147483584, 0, -127, -127, -129, -127,
+    128, 128, 0, 0, -1, 1, -128, -1,
+    127, -126, -130, -256, -64, 0, -512, -32,
+    1073741792, 0, -126, -126, -135, -121, 896, 18,
+    -2, 0, -1, 127, -128, -7, 121, -97,
+    -159, -3968, -4, -4, 0, -1, 1, 0,
+    -97, -97, -96, -160, -4096, -4, 0, -128,
+    -128, -128, 0, -96, -96, -95, -161, -4224,
+    -3, -29, -256, -64, 2147483584, 0, -95, -95,
+    -65, -191, -8064, -2, -2, 0, -1, 1,
+    0, -65, -65, -64, -192, -8192, -2, 0,
+    -128, -128, -128, 0, -64, -64, -1, -255,
+    -16256, -1, -1, 0, -1, 1, 0, -1,
+    -1, -256, 0, 16384, 1, 0, -128, -128,
+    -128, -128, -128, 0, 65407, -65663, -8388480, 0,
+    -128, 0, -1, 1, 65408, -1, -65409, 305419768,
+    -305420024, -439041024, 0, -128, -2147483648, -1, 255, 305419776,
+    -8, -305419784, 2147483519, 2147483521, 128, 0, -128, 0,
+    -1, 1, 2147483520, -1, -2147483521, 2147483520, 2147483520, 0,
+    0, -128, -128, -128, -128, -2147483648, -128, 2147483520,
+    65535, 65535, 0, 195890671, 195890671, 65535, 65535, 65535,
+    0, 65535, 65535, 65536, 65534, 65535, 65535, 0,
+    131070, 32767, 32767, 1, 65535, 65534, 65534, 65536,
+    -65535, -65535, 0, -2147483648, 0, 0, 65535, -1,
+    -65536, 65537, 65533, 131070, 32767, 1, 262140, 16383,
+    16383, 2, 65535, 65533, 65528, 65542, -458745, -9362,
+    1, -33554432, 0, 0, 65529, -1, -65530, 65566,
+    65504, 2031585, 2114, 1, -2147483648, 0, 0, 31,
+    65535, 65504, 65567, 65503, 2097120, 2047, 31, 65535,
+    65535, 65535, 32, 65535, 65503, 65568, 65502, 2162655,
+    1985, 30, 131070, 32767, 32767, 33, 65535, 65502,
+    65598, 65472, 4128705, 1040, 15, -2147483648, 0, 0,
+    63, 65535, 65472, 65599, 65471, 4194240, 1023, 63,
+    65535, 65535, 65535, 64, 65535, 65471, 65662, 65408,
+    8322945, 516, 3, -2147483648, 0, 0, 127, 65535,
+    65408, 65407, 65663, -8388480, -511, 127, 65535, 65535,
+    65535, 65408, -1, -65409, 131070, 0, -131071, 1,
+    0, -2147483648, 0, 0, 65535, 65535, 0, 305485431,
+    -305354361, 1145285000, 0, 65535, -16777216, 0, 0, 22136,
+    305463295, 305441159, -2147418114, -2147418112, 2147418113, 0, 65535, -2147483648,
+    0, 0, 65535, 2147483647, 2147418112, -2147418113, -2147418113, -2147483648,
+    0, 65535, 65535, 65535, 65535, 0, -2147418113, -2147418113,
+    305419896, 305419896, 0, 195890671, 195890671, 305419896, 305419896, 305419896,
+    0, 305419896, 305419896, 305419897, 305419895, 305419896, 305419896, 0,
+    610839792, 152709948, 152709948, 0, 305419897, 305419897, 305419895, 305419897,
+    -305419896, -305419896, 0, 0, 0, 0, 305419896, -1,
+    -305419897, 305419898, 305419894, 610839792, 152709948, 0, 1221679584, 76354974,
+    76354974, 0, 305419898, 305419898, 305419889, 305419903, -2137939272, -43631413,
+    5, -268435456, 9, 9, 305419896, -7, -305419903, 305419927,
+    305419865, 878082184, 9852254, 22, 0, 0, 0, 24,
+    305419903, 305419879, 305419928, 305419864, 1183502080, 9544371, 24, 305419896,
+    305419896, 305419896, 32, 305419896, 305419864, 305419929, 305419863, 1488921976,
+    9255148, 12, 610839792, 152709948, 152709948, 32, 305419897, 305419865,
+    305419959, 305419833, 2061584264, 4847934, 54, 0, 0, 0,
+    56, 305419903, 305419847, 305419960, 305419832, -1927963136, 4772185, 56,
+    305419896, 305419896, 305419896, 64, 305419896, 305419832, 305420023, 305419769,
+    133621128, 2404881, 9, 0, 0, 0, 120, 305419903,
+    305419783, 305419768, 305420024, -439041024, -2386092, 120, 305419896, 305419896,
+    305419896, 305419776, -8, -305419784, 305485431, 305354361, 1145285000, 4660,
+    26796, 0, 0, 0, 22136, 305463295, 305441159, 610839792,
+    0, 502585408, 1, 0, 2013265920, 18, 18, 305419896,
+    305419896, 0, -1842063753, -1842063751, -305419896, 0, 305419896, 0,
+    0, 0, 305419896, 2147483647, 1842063751, -1842063752, -1842063752, 0,
+    0, 305419896, 305419896, 305419896, 305419896, 0, -1842063752, -1842063752,
+    2147483647, 2147483647, 0, 195890671, 195890671, 2147483647, 2147483647, 2147483647,
+    0, 2147483647, 2147483647, -2147483648, 2147483646, 2147483647, 2147483647, 0,
+    -2, 1073741823, 1073741823, 1, 2147483647, 2147483646, 2147483646, -2147483648,
+    -2147483647, -2147483647, 0, -2147483648, 0, 0, 2147483647, -1,
+    -2147483648, -2147483647, 2147483645, -2, 1073741823, 1, -4, 536870911,
+    536870911, 2, 2147483647, 2147483645, 2147483640, -2147483642, -2147483641, -306783378,
+    1, -33554432, 63, 63, 2147483641, -1, -2147483642, -2147483618,
+    2147483616, 2147483617, 69273666, 1, -2147483648, 0, 0, 31,
+    2147483647, 2147483616, -2147483617, 2147483615, -32, 67108863, 31, 2147483647,
+    2147483647, 2147483647, 32, 2147483647, 2147483615, -2147483616, 2147483614, 2147483615,
+    65075262, 1, -2, 1073741823, 1073741823, 33, 2147483647, 2147483614,
+    -2147483586, 2147483584, 2147483585, 34087042, 1, -2147483648, 0, 0,
+    63, 2147483647, 2147483584, -2147483585, 2147483583, -64, 33554431, 63,
+    2147483647, 2147483647, 2147483647, 64, 2147483647, 2147483583, -2147483522, 2147483520,
+    2147483521, 16909320, 7, -2147483648, 0, 0, 127, 2147483647,
+    2147483520, 2147483519, -2147483521, 128, -16777215, 127, 2147483647, 2147483647,
+    2147483647, 2147483520, -1, -2147483521, -2147418114, 2147418112, 2147418113, 32768,
+    32767, -2147483648, 0, 0, 65535, 2147483647, 2147418112, -1842063753,
+    1842063751, -305419896, 7, 9544375, -16777216, 127, 127, 305419896,
+    2147483647, 1842063751, -2, 0, 1, 1, 0, -2147483648,
+    0, 0, 2147483647, 2147483647, 0, -1, -1, -2147483648,
+    0, 2147483647, 2147483647, 2147483647, 2147483647, 0, -1, -1,
+    -2147483648, -2147483648, 0, 195890671, 195890671, -2147483648, -2147483648, -2147483648,
+    0, -2147483648, -2147483648, -2147483647, 2147483647, -2147483648, -2147483648, 0,
+    0, -1073741824, 1073741824, 0, -2147483647, -2147483647, 2147483647, -2147483647,
+    -2147483648, -2147483648, 0, 0, -1, 1, -2147483648, -1,
+    2147483647, -2147483646, 2147483646, 0, -1073741824, 0, 0, -536870912,
+    536870912, 0, -2147483646, -2147483646, 2147483641, -2147483641, -2147483648, 306783378,
+    -2, 0, -64, 64, -2147483648, -7, 2147483641, -2147483617,
+    2147483617, -2147483648, -69273666, -2, 0, -1, 1, 0,
+    -2147483617, -2147483617, -2147483616, 2147483616, 0, -67108864, 0, -2147483648,
+    -2147483648, -2147483648, 0, -2147483616, -2147483616, -2147483615, 2147483615, -2147483648,
+    -65075262, -2, 0, -1073741824, 1073741824, 0, -2147483615, -2147483615,
+    -2147483585, 2147483585, -2147483648, -34087042, -2, 0, -1, 1,
+    0, -2147483585, -2147483585, -2147483584, 2147483584, 0, -33554432, 0,
+    -2147483648, -2147483648, -2147483648, 0, -2147483584, -2147483584, -2147483521, 2147483521,
+    -2147483648, -16909320, -8, 0, -1, 1, 0, -2147483521,
+    -2147483521, 2147483520, -2147483520, 0, 16777216, 0, -2147483648, -2147483648,
+    -2147483648, -2147483648, -128, 2147483520, -2147418113, 2147418113, -2147483648, -32768,
+    -32768, 0, -1, 1, 0, -2147418113, -2147418113, -1842063752,
+    1842063752, 0, -7, -9544376, 0, -128, 128, 0,
+    -1842063752, -1842063752, -1, 1, -2147483648, -1, -1, 0,
+    -1, 1, 0, -1, -1, 0, 0, 0,
+    1, 0, -2147483648, -2147483648, -2147483648, -2147483648, -2147483648, 0
+];
+
+const LONG_BINARY: [i64; 3072] = [
+    0, 0, 0, 195890671, 195890671, 0, 0, 0,
+    0, 0, 0, 0, 1, -1, 0, 0,
+    0, 0, 0, 0, 0, 1, 1, -1,
+    -1, 1, 0, 0, 0, 0, 0, 0,
+    0, -1, -1, 1, 3, -3, 0, 0,
+    0, 0, 0, 0, 0, 3, 3, -1,
+    -7, 7, 0, 0, 0, 0, 0, 0,
+    0, -7, -7, 1, 31, -31, 0, 0,
+    0, 0, 0, 0, 0, 31, 31, -1,
+    32, -32, 0, 0, 0, 0, 0, 0,
+    0, 32, 32, -1, 63, -63, 0, 0,
+    0, 0, 0, 0, 0, 63, 63, -1,
+    64, -64, 0, 0, 0, 0, 0, 0,
+    0, 64, 64, -1, 65, -65, 0, 0,
+    0, 0, 0, 0, 0, 65, 65, -1,
+    4294967295, -4294967295, 0, 0, 0, 0, 0, 0,
+    0, 4294967295, 4294967295, -1, 2147483648, -2147483648, 0, 0,
+    0, 0, 0, 0, 0, 2147483648, 2147483648, -1,
+    -2147483648, 2147483648, 0, 0, 0, 0, 0, 0,
+    0, -2147483648, -2147483648, 1, 81985529216486895, -81985529216486895, 0, 0,
+    0, 0, 0, 0, 0, 81985529216486895, 81985529216486895, -1,
+    9223372036854775807, -9223372036854775807, 0, 0, 0, 0, 0, 0,
+    0, 9223372036854775807, 9223372036854775807, -1, -9223372036854775808, -9223372036854775808, 0, 0,
+    0, 0, 0, 0, 0, -9223372036854775808, -9223372036854775808, 1,
+    1, 1, 0, 195890671, 195890671, 1, 1, 1,
+    0, 1, 1, 1, 2, 0, 1, 1,
+    0, 2, 0, 0, 1, 1, 0, 0,
+    0, 2, -1, -1, 0, -9223372036854775808, 0, 0,
+    1, -1, -2, 1, 4, -2, 3, 0,
+    1, 8, 0, 0, 1, 3, 2, -1,
+    -6, 8, -7, 0, 1, 144115188075855872, 0, 0,
+    1, -7, -8, 1, 32, -30, 31, 0,
+    1, 2147483648, 0, 0, 1, 31, 30, -1,
+    33, -31, 32, 0, 1, 4294967296, 0, 0,
+    0, 33, 33, -1, 64, -62, 63, 0,
+    1, -9223372036854775808, 0, 0, 1, 63, 62, -1,
+    65, -63, 64, 0, 1, 1, 1, 1,
+    0, 65, 65, -1, 66, -64, 65, 0,
+    1, 2, 0, 0, 1, 65, 64, -1,
+    4294967296, -4294967294, 4294967295, 0, 1, -9223372036854775808, 0, 0,
+    1, 4294967295, 4294967294, -1, 2147483649, -2147483647, 2147483648, 0,
+    1, 1, 1, 1, 0, 2147483649, 2147483649, -1,
+    -2147483647, 2147483649, -2147483648, 0, 1, 1, 1, 1,
+    0, -2147483647, -2147483647, 1, 81985529216486896, -81985529216486894, 81985529216486895, 0,
+    1, 140737488355328, 0, 0, 1, 81985529216486895, 81985529216486894, -1,
+    -9223372036854775808, -9223372036854775806, 9223372036854775807, 0, 1, -9223372036854775808, 0, 0,
+    1, 9223372036854775807, 9223372036854775806, -1, -9223372036854775807, -9223372036854775807, -9223372036854775808, 0,
+    1, 1, 1, 1, 0, -9223372036854775807, -9223372036854775807, 1,
+    -1, -1, 0, 195890671, 195890671, -1, -1, -1,
+    0, -1, -1, -1, 0, -2, -1, -1,
+    0, -2, -1, 9223372036854775807, 1, -1, -2, -1,
+    -2, 0, 1, 1, 0, -9223372036854775808, -1, 1,
+    -1, -1, 0, 0, 2, -4, -3, 0,
+    -1, -8, -1, 2305843009213693951, 3, -1, -4, -1,
+    -8, 6, 7, 0, -1, -144115188075855872, -1, 127,
+    -7, -1, 6, 1, 30, -32, -31, 0,
+    -1, -2147483648, -1, 8589934591, 31, -1, -32, -1,
+    31, -33, -32, 0, -1, -4294967296, -1, 4294967295,
+    32, -1, -33, -1, 62, -64, -63, 0,
+    -1, -9223372036854775808, -1, 1, 63, -1, -64, -1,
+    63, -65, -64, 0, -1, -1, -1, -1,
+    64, -1, -65, -1, 64, -66, -65, 0,
+    -1, -2, -1, 9223372036854775807, 65, -1, -66, -1,
+    4294967294, -4294967296, -4294967295, 0, -1, -9223372036854775808, -1, 1,
+    4294967295, -1, -4294967296, -1, 2147483647, -2147483649, -2147483648, 0,
+    -1, -1, -1, -1, 2147483648, -1, -2147483649, -1,
+    -2147483649, 2147483647, 2147483648, 0, -1, -1, -1, -1,
+    -2147483648, -1, 2147483647, 1, 81985529216486894, -81985529216486896, -81985529216486895, 0,
+    -1, -140737488355328, -1, 131071, 81985529216486895, -1, -81985529216486896, -1,
+    9223372036854775806, -9223372036854775808, -9223372036854775807, 0, -1, -9223372036854775808, -1, 1,
+    9223372036854775807, -1, -9223372036854775808, -1, 9223372036854775807, 9223372036854775807, -9223372036854775808, 0,
+    -1, -1, -1, -1, -9223372036854775808, -1, 9223372036854775807, 1,
+    3, 3, 0, 195890671, 195890671, 3, 3, 3,
+    0, 3, 3, 1, 4, 2, 3, 3,
+    0, 6, 1, 1, 1, 3, 2, 1,
+    2, 4, -3, -3, 0, -9223372036854775808, 0, 0,
+    3, -1, -4, 1, 6, 0, 9, 1,
+    0, 24, 0, 0, 3, 3, 0, 0,
+    -4, 10, -21, 0, 3, 432345564227567616, 0, 0,
+    1, -5, -6, 1, 34, -28, 93, 0,
+    3, 6442450944, 0, 0, 3, 31, 28, -1,
+    35, -29, 96, 0, 3, 12884901888, 0, 0,
+    0, 35, 35, -1, 66, -60, 189, 0,
+    3, -9223372036854775808, 0, 0, 3, 63, 60, -1,
+    67, -61, 192, 0, 3, 3, 3, 3,
+    0, 67, 67, -1, 68, -62, 195, 0,
+    3, 6, 1, 1, 1, 67, 66, -1,
+    4294967298, -4294967292, 12884901885, 0, 3, -9223372036854775808, 0, 0,
+    3, 4294967295, 4294967292, -1, 2147483651, -2147483645, 6442450944, 0,
+    3, 3, 3, 3, 0, 2147483651, 2147483651, -1,
+    -2147483645, 2147483651, -6442450944, 0, 3, 3, 3, 3,
+    0, -2147483645, -2147483645, 1, 81985529216486898, -81985529216486892, 245956587649460685, 0,
+    3, 422212465065984, 0, 0, 3, 81985529216486895, 81985529216486892, -1,
+    -9223372036854775806, -9223372036854775804, 9223372036854775805, 0, 3, -9223372036854775808, 0, 0,
+    3, 9223372036854775807, 9223372036854775804, -1, -9223372036854775805, -9223372036854775805, -9223372036854775808, 0,
+    3, 3, 3, 3, 0, -9223372036854775805, -9223372036854775805, 1,
+    -7, -7, 0, 195890671, 195890671, -7, -7, -7,
+    0, -7, -7, -1, -6, -8, -7, -7,
+    0, -14, -4, 9223372036854775804, 1, -7, -8, -1,
+    -8, -6, 7, 7, 0, -9223372036854775808, -1, 1,
+    -7, -1, 6, -1, -4, -10, -21, -2,
+    -1, -56, -1, 2305843009213693951, 1, -5, -6, -1,
+    -14, 0, 49, 1, 0, -1008806316530991104, -1, 127,
+    -7, -7, 0, 0, 24, -38, -217, 0,
+    -7, -15032385536, -1, 8589934591, 25, -1, -26, -1,
+    25, -39, -224, 0, -7, -30064771072, -1, 4294967295,
+    32, -7, -39, -1, 56, -70, -441, 0,
+    -7, -9223372036854775808, -1, 1, 57, -1, -58, -1,
+    57, -71, -448, 0, -7, -7, -7, -7,
+    64, -7, -71, -1, 58, -72, -455, 0,
+    -7, -14, -4, 9223372036854775804, 65, -7, -72, -1,
+    4294967288, -4294967302, -30064771065, 0, -7, -9223372036854775808, -1, 1,
+    4294967289, -1, -4294967290, -1, 2147483641, -2147483655, -15032385536, 0,
+    -7, -7, -7, -7, 2147483648, -7, -2147483655, -1,
+    -2147483655, 2147483641, 15032385536, 0, -7, -7, -7, -7,
+    -2147483648, -7, 2147483641, 1, 81985529216486888, -81985529216486902, -573898704515408265, 0,
+    -7, -985162418487296, -1, 131071, 81985529216486889, -1, -81985529216486890, -1,
+    9223372036854775800, 9223372036854775802, -9223372036854775801, 0, -7, -9223372036854775808, -1, 1,
+    9223372036854775801, -1, -9223372036854775802, -1, 9223372036854775801, 9223372036854775801, -9223372036854775808, 0,
+    -7, -7, -7, -7, -9223372036854775808, -7, 9223372036854775801, 1,
+    31, 31, 0, 195890671, 195890671, 31, 31, 31,
+    0, 31, 31, 1, 32, 30, 31, 31,
+    0, 62, 15, 15, 1, 31, 30, 1,
+    30, 32, -31, -31, 0, -9223372036854775808, 0, 0,
+    31, -1, -32, 1, 34, 28, 93, 10,
+    1, 248, 3, 3, 3, 31, 28, 1,
+    24, 38, -217, -4, 3, 4467570830351532032, 0, 0,
+    25, -1, -26, 1, 62, 0, 961, 1,
+    0, 66571993088, 0, 0, 31, 31, 0, 0,
+    63, -1, 992, 0, 31, 133143986176, 0, 0,
+    0, 63, 63, -1, 94, -32, 1953, 0,
+    31, -9223372036854775808, 0, 0, 31, 63, 32, -1,
+    95, -33, 1984, 0, 31, 31, 31, 31,
+    0, 95, 95, -1, 96, -34, 2015, 0,
+    31, 62, 15, 15, 1, 95, 94, -1,
+    4294967326, -4294967264, 133143986145, 0, 31, -9223372036854775808, 0, 0,
+    31, 4294967295, 4294967264, -1, 2147483679, -2147483617, 66571993088, 0,
+    31, 31, 31, 31, 0, 2147483679, 2147483679, -1,
+    -2147483617, 2147483679, -66571993088, 0, 31, 31, 31, 31,
+    0, -2147483617, -2147483617, 1, 81985529216486926, -81985529216486864, 2541551405711093745, 0,
+    31, 4362862139015168, 0, 0, 15, 81985529216486911, 81985529216486896, -1,
+    -9223372036854775778, -9223372036854775776, 9223372036854775777, 0, 31, -9223372036854775808, 0, 0,
+    31, 9223372036854775807, 9223372036854775776, -1, -9223372036854775777, -9223372036854775777, -9223372036854775808, 0,
+    31, 31, 31, 31, 0, -9223372036854775777, -9223372036854775777, 1,
+    32, 32, 0, 195890671, 195890671, 32, 32, 32,
+    0, 32, 32, 1, 33, 31, 32, 32,
+    0, 64, 16, 16, 0, 33, 33, 1,
+    31, 33, -32, -32, 0, 0, 0, 0,
+    32, -1, -33, 1, 35, 29, 96, 10,
+    2, 256, 4, 4, 0, 35, 35, 1,
+    25, 39, -224, -4, 4, 4611686018427387904, 0, 0,
+    32, -7, -39, 1, 63, 1, 992, 1,
+    1, 68719476736, 0, 0, 0, 63, 63, 1,
+    64, 0, 1024, 1, 0, 137438953472, 0, 0,
+    32, 32, 0, 0, 95, -31, 2016, 0,
+    32, 0, 0, 0, 32, 63, 31, -1,
+    96, -32, 2048, 0, 32, 32, 32, 32,
+    0, 96, 96, -1, 97, -33, 2080, 0,
+    32, 64, 16, 16, 0, 97, 97, -1,
+    4294967327, -4294967263, 137438953440, 0, 32, 0, 0, 0,
+    32, 4294967295, 4294967263, -1, 2147483680, -2147483616, 68719476736, 0,
+    32, 32, 32, 32, 0, 2147483680, 2147483680, -1,
+    -2147483616, 2147483680, -68719476736, 0, 32, 32, 32, 32,
+    0, -2147483616, -2147483616, 1, 81985529216486927, -81985529216486863, 2623536934927580640, 0,
+    32, 4503599627370496, 0, 0, 32, 81985529216486895, 81985529216486863, -1,
+    -9223372036854775777, -9223372036854775775, -32, 0, 32, 0, 0, 0,
+    32, 9223372036854775807, 9223372036854775775, -1, -9223372036854775776, -9223372036854775776, 0, 0,
+    32, 32, 32, 32, 0, -9223372036854775776, -9223372036854775776, 1,
+    63, 63, 0, 195890671, 195890671, 63, 63, 63,
+    0, 63, 63, 1, 64, 62, 63, 63,
+    0, 126, 31, 31, 1, 63, 62, 1,
+    62, 64, -63, -63, 0, -9223372036854775808, 0, 0,
+    63, -1, -64, 1, 66, 60, 189, 21,
+    0, 504, 7, 7, 3, 63, 60, 1,
+    56, 70, -441, -9, 0, 9079256848778919936, 0, 0,
+    57, -1, -58, 1, 94, 32, 1953, 2,
+    1, 135291469824, 0, 0, 31, 63, 32, 1,
+    95, 31, 2016, 1, 31, 270582939648, 0, 0,
+    32, 63, 31, 1, 126, 0, 3969, 1,
+    0, -9223372036854775808, 0, 0, 63, 63, 0, 0,
+    127, -1, 4032, 0, 63, 63, 63, 63,
+    0, 127, 127, -1, 128, -2, 4095, 0,
+    63, 126, 31, 31, 1, 127, 126, -1,
+    4294967358, -4294967232, 270582939585, 0, 63, -9223372036854775808, 0, 0,
+    63, 4294967295, 4294967232, -1, 2147483711, -2147483585, 135291469824, 0,
+    63, 63, 63, 63, 0, 2147483711, 2147483711, -1,
+    -2147483585, 2147483711, -135291469824, 0, 63, 63, 63, 63,
+    0, -2147483585, -2147483585, 1, 81985529216486958, -81985529216486832, 5165088340638674385, 0,
+    63, 8866461766385664, 0, 0, 47, 81985529216486911, 81985529216486864, -1,
+    -9223372036854775746, -9223372036854775744, 9223372036854775745, 0, 63, -9223372036854775808, 0, 0,
+    63, 9223372036854775807, 9223372036854775744, -1, -9223372036854775745, -9223372036854775745, -9223372036854775808, 0,
+    63, 63, 63, 63, 0, -9223372036854775745, -9223372036854775745, 1,
+    64, 64, 0, 195890671, 195890671, 64, 64, 64,
+    0, 64, 64, 1, 65, 63, 64, 64,
+    0, 128, 32, 32, 0, 65, 65, 1,
+    63, 65, -64, -64, 0, 0, 0, 0,
+    64, -1, -65, 1, 67, 61, 192, 21,
+    1, 512, 8, 8, 0, 67, 67, 1,
+    57, 71, -448, -9, 1, -9223372036854775808, 0, 0,
+    64, -7, -71, 1, 95, 33, 1984, 2,
+    2, 137438953472, 0, 0, 0, 95, 95, 1,
+    96, 32, 2048, 2, 0, 274877906944, 0, 0,
+    0, 96, 96, 1, 127, 1, 4032, 1,
+    1, 0, 0, 0, 0, 127, 127, 1,
+    128, 0, 4096, 1, 0, 64, 64, 64,
+    64, 64, 0, 0, 129, -1, 4160, 0,
+    64, 128, 32, 32, 64, 65, 1, -1,
+    4294967359, -4294967231, 274877906880, 0, 64, 0, 0, 0,
+    64, 4294967295, 4294967231, -1, 2147483712, -2147483584, 137438953472, 0,
+    64, 64, 64, 64, 0, 2147483712, 2147483712, -1,
+    -2147483584, 2147483712, -137438953472, 0, 64, 64, 64, 64,
+    0, -2147483584, -2147483584, 1, 81985529216486959, -81985529216486831, 5247073869855161280, 0,
+    64, 9007199254740992, 0, 0, 64, 81985529216486895, 81985529216486831, -1,
+    -9223372036854775745, -9223372036854775743, -64, 0, 64, 0, 0, 0,
+    64, 9223372036854775807, 9223372036854775743, -1, -9223372036854775744, -9223372036854775744, 0, 0,
+    64, 64, 64, 64, 0, -9223372036854775744, -9223372036854775744, 1,
+    65, 65, 0, 195890671, 195890671, 65, 65, 65,
+    0, 65, 65, 1, 66, 64, 65, 65,
+    0, 130, 32, 32, 1, 65, 64, 1,
+    64, 66, -65, -65, 0, -9223372036854775808, 0, 0,
+    65, -1, -66, 1, 68, 62, 195, 21,
+    2, 520, 8, 8, 1, 67, 66, 1,
+    58, 72, -455, -9, 2, -9079256848778919936, 0, 0,
+    65, -7, -72, 1, 96, 34, 2015, 2,
+    3, 139586437120, 0, 0, 1, 95, 94, 1,
+    97, 33, 2080, 2, 1, 279172874240, 0, 0,
+    0, 97, 97, 1, 128, 2, 4095, 1,
+    2, -9223372036854775808, 0, 0, 1, 127, 126, 1,
+    129, 1, 4160, 1, 1, 65, 65, 65,
+    64, 65, 1, 1, 130, 0, 4225, 1,
+    0, 130, 32, 32, 65, 65, 0, 0,
+    4294967360, -4294967230, 279172874175, 0, 65, -9223372036854775808, 0, 0,
+    65, 4294967295, 4294967230, -1, 2147483713, -2147483583, 139586437120, 0,
+    65, 65, 65, 65, 0, 2147483713, 2147483713, -1,
+    -2147483583, 2147483713, -139586437120, 0, 65, 65, 65, 65,
+    0, -2147483583, -2147483583, 1, 81985529216486960, -81985529216486830, 5329059399071648175, 0,
+    65, 9147936743096320, 0, 0, 65, 81985529216486895, 81985529216486830, -1,
+    -9223372036854775744, -9223372036854775742, 9223372036854775743, 0, 65, -9223372036854775808, 0, 0,
+    65, 9223372036854775807, 9223372036854775742, -1, -9223372036854775743, -9223372036854775743, -9223372036854775808, 0,
+    65, 65, 65, 65, 0, -9223372036854775743, -9223372036854775743, 1,
+    4294967295, 4294967295, 0, 195890671, 195890671, 4294967295, 4294967295, 4294967295,
+    0, 4294967295, 4294967295, 1, 4294967296, 4294967294, 4294967295, 4294967295,
+    0, 8589934590, 2147483647, 2147483647, 1, 4294967295, 4294967294, 1,
+    4294967294, 4294967296, -4294967295, -4294967295, 0, -9223372036854775808, 0, 0,
+    4294967295, -1, -4294967296, 1, 4294967298, 4294967292, 12884901885, 1431655765,
+    0, 34359738360, 536870911, 536870911, 3, 4294967295, 4294967292, 1,
+    4294967288, 4294967302, -30064771065, -613566756, 3, -144115188075855872, 0, 0,
+    4294967289, -1, -4294967290, 1, 4294967326, 4294967264, 133143986145, 138547332,
+    3, 9223372034707292160, 1, 1, 31, 4294967295, 4294967264, 1,
+    4294967327, 4294967263, 137438953440, 134217727, 31, -4294967296, 0, 0,
+    32, 4294967295, 4294967263, 1, 4294967358, 4294967232, 270582939585, 68174084,
+    3, -9223372036854775808, 0, 0, 63, 4294967295, 4294967232, 1,
+    4294967359, 4294967231, 274877906880, 67108863, 63, 4294967295, 4294967295, 4294967295,
+    64, 4294967295, 4294967231, 1, 4294967360, 4294967230, 279172874175, 66076419,
+    60, 8589934590, 2147483647, 2147483647, 65, 4294967295, 4294967230, 1,
+    8589934590, 0, -8589934591, 1, 0, -9223372036854775808, 0, 0,
+    4294967295, 4294967295, 0, 0, 6442450943, 2147483647, 9223372034707292160, 1,
+    2147483647, 4294967295, 4294967295, 4294967295, 2147483648, 4294967295, 2147483647, 1,
+    2147483647, 6442450943, -9223372034707292160, -1, 2147483647, 4294967295, 4294967295, 4294967295,
+    2147483648, -1, -2147483649, 1, 81985533511454190, -81985524921519600, -8608480572331511279, 0,
+    4294967295, -140737488355328, 0, 0, 2309737967, 81985531201716223, 81985528891978256, -1,
+    -9223372032559808514, -9223372032559808512, 9223372032559808513, 0, 4294967295, -9223372036854775808, 0, 0,
+    4294967295, 9223372036854775807, 9223372032559808512, -1, -9223372032559808513, -9223372032559808513, -9223372036854775808, 0,
+    4294967295, 4294967295, 4294967295, 4294967295, 0, -9223372032559808513, -9223372032559808513, 1,
+    2147483648, 2147483648, 0, 195890671, 195890671, 2147483648, 2147483648, 2147483648,
+    0, 2147483648, 2147483648, 1, 2147483649, 2147483647, 2147483648, 2147483648,
+    0, 4294967296, 1073741824, 1073741824, 0, 2147483649, 2147483649, 1,
+    2147483647, 2147483649, -2147483648, -2147483648, 0, 0, 0, 0,
+    2147483648, -1, -2147483649, 1, 2147483651, 2147483645, 6442450944, 715827882,
+    2, 17179869184, 268435456, 268435456, 0, 2147483651, 2147483651, 1,
+    2147483641, 2147483655, -15032385536, -306783378, 2, 0, 0, 0,
+    2147483648, -7, -2147483655, 1, 2147483679, 2147483617, 66571993088, 69273666,
+    2, 4611686018427387904, 1, 1, 0, 2147483679, 2147483679, 1,
+    2147483680, 2147483616, 68719476736, 67108864, 0, -9223372036854775808, 0, 0,
+    0, 2147483680, 2147483680, 1, 2147483711, 2147483585, 135291469824, 34087042,
+    2, 0, 0, 0, 0, 2147483711, 2147483711, 1,
+    2147483712, 2147483584, 137438953472, 33554432, 0, 2147483648, 2147483648, 2147483648,
+    0, 2147483712, 2147483712, 1, 2147483713, 2147483583, 139586437120, 33038209,
+    63, 4294967296, 1073741824, 1073741824, 0, 2147483713, 2147483713, 1,
+    6442450943, -2147483647, 9223372034707292160, 0, 2147483648, 0, 0, 0,
+    2147483648, 4294967295, 2147483647, -1, 4294967296, 0, 4611686018427387904, 1,
+    0, 2147483648, 2147483648, 2147483648, 2147483648, 2147483648, 0, 0,
+    0, 4294967296, -4611686018427387904, -1, 0, 2147483648, 2147483648, 2147483648,
+    2147483648, -2147483648, -4294967296, 1, 81985531363970543, -81985527069003247, -4263247521557512192, 0,
+    2147483648, 0, 0, 0, 2147483648, 81985529216486895, 81985527069003247, -1,
+    -9223372034707292161, -9223372034707292159, -2147483648, 0, 2147483648, 0, 0, 0,
+    2147483648, 9223372036854775807, 9223372034707292159, -1, -9223372034707292160, -9223372034707292160, 0, 0,
+    2147483648, 2147483648, 2147483648, 2147483648, 0, -9223372034707292160, -9223372034707292160, 1,
+    -2147483648, -2147483648, 0, 195890671, 195890671, -2147483648, -2147483648, -2147483648,
+    0, -2147483648, -2147483648, -1, -2147483647, -2147483649, -2147483648, -2147483648,
+    0, -4294967296, -1073741824, 9223372035781033984, 0, -2147483647, -2147483647, -1,
+    -2147483649, -2147483647, 2147483648, 2147483648, 0, 0, -1, 1,
+    -2147483648, -1, 2147483647, -1, -2147483645, -2147483651, -6442450944, -715827882,
+    -2, -17179869184, -268435456, 2305843008945258496, 0, -2147483645, -2147483645, -1,
+    -2147483655, -2147483641, 15032385536, 306783378, -2, 0, -1, 127,
+    -2147483648, -7, 2147483641, -1, -2147483617, -2147483679, -66571993088, -69273666,
+    -2, -4611686018427387904, -1, 8589934591, 0, -2147483617, -2147483617, -1,
+    -2147483616, -2147483680, -68719476736, -67108864, 0, -9223372036854775808, -1, 4294967295,
+    0, -2147483616, -2147483616, -1, -2147483585, -2147483711, -135291469824, -34087042,
+    -2, 0, -1, 1, 0, -2147483585, -2147483585, -1,
+    -2147483584, -2147483712, -137438953472, -33554432, 0, -2147483648, -2147483648, -2147483648,
+    0, -2147483584, -2147483584, -1, -2147483583, -2147483713, -139586437120, -33038209,
+    -63, -4294967296, -1073741824, 9223372035781033984, 0, -2147483583, -2147483583, -1,
+    2147483647, -6442450943, -9223372034707292160, 0, -2147483648, 0, -1, 1,
+    2147483648, -1, -2147483649, -1, 0, -4294967296, -4611686018427387904, -1,
+    0, -2147483648, -2147483648, -2147483648, 2147483648, -2147483648, -4294967296, -1,
+    -4294967296, 0, 4611686018427387904, 1, 0, -2147483648, -2147483648, -2147483648,
+    -2147483648, -2147483648, 0, 0, 81985527069003247, -81985531363970543, 4263247521557512192, 0,
+    -2147483648, 0, -1, 131071, 81985529054232576, -1985229329, -81985531039461905, -1,
+    9223372034707292159, 9223372034707292161, 2147483648, 0, -2147483648, 0, -1, 1,
+    9223372034707292160, -1, -9223372034707292161, -1, 9223372034707292160, 9223372034707292160, 0, 0,
+    -2147483648, -2147483648, -2147483648, -2147483648, -9223372036854775808, -2147483648, 9223372034707292160, 1,
+    81985529216486895, 81985529216486895, 0, 195890671, 195890671, 81985529216486895, 81985529216486895, 81985529216486895,
+    0, 81985529216486895, 81985529216486895, 1, 81985529216486896, 81985529216486894, 81985529216486895, 81985529216486895,
+    0, 163971058432973790, 40992764608243447, 40992764608243447, 1, 81985529216486895, 81985529216486894, 1,
+    81985529216486894, 81985529216486896, -81985529216486895, -81985529216486895, 0, -9223372036854775808, 0, 0,
+    81985529216486895, -1, -81985529216486896, 1, 81985529216486898, 81985529216486892, 245956587649460685, 27328509738828965,
+    0, 655884233731895160, 10248191152060861, 10248191152060861, 3, 81985529216486895, 81985529216486892, 1,
+    81985529216486888, 81985529216486902, -573898704515408265, -11712218459498127, 6, -2449958197289549824, 0, 0,
+    81985529216486889, -1, -81985529216486890, 1, 81985529216486926, 81985529216486864, 2541551405711093745, 2644694490854415,
+    30, -4263247521557512192, 38177487, 38177487, 15, 81985529216486911, 81985529216486896, 1,
+    81985529216486927, 81985529216486863, 2623536934927580640, 2562047788015215, 15, -8526495043115024384, 19088743, 19088743,
+    32, 81985529216486895, 81985529216486863, 1, 81985529216486958, 81985529216486832, 5165088340638674385, 1301357606610903,
+    6, -9223372036854775808, 0, 0, 47, 81985529216486911, 81985529216486864, 1,
+    81985529216486959, 81985529216486831, 5247073869855161280, 1281023894007607, 47, 81985529216486895, 81985529216486895, 81985529216486895,
+    64, 81985529216486895, 81985529216486831, 1, 81985529216486960, 81985529216486830, 5329059399071648175, 1261315834099798,
+    25, 163971058432973790, 40992764608243447, 40992764608243447, 65, 81985529216486895, 81985529216486830, 1,
+    81985533511454190, 81985524921519600, -8608480572331511279, 19088743, 2328826710, -9223372036854775808, 0, 0,
+    2309737967, 81985531201716223, 81985528891978256, 1, 81985531363970543, 81985527069003247, -4263247521557512192, 38177487,
+    162254319, 81985529216486895, 81985529216486895, 81985529216486895, 2147483648, 81985529216486895, 81985527069003247, 1,
+    81985527069003247, 81985531363970543, 4263247521557512192, -38177487, 162254319, 81985529216486895, 81985529216486895, 81985529216486895,
+    81985529054232576, -1985229329, -81985531039461905, 1, 163971058432973790, 0, -2547381487788710623, 1,
+    0, -1803832388250238976, 582, 582, 81985529216486895, 81985529216486895, 0, 0,
+    -9141386507638288914, -9141386507638288912, 9141386507638288913, 0, 81985529216486895, -9223372036854775808, 0, 0,
+    81985529216486895, 9223372036854775807, 9141386507638288912, -1, -9141386507638288913, -9141386507638288913, -9223372036854775808, 0,
+    81985529216486895, 81985529216486895, 81985529216486895, 81985529216486895, 0, -9141386507638288913, -9141386507638288913, 1,
+    9223372036854775807, 9223372036854775807, 0, 195890671, 195890671, 9223372036854775807, 9223372036854775807, 9223372036854775807,
+    0, 9223372036854775807, 9223372036854775807, 1, -9223372036854775808, 9223372036854775806, 9223372036854775807, 9223372036854775807,
+    0, -2, 4611686018427387903, 4611686018427387903, 1, 9223372036854775807, 9223372036854775806, 1,
+    9223372036854775806, -9223372036854775808, -9223372036854775807, -9223372036854775807, 0, -9223372036854775808, 0, 0,
+    9223372036854775807, -1, -9223372036854775808, 1, -9223372036854775806, 9223372036854775804, 9223372036854775805, 3074457345618258602,
+    1, -8, 1152921504606846975, 1152921504606846975, 3, 9223372036854775807, 9223372036854775804, 1,
+    9223372036854775800, -9223372036854775802, -9223372036854775801, -1317624576693539401, 0, -144115188075855872, 63, 63,
+    9223372036854775801, -1, -9223372036854775802, 1, -9223372036854775778, 9223372036854775776, 9223372036854775777, 297528130221121800,
+    7, -2147483648, 4294967295, 4294967295, 31, 9223372036854775807, 9223372036854775776, 1,
+    -9223372036854775777, 9223372036854775775, -32, 288230376151711743, 31, -4294967296, 2147483647, 2147483647,
+    32, 9223372036854775807, 9223372036854775775, 1, -9223372036854775746, 9223372036854775744, 9223372036854775745, 146402730743726600,
+    7, -9223372036854775808, 0, 0, 63, 9223372036854775807, 9223372036854775744, 1,
+    -9223372036854775745, 9223372036854775743, -64, 144115188075855871, 63, 9223372036854775807, 9223372036854775807, 9223372036854775807,
+    64, 9223372036854775807, 9223372036854775743, 1, -9223372036854775744, 9223372036854775742, 9223372036854775743, 141898031336227320,
+    7, -2, 4611686018427387903, 4611686018427387903, 65, 9223372036854775807, 9223372036854775742, 1,
+    -9223372032559808514, 9223372032559808512, 9223372032559808513, 2147483648, 2147483647, -9223372036854775808, 0, 0,
+    4294967295, 9223372036854775807, 9223372032559808512, 1, -9223372034707292161, 9223372034707292159, -2147483648, 4294967295,
+    2147483647, 9223372036854775807, 9223372036854775807, 9223372036854775807, 2147483648, 9223372036854775807, 9223372034707292159, 1,
+    9223372034707292159, -9223372034707292161, 2147483648, -4294967295, 2147483647, 9223372036854775807, 9223372036854775807, 9223372036854775807,
+    9223372034707292160, -1, -9223372034707292161, 1, -9141386507638288914, 9141386507638288912, 9141386507638288913, 112,
+    40992764608243567, -140737488355328, 65535, 65535, 81985529216486895, 9223372036854775807, 9141386507638288912, 1,
+    -2, 0, 1, 1, 0, -9223372036854775808, 0, 0,
+    9223372036854775807, 9223372036854775807, 0, 0, -1, -1, -9223372036854775808, 0,
+    9223372036854775807, 9223372036854775807, 9223372036854775807, 9223372036854775807, 0, -1, -1, 1,
+    -9223372036854775808, -9223372036854775808, 0, 195890671, 195890671, -9223372036854775808, -9223372036854775808, -9223372036854775808,
+    0, -9223372036854775808, -9223372036854775808, -1, -9223372036854775807, 9223372036854775807, -9223372036854775808, -9223372036854775808,
+    0, 0, -4611686018427387904, 4611686018427387904, 0, -9223372036854775807, -9223372036854775807, -1,
+    9223372036854775807, -9223372036854775807, -9223372036854775808, -9223372036854775808, 0, 0, -1, 1,
+    -9223372036854775808, -1, 9223372036854775807, -1, -9223372036854775805, 9223372036854775805, -9223372036854775808, -3074457345618258602,
+    -2, 0, -1152921504606846976, 1152921504606846976, 0, -9223372036854775805, -9223372036854775805, -1,
+    9223372036854775801, -9223372036854775801, -9223372036854775808, 1317624576693539401, -1, 0, -64, 64,
+    -9223372036854775808, -7, 9223372036854775801, -1, -9223372036854775777, 9223372036854775777, -9223372036854775808, -297528130221121800,
+    -8, 0, -4294967296, 4294967296, 0, -9223372036854775777, -9223372036854775777, -1,
+    -9223372036854775776, 9223372036854775776, 0, -288230376151711744, 0, 0, -2147483648, 2147483648,
+    0, -9223372036854775776, -9223372036854775776, -1, -9223372036854775745, 9223372036854775745, -9223372036854775808, -146402730743726600,
+    -8, 0, -1, 1, 0, -9223372036854775745, -9223372036854775745, -1,
+    -9223372036854775744, 9223372036854775744, 0, -144115188075855872, 0, -9223372036854775808, -9223372036854775808, -9223372036854775808,
+    0, -9223372036854775744, -9223372036854775744, -1, -9223372036854775743, 9223372036854775743, -9223372036854775808, -141898031336227320,
+    -8, 0, -4611686018427387904, 4611686018427387904, 0, -9223372036854775743, -9223372036854775743, -1,
+    -9223372032559808513, 9223372032559808513, -9223372036854775808, -2147483648, -2147483648, 0, -1, 1,
+    0, -9223372032559808513, -9223372032559808513, -1, -9223372034707292160, 9223372034707292160, 0, -4294967296,
+    0, -9223372036854775808, -9223372036854775808, -9223372036854775808, 0, -9223372034707292160, -9223372034707292160, -1,
+    9223372034707292160, -9223372034707292160, 0, 4294967296, 0, -9223372036854775808, -9223372036854775808, -9223372036854775808,
+    -9223372036854775808, -2147483648, 9223372034707292160, -1, -9141386507638288913, 9141386507638288913, -9223372036854775808, -112,
+    -40992764608243568, 0, -65536, 65536, 0, -9141386507638288913, -9141386507638288913, -1,
+    -1, 1, -9223372036854775808, -1, -1, 0, -1, 1,
+    0, -1, -1, -1, 0, 0, 0, 1,
+    0, -9223372036854775808, -9223372036854775808, -9223372036854775808, -9223372036854775808, -9223372036854775808, 0, 0
+];
+
+const INT_UNARY: [i32; 96] = [
+    0, 0, 0, 0, 2, 1002, -1, 1,
+    1, 1, 4, 1003, 1, -1, 65535, -1,
+    0, 1001, -2, 2, 2, 2, 6, 1004,
+    7, -7, 65529, -7, -12, 995, -31, 31,
+    31, 31, 64, 1033, -32, 32, 32, 32,
+    66, 1034, -33, 33, 33, 33, 68, 1035,
+    -63, 63, 63, 63, 128, 1065, -64, 64,
+    64, 64, 130, 1066, -127, 127, 127, 127,
+    256, 1129, 128, -128, 65408, -128, -254, 874,
+    -65535, -1, 65535, -1, 131072, 66537, -305419896, 120,
+    22136, 22136, 610839794, 305420898, -2147483647, -1, 65535, -1,
+    0, -2147482647, -2147483648, 0, 0, 0, 2, -2147482646
+];
+
+const LONG_UNARY: [i64; 32] = [
+    0, 0, -1, 1, 1, -1, -3, 3,
+    7, -7, -31, 31, -32, 32, -63, 63,
+    -64, 64, -65, 65, -4294967295, -1, -2147483648, -2147483648,
+    2147483648, -2147483648, -81985529216486895, -1985229329, -9223372036854775807, -1, -9223372036854775808, 0
+];
+
+const FLOAT_BINARY: [f32; 1125] = [
+    f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x0), f32::NAN, f32::NAN, f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x80000000),
+    f32::NAN, f32::NAN, f32::from_bits(0x3f800000), f32::from_bits(0xbf800000), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0xbf800000),
+    f32::from_bits(0x3f800000), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x0), f32::from_bits(0x3f000000), f32::from_bits(0xbf000000), f32::from_bits(0x0), f32::from_bits(0x0),
+    f32::from_bits(0x0), f32::from_bits(0x40200000), f32::from_bits(0xc0200000), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0xc0200000), f32::from_bits(0x40200000),
+    f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x0), f32::from_bits(0x501502f9), f32::from_bits(0xd01502f9), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x0),
+    f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x0), f32::from_bits(0x4f000000), f32::from_bits(0xcf000000), f32::from_bits(0x0),
+    f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x1),
+    f32::from_bits(0x80000001), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x0), f32::INFINITY, -f32::INFINITY, f32::NAN, f32::from_bits(0x0),
+    f32::from_bits(0x0), -f32::INFINITY, f32::INFINITY, f32::NAN, f32::from_bits(0x80000000), f32::from_bits(0x0), f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::from_bits(0x0), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::NAN, f32::NAN,
+    f32::from_bits(0x80000000), f32::from_bits(0x0), f32::from_bits(0x0), f32::NAN, f32::NAN, f32::from_bits(0x3f800000), f32::from_bits(0xbf800000), f32::from_bits(0x80000000),
+    f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0xbf800000), f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x80000000), f32::from_bits(0x3f000000),
+    f32::from_bits(0xbf000000), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x40200000), f32::from_bits(0xc0200000), f32::from_bits(0x80000000), f32::from_bits(0x80000000),
+    f32::from_bits(0x80000000), f32::from_bits(0xc0200000), f32::from_bits(0x40200000), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x80000000), f32::from_bits(0x501502f9), f32::from_bits(0xd01502f9),
+    f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x80000000),
+    f32::from_bits(0x4f000000), f32::from_bits(0xcf000000), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), f32::from_bits(0x80000000),
+    f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x1), f32::from_bits(0x80000001), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::from_bits(0x80000000), f32::INFINITY,
+    -f32::INFINITY, f32::NAN, f32::from_bits(0x80000000), f32::from_bits(0x80000000), -f32::INFINITY, f32::INFINITY, f32::NAN, f32::from_bits(0x0),
+    f32::from_bits(0x80000000), f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::from_bits(0x3f800000), f32::from_bits(0x3f800000),
+    f32::from_bits(0x0), f32::INFINITY, f32::NAN, f32::from_bits(0x3f800000), f32::from_bits(0x3f800000), f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN,
+    f32::from_bits(0x40000000), f32::from_bits(0x0), f32::from_bits(0x3f800000), f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x40000000), f32::from_bits(0xbf800000),
+    f32::from_bits(0xbf800000), f32::from_bits(0x0), f32::from_bits(0x3fc00000), f32::from_bits(0x3f000000), f32::from_bits(0x3f000000), f32::from_bits(0x40000000), f32::from_bits(0x0), f32::from_bits(0x40600000),
+    f32::from_bits(0xbfc00000), f32::from_bits(0x40200000), f32::from_bits(0x3ecccccd), f32::from_bits(0x3f800000), f32::from_bits(0xbfc00000), f32::from_bits(0x40600000), f32::from_bits(0xc0200000), f32::from_bits(0xbecccccd),
+    f32::from_bits(0x3f800000), f32::from_bits(0x501502f9), f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9), f32::from_bits(0x2edbe6ff), f32::from_bits(0x3f800000), f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9),
+    f32::from_bits(0xd01502f9), f32::from_bits(0xaedbe6ff), f32::from_bits(0x3f800000), f32::from_bits(0x4f000000), f32::from_bits(0xcf000000), f32::from_bits(0x4f000000), f32::from_bits(0x30000000), f32::from_bits(0x3f800000),
+    f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), f32::from_bits(0x7f7fffff), f32::from_bits(0x200000), f32::from_bits(0x3f800000), f32::from_bits(0x3f800000), f32::from_bits(0x3f800000), f32::from_bits(0x1),
+    f32::INFINITY, f32::from_bits(0x0), f32::INFINITY, -f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0x3f800000), -f32::INFINITY,
+    f32::INFINITY, -f32::INFINITY, f32::from_bits(0x80000000), f32::from_bits(0x3f800000), f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::from_bits(0xbf800000), f32::from_bits(0xbf800000), f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN, f32::from_bits(0xbf800000), f32::from_bits(0xbf800000),
+    f32::from_bits(0x0), f32::INFINITY, f32::NAN, f32::from_bits(0x0), f32::from_bits(0xc0000000), f32::from_bits(0xbf800000), f32::from_bits(0xbf800000), f32::from_bits(0x80000000),
+    f32::from_bits(0xc0000000), f32::from_bits(0x0), f32::from_bits(0x3f800000), f32::from_bits(0x3f800000), f32::from_bits(0x80000000), f32::from_bits(0xbf000000), f32::from_bits(0xbfc00000), f32::from_bits(0xbf000000),
+    f32::from_bits(0xc0000000), f32::from_bits(0x80000000), f32::from_bits(0x3fc00000), f32::from_bits(0xc0600000), f32::from_bits(0xc0200000), f32::from_bits(0xbecccccd), f32::from_bits(0xbf800000), f32::from_bits(0xc0600000),
+    f32::from_bits(0x3fc00000), f32::from_bits(0x40200000), f32::from_bits(0x3ecccccd), f32::from_bits(0xbf800000), f32::from_bits(0x501502f9), f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9), f32::from_bits(0xaedbe6ff),
+    f32::from_bits(0xbf800000), f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x2edbe6ff), f32::from_bits(0xbf800000), f32::from_bits(0x4f000000), f32::from_bits(0xcf000000),
+    f32::from_bits(0xcf000000), f32::from_bits(0xb0000000), f32::from_bits(0xbf800000), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), f32::from_bits(0xff7fffff), f32::from_bits(0x80200000), f32::from_bits(0xbf800000),
+    f32::from_bits(0xbf800000), f32::from_bits(0xbf800000), f32::from_bits(0x80000001), -f32::INFINITY, f32::from_bits(0x80000000), f32::INFINITY, -f32::INFINITY, -f32::INFINITY,
+    f32::from_bits(0x80000000), f32::from_bits(0xbf800000), -f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0xbf800000), f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::from_bits(0x3f000000), f32::from_bits(0x3f000000), f32::from_bits(0x0), f32::INFINITY,
+    f32::NAN, f32::from_bits(0x3f000000), f32::from_bits(0x3f000000), f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN, f32::from_bits(0x3fc00000), f32::from_bits(0xbf000000),
+    f32::from_bits(0x3f000000), f32::from_bits(0x3f000000), f32::from_bits(0x3f000000), f32::from_bits(0xbf000000), f32::from_bits(0x3fc00000), f32::from_bits(0xbf000000), f32::from_bits(0xbf000000), f32::from_bits(0x3f000000),
+    f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::from_bits(0x3e800000), f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::from_bits(0x40400000), f32::from_bits(0xc0000000), f32::from_bits(0x3fa00000),
+    f32::from_bits(0x3e4ccccd), f32::from_bits(0x3f000000), f32::from_bits(0xc0000000), f32::from_bits(0x40400000), f32::from_bits(0xbfa00000), f32::from_bits(0xbe4ccccd), f32::from_bits(0x3f000000), f32::from_bits(0x501502f9),
+    f32::from_bits(0xd01502f9), f32::from_bits(0x4f9502f9), f32::from_bits(0x2e5be6ff), f32::from_bits(0x3f000000), f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9), f32::from_bits(0xcf9502f9), f32::from_bits(0xae5be6ff),
+    f32::from_bits(0x3f000000), f32::from_bits(0x4f000000), f32::from_bits(0xcf000000), f32::from_bits(0x4e800000), f32::from_bits(0x2f800000), f32::from_bits(0x3f000000), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff),
+    f32::from_bits(0x7effffff), f32::from_bits(0x100000), f32::from_bits(0x3f000000), f32::from_bits(0x3f000000), f32::from_bits(0x3f000000), f32::from_bits(0x0), f32::INFINITY, f32::from_bits(0x0),
+    f32::INFINITY, -f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0x3f000000), -f32::INFINITY, f32::INFINITY, -f32::INFINITY,
+    f32::from_bits(0x80000000), f32::from_bits(0x3f000000), f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::from_bits(0x40200000),
+    f32::from_bits(0x40200000), f32::from_bits(0x0), f32::INFINITY, f32::NAN, f32::from_bits(0x40200000), f32::from_bits(0x40200000), f32::from_bits(0x80000000), -f32::INFINITY,
+    f32::NAN, f32::from_bits(0x40600000), f32::from_bits(0x3fc00000), f32::from_bits(0x40200000), f32::from_bits(0x40200000), f32::from_bits(0x3f000000), f32::from_bits(0x3fc00000), f32::from_bits(0x40600000),
+    f32::from_bits(0xc0200000), f32::from_bits(0xc0200000), f32::from_bits(0x3f000000), f32::from_bits(0x40400000), f32::from_bits(0x40000000), f32::from_bits(0x3fa00000), f32::from_bits(0x40a00000), f32::from_bits(0x0),
+    f32::from_bits(0x40a00000), f32::from_bits(0x0), f32::from_bits(0x40c80000), f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x40a00000), f32::from_bits(0xc0c80000),
+    f32::from_bits(0xbf800000), f32::from_bits(0x0), f32::from_bits(0x501502f9), f32::from_bits(0xd01502f9), f32::from_bits(0x50ba43b7), f32::from_bits(0x2f89705f), f32::from_bits(0x40200000), f32::from_bits(0xd01502f9),
+    f32::from_bits(0x501502f9), f32::from_bits(0xd0ba43b7), f32::from_bits(0xaf89705f), f32::from_bits(0x40200000), f32::from_bits(0x4f000000), f32::from_bits(0xcf000000), f32::from_bits(0x4fa00000), f32::from_bits(0x30a00000),
+    f32::from_bits(0x40200000), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), f32::INFINITY, f32::from_bits(0x500000), f32::from_bits(0x40200000), f32::from_bits(0x40200000), f32::from_bits(0x40200000),
+    f32::from_bits(0x2), f32::INFINITY, f32::from_bits(0x0), f32::INFINITY, -f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0x40200000),
+    -f32::INFINITY, f32::INFINITY, -f32::INFINITY, f32::from_bits(0x80000000), f32::from_bits(0x40200000), f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::from_bits(0xc0200000), f32::from_bits(0xc0200000), f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN, f32::from_bits(0xc0200000),
+    f32::from_bits(0xc0200000), f32::from_bits(0x0), f32::INFINITY, f32::NAN, f32::from_bits(0xbfc00000), f32::from_bits(0xc0600000), f32::from_bits(0xc0200000), f32::from_bits(0xc0200000),
+    f32::from_bits(0xbf000000), f32::from_bits(0xc0600000), f32::from_bits(0xbfc00000), f32::from_bits(0x40200000), f32::from_bits(0x40200000), f32::from_bits(0xbf000000), f32::from_bits(0xc0000000), f32::from_bits(0xc0400000),
+    f32::from_bits(0xbfa00000), f32::from_bits(0xc0a00000), f32::from_bits(0x80000000), f32::from_bits(0x0), f32::from_bits(0xc0a00000), f32::from_bits(0xc0c80000), f32::from_bits(0xbf800000), f32::from_bits(0x80000000),
+    f32::from_bits(0xc0a00000), f32::from_bits(0x0), f32::from_bits(0x40c80000), f32::from_bits(0x3f800000), f32::from_bits(0x80000000), f32::from_bits(0x501502f9), f32::from_bits(0xd01502f9), f32::from_bits(0xd0ba43b7),
+    f32::from_bits(0xaf89705f), f32::from_bits(0xc0200000), f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9), f32::from_bits(0x50ba43b7), f32::from_bits(0x2f89705f), f32::from_bits(0xc0200000), f32::from_bits(0x4f000000),
+    f32::from_bits(0xcf000000), f32::from_bits(0xcfa00000), f32::from_bits(0xb0a00000), f32::from_bits(0xc0200000), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), -f32::INFINITY, f32::from_bits(0x80500000),
+    f32::from_bits(0xc0200000), f32::from_bits(0xc0200000), f32::from_bits(0xc0200000), f32::from_bits(0x80000002), -f32::INFINITY, f32::from_bits(0x80000000), f32::INFINITY, -f32::INFINITY,
+    -f32::INFINITY, f32::from_bits(0x80000000), f32::from_bits(0xc0200000), -f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0xc0200000),
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x0),
+    f32::INFINITY, f32::NAN, f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN, f32::from_bits(0x501502f9),
+    f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x0), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9),
+    f32::from_bits(0x0), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x4f9502f9), f32::from_bits(0x509502f9), f32::from_bits(0x0), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9),
+    f32::from_bits(0x50ba43b7), f32::from_bits(0x4f6e6b28), f32::from_bits(0x0), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0xd0ba43b7), f32::from_bits(0xcf6e6b28), f32::from_bits(0x0),
+    f32::from_bits(0x509502f9), f32::from_bits(0x0), f32::from_bits(0x60ad78ec), f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x509502f9), f32::from_bits(0xe0ad78ec),
+    f32::from_bits(0xbf800000), f32::from_bits(0x0), f32::from_bits(0x503502f9), f32::from_bits(0x4fea05f2), f32::from_bits(0x5f9502f9), f32::from_bits(0x409502f9), f32::from_bits(0x4ea817c8), f32::from_bits(0x7f7fffff),
+    f32::from_bits(0xff7fffff), f32::INFINITY, f32::from_bits(0x101502fa), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x59502f9), f32::INFINITY,
+    f32::from_bits(0x0), f32::INFINITY, -f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0x501502f9), -f32::INFINITY, f32::INFINITY,
+    -f32::INFINITY, f32::from_bits(0x80000000), f32::from_bits(0x501502f9), f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9), f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN, f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9), f32::from_bits(0x0),
+    f32::INFINITY, f32::NAN, f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9), f32::from_bits(0x80000000), f32::from_bits(0xd01502f9),
+    f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9), f32::from_bits(0x501502f9), f32::from_bits(0x80000000), f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9), f32::from_bits(0xcf9502f9), f32::from_bits(0xd09502f9),
+    f32::from_bits(0x80000000), f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9), f32::from_bits(0xd0ba43b7), f32::from_bits(0xcf6e6b28), f32::from_bits(0x80000000), f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9),
+    f32::from_bits(0x50ba43b7), f32::from_bits(0x4f6e6b28), f32::from_bits(0x80000000), f32::from_bits(0x0), f32::from_bits(0xd09502f9), f32::from_bits(0xe0ad78ec), f32::from_bits(0xbf800000), f32::from_bits(0x80000000),
+    f32::from_bits(0xd09502f9), f32::from_bits(0x0), f32::from_bits(0x60ad78ec), f32::from_bits(0x3f800000), f32::from_bits(0x80000000), f32::from_bits(0xcfea05f2), f32::from_bits(0xd03502f9), f32::from_bits(0xdf9502f9),
+    f32::from_bits(0xc09502f9), f32::from_bits(0xcea817c8), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), -f32::INFINITY, f32::from_bits(0x901502fa), f32::from_bits(0xd01502f9), f32::from_bits(0xd01502f9),
+    f32::from_bits(0xd01502f9), f32::from_bits(0x859502f9), -f32::INFINITY, f32::from_bits(0x80000000), f32::INFINITY, -f32::INFINITY, -f32::INFINITY, f32::from_bits(0x80000000),
+    f32::from_bits(0xd01502f9), -f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0xd01502f9), f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::from_bits(0x4f000000), f32::from_bits(0x4f000000), f32::from_bits(0x0), f32::INFINITY, f32::NAN,
+    f32::from_bits(0x4f000000), f32::from_bits(0x4f000000), f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN, f32::from_bits(0x4f000000), f32::from_bits(0x4f000000), f32::from_bits(0x4f000000),
+    f32::from_bits(0x4f000000), f32::from_bits(0x0), f32::from_bits(0x4f000000), f32::from_bits(0x4f000000), f32::from_bits(0xcf000000), f32::from_bits(0xcf000000), f32::from_bits(0x0), f32::from_bits(0x4f000000),
+    f32::from_bits(0x4f000000), f32::from_bits(0x4e800000), f32::from_bits(0x4f800000), f32::from_bits(0x0), f32::from_bits(0x4f000000), f32::from_bits(0x4f000000), f32::from_bits(0x4fa00000), f32::from_bits(0x4e4ccccd),
+    f32::from_bits(0x3f000000), f32::from_bits(0x4f000000), f32::from_bits(0x4f000000), f32::from_bits(0xcfa00000), f32::from_bits(0xce4ccccd), f32::from_bits(0x3f000000), f32::from_bits(0x503502f9), f32::from_bits(0xcfea05f2),
+    f32::from_bits(0x5f9502f9), f32::from_bits(0x3e5be6ff), f32::from_bits(0x4f000000), f32::from_bits(0xcfea05f2), f32::from_bits(0x503502f9), f32::from_bits(0xdf9502f9), f32::from_bits(0xbe5be6ff), f32::from_bits(0x4f000000),
+    f32::from_bits(0x4f800000), f32::from_bits(0x0), f32::from_bits(0x5e800000), f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), f32::INFINITY,
+    f32::from_bits(0xf000001), f32::from_bits(0x4f000000), f32::from_bits(0x4f000000), f32::from_bits(0x4f000000), f32::from_bits(0x4800000), f32::INFINITY, f32::from_bits(0x0), f32::INFINITY,
+    -f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0x4f000000), -f32::INFINITY, f32::INFINITY, -f32::INFINITY, f32::from_bits(0x80000000),
+    f32::from_bits(0x4f000000), f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff),
+    f32::from_bits(0x0), f32::INFINITY, f32::NAN, f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN,
+    f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::from_bits(0x0), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff),
+    f32::from_bits(0xff7fffff), f32::from_bits(0x0), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::from_bits(0x7effffff), f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0x7f7fffff),
+    f32::from_bits(0x7f7fffff), f32::INFINITY, f32::from_bits(0x7ecccccc), f32::from_bits(0x0), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), -f32::INFINITY, f32::from_bits(0xfecccccc),
+    f32::from_bits(0x0), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::INFINITY, f32::from_bits(0x6edbe6fe), f32::from_bits(0x4f869d68), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff),
+    -f32::INFINITY, f32::from_bits(0xeedbe6fe), f32::from_bits(0x4f869d68), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::INFINITY, f32::from_bits(0x6fffffff), f32::from_bits(0x0),
+    f32::INFINITY, f32::from_bits(0x0), f32::INFINITY, f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::from_bits(0x7f7fffff), f32::from_bits(0x7f7fffff), f32::from_bits(0x34ffffff),
+    f32::INFINITY, f32::from_bits(0x0), f32::INFINITY, -f32::INFINITY, f32::INFINITY, f32::from_bits(0x0), f32::from_bits(0x7f7fffff), -f32::INFINITY,
+    f32::INFINITY, -f32::INFINITY, f32::from_bits(0x80000000), f32::from_bits(0x7f7fffff), f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::from_bits(0x1), f32::from_bits(0x1), f32::from_bits(0x0), f32::INFINITY, f32::NAN, f32::from_bits(0x1), f32::from_bits(0x1),
+    f32::from_bits(0x80000000), -f32::INFINITY, f32::NAN, f32::from_bits(0x3f800000), f32::from_bits(0xbf800000), f32::from_bits(0x1), f32::from_bits(0x1), f32::from_bits(0x1),
+    f32::from_bits(0xbf800000), f32::from_bits(0x3f800000), f32::from_bits(0x80000001), f32::from_bits(0x80000001), f32::from_bits(0x1), f32::from_bits(0x3f000000), f32::from_bits(0xbf000000), f32::from_bits(0x0),
+    f32::from_bits(0x2), f32::from_bits(0x1), f32::from_bits(0x40200000), f32::from_bits(0xc0200000), f32::from_bits(0x2), f32::from_bits(0x0), f32::from_bits(0x1), f32::from_bits(0xc0200000),
+    f32::from_bits(0x40200000), f32::from_bits(0x80000002), f32::from_bits(0x80000000), f32::from_bits(0x1), f32::from_bits(0x501502f9), f32::from_bits(0xd01502f9), f32::from_bits(0x59502f9), f32::from_bits(0x0),
+    f32::from_bits(0x1), f32::from_bits(0xd01502f9), f32::from_bits(0x501502f9), f32::from_bits(0x859502f9), f32::from_bits(0x80000000), f32::from_bits(0x1), f32::from_bits(0x4f000000), f32::from_bits(0xcf000000),
+    f32::from_bits(0x4800000), f32::from_bits(0x0), f32::from_bits(0x1), f32::from_bits(0x7f7fffff), f32::from_bits(0xff7fffff), f32::from_bits(0x34ffffff), f32::from_bits(0x0), f32::from_bits(0x1),
+    f32::from_bits(0x2), f32::from_bits(0x0), f32::from_bits(0x0), f32::from_bits(0x3f800000), f32::from_bits(0x0), f32::INFINITY, -f32::INFINITY, f32::INFINITY,
+    f32::from_bits(0x0), f32::from_bits(0x1), -f32::INFINITY, f32::INFINITY, -f32::INFINITY, f32::from_bits(0x80000000), f32::from_bits(0x1), f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::INFINITY, f32::INFINITY, f32::NAN, f32::INFINITY,
+    f32::NAN, f32::INFINITY, f32::INFINITY, f32::NAN, -f32::INFINITY, f32::NAN, f32::INFINITY, f32::INFINITY,
+    f32::INFINITY, f32::INFINITY, f32::NAN, f32::INFINITY, f32::INFINITY, -f32::INFINITY, -f32::INFINITY, f32::NAN,
+    f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::NAN, f32::INFINITY, f32::INFINITY, f32::INFINITY,
+    f32::INFINITY, f32::NAN, f32::INFINITY, f32::INFINITY, -f32::INFINITY, -f32::INFINITY, f32::NAN, f32::INFINITY,
+    f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::NAN, f32::INFINITY, f32::INFINITY, -f32::INFINITY, -f32::INFINITY,
+    f32::NAN, f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::NAN, f32::INFINITY, f32::INFINITY,
+    f32::INFINITY, f32::INFINITY, f32::NAN, f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::NAN,
+    f32::INFINITY, f32::NAN, f32::INFINITY, f32::NAN, f32::NAN, f32::NAN, f32::INFINITY, -f32::INFINITY,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, -f32::INFINITY,
+    -f32::INFINITY, f32::NAN, -f32::INFINITY, f32::NAN, -f32::INFINITY, -f32::INFINITY, f32::NAN, f32::INFINITY,
+    f32::NAN, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, f32::NAN, -f32::INFINITY, -f32::INFINITY,
+    f32::INFINITY, f32::INFINITY, f32::NAN, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, f32::NAN,
+    -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, f32::NAN, -f32::INFINITY, -f32::INFINITY, f32::INFINITY,
+    f32::INFINITY, f32::NAN, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, f32::NAN, -f32::INFINITY,
+    -f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::NAN, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY,
+    f32::NAN, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, -f32::INFINITY, f32::NAN, -f32::INFINITY, -f32::INFINITY,
+    -f32::INFINITY, -f32::INFINITY, f32::NAN, f32::NAN, -f32::INFINITY, -f32::INFINITY, f32::NAN, f32::NAN,
+    -f32::INFINITY, f32::NAN, f32::INFINITY, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN,
+    f32::NAN, f32::NAN, f32::NAN, f32::NAN, f32::NAN
+];
+
+const DOUBLE_BINARY: [f64; 1280] = [
+    f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x0), f64::NAN, f64::NAN, f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x8000000000000000),
+    f64::NAN, f64::NAN, f64::from_bits(0x3ff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0xbff0000000000000),
+    f64::from_bits(0x3ff0000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x0), f64::from_bits(0x3fe0000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0x0), f64::from_bits(0x0),
+    f64::from_bits(0x0), f64::from_bits(0x4004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0xc004000000000000), f64::from_bits(0x4004000000000000),
+    f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x0), f64::from_bits(0x4202a05f20000000), f64::from_bits(0xc202a05f20000000), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x0),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x0), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0xc1dfffffffe00000), f64::from_bits(0x0),
+    f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x1), f64::from_bits(0x8000000000000001), f64::from_bits(0x0), f64::from_bits(0x0),
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::NAN, f64::from_bits(0x0), f64::from_bits(0x0), -f64::INFINITY, f64::INFINITY,
+    f64::NAN, f64::from_bits(0x8000000000000000), f64::from_bits(0x0), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x0), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::NAN, f64::NAN, f64::from_bits(0x8000000000000000), f64::from_bits(0x0), f64::from_bits(0x0),
+    f64::NAN, f64::NAN, f64::from_bits(0x3ff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0xbff0000000000000),
+    f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x8000000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000),
+    f64::from_bits(0x8000000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x4004000000000000),
+    f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x8000000000000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0xc202a05f20000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x8000000000000000), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0xc1dfffffffe00000), f64::from_bits(0x8000000000000000),
+    f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x1), f64::from_bits(0x8000000000000001), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000),
+    f64::from_bits(0x8000000000000000), f64::INFINITY, -f64::INFINITY, f64::NAN, f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), -f64::INFINITY, f64::INFINITY,
+    f64::NAN, f64::from_bits(0x0), f64::from_bits(0x8000000000000000), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0), f64::INFINITY, f64::NAN, f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x8000000000000000),
+    -f64::INFINITY, f64::NAN, f64::from_bits(0x4000000000000000), f64::from_bits(0x0), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0), f64::from_bits(0x0),
+    f64::from_bits(0x4000000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x0), f64::from_bits(0x3ff8000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x4000000000000000),
+    f64::from_bits(0x0), f64::from_bits(0x400c000000000000), f64::from_bits(0xbff8000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x3fd999999999999a), f64::from_bits(0x3ff0000000000000), f64::from_bits(0xbff8000000000000), f64::from_bits(0x400c000000000000),
+    f64::from_bits(0xc004000000000000), f64::from_bits(0xbfd999999999999a), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x4202a05f20080000), f64::from_bits(0xc202a05f1ff80000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x3ddb7cdfd9d7bdbb), f64::from_bits(0x3ff0000000000000),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xbbfd83c94fb6d2ac), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x41e0000000100000), f64::from_bits(0xc1dfffffffa00000), f64::from_bits(0x41dfffffffe00000),
+    f64::from_bits(0x3e00000000100000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x3bffbc8060b992b4), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x4000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x1), f64::INFINITY,
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0x3ff0000000000000), -f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0x3ff0000000000000), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x8000000000000000), -f64::INFINITY, f64::NAN, f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x0),
+    f64::INFINITY, f64::NAN, f64::from_bits(0x0), f64::from_bits(0xc000000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0xc000000000000000),
+    f64::from_bits(0x0), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0xbff8000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0xc000000000000000),
+    f64::from_bits(0x8000000000000000), f64::from_bits(0x3ff8000000000000), f64::from_bits(0xc00c000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0xbfd999999999999a), f64::from_bits(0xbff0000000000000), f64::from_bits(0xc00c000000000000), f64::from_bits(0x3ff8000000000000),
+    f64::from_bits(0x4004000000000000), f64::from_bits(0x3fd999999999999a), f64::from_bits(0xbff0000000000000), f64::from_bits(0x4202a05f1ff80000), f64::from_bits(0xc202a05f20080000), f64::from_bits(0xc202a05f20000000), f64::from_bits(0xbddb7cdfd9d7bdbb), f64::from_bits(0xbff0000000000000),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0x3bfd83c94fb6d2ac), f64::from_bits(0xbff0000000000000), f64::from_bits(0x41dfffffffa00000), f64::from_bits(0xc1e0000000100000), f64::from_bits(0xc1dfffffffe00000),
+    f64::from_bits(0xbe00000000100000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0xbbffbc8060b992b4), f64::from_bits(0xbff0000000000000), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::from_bits(0xffefffffffffffff), f64::from_bits(0x8004000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x8000000000000001), -f64::INFINITY,
+    f64::from_bits(0x8000000000000000), f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0xbff0000000000000), -f64::INFINITY, f64::INFINITY,
+    f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0xbff0000000000000), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x0), f64::INFINITY, f64::NAN, f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x8000000000000000),
+    -f64::INFINITY, f64::NAN, f64::from_bits(0x3ff8000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0xbfe0000000000000),
+    f64::from_bits(0x3ff8000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0), f64::from_bits(0x3fd0000000000000), f64::from_bits(0x3ff0000000000000),
+    f64::from_bits(0x0), f64::from_bits(0x4008000000000000), f64::from_bits(0xc000000000000000), f64::from_bits(0x3ff4000000000000), f64::from_bits(0x3fc999999999999a), f64::from_bits(0x3fe0000000000000), f64::from_bits(0xc000000000000000), f64::from_bits(0x4008000000000000),
+    f64::from_bits(0xbff4000000000000), f64::from_bits(0xbfc999999999999a), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x4202a05f20040000), f64::from_bits(0xc202a05f1ffc0000), f64::from_bits(0x41f2a05f20000000), f64::from_bits(0x3dcb7cdfd9d7bdbb), f64::from_bits(0x3fe0000000000000),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0xc3d158e460913d00), f64::from_bits(0xbbed83c94fb6d2ac), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x41e0000000000000), f64::from_bits(0xc1dfffffffc00000), f64::from_bits(0x41cfffffffe00000),
+    f64::from_bits(0x3df0000000100000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0x43d02207973f6440), f64::from_bits(0x3befbc8060b992b4), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::from_bits(0x7fdfffffffffffff), f64::from_bits(0x2000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x0), f64::INFINITY,
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0x3fe0000000000000), -f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0x3fe0000000000000), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x4004000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x0), f64::INFINITY, f64::NAN, f64::from_bits(0x4004000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x8000000000000000),
+    -f64::INFINITY, f64::NAN, f64::from_bits(0x400c000000000000), f64::from_bits(0x3ff8000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x3ff8000000000000),
+    f64::from_bits(0x400c000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x4008000000000000), f64::from_bits(0x4000000000000000), f64::from_bits(0x3ff4000000000000), f64::from_bits(0x4014000000000000),
+    f64::from_bits(0x0), f64::from_bits(0x4014000000000000), f64::from_bits(0x0), f64::from_bits(0x4019000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x4014000000000000),
+    f64::from_bits(0xc019000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x0), f64::from_bits(0x4202a05f20140000), f64::from_bits(0xc202a05f1fec0000), f64::from_bits(0x42174876e8000000), f64::from_bits(0x3df12e0be826d695), f64::from_bits(0x4004000000000000),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0xc3f5af1d78b58c40), f64::from_bits(0xbc12725dd1d243ac), f64::from_bits(0x4004000000000000), f64::from_bits(0x41e0000000400000), f64::from_bits(0xc1dfffffff400000), f64::from_bits(0x41f3ffffffec0000),
+    f64::from_bits(0x3e14000000140000), f64::from_bits(0x4004000000000000), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0x43f42a897d0f3d50), f64::from_bits(0x3c13d5d03c73fbb0), f64::from_bits(0x4004000000000000), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::INFINITY, f64::from_bits(0xa000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x2), f64::INFINITY,
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0x4004000000000000), -f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0x4004000000000000), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0xc004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x8000000000000000), -f64::INFINITY, f64::NAN, f64::from_bits(0xc004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x0),
+    f64::INFINITY, f64::NAN, f64::from_bits(0xbff8000000000000), f64::from_bits(0xc00c000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0xc00c000000000000),
+    f64::from_bits(0xbff8000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0xc000000000000000), f64::from_bits(0xc008000000000000), f64::from_bits(0xbff4000000000000), f64::from_bits(0xc014000000000000),
+    f64::from_bits(0x8000000000000000), f64::from_bits(0x0), f64::from_bits(0xc014000000000000), f64::from_bits(0xc019000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0xc014000000000000), f64::from_bits(0x0),
+    f64::from_bits(0x4019000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x4202a05f1fec0000), f64::from_bits(0xc202a05f20140000), f64::from_bits(0xc2174876e8000000), f64::from_bits(0xbdf12e0be826d695), f64::from_bits(0xc004000000000000),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0x43f5af1d78b58c40), f64::from_bits(0x3c12725dd1d243ac), f64::from_bits(0xc004000000000000), f64::from_bits(0x41dfffffff400000), f64::from_bits(0xc1e0000000400000), f64::from_bits(0xc1f3ffffffec0000),
+    f64::from_bits(0xbe14000000140000), f64::from_bits(0xc004000000000000), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0xc3f42a897d0f3d50), f64::from_bits(0xbc13d5d03c73fbb0), f64::from_bits(0xc004000000000000), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), -f64::INFINITY, f64::from_bits(0x800a000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x8000000000000002), -f64::INFINITY,
+    f64::from_bits(0x8000000000000000), f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0xc004000000000000), -f64::INFINITY, f64::INFINITY,
+    f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0xc004000000000000), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x4202a05f20000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x0), f64::INFINITY, f64::NAN, f64::from_bits(0x4202a05f20000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x8000000000000000),
+    -f64::INFINITY, f64::NAN, f64::from_bits(0x4202a05f20080000), f64::from_bits(0x4202a05f1ff80000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x0), f64::from_bits(0x4202a05f1ff80000),
+    f64::from_bits(0x4202a05f20080000), f64::from_bits(0xc202a05f20000000), f64::from_bits(0xc202a05f20000000), f64::from_bits(0x0), f64::from_bits(0x4202a05f20040000), f64::from_bits(0x4202a05f1ffc0000), f64::from_bits(0x41f2a05f20000000), f64::from_bits(0x4212a05f20000000),
+    f64::from_bits(0x0), f64::from_bits(0x4202a05f20140000), f64::from_bits(0x4202a05f1fec0000), f64::from_bits(0x42174876e8000000), f64::from_bits(0x41edcd6500000000), f64::from_bits(0x0), f64::from_bits(0x4202a05f1fec0000), f64::from_bits(0x4202a05f20140000),
+    f64::from_bits(0xc2174876e8000000), f64::from_bits(0xc1edcd6500000000), f64::from_bits(0x0), f64::from_bits(0x4212a05f20000000), f64::from_bits(0x0), f64::from_bits(0x4415af1d78b58c40), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0),
+    f64::from_bits(0xc3e158e46046bb84), f64::from_bits(0x43e158e460dbbe7c), f64::from_bits(0xc5f431e0fae6d721), f64::from_bits(0xbe112e0be826d695), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x4206a05f1ffc0000), f64::from_bits(0x41fd40be40080000), f64::from_bits(0x43f2a05f1fed5fa1),
+    f64::from_bits(0x4012a05f2012a05f), f64::from_bits(0x41d502f900800000), f64::from_bits(0x43e022079789e5bc), f64::from_bits(0xc3e0220796f4e2c4), f64::from_bits(0x45f2c7fcc060ebe9), f64::from_bits(0x3e1279150fa5a1ea), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::INFINITY, f64::from_bits(0x202a05f20000001), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x2540be400), f64::INFINITY,
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0x4202a05f20000000), -f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0x4202a05f20000000), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x8000000000000000), -f64::INFINITY, f64::NAN, f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x0),
+    f64::INFINITY, f64::NAN, f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x8000000000000000), f64::from_bits(0xc3e158e460913d00),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0x8000000000000000), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3d158e460913d00), f64::from_bits(0xc3f158e460913d00),
+    f64::from_bits(0x8000000000000000), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3f5af1d78b58c40), f64::from_bits(0xc3cbc16d674ec800), f64::from_bits(0x8000000000000000), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00),
+    f64::from_bits(0x43f5af1d78b58c40), f64::from_bits(0x43cbc16d674ec800), f64::from_bits(0x8000000000000000), f64::from_bits(0xc3e158e46046bb84), f64::from_bits(0xc3e158e460dbbe7c), f64::from_bits(0xc5f431e0fae6d721), f64::from_bits(0xc1cdcd6500000000), f64::from_bits(0x8000000000000000),
+    f64::from_bits(0xc3f158e460913d00), f64::from_bits(0x0), f64::from_bits(0x47d2ced32a16a1b1), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0xc3e158e460813d00), f64::from_bits(0xc3e158e460a13d00), f64::from_bits(0xc5d158e4607fe41c),
+    f64::from_bits(0xc1f158e460a295e4), f64::from_bits(0xc1b4af2305000000), f64::from_bits(0xc3a36dcc951d8c00), f64::from_bits(0xc3f0bd75fbe850a0), f64::from_bits(0xc7d17dc980be01e5), f64::from_bits(0xbff1344d1344d134), f64::from_bits(0xc3a36dcc951d8c00), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), -f64::INFINITY, f64::from_bits(0x83e158e460913d01), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x80c158e460913d00), -f64::INFINITY,
+    f64::from_bits(0x8000000000000000), f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0xc3e158e460913d00), -f64::INFINITY, f64::INFINITY,
+    f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0xc3e158e460913d00), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x0), f64::INFINITY, f64::NAN, f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x8000000000000000),
+    -f64::INFINITY, f64::NAN, f64::from_bits(0x41e0000000100000), f64::from_bits(0x41dfffffffa00000), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x41dfffffffa00000),
+    f64::from_bits(0x41e0000000100000), f64::from_bits(0xc1dfffffffe00000), f64::from_bits(0xc1dfffffffe00000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x41e0000000000000), f64::from_bits(0x41dfffffffc00000), f64::from_bits(0x41cfffffffe00000), f64::from_bits(0x41efffffffe00000),
+    f64::from_bits(0x0), f64::from_bits(0x41e0000000400000), f64::from_bits(0x41dfffffff400000), f64::from_bits(0x41f3ffffffec0000), f64::from_bits(0x41c9999999800000), f64::from_bits(0x0), f64::from_bits(0x41dfffffff400000), f64::from_bits(0x41e0000000400000),
+    f64::from_bits(0xc1f3ffffffec0000), f64::from_bits(0xc1c9999999800000), f64::from_bits(0x0), f64::from_bits(0x4206a05f1ffc0000), f64::from_bits(0xc1fd40be40080000), f64::from_bits(0x43f2a05f1fed5fa1), f64::from_bits(0x3fcb7cdfd9bc40db), f64::from_bits(0x41dfffffffe00000),
+    f64::from_bits(0xc3e158e460813d00), f64::from_bits(0x43e158e460a13d00), f64::from_bits(0xc5d158e4607fe41c), f64::from_bits(0xbded83c94f994ee3), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x41efffffffe00000), f64::from_bits(0x0), f64::from_bits(0x43cfffffffc00000),
+    f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0), f64::from_bits(0x43e02207974f6440), f64::from_bits(0xc3e02207972f6440), f64::from_bits(0x45d02207972f4238), f64::from_bits(0x3defbc806099d633), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::INFINITY, f64::from_bits(0x1dfffffffe00001), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0x80000000), f64::INFINITY,
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0x41dfffffffe00000), -f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0x41dfffffffe00000), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x0), f64::INFINITY, f64::NAN, f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x8000000000000000),
+    -f64::INFINITY, f64::NAN, f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x0), f64::from_bits(0x43e02207973f6440),
+    f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0x0), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43d02207973f6440), f64::from_bits(0x43f02207973f6440),
+    f64::from_bits(0x0), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43f42a897d0f3d50), f64::from_bits(0x43c9d00c25323a00), f64::from_bits(0x0), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440),
+    f64::from_bits(0xc3f42a897d0f3d50), f64::from_bits(0xc3c9d00c25323a00), f64::from_bits(0x0), f64::from_bits(0x43e022079789e5bc), f64::from_bits(0x43e0220796f4e2c4), f64::from_bits(0x45f2c7fcc060ebe9), f64::from_bits(0x41cbb75640000000), f64::from_bits(0x0),
+    f64::from_bits(0xc3a36dcc951d8c00), f64::from_bits(0x43f0bd75fbe850a0), f64::from_bits(0xc7d17dc980be01e5), f64::from_bits(0xbfedc28f5c28f5c3), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207974f6440), f64::from_bits(0x43e02207972f6440), f64::from_bits(0x45d02207972f4238),
+    f64::from_bits(0x41f02207974f8648), f64::from_bits(0x41df0c8f2e800000), f64::from_bits(0x43f02207973f6440), f64::from_bits(0x0), f64::from_bits(0x47d044578ec52fd7), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::INFINITY, f64::from_bits(0x3e02207973f6441), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc02207973f6440), f64::INFINITY,
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0x43e02207973f6440), -f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0x43e02207973f6440), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x0), f64::INFINITY, f64::NAN, f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x8000000000000000),
+    -f64::INFINITY, f64::NAN, f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x0), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0x7fefffffffffffff), f64::from_bits(0xffefffffffffffff), f64::from_bits(0xffefffffffffffff), f64::from_bits(0x0), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fdfffffffffffff), f64::INFINITY,
+    f64::from_bits(0x0), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::INFINITY, f64::from_bits(0x7fd9999999999999), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff),
+    -f64::INFINITY, f64::from_bits(0xffd9999999999999), f64::from_bits(0x3fe0000000000000), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::INFINITY, f64::from_bits(0x7ddb7cdfd9d7bdba), f64::from_bits(0x41eebb8b00000000),
+    f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), -f64::INFINITY, f64::from_bits(0xfbfd83c94fb6d2ab), f64::from_bits(0x43966d8eb202e000), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::INFINITY,
+    f64::from_bits(0x7e00000000100000), f64::from_bits(0x41dffffe00200000), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::INFINITY, f64::from_bits(0x7bffbc8060b992b3), f64::from_bits(0x43cddd7c81605f00), f64::INFINITY,
+    f64::from_bits(0x0), f64::INFINITY, f64::from_bits(0x3ff0000000000000), f64::from_bits(0x0), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x7fefffffffffffff), f64::from_bits(0x3ccfffffffffffff), f64::INFINITY,
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0x7fefffffffffffff), -f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0x7fefffffffffffff), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::from_bits(0x1), f64::from_bits(0x1), f64::from_bits(0x0), f64::INFINITY, f64::NAN, f64::from_bits(0x1), f64::from_bits(0x1), f64::from_bits(0x8000000000000000),
+    -f64::INFINITY, f64::NAN, f64::from_bits(0x3ff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x1), f64::from_bits(0x1), f64::from_bits(0x1), f64::from_bits(0xbff0000000000000),
+    f64::from_bits(0x3ff0000000000000), f64::from_bits(0x8000000000000001), f64::from_bits(0x8000000000000001), f64::from_bits(0x1), f64::from_bits(0x3fe0000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0x0), f64::from_bits(0x2),
+    f64::from_bits(0x1), f64::from_bits(0x4004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x2), f64::from_bits(0x0), f64::from_bits(0x1), f64::from_bits(0xc004000000000000), f64::from_bits(0x4004000000000000),
+    f64::from_bits(0x8000000000000002), f64::from_bits(0x8000000000000000), f64::from_bits(0x1), f64::from_bits(0x4202a05f20000000), f64::from_bits(0xc202a05f20000000), f64::from_bits(0x2540be400), f64::from_bits(0x0), f64::from_bits(0x1),
+    f64::from_bits(0xc3e158e460913d00), f64::from_bits(0x43e158e460913d00), f64::from_bits(0x80c158e460913d00), f64::from_bits(0x8000000000000000), f64::from_bits(0x1), f64::from_bits(0x41dfffffffe00000), f64::from_bits(0xc1dfffffffe00000), f64::from_bits(0x80000000),
+    f64::from_bits(0x0), f64::from_bits(0x1), f64::from_bits(0x43e02207973f6440), f64::from_bits(0xc3e02207973f6440), f64::from_bits(0xc02207973f6440), f64::from_bits(0x0), f64::from_bits(0x1), f64::from_bits(0x7fefffffffffffff),
+    f64::from_bits(0xffefffffffffffff), f64::from_bits(0x3ccfffffffffffff), f64::from_bits(0x0), f64::from_bits(0x1), f64::from_bits(0x2), f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x3ff0000000000000),
+    f64::from_bits(0x0), f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::from_bits(0x0), f64::from_bits(0x1), -f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, f64::from_bits(0x8000000000000000), f64::from_bits(0x1), f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::INFINITY, f64::INFINITY, f64::NAN, f64::INFINITY, f64::NAN, f64::INFINITY, f64::INFINITY, f64::NAN,
+    -f64::INFINITY, f64::NAN, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::NAN, f64::INFINITY,
+    f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::NAN, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY,
+    f64::NAN, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::NAN, f64::INFINITY, f64::INFINITY,
+    -f64::INFINITY, -f64::INFINITY, f64::NAN, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::NAN,
+    f64::INFINITY, f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::NAN, f64::INFINITY, f64::INFINITY, f64::INFINITY,
+    f64::INFINITY, f64::NAN, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::NAN, f64::INFINITY,
+    f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::NAN, f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY,
+    f64::NAN, f64::INFINITY, f64::NAN, f64::INFINITY, f64::NAN, f64::NAN, f64::NAN, f64::INFINITY,
+    -f64::INFINITY, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    -f64::INFINITY, -f64::INFINITY, f64::NAN, -f64::INFINITY, f64::NAN, -f64::INFINITY, -f64::INFINITY, f64::NAN,
+    f64::INFINITY, f64::NAN, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::NAN, -f64::INFINITY,
+    -f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::NAN, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY,
+    f64::NAN, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::NAN, -f64::INFINITY, -f64::INFINITY,
+    f64::INFINITY, f64::INFINITY, f64::NAN, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::NAN,
+    -f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::NAN, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY,
+    -f64::INFINITY, f64::NAN, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::NAN, -f64::INFINITY,
+    -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::NAN, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY, -f64::INFINITY,
+    f64::NAN, f64::NAN, -f64::INFINITY, -f64::INFINITY, f64::NAN, f64::NAN, -f64::INFINITY, f64::NAN,
+    f64::INFINITY, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN,
+    f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN
+];
+
+const COMPARISONS: [i32; 481] = [
+    28, 28, 41, 50, 41, 41, 50, 41,
+    50, 41, 41, 41, 41, 50, 32, 28,
+    28, 41, 50, 41, 41, 50, 41, 50,
+    41, 41, 41, 41, 50, 32, 50, 50,
+    28, 50, 50, 41, 50, 41, 50, 41,
+    41, 50, 41, 50, 32, 41, 41, 41,
+    28, 41, 41, 50, 41, 50, 41, 41,
+    41, 41, 50, 32, 50, 50, 41, 50,
+    28, 41, 50, 41, 50, 41, 41, 50,
+    41, 50, 32, 50, 50, 50, 50, 50,
+    28, 50, 41, 50, 41, 41, 50, 41,
+    50, 32, 41, 41, 41, 41, 41, 41,
+    28, 41, 50, 41, 41, 41, 41, 50,
+    32, 50, 50, 50, 50, 50, 50, 50,
+    28, 50, 50, 41, 50, 41, 50, 32,
+    41, 41, 41, 41, 41, 41, 41, 41,
+    28, 41, 41, 41, 41, 50, 32, 50,
+    50, 50, 50, 50, 50, 50, 41, 50,
+    28, 41, 50, 41, 50, 32, 50, 50,
+    50, 50, 50, 50, 50, 50, 50, 50,
+    28, 50, 41, 50, 32, 50, 50, 41,
+    50, 41, 41, 50, 41, 50, 41, 41,
+    28, 41, 50, 32, 50, 50, 50, 50,
+    50, 50, 50, 50, 50, 50, 50, 50,
+    28, 50, 32, 41, 41, 41, 41, 41,
+    41, 41, 41, 41, 41, 41, 41, 41,
+    28, 32, 32, 32, 32, 32, 32, 32,
+    32, 32, 32, 32, 32, 32, 32, 32,
+    32, 28, 28, 41, 50, 41, 41, 50,
+    41, 50, 41, 41, 41, 41, 41, 50,
+    32, 28, 28, 41, 50, 41, 41, 50,
+    41, 50, 41, 41, 41, 41, 41, 50,
+    32, 50, 50, 28, 50, 50, 41, 50,
+    41, 50, 41, 41, 41, 50, 41, 50,
+    32, 41, 41, 41, 28, 41, 41, 50,
+    41, 50, 41, 41, 41, 41, 41, 50,
+    32, 50, 50, 41, 50, 28, 41, 50,
+    41, 50, 41, 41, 41, 50, 41, 50,
+    32, 50, 50, 50, 50, 50, 28, 50,
+    41, 50, 41, 41, 41, 50, 41, 50,
+    32, 41, 41, 41, 41, 41, 41, 28,
+    41, 50, 41, 41, 41, 41, 41, 50,
+    32, 50, 50, 50, 50, 50, 50, 50,
+    28, 50, 50, 41, 41, 50, 41, 50,
+    32, 41, 41, 41, 41, 41, 41, 41,
+    41, 28, 41, 41, 41, 41, 41, 50,
+    32, 50, 50, 50, 50, 50, 50, 50,
+    41, 50, 28, 41, 41, 50, 41, 50,
+    32, 50, 50, 50, 50, 50, 50, 50,
+    50, 50, 50, 28, 41, 50, 41, 50,
+    32, 50, 50, 50, 50, 50, 50, 50,
+    50, 50, 50, 50, 28, 50, 41, 50,
+    32, 50, 50, 41, 50, 41, 41, 50,
+    41, 50, 41, 41, 41, 28, 41, 50,
+    32, 50, 50, 50, 50, 50, 50, 50,
+    50, 50, 50, 50, 50, 50, 28, 50,
+    32, 41, 41, 41, 41, 41, 41, 41,
+    41, 41, 41, 41, 41, 41, 41, 28,
+    32, 32, 32, 32, 32, 32, 32, 32,
+    32, 32, 32, 32, 32, 32, 32, 32,
+    32
+];
+
+const CONVERSIONS: [i64; 62] = [
+    0, 0, 0, 0, 1, 1, -1, -1,
+    0, 0, 2, 2, -2, -2, 2147483647, 10000000000,
+    -2147483648, -10000000000, 2147483647, 2147483648, 2147483647, 9223372036854775807, 0, 0,
+    2147483647, 9223372036854775807, -2147483648, -9223372036854775808, 0, 0, 0, 0,
+    0, 0, 1, 1, -1, -1, 0, 0,
+    2, 2, -2, -2, 2147483647, 10000000000, -2147483648, -9223372036854775808,
+    2147483647, 2147483647, 2147483647, 9223372036854775807, 2147483647, 9223372036854775807, 0, 0,
+    2147483647, 9223372036854775807, -2147483648, -9223372036854775808, 0, 0
+];
+
+const WIDENING: [f64; 126] = [
+    f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x4000000000000000), f64::from_bits(0x4000000000000000),
+    f64::from_bits(0xc01c000000000000), f64::from_bits(0xc01c000000000000), f64::from_bits(0x403f000000000000), f64::from_bits(0x403f000000000000), f64::from_bits(0x4040000000000000), f64::from_bits(0x4040000000000000), f64::from_bits(0x4040800000000000), f64::from_bits(0x4040800000000000),
+    f64::from_bits(0x404f800000000000), f64::from_bits(0x404f800000000000), f64::from_bits(0x4050000000000000), f64::from_bits(0x4050000000000000), f64::from_bits(0x405fc00000000000), f64::from_bits(0x405fc00000000000), f64::from_bits(0xc060000000000000), f64::from_bits(0xc060000000000000),
+    f64::from_bits(0x40efffe000000000), f64::from_bits(0x40efffe000000000), f64::from_bits(0x41b2345680000000), f64::from_bits(0x41b2345678000000), f64::from_bits(0x41e0000000000000), f64::from_bits(0x41dfffffffc00000), f64::from_bits(0xc1e0000000000000), f64::from_bits(0xc1e0000000000000),
+    f64::from_bits(0x0), f64::from_bits(0x0), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x4008000000000000), f64::from_bits(0x4008000000000000),
+    f64::from_bits(0xc01c000000000000), f64::from_bits(0xc01c000000000000), f64::from_bits(0x403f000000000000), f64::from_bits(0x403f000000000000), f64::from_bits(0x4040000000000000), f64::from_bits(0x4040000000000000), f64::from_bits(0x404f800000000000), f64::from_bits(0x404f800000000000),
+    f64::from_bits(0x4050000000000000), f64::from_bits(0x4050000000000000), f64::from_bits(0x4050400000000000), f64::from_bits(0x4050400000000000), f64::from_bits(0x41f0000000000000), f64::from_bits(0x41efffffffe00000), f64::from_bits(0x41e0000000000000), f64::from_bits(0x41e0000000000000),
+    f64::from_bits(0xc1e0000000000000), f64::from_bits(0xc1e0000000000000), f64::from_bits(0x4372345680000000), f64::from_bits(0x43723456789abcdf), f64::from_bits(0x43e0000000000000), f64::from_bits(0x43e0000000000000), f64::from_bits(0xc3e0000000000000), f64::from_bits(0xc3e0000000000000),
+    f64::from_bits(0x0), f64::from_bits(0x8000000000000000), f64::from_bits(0x8000000000000000), f64::from_bits(0x0), f64::from_bits(0x3ff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x3ff0000000000000),
+    f64::from_bits(0x3fe0000000000000), f64::from_bits(0xbfe0000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0xc202a05f20000000),
+    f64::from_bits(0xc202a05f20000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0x41e0000000000000), f64::from_bits(0xc1e0000000000000), f64::from_bits(0x47efffffe0000000), f64::from_bits(0xc7efffffe0000000), f64::from_bits(0x36a0000000000000), f64::from_bits(0xb6a0000000000000),
+    f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::NAN, f64::NAN, f64::from_bits(0x0), f64::from_bits(0x8000000000000000),
+    f64::from_bits(0x8000000000000000), f64::from_bits(0x0), f64::from_bits(0x3ff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0xbff0000000000000), f64::from_bits(0x3ff0000000000000), f64::from_bits(0x3fe0000000000000), f64::from_bits(0xbfe0000000000000),
+    f64::from_bits(0x4004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0xc004000000000000), f64::from_bits(0x4004000000000000), f64::from_bits(0x4202a05f20000000), f64::from_bits(0xc202a05f20000000), f64::from_bits(0xc3e158e460000000), f64::from_bits(0x43e158e460913d00),
+    f64::from_bits(0x41e0000000000000), f64::from_bits(0xc1dfffffffe00000), f64::from_bits(0x43e02207a0000000), f64::from_bits(0xc3e02207973f6440), f64::INFINITY, f64::from_bits(0xffefffffffffffff), f64::from_bits(0x0), f64::from_bits(0x8000000000000001),
+    f64::INFINITY, -f64::INFINITY, -f64::INFINITY, f64::INFINITY, f64::NAN, f64::NAN
+];
+
+const NARROW_ARRAYS: [i32; 48] = [
+    100, 55296, -1, 101, 55297, 0, 99, 55295,
+    -2, 102, 55298, 1, 93, 55289, -8, -125,
+    55327, 30, -124, 55328, 31, -123, 55329, 32,
+    -93, 55359, 62, -92, 55360, 63, -29, 55423,
+    126, -28, 55168, -129, 99, 55295, -2, -36,
+    11896, 22135, 99, 55295, -2, 100, 55296, -1
+];
+
+fn int_results(runtime: &mut Runtime, path: &Path, method: &str) -> Vec<i32> {
+    return extract_int_array(&run_method(runtime, path, method, &Vec::new(), "[I")).unwrap();
+}
+
+fn long_results(runtime: &mut Runtime, path: &Path, method: &str) -> Vec<i64> {
+    let array = ArrayHandle::from_variable(&run_method(runtime, path, method, &Vec::new(), "[J")).unwrap();
+    return array.to_vec().iter().map(|x| x.to_long()).collect();
+}
+
+fn float_results(runtime: &mut Runtime, path: &Path, method: &str) -> Vec<f32> {
+    let array = ArrayHandle::from_variable(&run_method(runtime, path, method, &Vec::new(), "[F")).unwrap();
+    return array.to_vec().iter().map(|x| x.to_float()).collect();
+}
+
+fn double_results(runtime: &mut Runtime, path: &Path, method: &str) -> Vec<f64> {
+    let array = ArrayHandle::from_variable(&run_method(runtime, path, method, &Vec::new(), "[D")).unwrap();
+    return array.to_vec().iter().map(|x| x.to_double()).collect();
+}
+
+fn check<T: PartialEq + std::fmt::Debug>(method: &str, actual: &[T], expected: &[T]) {
+    assert_eq!(actual.len(), expected.len(), "{} returned the wrong number of results", method);
+    for i in 0..expected.len() {
+        assert_eq!(actual[i], expected[i], "{} differs at {}", method, i);
+    }
+}
+
+// NaNs compare equal to each other, zeros must match in sign
+fn check_floats(method: &str, actual: &[f32], expected: &[f32]) {
+    let to_bits = |x: &f32| if x.is_nan() { std::f32::NAN.to_bits() } else { x.to_bits() };
+    check(method, &actual.iter().map(&to_bits).collect::<Vec<_>>(), &expected.iter().map(&to_bits).collect::<Vec<_>>());
+}
+
+fn check_doubles(method: &str, actual: &[f64], expected: &[f64]) {
+    let to_bits = |x: &f64| if x.is_nan() { std::f64::NAN.to_bits() } else { x.to_bits() };
+    check(method, &actual.iter().map(&to_bits).collect::<Vec<_>>(), &expected.iter().map(&to_bits).collect::<Vec<_>>());
+}
+
+#[test]
+fn conformance() {
+    let (mut runtime, class_path) = setup("conformance", r##"
+        public class conformance {
+            private static final int DIV_ZERO = 0x0BAD0DEF;
+
+            private static final int[] INTS = { 0, 1, -1, 2, -7, 31, 32, 33, 63, 64, 127, -128, 65535, 0x12345678, 0x7fffffff, 0x80000000 };
+            private static final long[] LONGS = { 0L, 1L, -1L, 3L, -7L, 31L, 32L, 63L, 64L, 65L, 0xffffffffL, 0x80000000L, -0x80000000L,
+                0x123456789abcdefL, 0x7fffffffffffffffL, 0x8000000000000000L };
+            private static final float[] FLOATS = { 0.0f, -0.0f, 1.0f, -1.0f, 0.5f, 2.5f, -2.5f, 1e10f, -1e10f, 2147483648.0f,
+                Float.MAX_VALUE, Float.MIN_VALUE, Float.POSITIVE_INFINITY, Float.NEGATIVE_INFINITY, Float.NaN };
+            private static final double[] DOUBLES = { 0.0, -0.0, 1.0, -1.0, 0.5, 2.5, -2.5, 1e10, -1e19, 2147483647.5, 9.3e18,
+                Double.MAX_VALUE, Double.MIN_VALUE, Double.POSITIVE_INFINITY, Double.NEGATIVE_INFINITY, Double.NaN };
+
+            private static int intDivide(int a, int b) {
+                try { return a / b; } catch (ArithmeticException e) { return DIV_ZERO; }
+            }
+
+            private static int intRemainder(int a, int b) {
+                try { return a % b; } catch (ArithmeticException e) { return DIV_ZERO; }
+            }
+
+            private static long longDivide(long a, long b) {
+                try { return a / b; } catch (ArithmeticException e) { return DIV_ZERO; }
+            }
+
+            private static long longRemainder(long a, long b) {
+                try { return a % b; } catch (ArithmeticException e) { return DIV_ZERO; }
+            }
+
+            public static int[] intBinary() {
+                int[] r = new int[INTS.length * INTS.length * 11];
+                int n = 0;
+                for (int a : INTS) {
+                    for (int b : INTS) {
+                        r[n++] = a + b;
+                        r[n++] = a - b;
+                        r[n++] = a * b;
+                        r[n++] = intDivide(a, b);
+                        r[n++] = intRemainder(a, b);
+                        r[n++] = a << b;
+                        r[n++] = a >> b;
+                        r[n++] = a >>> b;
+                        r[n++] = a & b;
+                        r[n++] = a | b;
+                        r[n++] = a ^ b;
+                    }
+                }
+                return r;
+            }
+
+            public static long[] longBinary() {
+                long[] r = new long[LONGS.length * LONGS.length * 12];
+                int n = 0;
+                for (long a : LONGS) {
+                    for (long b : LONGS) {
+                        r[n++] = a + b;
+                        r[n++] = a - b;
+                        r[n++] = a * b;
+                        r[n++] = longDivide(a, b);
+                        r[n++] = longRemainder(a, b);
+                        r[n++] = a << b;
+                        r[n++] = a >> b;
+                        r[n++] = a >>> b;
+                        r[n++] = a & b;
+                        r[n++] = a | b;
+                        r[n++] = a ^ b;
+                        r[n++] = a < b ? -1 : a == b ? 0 : 1;
+                    }
+                }
+                return r;
+            }
+
+            public static int[] intUnary() {
+                int[] r = new int[INTS.length * 6];
+                int n = 0;
+                for (int a : INTS) {
+                    r[n++] = -a;
+                    r[n++] = (byte) a;
+                    r[n++] = (char) a;
+                    r[n++] = (short) a;
+                    r[n++] = a++ + ++a;
+                    r[n++] = a += 1000;
+                }
+                return r;
+            }
+
+            public static long[] longUnary() {
+                long[] r = new long[LONGS.length * 2];
+                int n = 0;
+                for (long a : LONGS) {
+                    r[n++] = -a;
+                    r[n++] = (int) a;
+                }
+                return r;
+            }
+
+            public static float[] floatBinary() {
+                float[] r = new float[FLOATS.length * FLOATS.length * 5];
+                int n = 0;
+                for (float a : FLOATS) {
+                    for (float b : FLOATS) {
+                        r[n++] = a + b;
+                        r[n++] = a - b;
+                        r[n++] = a * b;
+                        r[n++] = a / b;
+                        r[n++] = a % b;
+                    }
+                }
+                return r;
+            }
+
+            public static double[] doubleBinary() {
+                double[] r = new double[DOUBLES.length * DOUBLES.length * 5];
+                int n = 0;
+                for (double a : DOUBLES) {
+                    for (double b : DOUBLES) {
+                        r[n++] = a + b;
+                        r[n++] = a - b;
+                        r[n++] = a * b;
+                        r[n++] = a / b;
+                        r[n++] = a % b;
+                    }
+                }
+                return r;
+            }
+
+            // Each comparison sets one bit, covering both the fcmpl and fcmpg forms javac picks
+            public static int[] comparisons() {
+                int[] r = new int[FLOATS.length * FLOATS.length + DOUBLES.length * DOUBLES.length];
+                int n = 0;
+                for (float a : FLOATS) {
+                    for (float b : FLOATS) {
+                        r[n++] = (a < b ? 1 : 0) | (a > b ? 2 : 0) | (a == b ? 4 : 0) | (a <= b ? 8 : 0) | (a >= b ? 16 : 0) | (a != b ? 32 : 0);
+                    }
+                }
+                for (double a : DOUBLES) {
+                    for (double b : DOUBLES) {
+                        r[n++] = (a < b ? 1 : 0) | (a > b ? 2 : 0) | (a == b ? 4 : 0) | (a <= b ? 8 : 0) | (a >= b ? 16 : 0) | (a != b ? 32 : 0);
+                    }
+                }
+                return r;
+            }
+
+            // Float and double narrowed to int and long, then widened the other way
+            public static long[] conversions() {
+                long[] r = new long[FLOATS.length * 2 + DOUBLES.length * 2];
+                int n = 0;
+                for (float a : FLOATS) {
+                    r[n++] = (int) a;
+                    r[n++] = (long) a;
+                }
+                for (double a : DOUBLES) {
+                    r[n++] = (int) a;
+                    r[n++] = (long) a;
+                }
+                return r;
+            }
+
+            public static double[] widening() {
+                double[] r = new double[INTS.length * 2 + LONGS.length * 2 + FLOATS.length * 2 + DOUBLES.length * 2];
+                int n = 0;
+                for (int a : INTS) {
+                    r[n++] = (float) a;
+                    r[n++] = (double) a;
+                }
+                for (long a : LONGS) {
+                    r[n++] = (float) a;
+                    r[n++] = (double) a;
+                }
+                for (float a : FLOATS) {
+                    r[n++] = (double) a;
+                    r[n++] = -a;
+                }
+                for (double a : DOUBLES) {
+                    r[n++] = (float) a;
+                    r[n++] = -a;
+                }
+                return r;
+            }
+
+            // Narrow array stores keep exactly 8 or 16 bits, chars unsigned and bytes and shorts signed
+            public static int[] narrowArrays() {
+                byte[] bytes = new byte[INTS.length];
+                char[] chars = new char[INTS.length];
+                short[] shorts = new short[INTS.length];
+                for (int i = 0; i < INTS.length; i++) {
+                    bytes[i] = (byte) INTS[i];
+                    chars[i] = (char) INTS[i];
+                    shorts[i] = (short) INTS[i];
+                    bytes[i] += 100;
+                    chars[i] += 0xd800;
+                    shorts[i] -= 1;
+                }
+                int[] r = new int[INTS.length * 3];
+                for (int i = 0; i < INTS.length; i++) {
+                    r[i * 3] = bytes[i];
+                    r[i * 3 + 1] = chars[i];
+                    r[i * 3 + 2] = shorts[i];
+                }
+                return r;
+            }
+        }
+    "##, false);
+
+    let path = class_path.as_path();
+    check("intBinary", &int_results(&mut runtime, path, "intBinary"), &INT_BINARY);
+    check("longBinary", &long_results(&mut runtime, path, "longBinary"), &LONG_BINARY);
+    check("intUnary", &int_results(&mut runtime, path, "intUnary"), &INT_UNARY);
+    check("longUnary", &long_results(&mut runtime, path, "longUnary"), &LONG_UNARY);
+    check_floats("floatBinary", &float_results(&mut runtime, path, "floatBinary"), &FLOAT_BINARY);
+    check_doubles("doubleBinary", &double_results(&mut runtime, path, "doubleBinary"), &DOUBLE_BINARY);
+    check("comparisons", &int_results(&mut runtime, path, "comparisons"), &COMPARISONS);
+    check("conversions", &long_results(&mut runtime, path, "conversions"), &CONVERSIONS);
+    check_doubles("widening", &double_results(&mut runtime, path, "widening"), &WIDENING);
+    check("narrowArrays", &int_results(&mut runtime, path, "narrowArrays"), &NARROW_ARRAYS);
+}
@@ -0,0 +1,77 @@
+mod common;
+use common::*;
+
+#[test]
+fn dispatch() {
+    // Enough long locals to push the last ones past index 255 so they need WIDE
+    let mut wide_locals = String::new();
+    let mut wide_sum = String::from("0");
+    for i in 0..140 {
+        wide_locals.push_str(format!("long l{} = x + {};\n", i, i).as_str());
+        wide_sum.push_str(format!(" + l{}", i).as_str());
+    }
+
+    let source = format!(r##"
+        public class dispatch {{
+            public static int negativeSwitch(int x) {{
+                switch(x) {{
+                    case -2: return 20;
+                    case -1: return 10;
+                    case 0: return 0;
+                    case 1: return -10;
+                    default: return 99;
+                }}
+            }}
+
+            public static int sparseSwitch(int x) {{
+                switch(x) {{
+                    case -100000: return 1;
+                    case 7: return 2;
+                    case 1000: return 3;
+                    case 123456: return 4;
+                    default: return 5;
+                }}
+            }}
+
+            public static long wideLocals(long x, long y) {{
+                {}
+                for (int i = 0; i < 300; i++) {{
+                    l139 += i;
+                }}
+                return y + {};
+            }}
+
+            public static int rethrowInLoop(int n) {{
+                int caught = 0;
+                for (int i = 0; i < n; i++) {{
+                    try {{
+                        try {{
+                            if (i % 2 == 0) {{
+                                throw new IllegalArgumentException();
+                            }}
+                        }} catch (IllegalArgumentException e) {{
+                            caught += 1;
+                            throw new IllegalStateException();
+                        }}
+                    }} catch (IllegalStateException e) {{
+                        if (e.fillInStackTrace() == e) {{
+                            caught += 100;
+                        }}
+                    }}
+                }}
+                return caught;
+            }}
+        }}
+    "##, wide_locals, wide_sum);
+    let (mut runtime, class_path) = setup("dispatch", source.as_str(), false);
+
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "negativeSwitch", -2), 20);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "negativeSwitch", -1), 10);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "negativeSwitch", 1), -10);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "negativeSwitch", -3), 99);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "sparseSwitch", -100000), 1);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "sparseSwitch", 123456), 4);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "sparseSwitch", 8), 5);
+    assert_eq!(long2_long_call(&mut runtime, class_path.as_path(), "wideLocals", 1, 0), 140 + 9730 + 44850);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "rethrowInLoop", 5), 303);
+}
@@ -0,0 +1,75 @@
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::{RunnerError, run};
+
+#[test]
+fn exit() {
+    let (mut runtime, class_path) = setup("exits", r##"
+        class Hooks {
+            static int ran = 0;
+        }
+
+        public class exits {
+            public static int exit(int status) {
+                Runtime.getRuntime().addShutdownHook(new Thread("counting") {
+                    public void run() {
+                        Hooks.ran += 1;
+                    }
+                });
+                Runtime.getRuntime().addShutdownHook(new Thread(new Runnable() {
+                    public void run() {
+                        Hooks.ran += 10;
+                        throw new RuntimeException("hook failed");
+                    }
+                }, "throwing"));
+                try {
+                    System.exit(status);
+                } finally {
+                    Hooks.ran += 1000;
+                }
+                return -1;
+            }
+
+            public static int ran() {
+                return Hooks.ran;
+            }
+        }
+    "##, true);
+    match run(&mut runtime, class_path.as_path(), "exit", &vec!(Variable::Int(42)), "I") {
+        Err(RunnerError::ExitCode(42)) => {}
+        other => panic!("Expected exit code 42, got {:?}", other),
+    }
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "ran"), 11);
+}
+
+#[test]
+fn halt() {
+    let (mut runtime, class_path) = setup("halts", r##"
+        class Hooks {
+            static int ran = 0;
+        }
+
+        public class halts {
+            public static int halt() {
+                Runtime.getRuntime().addShutdownHook(new Thread("counting") {
+                    public void run() {
+                        Hooks.ran += 1;
+                    }
+                });
+                Runtime.getRuntime().halt(3);
+                return -1;
+            }
+
+            public static int ran() {
+                return Hooks.ran;
+            }
+        }
+    "##, true);
+    match run(&mut runtime, class_path.as_path(), "halt", &Vec::new(), "I") {
+        Err(RunnerError::ExitCode(3)) => {}
+        other => panic!("Expected exit code 3, got {:?}", other),
+    }
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "ran"), 0);
+}
@@ -0,0 +1,111 @@
+mod common;
+use common::*;
+use std::env;
+use std::fs;
+use std::os::unix::fs::symlink;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[test]
+fn file_io() {
+    let (mut runtime, class_path) = setup("files", r##"
+        import java.io.*;
+
+        public class files {
+            public static boolean streams() throws IOException {
+                FileOutputStream out = new FileOutputStream("/data.bin", false);
+                out.write(new byte[] {1, 2, 3, 4, 5, 6}, 1, 4);
+                out.write(7);
+                out.close();
+                FileOutputStream more = new FileOutputStream("/data.bin", true);
+                more.write(8);
+                more.close();
+
+                FileInputStream in = new FileInputStream("/data.bin");
+                byte[] buffer = new byte[8];
+                boolean ok = in.available() == 6 && in.read() == 2 && in.skip(2) == 2 && in.available() == 3;
+                ok = ok && in.read(buffer, 1, 8 - 1) == 3 && buffer[1] == 5 && buffer[2] == 7 && buffer[3] == 8;
+                ok = ok && in.read() == -1 && in.read(buffer, 0, 8) == -1 && in.read(buffer, 0, 0) == 0;
+                in.close();
+                try {
+                    in.read();
+                    return false;
+                } catch (IOException e) {
+                }
+                return ok;
+            }
+
+            public static boolean randomAccess() throws IOException {
+                RandomAccessFile file = new RandomAccessFile("/random.bin", "rw");
+                file.write(new byte[] {10, 20, 30, 40}, 0, 4);
+                boolean ok = file.getFilePointer() == 4 && file.length() == 4;
+                file.seek(1);
+                ok = ok && file.read() == 20;
+                file.write(99);
+                file.seek(0);
+                byte[] buffer = new byte[4];
+                ok = ok && file.read(buffer, 0, 4) == 4 && buffer[2] == 99 && buffer[3] == 40;
+                file.setLength(2);
+                ok = ok && file.length() == 2 && file.getFilePointer() == 2 && file.read() == -1;
+                file.close();
+                return ok;
+            }
+
+            public static int missing() {
+                int failures = 0;
+                try { new FileInputStream("/nothing/here"); } catch (FileNotFoundException e) { failures++; }
+                try { new FileInputStream("/../../../etc/passwd"); } catch (FileNotFoundException e) { failures++; }
+                try { new FileInputStream("/"); } catch (FileNotFoundException e) { failures++; }
+                try { new RandomAccessFile("/nothing", "r"); } catch (FileNotFoundException e) { failures++; }
+                return failures;
+            }
+
+            public static boolean fileSystem() throws IOException {
+                File dir = new File("/dir");
+                boolean ok = !dir.exists() && dir.mkdir() && dir.exists() && dir.isDirectory() && !dir.isFile();
+                File file = new File("/dir/a.txt");
+                ok = ok && file.createNewFile() && !file.createNewFile() && file.isFile() && file.length() == 0 && file.canRead();
+                ok = ok && file.lastModified() > 0;
+                ok = ok && file.renameTo(new File("/dir/b.txt")) && !file.exists();
+                String[] names = dir.list();
+                ok = ok && names.length == 1 && names[0].equals("b.txt") && new File("/dir/nothing").list() == null;
+                ok = ok && !dir.delete() && new File("/dir/b.txt").delete() && dir.delete() && !dir.exists();
+                ok = ok && new File("/a/./b/../c").getCanonicalPath().equals("/a/c");
+                return ok;
+            }
+
+            public static int escape() {
+                return new File("/../outside").exists() ? 1 : 0;
+            }
+
+            public static int symlinks() throws IOException {
+                int refused = 0;
+                try { new FileInputStream("/secret"); } catch (FileNotFoundException e) { refused++; }
+                try { new FileOutputStream("/up/written", false); } catch (FileNotFoundException e) { refused++; }
+                try { new FileOutputStream("/dangling", false); } catch (FileNotFoundException e) { refused++; }
+                try { new File("/up/created").createNewFile(); } catch (IOException e) { refused++; }
+                refused += new File("/up/outside").exists() ? 0 : 1;
+                refused += new File("/inside").exists() ? 0 : 1;
+                return refused;
+            }
+        }
+    "##, true);
+    let base = env::temp_dir().join(format!("rjvm-files-{}-{}", process::id(), SystemTime::now().duration_since(UNIX_EPOCH).unwrap().subsec_nanos()));
+    let root = base.join("root");
+    fs::create_dir_all(&root).unwrap();
+    fs::write(base.join("outside"), b"x").unwrap();
+    symlink(base.join("outside"), root.join("secret")).unwrap();
+    symlink(&base, root.join("up")).unwrap();
+    symlink(base.join("dangled"), root.join("dangling")).unwrap();
+    symlink(root.join("data.bin"), root.join("inside")).unwrap();
+    runtime.set_file_root(Some(root.clone()));
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "streams"), true);
+    assert_eq!(fs::read(root.join("data.bin")).unwrap(), vec!(2, 3, 4, 5, 7, 8));
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "randomAccess"), true);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "missing"), 4);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "fileSystem"), true);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "escape"), 0);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "symlinks"), 5);
+    assert!(!base.join("written").exists() && !base.join("dangled").exists() && !base.join("created").exists());
+    fs::remove_dir_all(&base).unwrap();
+}
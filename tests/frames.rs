@@ -0,0 +1,41 @@
+mod common;
+use common::*;
+
+#[test]
+fn frames() {
+    let (mut runtime, class_path) = setup("frames", r##"
+        public class frames {
+            private static int fib(int n) {
+                if (n < 2) {
+                    return n;
+                }
+                return fib(n - 1) + fib(n - 2);
+            }
+
+            private static int throwAt(int depth, int target) {
+                if (depth == target) {
+                    throw new IllegalStateException();
+                }
+                int local = depth * 3;
+                return throwAt(depth + 1, target) + local;
+            }
+
+            public static int recursiveFib(int n) {
+                return fib(n);
+            }
+
+            public static int unwindAndContinue(int n) {
+                int before = n * 7;
+                try {
+                    throwAt(0, n);
+                } catch (IllegalStateException e) {
+                    before += 1;
+                }
+                return before + fib(5);
+            }
+        }
+    "##, false);
+
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "recursiveFib", 15), 610);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "unwindAndContinue", 20), 146);
+}
@@ -0,0 +1,154 @@
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::{ArrayHandle, ObjectHandle, extract_hash_map, extract_int_array, extract_string, make_hash_map, make_int_array};
+use std::collections::HashMap;
+
+#[test]
+pub fn handles() {
+    let (mut runtime, class_path) = setup("handles", r##"
+        import java.util.HashMap;
+
+        public class handles {
+            public int x;
+            public long y;
+            public String name;
+
+            public handles(int x, long y, String name) {
+                this.x = x;
+                this.y = y;
+                this.name = name;
+            }
+
+            public static int sumX(handles a, handles b) {
+                return a.x + b.x;
+            }
+
+            public static int sumArray(int[] values) {
+                int total = 0;
+                for (int value : values) {
+                    total += value;
+                }
+                return total;
+            }
+
+            public static int[] makeArray(int size) {
+                int[] ret = new int[size];
+                for (int i = 0; i < size; i++) {
+                    ret[i] = i * 2;
+                }
+                return ret;
+            }
+
+            public static String lookup(HashMap<String, String> map, String key) {
+                return map.get(key);
+            }
+
+            public static HashMap<String, String> makeMap(String key, String value) {
+                HashMap<String, String> map = new HashMap<String, String>();
+                map.put(key, value);
+                return map;
+            }
+        }
+    "##, true);
+
+    let name = make_string(&mut runtime, "first").unwrap();
+    let a = ObjectHandle::new(&mut runtime, "handles", "(IJLjava/lang/String;)V", &vec!(Variable::Int(3), Variable::Long(1 << 40), name)).unwrap();
+    assert_eq!(a.class_name(), "handles");
+    assert_eq!(a.get_field(&mut runtime, "x").unwrap().to_int(), 3);
+    assert_eq!(a.get_field(&mut runtime, "y").unwrap().to_long(), 1 << 40);
+    let name_var = a.get_field(&mut runtime, "name").unwrap();
+    assert_eq!(extract_string(&mut runtime, &name_var).unwrap(), "first");
+
+    a.set_field(&mut runtime, "x", Variable::Int(10)).unwrap();
+    assert_eq!(a.get_field(&mut runtime, "x").unwrap().to_int(), 10);
+    assert!(a.set_field(&mut runtime, "x", Variable::Long(10)).is_err());
+    assert!(a.set_field(&mut runtime, "missing", Variable::Int(10)).is_err());
+    assert!(ObjectHandle::new(&mut runtime, "handles", "(IJLjava/lang/String;)V", &vec!(Variable::Int(3))).is_err());
+
+    let name2 = make_string(&mut runtime, "second").unwrap();
+    let b = ObjectHandle::new(&mut runtime, "handles", "(IJLjava/lang/String;)V", &vec!(Variable::Int(5), Variable::Long(0), name2)).unwrap();
+    let sum = run_method(&mut runtime, class_path.as_path(), "sumX", &vec!(a.to_variable(), b.to_variable()), "I");
+    assert_eq!(sum.to_int(), 15);
+
+    let values = make_int_array(&mut runtime, &[1, 2, 3, 4]).unwrap();
+    assert_eq!(run_method(&mut runtime, class_path.as_path(), "sumArray", &vec!(values), "I").to_int(), 10);
+
+    let returned = run_method(&mut runtime, class_path.as_path(), "makeArray", &vec!(Variable::Int(4)), "[I");
+    assert_eq!(extract_int_array(&returned).unwrap(), vec!(0, 2, 4, 6));
+
+    let ints = ArrayHandle::new_primitive(&mut runtime, "I", 3).unwrap();
+    assert_eq!(ints.len(), 3);
+    assert_eq!(ints.element_descriptor(), "I");
+    ints.set(&mut runtime, 1, Variable::Int(7)).unwrap();
+    assert!(ints.set(&mut runtime, 3, Variable::Int(7)).is_err());
+    assert!(ints.set(&mut runtime, 0, Variable::Float(1.0)).is_err());
+    assert_eq!(run_method(&mut runtime, class_path.as_path(), "sumArray", &vec!(ints.to_variable()), "I").to_int(), 7);
+
+    let strings = ArrayHandle::new_object(&mut runtime, "java/lang/String", 2).unwrap();
+    assert!(!strings.is_primitive());
+    assert!(strings.get(0).unwrap().is_null());
+    let hello = make_string(&mut runtime, "hello").unwrap();
+    strings.set(&mut runtime, 0, hello).unwrap();
+    assert!(strings.set(&mut runtime, 1, Variable::Int(1)).is_err());
+    let hello_var = strings.get(0).unwrap();
+    assert_eq!(extract_string(&mut runtime, &hello_var).unwrap(), "hello");
+
+    let mut map = HashMap::new();
+    map.insert(String::from("key"), String::from("value"));
+    map.insert(String::from("other"), String::from("thing"));
+    let map_var = make_hash_map(&mut runtime, &map).unwrap();
+    assert_eq!(str_str_call_with(&mut runtime, class_path.as_path(), "lookup", map_var.clone(), "other").unwrap(), "thing");
+    assert_eq!(extract_hash_map(&mut runtime, &map_var).unwrap(), map);
+
+    let key = make_string(&mut runtime, "a").unwrap();
+    let value = make_string(&mut runtime, "b").unwrap();
+    let returned_map = run_method(&mut runtime, class_path.as_path(), "makeMap", &vec!(key, value), "Ljava/util/HashMap;");
+    let extracted = extract_hash_map(&mut runtime, &returned_map).unwrap();
+    assert_eq!(extracted.len(), 1);
+    assert_eq!(extracted.get("a").unwrap(), "b");
+}
+
+fn str_str_call_with(runtime: &mut Runtime, path: &Path, method: &str, first: Variable, arg: &str) -> Option<String> {
+    let argvar = make_string(runtime, arg).expect("Couldn't create string for argument");
+    let ret = run_method(runtime, path, method, &vec!(first, argvar), "Ljava/lang/String;");
+    if ret.is_null() {
+        return None;
+    } else {
+        return Some(ret.extract_string());
+    }
+}
+
+#[test]
+fn handle_reference_types() {
+    let (mut runtime, _class_path) = setup("handle_types", r##"
+        public class handle_types {
+            public Runnable task;
+            public Object[] items;
+            public Runnable[] tasks;
+
+            static class Task implements Runnable {
+                public void run() {
+                }
+            }
+
+            static class Plain {
+            }
+        }
+    "##, false);
+
+    let holder = ObjectHandle::new(&mut runtime, "handle_types", "()V", &Vec::new()).unwrap();
+    let task = ObjectHandle::new(&mut runtime, "handle_types$Task", "()V", &Vec::new()).unwrap();
+    let plain = ObjectHandle::new(&mut runtime, "handle_types$Plain", "()V", &Vec::new()).unwrap();
+    holder.set_field(&mut runtime, "task", task.to_variable()).unwrap();
+    assert!(holder.set_field(&mut runtime, "task", plain.to_variable()).is_err());
+
+    let tasks = ArrayHandle::new_object(&mut runtime, "handle_types$Task", 2).unwrap();
+    holder.set_field(&mut runtime, "items", tasks.to_variable()).unwrap();
+    holder.set_field(&mut runtime, "tasks", tasks.to_variable()).unwrap();
+    let plains = ArrayHandle::new_object(&mut runtime, "handle_types$Plain", 2).unwrap();
+    assert!(holder.set_field(&mut runtime, "tasks", plains.to_variable()).is_err());
+    let ints = ArrayHandle::new_primitive(&mut runtime, "I", 2).unwrap();
+    assert!(holder.set_field(&mut runtime, "items", ints.to_variable()).is_err());
+}
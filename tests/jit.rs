@@ -0,0 +1,168 @@
+mod common;
+use common::*;
+
+const SOURCE: &'static str = r##"
+    public class jit {
+        private static int square(int x) {
+            return x * x;
+        }
+
+        private static int fib(int n) {
+            if (n < 2) {
+                return n;
+            }
+            return fib(n - 1) + fib(n - 2);
+        }
+
+        private static long mix(long a, int shift) {
+            return (a << shift) ^ ((a * 3) >> 2) ^ (a >>> 3);
+        }
+
+        private static int classify(int x) {
+            switch (x) {
+                case -5: return 1;
+                case 0: return 2;
+                case 3: return 3;
+                case 1000: return 4;
+                default: return x % 2 == 0 ? 5 : 6;
+            }
+        }
+
+        private static int narrow(int x) {
+            return (byte) x + (char) x + (short) x;
+        }
+
+        private static float blend(float a, float b) {
+            return a > b ? a * 0.5f - b : b / 2.0f + a;
+        }
+
+        public static int sumSquares(int n) {
+            int total = 0;
+            for (int i = 0; i < n; i++) {
+                total += square(i);
+            }
+            return total;
+        }
+
+        public static int fibs(int n) {
+            int total = 0;
+            for (int i = 0; i < n; i++) {
+                total += fib(i);
+            }
+            return total;
+        }
+
+        public static long mixLoop(long a, long n) {
+            long total = 0;
+            for (long i = 0; i < n; i++) {
+                total += mix(a + i, (int) i & 31);
+            }
+            return total;
+        }
+
+        public static int classifyLoop(int n) {
+            int total = 0;
+            for (int i = -10; i < n; i++) {
+                total = total * 3 + classify(i);
+            }
+            return total;
+        }
+
+        public static int narrowLoop(int n) {
+            int total = 0;
+            for (int i = 0; i < n; i++) {
+                total += narrow(i & 127);
+            }
+            return total;
+        }
+
+        public static float blendLoop(float a, float b) {
+            float total = 0;
+            for (int i = 0; i < 100; i++) {
+                total = blend(total + a, b - i);
+            }
+            return total;
+        }
+
+        public static double conversions(double x, double y) {
+            double total = y;
+            for (int i = 0; i < 50; i++) {
+                double v = x * i - y;
+                long big = (long) (v * 1e12);
+                total += (int) v + (float) v + (int) (big >> 40);
+                if ((float) v < (float) y) {
+                    total -= 0.5;
+                }
+            }
+            return total;
+        }
+    }
+"##;
+
+fn run_all(runtime: &mut Runtime, path: &Path) -> (i32, i32, i64, i32, i32, f32, f64) {
+    return (
+        int_int_call(runtime, path, "sumSquares", 1000),
+        int_int_call(runtime, path, "fibs", 20),
+        long2_long_call(runtime, path, "mixLoop", 123456789, 300),
+        int_int_call(runtime, path, "classifyLoop", 1010),
+        int_int_call(runtime, path, "narrowLoop", 700),
+        float2_float_call(runtime, path, "blendLoop", 1.5, 40.0),
+        double2_double_call(runtime, path, "conversions", 1e7, 3.25),
+    );
+}
+
+#[test]
+fn jit() {
+    let (mut runtime, class_path) = setup("jit", SOURCE, false);
+    runtime.set_jit_enabled(false);
+    let interpreted = run_all(&mut runtime, class_path.as_path());
+    assert_eq!(runtime.jit_compiled_count(), 0);
+
+    let (mut runtime, class_path) = setup("jit", SOURCE, false);
+    runtime.set_jit_thresholds(5, 50);
+    let compiled = run_all(&mut runtime, class_path.as_path());
+    assert!(runtime.jit_compiled_count() >= 7);
+
+    assert_eq!(compiled, interpreted);
+    assert_eq!(compiled.0, 332833500);
+    assert_eq!(compiled.1, 10945);
+}
+
+const OVERFLOW_SOURCE: &'static str = r##"
+    public class jit_overflow {
+        private static int down(int n) {
+            return down(n + 1) + 1;
+        }
+
+        private static int count(int n) {
+            return n == 0 ? 0 : count(n - 1) + 1;
+        }
+
+        public static int overflow(int n) {
+            try {
+                return down(n);
+            } catch (StackOverflowError e) {
+                return -1;
+            }
+        }
+
+        public static int deep(int n) {
+            return count(n);
+        }
+    }
+"##;
+
+#[test]
+fn jit_stack_overflow() {
+    let (mut runtime, class_path) = setup("jit_overflow", OVERFLOW_SOURCE, false);
+    runtime.set_jit_enabled(false);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "overflow", 0), -1);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "deep", 3000), 3000);
+
+    let (mut runtime, class_path) = setup("jit_overflow", OVERFLOW_SOURCE, false);
+    runtime.set_jit_thresholds(5, 50);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "overflow", 0), -1);
+    assert!(runtime.jit_compiled_count() >= 1);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "deep", 3000), 3000);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "overflow", 0), -1);
+}
@@ -21,8 +21,8 @@ fn lookupswitch() {
             }
         }
     "##, false);
-    assert_eq!(run_method(&mut runtime, class_path.as_path(), "check", &vec!(Variable::Char('a')), "Z"), Variable::Int(0));
-    assert_eq!(run_method(&mut runtime, class_path.as_path(), "check", &vec!(Variable::Char('.')), "Z"), Variable::Int(1));
-    assert_eq!(run_method(&mut runtime, class_path.as_path(), "check", &vec!(Variable::Char('>')), "Z"), Variable::Int(1));
-    assert_eq!(run_method(&mut runtime, class_path.as_path(), "check", &vec!(Variable::Char(' ')), "Z"), Variable::Int(0));
+    assert_eq!(run_method(&mut runtime, class_path.as_path(), "check", &vec!(Variable::Char('a' as u16)), "Z"), Variable::Int(0));
+    assert_eq!(run_method(&mut runtime, class_path.as_path(), "check", &vec!(Variable::Char('.' as u16)), "Z"), Variable::Int(1));
+    assert_eq!(run_method(&mut runtime, class_path.as_path(), "check", &vec!(Variable::Char('>' as u16)), "Z"), Variable::Int(1));
+    assert_eq!(run_method(&mut runtime, class_path.as_path(), "check", &vec!(Variable::Char(' ' as u16)), "Z"), Variable::Int(0));
 }
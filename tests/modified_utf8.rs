@@ -0,0 +1,127 @@
+extern crate rand;
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::{ClassReadError, decode_modified_utf8, encode_modified_utf8, read_class};
+use self::rand::{Rng, SeedableRng, XorShiftRng};
+use std::fs;
+
+fn is_utf8_error<T>(result: Result<T, ClassReadError>) -> bool {
+    return match result {
+        Err(ClassReadError::UTF8Error(_)) => true,
+        _ => false,
+    };
+}
+
+// Units biased towards the boundaries between encoded lengths and the surrogate range
+fn random_unit(rng: &mut XorShiftRng) -> u16 {
+    return match rng.gen_range(0, 6) {
+        0 => rng.gen_range(0, 0x80),
+        1 => rng.gen_range(0x7E, 0x802),
+        2 => rng.gen_range(0xD800, 0xE000),
+        3 => [0, 0x7F, 0x80, 0x7FF, 0x800, 0xFFFF][rng.gen_range(0, 6)],
+        _ => rng.gen(),
+    };
+}
+
+fn random_byte(rng: &mut XorShiftRng) -> u8 {
+    return match rng.gen_range(0, 4) {
+        0 => rng.gen_range(0x80, 0xC0),
+        1 => [0x00, 0xC0, 0xC1, 0xE0, 0xED, 0xEF, 0xF0, 0xFF][rng.gen_range(0, 8)],
+        _ => rng.gen(),
+    };
+}
+
+#[test]
+fn modified_utf8_encoding() {
+    let cases: Vec<(Vec<u16>, Vec<u8>)> = vec!(
+        (vec!(0), vec!(0xC0, 0x80)),
+        (vec!(0x41, 0x7F), vec!(0x41, 0x7F)),
+        (vec!(0x80, 0x7FF), vec!(0xC2, 0x80, 0xDF, 0xBF)),
+        (vec!(0x800, 0xFFFF), vec!(0xE0, 0xA0, 0x80, 0xEF, 0xBF, 0xBF)),
+        (vec!(0xD834, 0xDD1E), vec!(0xED, 0xA0, 0xB4, 0xED, 0xB4, 0x9E)),
+        (vec!(0xDFFF, 0x41, 0xD800), vec!(0xED, 0xBF, 0xBF, 0x41, 0xED, 0xA0, 0x80)),
+    );
+    for (units, bytes) in cases {
+        assert_eq!(encode_modified_utf8(&units), bytes);
+        assert_eq!(decode_modified_utf8(&bytes).unwrap(), units);
+    }
+
+    let malformed: Vec<Vec<u8>> = vec!(
+        vec!(0x00),
+        vec!(0x41, 0xC3),
+        vec!(0xE6, 0x97),
+        vec!(0xC3, 0x41),
+        vec!(0xE6, 0x97, 0xC0),
+        vec!(0x80),
+        vec!(0xF0, 0x9D, 0x84, 0x9E),
+        vec!(0xC1, 0x81),
+        vec!(0xE0, 0x81, 0x81),
+        vec!(0xFF),
+    );
+    for bytes in malformed {
+        assert!(is_utf8_error(decode_modified_utf8(&bytes)), "{:?} decoded", bytes);
+    }
+}
+
+#[test]
+fn modified_utf8_fuzz() {
+    let mut rng: XorShiftRng = SeedableRng::from_seed([0x6d75_7466, 8, 0x4a56_4d21, 1]);
+    for _ in 0..10000 {
+        let length = rng.gen_range(0, 32);
+        let units: Vec<u16> = (0..length).map(|_| random_unit(&mut rng)).collect();
+        assert_eq!(decode_modified_utf8(&encode_modified_utf8(&units)).unwrap(), units);
+    }
+
+    // Every sequence either fails cleanly or is the one canonical encoding of what it decodes to
+    for _ in 0..50000 {
+        let length = rng.gen_range(0, 12);
+        let bytes: Vec<u8> = (0..length).map(|_| random_byte(&mut rng)).collect();
+        match decode_modified_utf8(&bytes) {
+            Ok(units) => assert_eq!(encode_modified_utf8(&units), bytes),
+            Err(e) => assert!(is_utf8_error(Err::<(), _>(e))),
+        }
+    }
+}
+
+#[test]
+fn modified_utf8_class_constants() {
+    let (_runtime, class_path) = setup("mutf8", r##"
+        public class mutf8 {
+            public static String marker() {
+                return "marker\u00e9\u65e5\ud834\udd1e";
+            }
+        }
+    "##, false);
+
+    let original = fs::read(class_path.as_path()).unwrap();
+    assert!(read_class(&original).is_ok());
+
+    let marker = encode_modified_utf8(&"marker\u{e9}\u{65e5}\u{1d11e}".encode_utf16().collect::<Vec<u16>>());
+    let start = original.windows(marker.len()).position(|x| x == &marker[..]).unwrap();
+
+    // Each of these breaks whichever sequence it lands in, other bytes may or may not but must not panic
+    let mut rng: XorShiftRng = SeedableRng::from_seed([1, 2, 3, 4]);
+    for offset in start + 6..start + marker.len() {
+        for &value in [0x00, 0x41, 0xC3, 0xF0, 0xFF].iter().filter(|x| **x != original[offset]) {
+            let mut data = original.clone();
+            data[offset] = value;
+            match read_class(&data) {
+                Err(ClassReadError::ClassFormatError(at, _)) => assert_eq!(at, start),
+                other => panic!("{:#x} at {} gave {:?}", value, offset, other.map(|_| ())),
+            }
+        }
+        for _ in 0..20 {
+            let mut data = original.clone();
+            data[offset] = rng.gen();
+            let _ = read_class(&data);
+        }
+    }
+
+    // Cutting the class off inside the constant fails rather than giving a short string
+    match read_class(&original[..start + 3]) {
+        Err(ClassReadError::ClassFormatError(at, _)) => assert_eq!(at, start),
+        other => panic!("Truncated constant gave {:?}", other.map(|_| ())),
+    }
+}
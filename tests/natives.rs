@@ -0,0 +1,79 @@
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::ObjectHandle;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+pub fn register_natives() {
+    let (mut runtime, class_path) = setup("natives", r##"
+        public class natives {
+            private int base;
+
+            private static native int add(int a, int b);
+            private static native long twice(long a);
+            private static native void record(int value);
+            private static native void missing();
+            private native int offset(int value);
+
+            public static int callAdd(int a, int b) {
+                return add(a, b);
+            }
+
+            public static long callTwice(long a, long b) {
+                return twice(a) + b;
+            }
+
+            public static int callRecord(int a) {
+                record(a);
+                record(a + 1);
+                return a;
+            }
+
+            public static int callOffset(int a) {
+                natives n = new natives();
+                n.base = 100;
+                return n.offset(a);
+            }
+
+            public static boolean callMissing() {
+                try {
+                    missing();
+                } catch (UnsatisfiedLinkError e) {
+                    return true;
+                }
+                return false;
+            }
+        }
+    "##, false);
+
+    runtime.register_native("natives", "add", "(II)I", |_runtime, args| {
+        return Ok(Some(Variable::Int(args[0].to_int() + args[1].to_int())));
+    });
+    runtime.register_native("natives", "twice", "(J)J", |_runtime, args| {
+        return Ok(Some(Variable::Long(args[0].to_long() * 2)));
+    });
+    let recorded = Rc::new(RefCell::new(Vec::new()));
+    let recorded_clone = recorded.clone();
+    runtime.register_native("natives", "record", "(I)V", move |_runtime, args| {
+        recorded_clone.borrow_mut().push(args[0].to_int());
+        return Ok(None);
+    });
+    runtime.register_native("natives", "offset", "(I)I", |runtime, args| {
+        let receiver = ObjectHandle::from_variable(&args[0]).unwrap();
+        let base = receiver.get_field(runtime, "base").unwrap().to_int();
+        return Ok(Some(Variable::Int(base + args[1].to_int())));
+    });
+
+    assert_eq!(int2_int_call(&mut runtime, class_path.as_path(), "callAdd", 3, 4), 7);
+    assert_eq!(long2_long_call(&mut runtime, class_path.as_path(), "callTwice", 1 << 33, 1), (1 << 34) + 1);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "callRecord", 5), 5);
+    assert_eq!(*recorded.borrow(), vec!(5, 6));
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "callOffset", 5), 105);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "callMissing"), true);
+
+    assert!(runtime.unregister_native("natives", "add", "(II)I"));
+    assert!(!runtime.unregister_native("natives", "add", "(II)I"));
+}
@@ -1,5 +1,9 @@
 mod common;
 use common::*;
+use std::cell::RefCell;
+use std::io;
+use std::io::Write;
+use std::rc::Rc;
 
 #[test]
 pub fn print() {
@@ -13,8 +17,47 @@ pub fn print() {
             }
         }
     "##, true);
+    runtime.capture_output();
     str_void_call(&mut runtime, class_path.as_path(), "stdout", "abcdef");
-    assert_eq!(runtime.stdout, "abcdef\n");
+    assert_eq!(runtime.captured_stdout(), "abcdef\n");
     str_void_call(&mut runtime, class_path.as_path(), "stderr", "123456789");
-    assert_eq!(runtime.stderr, "123456789\n");
+    assert_eq!(runtime.captured_stderr(), "123456789\n");
+}
+
+struct Shared(Rc<RefCell<Vec<u8>>>, Rc<RefCell<usize>>);
+
+impl Write for Shared {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(data);
+        return Ok(data.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        *self.1.borrow_mut() = self.0.borrow().len();
+        return Ok(());
+    }
+}
+
+#[test]
+pub fn print_sinks() {
+    let (mut runtime, class_path) = setup("print_sinks", r##"
+        public class print_sinks {
+            public static void utf8() {
+                System.out.write(new byte[] {'a', (byte) 0xC3, (byte) 0xA9, (byte) 0xE2, (byte) 0x82, (byte) 0xAC}, 0, 6);
+                System.out.flush();
+            }
+        }
+    "##, true);
+    let (bytes, flushed) = (Rc::new(RefCell::new(Vec::new())), Rc::new(RefCell::new(0)));
+    runtime.set_stdout(Shared(bytes.clone(), flushed.clone()));
+    run_method(&mut runtime, class_path.as_path(), "utf8", &Vec::new(), "V");
+    assert_eq!(*bytes.borrow(), vec!(b'a', 0xC3, 0xA9, 0xE2, 0x82, 0xAC));
+    assert_eq!(*flushed.borrow(), 6);
+
+    runtime.capture_output();
+    run_method(&mut runtime, class_path.as_path(), "utf8", &Vec::new(), "V");
+    assert_eq!(runtime.captured_stdout(), "a\u{e9}\u{20ac}");
+    assert_eq!(runtime.captured_stderr(), "");
+    runtime.set_stdout(io::sink());
+    assert_eq!(runtime.captured_stdout(), "");
 }
@@ -1,5 +1,8 @@
 mod common;
 use common::*;
+use std::env;
+use std::fs;
+use std::process;
 
 #[test]
 pub fn get_property() {
@@ -12,11 +15,79 @@ pub fn get_property() {
                 System.setProperty(prop, value);
             }
         }
-    "##, true);
+    "##, false);
+    runtime.set_env("LC_ALL", "C");
+    runtime.initialise().unwrap();
     assert!(str_str_call(&mut runtime, class_path.as_path(), "get", "abcd").is_none());
-    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "file.encoding").unwrap(), "us-ascii");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "file.encoding").unwrap(), "US-ASCII");
     str2_void_call(&mut runtime, class_path.as_path(), "set", "file.encoding", "abc");
     assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "file.encoding").unwrap(), "abc");
 }
 
+#[test]
+pub fn host_properties() {
+    let (mut runtime, class_path) = setup("host_properties", r##"
+        public class host_properties {
+            public static String get(String prop) {
+                return System.getProperty(prop);
+            }
+            public static String env(String name) {
+                return System.getenv(name);
+            }
+        }
+    "##, false);
+    runtime.set_property("java.version", "1.8.0_rjvm");
+    runtime.set_property("rjvm.added", "yes");
+    runtime.set_env("RJVM_VARIABLE", "value");
+    runtime.set_env("HOME", "/home/rjvm");
+    runtime.remove_env("PATH");
+    runtime.set_env("LC_ALL", "");
+    runtime.set_env("LC_CTYPE", "en_GB.ISO-8859-1@euro");
+    runtime.initialise().unwrap();
+
+    let class_dir = String::from(class_path.parent().unwrap().to_str().unwrap());
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "java.version").unwrap(), "1.8.0_rjvm");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "rjvm.added").unwrap(), "yes");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "java.specification.version").unwrap(), "1.8");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "path.separator").unwrap(), ":");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "java.class.path").unwrap(), class_dir);
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "user.home").unwrap(), "/home/rjvm");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "file.encoding").unwrap(), "ISO-8859-1");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "sun.jnu.encoding").unwrap(), "ISO-8859-1");
+    assert!(str_str_call(&mut runtime, class_path.as_path(), "get", "java.home").unwrap().ends_with("jre"));
+    assert!(!str_str_call(&mut runtime, class_path.as_path(), "get", "os.name").unwrap().is_empty());
+    assert!(!str_str_call(&mut runtime, class_path.as_path(), "get", "user.dir").unwrap().is_empty());
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "env", "RJVM_VARIABLE").unwrap(), "value");
+    assert!(str_str_call(&mut runtime, class_path.as_path(), "env", "PATH").is_none());
+}
 
+#[test]
+pub fn root_properties() {
+    let source = r##"
+        public class root_properties {
+            public static String get(String prop) {
+                return System.getProperty(prop);
+            }
+        }
+    "##;
+    let root = env::temp_dir().join(format!("rjvm-properties-{}", process::id()));
+    fs::create_dir_all(root.join("home").join("rjvm")).unwrap();
+
+    let (mut runtime, class_path) = setup("root_properties", source, false);
+    runtime.set_file_root(Some(root.clone()));
+    runtime.set_env("HOME", root.join("home").join("rjvm").to_str().unwrap());
+    for name in &["LC_ALL", "LC_CTYPE", "LANG"] {
+        runtime.remove_env(name);
+    }
+    runtime.initialise().unwrap();
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "user.home").unwrap(), "/home/rjvm");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "user.dir").unwrap(), "/");
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "file.encoding").unwrap(), "UTF-8");
+
+    let (mut runtime, class_path) = setup("root_properties", source, false);
+    runtime.set_file_root(Some(root.clone()));
+    runtime.set_env("HOME", "/home/rjvm");
+    runtime.initialise().unwrap();
+    assert_eq!(str_str_call(&mut runtime, class_path.as_path(), "get", "user.home").unwrap(), "/");
+    fs::remove_dir_all(&root).unwrap();
+}
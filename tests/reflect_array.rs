@@ -0,0 +1,108 @@
+mod common;
+use common::*;
+
+#[test]
+fn reflect_array() {
+    let (mut runtime, class_path) = setup("reflect_array", r##"
+        import java.lang.reflect.Array;
+
+        public class reflect_array implements Cloneable {
+            private int value = 5;
+            private int[] shared = new int[] {1, 2};
+
+            static class Plain {
+            }
+
+            public static int cloneArrays() {
+                int[] ints = new int[] {1, 2, 3};
+                int[] intCopy = ints.clone();
+                intCopy[0] = 10;
+                String[] strings = new String[] {"a", "b"};
+                String[] stringCopy = strings.clone();
+                return (intCopy != ints ? 1 : 0) + (ints[0] == 1 ? 2 : 0) + intCopy[0] + intCopy[2] * 100 +
+                    (stringCopy[1] == strings[1] ? 1000 : 0) + (stringCopy.getClass() == String[].class ? 10000 : 0);
+            }
+
+            public static int cloneObjects() throws CloneNotSupportedException {
+                reflect_array original = new reflect_array();
+                reflect_array copy = (reflect_array) original.clone();
+                original.value = 6;
+                return copy.value + (copy.shared == original.shared ? 10 : 0) + (copy != original ? 100 : 0);
+            }
+
+            public static boolean notCloneable() {
+                try {
+                    new Plain() {
+                        Object copy() throws CloneNotSupportedException {
+                            return clone();
+                        }
+                    }.copy();
+                } catch (CloneNotSupportedException e) {
+                    return true;
+                }
+                return false;
+            }
+
+            public static int accessors() {
+                int[] ints = (int[]) Array.newInstance(int.class, 3);
+                Array.setInt(ints, 0, 7);
+                Array.set(ints, 1, new Short((short) 8));
+                Array.setShort(ints, 2, (short) 9);
+                long widened = Array.getLong(ints, 2);
+                Object boxed = Array.get(ints, 0);
+                return Array.getLength(ints) * 1000 + ints[0] * 100 + ints[1] * 10 + (int) widened + (boxed instanceof Integer ? 10000 : 0);
+            }
+
+            public static int failures() {
+                int failures = 0;
+                int[] ints = new int[1];
+                String[] strings = new String[1];
+                try { Array.getLength(new Object()); } catch (IllegalArgumentException e) { failures++; }
+                try { Array.getLength(null); } catch (NullPointerException e) { failures++; }
+                try { Array.get(ints, 1); } catch (ArrayIndexOutOfBoundsException e) { failures++; }
+                try { Array.setLong(ints, 0, 1L); } catch (IllegalArgumentException e) { failures++; }
+                try { Array.getShort(ints, 0); } catch (IllegalArgumentException e) { failures++; }
+                try { Array.set(strings, 0, new Object()); } catch (IllegalArgumentException e) { failures++; }
+                try { Array.newInstance(int.class, -1); } catch (NegativeArraySizeException e) { failures++; }
+                try { Array.newInstance(void.class, 1); } catch (IllegalArgumentException e) { failures++; }
+                Array.set(strings, 0, "fits");
+                return failures;
+            }
+
+            public static int multi() {
+                int[][] grid = (int[][]) Array.newInstance(int.class, 2, 3);
+                grid[1][2] = 4;
+                String[][][] cube = (String[][][]) Array.newInstance(String.class, 1, 2, 3);
+                return grid.length * 1000 + grid[0].length * 100 + grid[1][2] + cube[0][1].length * 10 + (cube[0][1][2] == null ? 10000 : 0);
+            }
+        }
+    "##, false);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "cloneArrays"), 1 + 2 + 10 + 300 + 1000 + 10000);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "cloneObjects"), 115);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "notCloneable"), true);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "accessors"), 13789);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "failures"), 8);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "multi"), 12334);
+}
+
+#[test]
+fn reflect_array_copies() {
+    let (mut runtime, class_path) = setup("reflect_array_copies", r##"
+        import java.util.ArrayList;
+        import java.util.Arrays;
+
+        public class reflect_array_copies {
+            public static int copies() {
+                ArrayList<String> list = new ArrayList<String>();
+                list.add("x");
+                list.add("y");
+                String[] typed = list.toArray(new String[0]);
+                Object[] plain = list.toArray();
+                Integer[] grown = Arrays.copyOf(new Integer[] {new Integer(1), new Integer(2)}, 4);
+                return typed.length * 1000 + (typed[1] == list.get(1) ? 100 : 0) + plain.length * 10 + grown.length +
+                    (grown[3] == null && grown.getClass() == Integer[].class ? 10000 : 0);
+            }
+        }
+    "##, false);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "copies"), 12124);
+}
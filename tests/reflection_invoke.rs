@@ -0,0 +1,83 @@
+mod common;
+use common::*;
+
+#[test]
+fn reflection_invoke() {
+    let (mut runtime, class_path) = setup("reflection_invoke", r##"
+        import java.lang.reflect.*;
+
+        public class reflection_invoke {
+            static class Base {
+                public int value() { return 1; }
+            }
+
+            static class Derived extends Base {
+                private int seed;
+
+                public Derived() { this(40); }
+                private Derived(int seed) { this.seed = seed; }
+
+                public int value() { return seed + 2; }
+                private String secret(long a, double b) { return "secret"; }
+                public static long widen(long a, double b) { return a + (long) b; }
+                public void fail() throws IllegalStateException { throw new IllegalStateException("failed"); }
+            }
+
+            public static boolean dispatch() throws Exception {
+                Method value = Base.class.getDeclaredMethod("value");
+                return ((Integer) value.invoke(new Derived())).intValue() == 42 && value.getReturnType() == int.class;
+            }
+
+            public static boolean staticWidening() throws Exception {
+                Method widen = Derived.class.getDeclaredMethod("widen", long.class, double.class);
+                Object result = widen.invoke(null, Integer.valueOf(5), Float.valueOf(2.5f));
+                return result instanceof Long && ((Long) result).longValue() == 7 && Modifier.isStatic(widen.getModifiers());
+            }
+
+            public static boolean privateMethod() throws Exception {
+                Method secret = Derived.class.getDeclaredMethod("secret", long.class, double.class);
+                secret.setAccessible(true);
+                return ((String) secret.invoke(new Derived(), 1L, 2.0)).intern() == "secret".intern() && secret.getParameterCount() == 2;
+            }
+
+            public static boolean wrapsException() throws Exception {
+                Method fail = Derived.class.getDeclaredMethod("fail");
+                try {
+                    fail.invoke(new Derived());
+                } catch (InvocationTargetException e) {
+                    return e.getTargetException() instanceof IllegalStateException;
+                } catch (IllegalStateException e) {
+                    return false;
+                }
+                return false;
+            }
+
+            public static int badArguments() throws Exception {
+                Method widen = Derived.class.getDeclaredMethod("widen", long.class, double.class);
+                Method value = Derived.class.getDeclaredMethod("value");
+                int failures = 0;
+                try { widen.invoke(null, 1L); } catch (IllegalArgumentException e) { failures++; }
+                try { widen.invoke(null, 1.0, 1L); } catch (IllegalArgumentException e) { failures++; }
+                try { widen.invoke(null, "1", 1L); } catch (IllegalArgumentException e) { failures++; }
+                try { value.invoke(new Base()); } catch (IllegalArgumentException e) { failures++; }
+                try { value.invoke(null); } catch (NullPointerException e) { failures++; }
+                return failures;
+            }
+
+            public static boolean noInitializers() {
+                for (Method method : Derived.class.getDeclaredMethods()) {
+                    if (method.getName().startsWith("<")) {
+                        return false;
+                    }
+                }
+                return Derived.class.getDeclaredMethods().length == 4;
+            }
+        }
+    "##, true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "dispatch"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "staticWidening"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "privateMethod"), true);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "wrapsException"), true);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "badArguments"), 5);
+    assert_eq!(void_bool_call(&mut runtime, class_path.as_path(), "noInitializers"), true);
+}
@@ -0,0 +1,58 @@
+mod common;
+use common::*;
+
+#[test]
+fn resolution() {
+    let (mut runtime, class_path) = setup("resolution", r##"
+        public class resolution {
+            private static class Base {
+                public static int counter;
+                public int value;
+                public int get() { return value; }
+            }
+
+            private static class Doubled extends Base {
+                public int get() { return value * 2; }
+            }
+
+            private static class Counted extends Base {
+                public static int extra = 5;
+            }
+
+            private static long mix(long a, int b, double c, long d) {
+                return a + b + (long)c + d;
+            }
+
+            public static int polymorphicLoop(int n) {
+                Base[] objects = new Base[] { new Base(), new Doubled(), new Base(), new Doubled() };
+                int total = 0;
+                for (int i = 0; i < n; i++) {
+                    Base b = objects[i % objects.length];
+                    b.value = i;
+                    total += b.get();
+                }
+                return total;
+            }
+
+            public static int inheritedStatics(int n) {
+                for (int i = 0; i < n; i++) {
+                    Counted.counter++;
+                }
+                return Base.counter + Counted.extra;
+            }
+
+            public static long wideArguments(long a, long b) {
+                long total = 0;
+                for (int i = 0; i < 3; i++) {
+                    total += mix(a, i, 1.5, b);
+                }
+                return total;
+            }
+        }
+    "##, false);
+
+    // 0 + 2 + 2 + 6 + 4 + 10 + 6 + 14
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "polymorphicLoop", 8), 44);
+    assert_eq!(int_int_call(&mut runtime, class_path.as_path(), "inheritedStatics", 10), 15);
+    assert_eq!(long2_long_call(&mut runtime, class_path.as_path(), "wideArguments", 1 << 33, 7), 3 * ((1 << 33) + 1 + 7) + 3);
+}
@@ -0,0 +1,72 @@
+mod common;
+use common::*;
+
+#[test]
+fn slots() {
+    let (mut runtime, class_path) = setup("slots", r##"
+        public class slots {
+            private long counter;
+            private double total;
+            private static long ticks;
+
+            private static long next() {
+                return ticks++;
+            }
+
+            // dup_x1 on an int field, dup2_x1 on a long field
+            public static long fieldIncrements(long start) {
+                slots s = new slots();
+                s.counter = start;
+                long before = s.counter++;
+                int copies = 0;
+                for (int i = 0; i < 3; i++) {
+                    copies += (int) s.counter++;
+                }
+                return before * 1000 + s.counter * 10 + copies;
+            }
+
+            // dup2_x2 and dup2 on long array elements
+            public static long arrayIncrements(long x) {
+                long[] values = new long[4];
+                long last = 0;
+                for (int i = 0; i < values.length; i++) {
+                    last = values[i] = x + i;
+                    long old = values[i]++;
+                    last += old;
+                }
+                return values[0] + values[3] + last;
+            }
+
+            // Doubles share slots with ints and references across scopes
+            public static double mixedLocals(double x, int n) {
+                double result = 0;
+                {
+                    slots s = new slots();
+                    s.total = x;
+                    s.total += n;
+                    result += s.total;
+                }
+                {
+                    long l = (long) x;
+                    int i = n * 2;
+                    result += l + i;
+                }
+                long a = 3, b = 4;
+                double d = a * b + x;
+                return result + d;
+            }
+
+            public static long staticIncrements() {
+                ticks = 40;
+                long first = next();
+                long second = next();
+                return first * 100 + second + ticks;
+            }
+        }
+    "##, false);
+
+    assert_eq!(long_long_call(&mut runtime, class_path.as_path(), "fieldIncrements", 5), 5 * 1000 + 9 * 10 + 6 + 7 + 8);
+    assert_eq!(long_long_call(&mut runtime, class_path.as_path(), "arrayIncrements", -1000000000000), (-1000000000000 + 1) + (-1000000000000 + 4) + (-1000000000000 + 3) * 2);
+    assert_eq!(double_int_double_call(&mut runtime, class_path.as_path(), "mixedLocals", 2.5, 7), (2.5 + 7.0) + (2.0 + 14.0) + (12.0 + 2.5));
+    assert_eq!(void_long_call(&mut runtime, class_path.as_path(), "staticIncrements"), 4000 + 41 + 42);
+}
@@ -0,0 +1,32 @@
+mod common;
+use common::*;
+use std::io::Cursor;
+
+#[test]
+fn stdin() {
+    let (mut runtime, class_path) = setup("stdin", r##"
+        import java.io.*;
+
+        public class stdin {
+            public static int sum() throws IOException {
+                int sum = 0;
+                for (int b = System.in.read(); b != -1; b = System.in.read()) {
+                    sum += b;
+                }
+                return sum;
+            }
+
+            public static String chunk() throws IOException {
+                byte[] buffer = new byte[5];
+                System.in.skip(2);
+                return new String(buffer, 0, System.in.read(buffer, 0, 5), "US-ASCII");
+            }
+        }
+    "##, true);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "sum"), 0);
+    runtime.set_stdin_bytes(&[1, 2, 3, 250]);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "sum"), 256);
+    runtime.set_stdin(Cursor::new(String::from("..hello world")));
+    assert_eq!(void_str_call(&mut runtime, class_path.as_path(), "chunk"), "hello");
+    assert_eq!(void_str_call(&mut runtime, class_path.as_path(), "chunk"), "orld");
+}
@@ -49,3 +49,41 @@ pub fn trycatch_nested() {
     "##, false);
     assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "test"), 0x2);
 }
+
+#[test]
+pub fn trycatch_superclass() {
+    let (mut runtime, class_path) = setup("trycatch", r##"
+        public class trycatch {
+            private static class A {
+                public static A a;
+
+                public int x = 1;
+            }
+
+            private static class Failure extends Exception {
+            }
+
+            private static int fail() throws Failure {
+                throw new Failure();
+            }
+
+            public static int test() {
+                int result = 0;
+                try {
+                    result = A.a.x;
+                } catch (ArithmeticException e) {
+                    result = 1;
+                } catch (RuntimeException e) {
+                    result = 2;
+                }
+                try {
+                    fail();
+                } catch (Exception e) {
+                    result = result * 10 + 3;
+                }
+                return result;
+            }
+        }
+    "##, false);
+    assert_eq!(void_int_call(&mut runtime, class_path.as_path(), "test"), 23);
+}
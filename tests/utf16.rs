@@ -0,0 +1,63 @@
+extern crate rjvm;
+
+mod common;
+use common::*;
+use rjvm::{ArrayHandle, extract_string, extract_string_utf16, make_string_utf16};
+
+#[test]
+fn utf16() {
+    let (mut runtime, class_path) = setup("utf16", r##"
+        public class utf16 {
+            public static String literal() {
+                return "Gr\u00fc\u00dfe \u65e5\u672c \ud834\udd1e\ud83d\ude00";
+            }
+
+            public static String loneSurrogates() {
+                return "\ud800x\udfff";
+            }
+
+            public static char[] chars() {
+                char[] chars = { '\ud834', '\udd1e', 'A', '\uffff' };
+                chars[2] += 0x10000;
+                chars[3]++;
+                return chars;
+            }
+
+            public static int codeUnits(char[] s) {
+                int sum = 0;
+                for (int i = 0; i < s.length; i++) {
+                    sum = sum * 31 + s[i];
+                }
+                return sum;
+            }
+        }
+    "##, false);
+
+    let path = class_path.as_path();
+    let text = "Gr\u{fc}\u{df}e \u{65e5}\u{672c} \u{1d11e}\u{1f600}";
+
+    // Supplementary characters are stored as surrogate pairs and come back out whole
+    let literal = run_method(&mut runtime, path, "literal", &Vec::new(), "Ljava/lang/String;");
+    assert_eq!(extract_string_utf16(&mut runtime, &literal).unwrap(), text.encode_utf16().collect::<Vec<u16>>());
+    assert_eq!(extract_string(&mut runtime, &literal).unwrap(), text);
+    let made = make_string(&mut runtime, text).unwrap();
+    assert_eq!(extract_string_utf16(&mut runtime, &made).unwrap(), text.encode_utf16().collect::<Vec<u16>>());
+    assert_eq!(extract_string(&mut runtime, &made).unwrap(), text);
+
+    // Lone surrogates only survive through the UTF-16 helpers
+    let lone = run_method(&mut runtime, path, "loneSurrogates", &Vec::new(), "Ljava/lang/String;");
+    assert_eq!(extract_string_utf16(&mut runtime, &lone).unwrap(), vec!(0xd800, 'x' as u16, 0xdfff));
+    assert!(extract_string(&mut runtime, &lone).is_err());
+    let made = make_string_utf16(&mut runtime, &[0xdc00, 0x41, 0xd800]).unwrap();
+    assert_eq!(extract_string_utf16(&mut runtime, &made).unwrap(), vec!(0xdc00, 0x41, 0xd800));
+
+    let chars = ArrayHandle::from_variable(&run_method(&mut runtime, path, "chars", &Vec::new(), "[C")).unwrap();
+    assert_eq!(chars.to_vec(), vec!(Variable::Char(0xd834), Variable::Char(0xdd1e), Variable::Char(0x41), Variable::Char(0)));
+
+    let array = ArrayHandle::new_primitive(&mut runtime, "C", 3).unwrap();
+    for (i, unit) in [0xd83d, 0xde00, 0xffff].iter().enumerate() {
+        array.set(&mut runtime, i, Variable::Char(*unit)).unwrap();
+    }
+    let hash = (0xd83d * 31 + 0xde00) * 31 + 0xffff;
+    assert_eq!(run_method(&mut runtime, path, "codeUnits", &vec!(array.to_variable()), "I"), Variable::Int(hash));
+}